@@ -0,0 +1,136 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Benchmarks for `BoundedVec`.
+
+use bounded_collections::{BoundedVec, ConstU32};
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use scale_codec::{Decode, Encode};
+
+type Bound = ConstU32<{ 64 * 1024 }>;
+
+const LENGTHS: [usize; 3] = [32, 1024, 64 * 1024];
+
+criterion_group!(benches, encode, decode, try_extend, try_from);
+criterion_main!(benches);
+
+// An element type to benchmark with.
+trait Item: Clone + Encode + Decode {
+	const NAME: &'static str;
+	fn new(i: usize) -> Self;
+}
+
+impl Item for u8 {
+	const NAME: &'static str = "u8";
+	fn new(i: usize) -> Self {
+		i as u8
+	}
+}
+
+impl Item for u32 {
+	const NAME: &'static str = "u32";
+	fn new(i: usize) -> Self {
+		i as u32
+	}
+}
+
+// A 32-byte struct, such as a hash.
+impl Item for [u8; 32] {
+	const NAME: &'static str = "hash";
+	fn new(i: usize) -> Self {
+		[i as u8; 32]
+	}
+}
+
+fn items<T: Item>(len: usize) -> Vec<T> {
+	(0..len).map(T::new).collect()
+}
+
+fn bounded<T: Item>(len: usize) -> BoundedVec<T, Bound> {
+	items(len).try_into().ok().expect("lengths are within the bound; qed")
+}
+
+fn encode(c: &mut Criterion) {
+	encode_for::<u8>(c);
+	encode_for::<u32>(c);
+	encode_for::<[u8; 32]>(c);
+}
+
+fn encode_for<T: Item>(c: &mut Criterion) {
+	let mut group = c.benchmark_group(format!("encode_{}", T::NAME));
+	for len in LENGTHS {
+		let v = bounded::<T>(len);
+		group.bench_with_input(BenchmarkId::new("encode", len), &v, |b, v| b.iter(|| black_box(v).encode()));
+		group.bench_with_input(BenchmarkId::new("using_encoded", len), &v, |b, v| {
+			b.iter(|| black_box(v).using_encoded(|bytes| bytes.len()))
+		});
+	}
+	group.finish();
+}
+
+fn decode(c: &mut Criterion) {
+	decode_for::<u8>(c);
+	decode_for::<u32>(c);
+	decode_for::<[u8; 32]>(c);
+}
+
+fn decode_for<T: Item>(c: &mut Criterion) {
+	let mut group = c.benchmark_group(format!("decode_{}", T::NAME));
+	for len in LENGTHS {
+		let encoded = bounded::<T>(len).encode();
+		group.bench_with_input(BenchmarkId::from_parameter(len), &encoded, |b, encoded| {
+			b.iter(|| BoundedVec::<T, Bound>::decode(&mut &black_box(encoded)[..]).unwrap())
+		});
+	}
+	group.finish();
+}
+
+fn try_extend(c: &mut Criterion) {
+	try_extend_for::<u8>(c);
+	try_extend_for::<u32>(c);
+	try_extend_for::<[u8; 32]>(c);
+}
+
+fn try_extend_for<T: Item>(c: &mut Criterion) {
+	let mut group = c.benchmark_group(format!("try_extend_{}", T::NAME));
+	for len in LENGTHS {
+		let items = items::<T>(len);
+		group.bench_with_input(BenchmarkId::from_parameter(len), &items, |b, items| {
+			b.iter_batched(
+				|| (BoundedVec::<T, Bound>::new(), items.clone()),
+				|(mut v, items)| {
+					v.try_extend(items.into_iter()).unwrap();
+					v
+				},
+				BatchSize::SmallInput,
+			)
+		});
+	}
+	group.finish();
+}
+
+fn try_from(c: &mut Criterion) {
+	try_from_for::<u8>(c);
+	try_from_for::<u32>(c);
+	try_from_for::<[u8; 32]>(c);
+}
+
+fn try_from_for<T: Item>(c: &mut Criterion) {
+	let mut group = c.benchmark_group(format!("try_from_{}", T::NAME));
+	for len in LENGTHS {
+		let items = items::<T>(len);
+		group.bench_with_input(BenchmarkId::from_parameter(len), &items, |b, items| {
+			b.iter_batched(
+				|| items.clone(),
+				|items| BoundedVec::<T, Bound>::try_from(items).ok().unwrap(),
+				BatchSize::SmallInput,
+			)
+		});
+	}
+	group.finish();
+}
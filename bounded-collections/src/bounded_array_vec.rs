@@ -0,0 +1,556 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A bounded vector backed by an inline array.
+//!
+//! [`BoundedArrayVec`] never allocates: its items live inline, so it is available without the `alloc`
+//! feature. Only the conversions to and from `Vec` and `BoundedVec` allocate, and they require it.
+
+#[cfg(feature = "alloc")]
+use crate::{bounded_vec::FromConstLen, BoundValue, BoundedVec};
+use crate::{TryExtend, TryFromIterator};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::{
+	mem::MaybeUninit,
+	ops::{Deref, DerefMut},
+	ptr, slice,
+};
+
+/// A vector with a fixed capacity of `N` elements which are stored inline.
+///
+/// Encodes exactly like `BoundedVec<T, ConstU32<N>>` (and thus like `Vec<T>`), so both can be used
+/// interchangeably on the wire.
+pub struct BoundedArrayVec<T, const N: usize> {
+	// Invariant: `len <= N` and the first `len` items of `data` are initialized.
+	data: [MaybeUninit<T>; N],
+	len: usize,
+}
+
+impl<T, const N: usize> BoundedArrayVec<T, N> {
+	/// Create `Self` with no items.
+	pub const fn new() -> Self {
+		// An array of `MaybeUninit` does not require initialization.
+		Self { data: [const { MaybeUninit::uninit() }; N], len: 0 }
+	}
+
+	/// Get the bound of the type in `usize`.
+	pub const fn bound() -> usize {
+		N
+	}
+
+	/// Returns the number of items in self.
+	pub const fn len(&self) -> usize {
+		self.len
+	}
+
+	/// Returns true if self contains no items.
+	pub const fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	/// Returns true if this collection is full.
+	pub const fn is_full(&self) -> bool {
+		self.len >= N
+	}
+
+	/// Exactly the same semantics as [`Vec::push`], but returns an `Err` (and is a noop) if the
+	/// new length of the vector exceeds `N`.
+	pub fn try_push(&mut self, element: T) -> Result<(), T> {
+		if self.len < N {
+			self.data[self.len].write(element);
+			self.len += 1;
+			Ok(())
+		} else {
+			Err(element)
+		}
+	}
+
+	/// Exactly the same semantics as [`Vec::pop`].
+	pub fn pop(&mut self) -> Option<T> {
+		if self.len == 0 {
+			return None
+		}
+		self.len -= 1;
+		// SAFETY: the item at `len` was initialized and is no longer considered part of `self`, so it
+		// is read out exactly once.
+		Some(unsafe { self.data[self.len].assume_init_read() })
+	}
+
+	/// Exactly the same semantics as `Vec::truncate`.
+	pub fn truncate(&mut self, len: usize) {
+		if len >= self.len {
+			return
+		}
+		let tail = ptr::slice_from_raw_parts_mut(self.data[len..].as_mut_ptr() as *mut T, self.len - len);
+		// Update the length first, so that a panicking destructor cannot cause a double drop.
+		self.len = len;
+		// SAFETY: the items in `tail` were initialized and are no longer part of `self`.
+		unsafe { ptr::drop_in_place(tail) };
+	}
+
+	/// Exactly the same semantics as `Vec::clear`.
+	pub fn clear(&mut self) {
+		self.truncate(0)
+	}
+
+	/// Return a slice of the items in self.
+	pub fn as_slice(&self) -> &[T] {
+		// SAFETY: the first `len` items are initialized.
+		unsafe { slice::from_raw_parts(self.data.as_ptr() as *const T, self.len) }
+	}
+
+	/// Return a mutable slice of the items in self.
+	pub fn as_mut_slice(&mut self) -> &mut [T] {
+		// SAFETY: the first `len` items are initialized.
+		unsafe { slice::from_raw_parts_mut(self.data.as_mut_ptr() as *mut T, self.len) }
+	}
+}
+
+impl<T, const N: usize> Drop for BoundedArrayVec<T, N> {
+	fn drop(&mut self) {
+		self.clear()
+	}
+}
+
+impl<T, const N: usize> Default for BoundedArrayVec<T, N> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T, const N: usize> TryExtend<T> for BoundedArrayVec<T, N> {
+	type Error = ();
+
+	fn try_extend<I: IntoIterator<Item = T>>(&mut self, iter: I) -> Result<(), ()> {
+		let len = self.len;
+		for item in iter {
+			if self.try_push(item).is_err() {
+				self.truncate(len);
+				return Err(())
+			}
+		}
+		Ok(())
+	}
+}
+
+impl<T, const N: usize> TryFromIterator<T> for BoundedArrayVec<T, N> {
+	type Error = ();
+
+	fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<Self, ()> {
+		let mut array_vec = Self::default();
+		TryExtend::try_extend(&mut array_vec, iter)?;
+		Ok(array_vec)
+	}
+}
+
+impl<T: Clone, const N: usize> Clone for BoundedArrayVec<T, N> {
+	fn clone(&self) -> Self {
+		let mut cloned = Self::new();
+		for item in self.iter() {
+			// Cannot fail, since `cloned` has the same capacity as `self`. If `clone` panics, the
+			// items pushed so far are dropped by `cloned`.
+			let _ = cloned.try_push(item.clone());
+		}
+		cloned
+	}
+}
+
+impl<T: core::fmt::Debug, const N: usize> core::fmt::Debug for BoundedArrayVec<T, N> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		f.debug_tuple("BoundedArrayVec").field(&self.as_slice()).field(&N).finish()
+	}
+}
+
+impl<T, const N: usize> Deref for BoundedArrayVec<T, N> {
+	type Target = [T];
+
+	fn deref(&self) -> &Self::Target {
+		self.as_slice()
+	}
+}
+
+impl<T, const N: usize> DerefMut for BoundedArrayVec<T, N> {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		self.as_mut_slice()
+	}
+}
+
+impl<T, const N: usize> AsRef<[T]> for BoundedArrayVec<T, N> {
+	fn as_ref(&self) -> &[T] {
+		self.as_slice()
+	}
+}
+
+impl<T, const N: usize> AsMut<[T]> for BoundedArrayVec<T, N> {
+	fn as_mut(&mut self) -> &mut [T] {
+		self.as_mut_slice()
+	}
+}
+
+impl<T: PartialEq, const N: usize, const M: usize> PartialEq<BoundedArrayVec<T, M>> for BoundedArrayVec<T, N> {
+	fn eq(&self, other: &BoundedArrayVec<T, M>) -> bool {
+		self.as_slice() == other.as_slice()
+	}
+}
+
+impl<T: PartialEq, const N: usize> PartialEq<[T]> for BoundedArrayVec<T, N> {
+	fn eq(&self, other: &[T]) -> bool {
+		self.as_slice() == other
+	}
+}
+
+impl<T: Eq, const N: usize> Eq for BoundedArrayVec<T, N> {}
+
+#[cfg(feature = "alloc")]
+impl<T: PartialEq, S: BoundValue, const N: usize> PartialEq<BoundedVec<T, S>> for BoundedArrayVec<T, N> {
+	fn eq(&self, other: &BoundedVec<T, S>) -> bool {
+		self.as_slice() == &other[..]
+	}
+}
+
+// Custom implementation of `Hash` to hash exactly like a slice.
+impl<T: core::hash::Hash, const N: usize> core::hash::Hash for BoundedArrayVec<T, N> {
+	fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+		self.as_slice().hash(state);
+	}
+}
+
+impl<'a, T, const N: usize> core::iter::IntoIterator for &'a BoundedArrayVec<T, N> {
+	type Item = &'a T;
+	type IntoIter = core::slice::Iter<'a, T>;
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+impl<'a, T, const N: usize> core::iter::IntoIterator for &'a mut BoundedArrayVec<T, N> {
+	type Item = &'a mut T;
+	type IntoIter = core::slice::IterMut<'a, T>;
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter_mut()
+	}
+}
+
+impl<T: Clone, const N: usize> TryFrom<&[T]> for BoundedArrayVec<T, N> {
+	type Error = ();
+	fn try_from(t: &[T]) -> Result<Self, Self::Error> {
+		if t.len() > N {
+			return Err(())
+		}
+		Ok(t.iter().cloned().fold(Self::new(), |mut v, item| {
+			// Cannot fail, since the length was checked above.
+			let _ = v.try_push(item);
+			v
+		}))
+	}
+}
+
+// Check that `L` elements fit within the capacity `N`.
+#[cfg(feature = "alloc")]
+struct CheckConstLen<const N: usize, const L: usize>;
+
+#[cfg(feature = "alloc")]
+impl<const N: usize, const L: usize> CheckConstLen<N, L> {
+	const ASSERTION: () = assert!(L <= N, "too many elements for the capacity of the bounded array vec");
+}
+
+#[cfg(feature = "alloc")]
+impl<T, const N: usize, const L: usize> FromConstLen<T, L> for BoundedArrayVec<T, N> {
+	fn from_const_len(items: Vec<T>) -> Self {
+		let () = CheckConstLen::<N, L>::ASSERTION;
+		Self::try_from(items).unwrap_or_else(|_| panic!("too many elements for the capacity of the bounded array vec"))
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<T, const N: usize> TryFrom<Vec<T>> for BoundedArrayVec<T, N> {
+	type Error = Vec<T>;
+	fn try_from(t: Vec<T>) -> Result<Self, Self::Error> {
+		if t.len() > N {
+			return Err(t)
+		}
+		let mut v = Self::new();
+		for item in t {
+			// Cannot fail, since the length was checked above.
+			let _ = v.try_push(item);
+		}
+		Ok(v)
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<T, S: BoundValue, const N: usize> TryFrom<BoundedVec<T, S>> for BoundedArrayVec<T, N> {
+	type Error = BoundedVec<T, S>;
+	fn try_from(t: BoundedVec<T, S>) -> Result<Self, Self::Error> {
+		// Cannot truncate, since `v` came out of a `BoundedVec<T, S>`.
+		Self::try_from(t.into_inner()).map_err(BoundedVec::truncate_from)
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<T, S: BoundValue, const N: usize> TryFrom<BoundedArrayVec<T, N>> for BoundedVec<T, S> {
+	type Error = BoundedArrayVec<T, N>;
+	fn try_from(mut t: BoundedArrayVec<T, N>) -> Result<Self, Self::Error> {
+		if t.len() > Self::bound() {
+			return Err(t)
+		}
+		let mut v = Vec::with_capacity(t.len());
+		let len = t.len;
+		// Ownership of the items is moved into `v`, so `t` must not drop them again.
+		t.len = 0;
+		// SAFETY: the first `len` items of `t` were initialized and are read out exactly once.
+		v.extend(t.data[..len].iter().map(|item| unsafe { item.assume_init_read() }));
+		// Cannot truncate, since the length was checked above.
+		Ok(Self::truncate_from(v))
+	}
+}
+
+#[cfg(any(feature = "scale-codec", feature = "jam-codec"))]
+macro_rules! codec_impl {
+	($codec:ident) => {
+		use super::*;
+
+		use $codec::{Compact, Decode, DecodeWithMemTracking, Encode, Error, Input, MaxEncodedLen, Output};
+
+		impl<T: Encode, const N: usize> Encode for BoundedArrayVec<T, N> {
+			fn size_hint(&self) -> usize {
+				self.as_slice().size_hint()
+			}
+
+			fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+				self.as_slice().encode_to(dest)
+			}
+		}
+
+		impl<T: Decode, const N: usize> Decode for BoundedArrayVec<T, N> {
+			fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+				// Same as the implementation for `BoundedVec`, we fail early if the len is too big.
+				let len: u32 = <Compact<u32>>::decode(input)?.into();
+				if len as usize > N {
+					return Err("BoundedArrayVec exceeds its limit".into());
+				}
+				let mut v = Self::new();
+				for _ in 0..len {
+					// Cannot fail, since the length was checked above.
+					let _ = v.try_push(T::decode(input)?);
+				}
+				Ok(v)
+			}
+		}
+
+		impl<T: DecodeWithMemTracking, const N: usize> DecodeWithMemTracking for BoundedArrayVec<T, N> {}
+
+		// `BoundedArrayVec`s encode to something which will always decode as a `Vec` or `BoundedVec`.
+		#[cfg(feature = "alloc")]
+		impl<T: Encode + Decode, const N: usize> $codec::EncodeLike<Vec<T>> for BoundedArrayVec<T, N> {}
+
+		#[cfg(feature = "alloc")]
+		impl<T: Encode + Decode, S: BoundValue, const N: usize> $codec::EncodeLike<BoundedVec<T, S>>
+			for BoundedArrayVec<T, N>
+		{
+		}
+
+		impl<T: MaxEncodedLen, const N: usize> MaxEncodedLen for BoundedArrayVec<T, N> {
+			fn max_encoded_len() -> usize {
+				// Same as `BoundedVec<T, ConstU32<N>>`: a compact length prefix plus each item. `N` may
+				// exceed `u32::MAX`, so it is sized as `u64` to never underestimate the prefix.
+				Compact(N as u64)
+					.encoded_size()
+					.saturating_add(N.saturating_mul(T::max_encoded_len()))
+			}
+		}
+	};
+}
+
+#[cfg(feature = "scale-codec")]
+mod scale_codec_impl {
+	codec_impl!(scale_codec);
+
+	impl<T: scale_info::TypeInfo + 'static, const N: usize> scale_info::TypeInfo for BoundedArrayVec<T, N> {
+		type Identity = Self;
+
+		fn type_info() -> scale_info::Type {
+			// Described the same way as `BoundedVec<T, S>`.
+			scale_info::Type::builder()
+				.path(scale_info::Path::new("BoundedArrayVec", module_path!()))
+				.type_params([scale_info::TypeParameter::new("T", Some(scale_info::meta_type::<T>()))])
+				.composite(scale_info::build::Fields::unnamed().field(|f| f.ty::<[T]>().type_name("Vec<T>")))
+		}
+	}
+}
+
+#[cfg(feature = "jam-codec")]
+mod jam_codec_impl {
+	codec_impl!(jam_codec);
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test {
+	use super::*;
+	use crate::{bounded_vec, ConstU32};
+	use std::{cell::Cell, rc::Rc};
+
+	// Counts how many times it was dropped, to catch leaks and double drops.
+	#[derive(Clone, Debug, PartialEq)]
+	struct DropCounter(Rc<Cell<usize>>);
+
+	impl Drop for DropCounter {
+		fn drop(&mut self) {
+			self.0.set(self.0.get() + 1);
+		}
+	}
+
+	#[test]
+	fn try_push_and_pop_work() {
+		let mut v = BoundedArrayVec::<u32, 3>::new();
+		assert!(v.is_empty());
+		assert_eq!(v.try_push(1), Ok(()));
+		assert_eq!(v.try_push(2), Ok(()));
+		assert_eq!(v.try_push(3), Ok(()));
+		assert!(v.is_full());
+		assert_eq!(v.try_push(4), Err(4));
+		assert_eq!(v.as_slice(), &[1, 2, 3]);
+
+		assert_eq!(v.pop(), Some(3));
+		assert_eq!(v.pop(), Some(2));
+		assert_eq!(v.pop(), Some(1));
+		assert_eq!(v.pop(), None);
+		assert!(v.is_empty());
+	}
+
+	#[test]
+	fn try_extend_works() {
+		let drops = Rc::new(Cell::new(0));
+		let mut v = BoundedArrayVec::<DropCounter, 3>::new();
+		v.try_push(DropCounter(drops.clone())).unwrap();
+
+		let items = (0..3).map(|_| DropCounter(drops.clone()));
+		assert_eq!(v.try_extend(items), Err(()));
+		assert_eq!(v.len(), 1);
+		assert_eq!(drops.get(), 3);
+
+		let items = (0..2).map(|_| DropCounter(drops.clone()));
+		assert_eq!(v.try_extend(items), Ok(()));
+		assert!(v.is_full());
+		assert_eq!(drops.get(), 3);
+	}
+
+	#[test]
+	fn zero_bound_works() {
+		let mut v = BoundedArrayVec::<u32, 0>::new();
+		assert!(v.is_full());
+		assert_eq!(v.try_push(1), Err(1));
+		assert_eq!(v.pop(), None);
+	}
+
+	#[test]
+	fn deref_works() {
+		let mut v = BoundedArrayVec::<u32, 4>::try_from(&[3, 1, 2][..]).unwrap();
+		assert_eq!(v.len(), 3);
+		assert_eq!(v.iter().sum::<u32>(), 6);
+		v.sort();
+		assert_eq!(&v[..], &[1, 2, 3]);
+	}
+
+	#[test]
+	fn drop_drops_initialized_items_once() {
+		let drops = Rc::new(Cell::new(0));
+		{
+			let mut v = BoundedArrayVec::<DropCounter, 4>::new();
+			v.try_push(DropCounter(drops.clone())).unwrap();
+			v.try_push(DropCounter(drops.clone())).unwrap();
+			v.try_push(DropCounter(drops.clone())).unwrap();
+			assert_eq!(drops.get(), 0);
+
+			drop(v.pop());
+			assert_eq!(drops.get(), 1);
+		}
+		assert_eq!(drops.get(), 3);
+	}
+
+	#[test]
+	fn truncate_and_clear_drop_items() {
+		let drops = Rc::new(Cell::new(0));
+		let mut v = BoundedArrayVec::<DropCounter, 4>::new();
+		for _ in 0..4 {
+			v.try_push(DropCounter(drops.clone())).unwrap();
+		}
+		v.truncate(5);
+		assert_eq!(drops.get(), 0);
+		v.truncate(2);
+		assert_eq!(drops.get(), 2);
+		assert_eq!(v.len(), 2);
+		v.clear();
+		assert_eq!(drops.get(), 4);
+		drop(v);
+		assert_eq!(drops.get(), 4);
+	}
+
+	#[test]
+	fn clone_works() {
+		let drops = Rc::new(Cell::new(0));
+		let mut v = BoundedArrayVec::<DropCounter, 4>::new();
+		v.try_push(DropCounter(drops.clone())).unwrap();
+		v.try_push(DropCounter(drops.clone())).unwrap();
+
+		let c = v.clone();
+		assert_eq!(c.len(), 2);
+		drop(v);
+		assert_eq!(drops.get(), 2);
+		drop(c);
+		assert_eq!(drops.get(), 4);
+	}
+
+	#[test]
+	fn bounded_vec_conversions_work() {
+		let b: BoundedVec<u32, ConstU32<4>> = bounded_vec![1, 2, 3];
+		let a = BoundedArrayVec::<u32, 4>::try_from(b.clone()).unwrap();
+		assert_eq!(a, b);
+		assert_eq!(BoundedArrayVec::<u32, 2>::try_from(b.clone()), Err(b));
+
+		let b = BoundedVec::<u32, ConstU32<3>>::try_from(a.clone()).unwrap();
+		assert_eq!(b, vec![1, 2, 3]);
+		assert_eq!(BoundedVec::<u32, ConstU32<2>>::try_from(a.clone()), Err(a));
+	}
+
+	#[test]
+	fn bounded_vec_conversion_moves_items() {
+		let drops = Rc::new(Cell::new(0));
+		let mut a = BoundedArrayVec::<DropCounter, 4>::new();
+		a.try_push(DropCounter(drops.clone())).unwrap();
+		a.try_push(DropCounter(drops.clone())).unwrap();
+
+		let b = BoundedVec::<DropCounter, ConstU32<4>>::try_from(a).unwrap();
+		assert_eq!(drops.get(), 0);
+		let a = BoundedArrayVec::<DropCounter, 4>::try_from(b).unwrap();
+		assert_eq!(drops.get(), 0);
+		drop(a);
+		assert_eq!(drops.get(), 2);
+	}
+
+	#[test]
+	#[cfg(feature = "scale-codec")]
+	fn encoding_same_as_bounded_vec() {
+		use scale_codec::{Decode, Encode, MaxEncodedLen};
+
+		let b: BoundedVec<u32, ConstU32<6>> = bounded_vec![0, 1, 2, 3, 4, 5];
+		let a = BoundedArrayVec::<u32, 6>::try_from(b.clone()).unwrap();
+		assert_eq!(a.encode(), b.encode());
+		assert_eq!(BoundedArrayVec::<u32, 6>::decode(&mut &b.encode()[..]).unwrap(), a);
+		assert_eq!(BoundedArrayVec::<u32, 6>::max_encoded_len(), BoundedVec::<u32, ConstU32<6>>::max_encoded_len());
+		// The length prefix of capacities beyond `u32::MAX` isn't truncated.
+		#[cfg(target_pointer_width = "64")]
+		assert_eq!(BoundedArrayVec::<u8, { 1 << 32 }>::max_encoded_len(), 6 + (1 << 32));
+
+		assert_eq!(
+			BoundedArrayVec::<u32, 5>::decode(&mut &b.encode()[..]),
+			Err("BoundedArrayVec exceeds its limit".into()),
+		);
+	}
+}
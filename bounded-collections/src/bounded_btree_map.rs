@@ -17,7 +17,7 @@
 
 //! Traits, types and structs to support a bounded BTreeMap.
 
-use crate::{Get, TryCollect};
+use crate::{BoundValue, TryCollect, TryExtend, TryFromIterator};
 use alloc::collections::BTreeMap;
 use core::{borrow::Borrow, marker::PhantomData, ops::Deref};
 #[cfg(feature = "serde")]
@@ -43,7 +43,7 @@ pub struct BoundedBTreeMap<K, V, S>(
 );
 
 #[cfg(feature = "serde")]
-impl<'de, K, V, S: Get<u32>> Deserialize<'de> for BoundedBTreeMap<K, V, S>
+impl<'de, K, V, S: BoundValue> Deserialize<'de> for BoundedBTreeMap<K, V, S>
 where
 	K: Deserialize<'de> + Ord,
 	V: Deserialize<'de>,
@@ -59,7 +59,7 @@ where
 		where
 			K: Deserialize<'de> + Ord,
 			V: Deserialize<'de>,
-			S: Get<u32>,
+			S: BoundValue,
 		{
 			type Value = BTreeMap<K, V>;
 
@@ -72,7 +72,7 @@ where
 				A: MapAccess<'de>,
 			{
 				let size = map.size_hint().unwrap_or(0);
-				let max = S::get() as usize;
+				let max = S::bound();
 				if size > max {
 					Err(A::Error::custom("map exceeds the size of the bounds"))
 				} else {
@@ -101,22 +101,36 @@ where
 
 impl<K, V, S> BoundedBTreeMap<K, V, S>
 where
-	S: Get<u32>,
+	S: BoundValue,
 {
 	/// Get the bound of the type in `usize`.
 	pub fn bound() -> usize {
-		S::get() as usize
+		S::bound()
 	}
 }
 
 impl<K, V, S> BoundedBTreeMap<K, V, S>
 where
 	K: Ord,
-	S: Get<u32>,
+	S: BoundValue,
 {
 	/// Create `Self` from `t` without any checks.
+	///
+	/// With the `defensive-checks` feature, the bound is still checked in debug builds.
 	fn unchecked_from(t: BTreeMap<K, V>) -> Self {
-		Self(t, Default::default())
+		let bounded = Self(t, Default::default());
+		bounded.check_invariant();
+		bounded
+	}
+
+	/// Check that the length of `self` is within the bound.
+	///
+	/// Called after every operation which could break this invariant. Does nothing unless the
+	/// `defensive-checks` feature is enabled and debug assertions are on.
+	#[inline(always)]
+	fn check_invariant(&self) {
+		#[cfg(feature = "defensive-checks")]
+		debug_assert!(self.0.len() <= Self::bound(), "BoundedBTreeMap exceeds its bound");
 	}
 
 	/// Exactly the same semantics as `BTreeMap::retain`.
@@ -178,7 +192,9 @@ where
 	/// In the `Err` case, returns the inserted pair so it can be further used without cloning.
 	pub fn try_insert(&mut self, key: K, value: V) -> Result<Option<V>, (K, V)> {
 		if self.len() < Self::bound() || self.0.contains_key(&key) {
-			Ok(self.0.insert(key, value))
+			let replaced = self.0.insert(key, value);
+			self.check_invariant();
+			Ok(replaced)
 		} else {
 			Err((key, value))
 		}
@@ -257,7 +273,7 @@ where
 impl<K, V, S> Default for BoundedBTreeMap<K, V, S>
 where
 	K: Ord,
-	S: Get<u32>,
+	S: BoundValue,
 {
 	fn default() -> Self {
 		Self::new()
@@ -276,7 +292,7 @@ where
 impl<K, V, S> core::fmt::Debug for BoundedBTreeMap<K, V, S>
 where
 	BTreeMap<K, V>: core::fmt::Debug,
-	S: Get<u32>,
+	S: BoundValue,
 {
 	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 		f.debug_tuple("BoundedBTreeMap").field(&self.0).field(&Self::bound()).finish()
@@ -295,18 +311,18 @@ impl<K: std::hash::Hash, V: std::hash::Hash, S> std::hash::Hash for BoundedBTree
 impl<K, V, S1, S2> PartialEq<BoundedBTreeMap<K, V, S1>> for BoundedBTreeMap<K, V, S2>
 where
 	BTreeMap<K, V>: PartialEq,
-	S1: Get<u32>,
-	S2: Get<u32>,
+	S1: BoundValue,
+	S2: BoundValue,
 {
 	fn eq(&self, other: &BoundedBTreeMap<K, V, S1>) -> bool {
-		S1::get() == S2::get() && self.0 == other.0
+		S1::bound_u64() == S2::bound_u64() && self.0 == other.0
 	}
 }
 
 impl<K, V, S> Eq for BoundedBTreeMap<K, V, S>
 where
 	BTreeMap<K, V>: Eq,
-	S: Get<u32>,
+	S: BoundValue,
 {
 }
 
@@ -322,7 +338,7 @@ where
 impl<K, V, S> PartialOrd for BoundedBTreeMap<K, V, S>
 where
 	BTreeMap<K, V>: PartialOrd,
-	S: Get<u32>,
+	S: BoundValue,
 {
 	fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
 		self.0.partial_cmp(&other.0)
@@ -332,7 +348,7 @@ where
 impl<K, V, S> Ord for BoundedBTreeMap<K, V, S>
 where
 	BTreeMap<K, V>: Ord,
-	S: Get<u32>,
+	S: BoundValue,
 {
 	fn cmp(&self, other: &Self) -> core::cmp::Ordering {
 		self.0.cmp(&other.0)
@@ -398,7 +414,7 @@ where
 impl<K, V, S> TryFrom<BTreeMap<K, V>> for BoundedBTreeMap<K, V, S>
 where
 	K: Ord,
-	S: Get<u32>,
+	S: BoundValue,
 {
 	type Error = ();
 
@@ -409,16 +425,60 @@ where
 	}
 }
 
+impl<K, V, S> TryExtend<(K, V)> for BoundedBTreeMap<K, V, S>
+where
+	K: Ord,
+	S: BoundValue,
+{
+	type Error = ();
+
+	/// Like [`Extend`] for [`BTreeMap`], values of existing keys are replaced.
+	///
+	/// The new entries are collected first and only inserted if they all fit, so replaced values
+	/// are only dropped on success.
+	fn try_extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) -> Result<(), ()> {
+		let remaining = Self::bound().saturating_sub(self.len());
+		let mut entries = BTreeMap::new();
+		let mut new_keys = 0usize;
+		for (key, value) in iter {
+			if !self.0.contains_key(&key) && !entries.contains_key(&key) {
+				new_keys += 1;
+				if new_keys > remaining {
+					return Err(())
+				}
+			}
+			entries.insert(key, value);
+		}
+		self.0.extend(entries);
+		self.check_invariant();
+		Ok(())
+	}
+}
+
+impl<K, V, S> TryFromIterator<(K, V)> for BoundedBTreeMap<K, V, S>
+where
+	K: Ord,
+	S: BoundValue,
+{
+	type Error = ();
+
+	fn try_from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Result<Self, ()> {
+		let mut map = Self::default();
+		TryExtend::try_extend(&mut map, iter)?;
+		Ok(map)
+	}
+}
+
 impl<I, K, V, Bound> TryCollect<BoundedBTreeMap<K, V, Bound>> for I
 where
 	K: Ord,
 	I: ExactSizeIterator + Iterator<Item = (K, V)>,
-	Bound: Get<u32>,
+	Bound: BoundValue,
 {
 	type Error = &'static str;
 
 	fn try_collect(self) -> Result<BoundedBTreeMap<K, V, Bound>, Self::Error> {
-		if self.len() > Bound::get() as usize {
+		if self.len() > Bound::bound() {
 			Err("iterator length too big")
 		} else {
 			Ok(BoundedBTreeMap::<K, V, Bound>::unchecked_from(self.collect::<BTreeMap<K, V>>()))
@@ -476,12 +536,12 @@ macro_rules! codec_impl {
 		where
 			K: Decode + Ord,
 			V: Decode,
-			S: Get<u32>,
+			S: BoundValue,
 		{
 			fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
 				// Fail early if the len is too big. This is a compact u32 which we will later put back.
 				let compact = <Compact<u32>>::decode(input)?;
-				if compact.0 > S::get() {
+				if u64::from(compact.0) > S::bound_u64() {
 					return Err("BoundedBTreeMap exceeds its limit".into());
 				}
 				// Reconstruct the original input by prepending the length we just read, then delegate the decoding to BTreeMap.
@@ -490,7 +550,7 @@ macro_rules! codec_impl {
 					read: 0,
 					inner: input,
 				})?;
-				Ok(Self(inner, PhantomData))
+				Ok(Self::unchecked_from(inner))
 			}
 
 			fn skip<I: Input>(input: &mut I) -> Result<(), Error> {
@@ -502,7 +562,7 @@ macro_rules! codec_impl {
 		where
 			K: DecodeWithMemTracking + Ord,
 			V: DecodeWithMemTracking,
-			S: Get<u32>,
+			S: BoundValue,
 			BoundedBTreeMap<K, V, S>: Decode,
 		{
 		}
@@ -511,12 +571,12 @@ macro_rules! codec_impl {
 		where
 			K: MaxEncodedLen,
 			V: MaxEncodedLen,
-			S: Get<u32>,
+			S: BoundValue,
 		{
 			fn max_encoded_len() -> usize {
 				Self::bound()
 					.saturating_mul(K::max_encoded_len().saturating_add(V::max_encoded_len()))
-					.saturating_add(Compact(S::get()).encoded_size())
+					.saturating_add(Compact(crate::saturated_bound_u32::<S>()).encoded_size())
 			}
 		}
 
@@ -563,7 +623,7 @@ mod test {
 	fn boundedmap_from_keys<K, S>(keys: &[K]) -> BoundedBTreeMap<K, (), S>
 	where
 		K: Ord + Copy,
-		S: Get<u32>,
+		S: BoundValue,
 	{
 		map_from_keys(keys).try_into().unwrap()
 	}
@@ -596,6 +656,33 @@ mod test {
 		assert_eq!(*bounded, map_from_keys(&[1, 0, 2, 3]));
 	}
 
+	#[test]
+	#[cfg(not(feature = "defensive-checks"))]
+	fn invariant_is_not_checked_without_defensive_checks() {
+		let bounded = BoundedBTreeMap::<u32, (), ConstU32<1>>::unchecked_from(map_from_keys(&[1, 2]));
+		assert_eq!(bounded.len(), 2);
+	}
+
+	#[test]
+	#[cfg(all(feature = "defensive-checks", debug_assertions))]
+	#[should_panic(expected = "BoundedBTreeMap exceeds its bound")]
+	fn invariant_is_checked_with_defensive_checks() {
+		let _ = BoundedBTreeMap::<u32, (), ConstU32<1>>::unchecked_from(map_from_keys(&[1, 2]));
+	}
+
+	#[test]
+	fn try_extend_works() {
+		let mut bounded: BoundedBTreeMap<u32, u32, ConstU32<4>> = [(1, 1), (2, 2)].into_iter().try_collect().unwrap();
+
+		// replacing existing keys does not count against the bound.
+		assert!(bounded.try_extend([(1, 10), (3, 3), (3, 30), (2, 20), (4, 4)]).is_ok());
+		assert_eq!(*bounded, BTreeMap::from([(1, 10), (2, 20), (3, 30), (4, 4)]));
+
+		// nothing is changed if new keys do not fit.
+		assert!(bounded.try_extend([(1, 100), (5, 5)]).is_err());
+		assert_eq!(*bounded, BTreeMap::from([(1, 10), (2, 20), (3, 30), (4, 4)]));
+	}
+
 	#[test]
 	fn deref_coercion_works() {
 		let bounded = boundedmap_from_keys::<u32, ConstU32<7>>(&[1, 2, 3]);
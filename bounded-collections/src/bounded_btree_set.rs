@@ -17,7 +17,7 @@
 
 //! Traits, types and structs to support a bounded `BTreeSet`.
 
-use crate::{Get, TryCollect};
+use crate::{BoundValue, TryCollect, TryExtend, TryFromIterator};
 use alloc::collections::BTreeSet;
 use core::{borrow::Borrow, marker::PhantomData, ops::Deref};
 #[cfg(feature = "serde")]
@@ -40,7 +40,7 @@ use serde::{
 pub struct BoundedBTreeSet<T, S>(BTreeSet<T>, #[cfg_attr(feature = "serde", serde(skip_serializing))] PhantomData<S>);
 
 #[cfg(feature = "serde")]
-impl<'de, T, S: Get<u32>> Deserialize<'de> for BoundedBTreeSet<T, S>
+impl<'de, T, S: BoundValue> Deserialize<'de> for BoundedBTreeSet<T, S>
 where
 	T: Ord + Deserialize<'de>,
 	S: Clone,
@@ -55,7 +55,7 @@ where
 		impl<'de, T, S> Visitor<'de> for BTreeSetVisitor<T, S>
 		where
 			T: Ord + Deserialize<'de>,
-			S: Get<u32> + Clone,
+			S: BoundValue + Clone,
 		{
 			type Value = BTreeSet<T>;
 
@@ -68,10 +68,7 @@ where
 				A: SeqAccess<'de>,
 			{
 				let size = seq.size_hint().unwrap_or(0);
-				let max = match usize::try_from(S::get()) {
-					Ok(n) => n,
-					Err(_) => return Err(A::Error::custom("can't convert to usize")),
-				};
+				let max = S::bound();
 				if size > max {
 					Err(A::Error::custom("out of bounds"))
 				} else {
@@ -98,22 +95,36 @@ where
 
 impl<T, S> BoundedBTreeSet<T, S>
 where
-	S: Get<u32>,
+	S: BoundValue,
 {
 	/// Get the bound of the type in `usize`.
 	pub fn bound() -> usize {
-		S::get() as usize
+		S::bound()
 	}
 }
 
 impl<T, S> BoundedBTreeSet<T, S>
 where
 	T: Ord,
-	S: Get<u32>,
+	S: BoundValue,
 {
 	/// Create `Self` from `t` without any checks.
+	///
+	/// With the `defensive-checks` feature, the bound is still checked in debug builds.
 	fn unchecked_from(t: BTreeSet<T>) -> Self {
-		Self(t, Default::default())
+		let bounded = Self(t, Default::default());
+		bounded.check_invariant();
+		bounded
+	}
+
+	/// Check that the length of `self` is within the bound.
+	///
+	/// Called after every operation which could break this invariant. Does nothing unless the
+	/// `defensive-checks` feature is enabled and debug assertions are on.
+	#[inline(always)]
+	fn check_invariant(&self) {
+		#[cfg(feature = "defensive-checks")]
+		debug_assert!(self.0.len() <= Self::bound(), "BoundedBTreeSet exceeds its bound");
 	}
 
 	/// Create a new `BoundedBTreeSet`.
@@ -155,7 +166,9 @@ where
 	/// In the `Err` case, returns the inserted item so it can be further used without cloning.
 	pub fn try_insert(&mut self, item: T) -> Result<bool, T> {
 		if self.len() < Self::bound() || self.0.contains(&item) {
-			Ok(self.0.insert(item))
+			let inserted = self.0.insert(item);
+			self.check_invariant();
+			Ok(inserted)
 		} else {
 			Err(item)
 		}
@@ -194,7 +207,7 @@ where
 impl<T, S> Default for BoundedBTreeSet<T, S>
 where
 	T: Ord,
-	S: Get<u32>,
+	S: BoundValue,
 {
 	fn default() -> Self {
 		Self::new()
@@ -213,7 +226,7 @@ where
 impl<T, S> core::fmt::Debug for BoundedBTreeSet<T, S>
 where
 	BTreeSet<T>: core::fmt::Debug,
-	S: Get<u32>,
+	S: BoundValue,
 {
 	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 		f.debug_tuple("BoundedBTreeSet").field(&self.0).field(&Self::bound()).finish()
@@ -232,25 +245,25 @@ impl<T: std::hash::Hash, S> std::hash::Hash for BoundedBTreeSet<T, S> {
 impl<T, S1, S2> PartialEq<BoundedBTreeSet<T, S1>> for BoundedBTreeSet<T, S2>
 where
 	BTreeSet<T>: PartialEq,
-	S1: Get<u32>,
-	S2: Get<u32>,
+	S1: BoundValue,
+	S2: BoundValue,
 {
 	fn eq(&self, other: &BoundedBTreeSet<T, S1>) -> bool {
-		S1::get() == S2::get() && self.0 == other.0
+		S1::bound_u64() == S2::bound_u64() && self.0 == other.0
 	}
 }
 
 impl<T, S> Eq for BoundedBTreeSet<T, S>
 where
 	BTreeSet<T>: Eq,
-	S: Get<u32>,
+	S: BoundValue,
 {
 }
 
 impl<T, S> PartialEq<BTreeSet<T>> for BoundedBTreeSet<T, S>
 where
 	BTreeSet<T>: PartialEq,
-	S: Get<u32>,
+	S: BoundValue,
 {
 	fn eq(&self, other: &BTreeSet<T>) -> bool {
 		self.0 == *other
@@ -260,7 +273,7 @@ where
 impl<T, S> PartialOrd for BoundedBTreeSet<T, S>
 where
 	BTreeSet<T>: PartialOrd,
-	S: Get<u32>,
+	S: BoundValue,
 {
 	fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
 		self.0.partial_cmp(&other.0)
@@ -270,7 +283,7 @@ where
 impl<T, S> Ord for BoundedBTreeSet<T, S>
 where
 	BTreeSet<T>: Ord,
-	S: Get<u32>,
+	S: BoundValue,
 {
 	fn cmp(&self, other: &Self) -> core::cmp::Ordering {
 		self.0.cmp(&other.0)
@@ -327,7 +340,7 @@ where
 impl<T, S> TryFrom<BTreeSet<T>> for BoundedBTreeSet<T, S>
 where
 	T: Ord,
-	S: Get<u32>,
+	S: BoundValue,
 {
 	type Error = ();
 
@@ -338,16 +351,52 @@ where
 	}
 }
 
+impl<T, S> TryExtend<T> for BoundedBTreeSet<T, S>
+where
+	T: Ord,
+	S: BoundValue,
+{
+	type Error = ();
+
+	/// The new items are collected first and only inserted if they all fit.
+	fn try_extend<I: IntoIterator<Item = T>>(&mut self, iter: I) -> Result<(), ()> {
+		let remaining = Self::bound().saturating_sub(self.len());
+		let mut items = BTreeSet::new();
+		for item in iter {
+			if !self.0.contains(&item) && items.insert(item) && items.len() > remaining {
+				return Err(())
+			}
+		}
+		self.0.extend(items);
+		self.check_invariant();
+		Ok(())
+	}
+}
+
+impl<T, S> TryFromIterator<T> for BoundedBTreeSet<T, S>
+where
+	T: Ord,
+	S: BoundValue,
+{
+	type Error = ();
+
+	fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<Self, ()> {
+		let mut set = Self::default();
+		TryExtend::try_extend(&mut set, iter)?;
+		Ok(set)
+	}
+}
+
 impl<I, T, Bound> TryCollect<BoundedBTreeSet<T, Bound>> for I
 where
 	T: Ord,
 	I: ExactSizeIterator + Iterator<Item = T>,
-	Bound: Get<u32>,
+	Bound: BoundValue,
 {
 	type Error = &'static str;
 
 	fn try_collect(self) -> Result<BoundedBTreeSet<T, Bound>, Self::Error> {
-		if self.len() > Bound::get() as usize {
+		if self.len() > Bound::bound() {
 			Err("iterator length too big")
 		} else {
 			Ok(BoundedBTreeSet::<T, Bound>::unchecked_from(self.collect::<BTreeSet<T>>()))
@@ -363,19 +412,19 @@ macro_rules! codec_impl {
 		impl<T, S> Decode for BoundedBTreeSet<T, S>
 		where
 			T: Decode + Ord,
-			S: Get<u32>,
+			S: BoundValue,
 		{
 			fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
 				// Same as the underlying implementation for `Decode` on `BTreeSet`, except we fail early if
 				// the len is too big.
 				let len: u32 = <Compact<u32>>::decode(input)?.into();
-				if len > S::get() {
+				if u64::from(len) > S::bound_u64() {
 					return Err("BoundedBTreeSet exceeds its limit".into());
 				}
 				input.descend_ref()?;
 				let inner = Result::from_iter((0..len).map(|_| Decode::decode(input)))?;
 				input.ascend_ref();
-				Ok(Self(inner, PhantomData))
+				Ok(Self::unchecked_from(inner))
 			}
 
 			fn skip<I: Input>(input: &mut I) -> Result<(), Error> {
@@ -386,12 +435,12 @@ macro_rules! codec_impl {
 		impl<T, S> MaxEncodedLen for BoundedBTreeSet<T, S>
 		where
 			T: MaxEncodedLen,
-			S: Get<u32>,
+			S: BoundValue,
 		{
 			fn max_encoded_len() -> usize {
 				Self::bound()
 					.saturating_mul(T::max_encoded_len())
-					.saturating_add(Compact(S::get()).encoded_size())
+					.saturating_add(Compact(crate::saturated_bound_u32::<S>()).encoded_size())
 			}
 		}
 
@@ -436,7 +485,7 @@ mod test {
 	fn boundedset_from_keys<T, S>(keys: &[T]) -> BoundedBTreeSet<T, S>
 	where
 		T: Ord + Copy,
-		S: Get<u32>,
+		S: BoundValue,
 	{
 		set_from_keys(keys).try_into().unwrap()
 	}
@@ -460,6 +509,34 @@ mod test {
 		assert_eq!(*bounded, set_from_keys(&[1, 0, 2, 3]));
 	}
 
+	#[test]
+	#[cfg(not(feature = "defensive-checks"))]
+	fn invariant_is_not_checked_without_defensive_checks() {
+		let bounded = BoundedBTreeSet::<u32, ConstU32<1>>::unchecked_from(set_from_keys(&[1, 2]));
+		assert_eq!(bounded.len(), 2);
+	}
+
+	#[test]
+	#[cfg(all(feature = "defensive-checks", debug_assertions))]
+	#[should_panic(expected = "BoundedBTreeSet exceeds its bound")]
+	fn invariant_is_checked_with_defensive_checks() {
+		let _ = BoundedBTreeSet::<u32, ConstU32<1>>::unchecked_from(set_from_keys(&[1, 2]));
+	}
+
+	#[test]
+	fn try_extend_works() {
+		let mut bounded = boundedset_from_keys::<u32, ConstU32<4>>(&[1, 2]);
+
+		// existing and duplicate items do not count against the bound.
+		assert!(bounded.try_extend([1, 3, 3, 2, 4]).is_ok());
+		assert_eq!(*bounded, set_from_keys(&[1, 2, 3, 4]));
+
+		// nothing is changed if new items do not fit.
+		let mut bounded = boundedset_from_keys::<u32, ConstU32<4>>(&[1, 2]);
+		assert!(bounded.try_extend([1, 3, 4, 5]).is_err());
+		assert_eq!(*bounded, set_from_keys(&[1, 2]));
+	}
+
 	#[test]
 	fn deref_coercion_works() {
 		let bounded = boundedset_from_keys::<u32, ConstU32<7>>(&[1, 2, 3]);
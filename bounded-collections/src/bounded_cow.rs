@@ -0,0 +1,290 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A clone-on-write smart pointer over bounded data.
+
+use crate::{BoundValue, BoundedSlice, BoundedVec};
+use core::{marker::PhantomData, ops::Deref};
+#[cfg(feature = "serde")]
+use serde::{Serialize, Serializer};
+
+/// A clone-on-write bounded vector.
+///
+/// Holds either a borrowed [`BoundedSlice`] or an owned [`BoundedVec`] with the same bound, and only
+/// clones the borrowed data when it is mutated for the first time (see [`Self::to_mut`]).
+pub enum BoundedCow<'a, T, S> {
+	/// Borrowed data.
+	Borrowed(BoundedSlice<'a, T, S>),
+	/// Owned data.
+	Owned(BoundedVec<T, S>),
+}
+
+impl<'a, T, S> BoundedCow<'a, T, S> {
+	/// Returns true if the data is borrowed.
+	pub fn is_borrowed(&self) -> bool {
+		matches!(self, Self::Borrowed(_))
+	}
+
+	/// Returns true if the data is owned.
+	pub fn is_owned(&self) -> bool {
+		!self.is_borrowed()
+	}
+}
+
+impl<'a, T: Clone, S> BoundedCow<'a, T, S> {
+	/// Acquire a mutable reference to the owned form of the data.
+	///
+	/// Clones the data if it is not already owned.
+	pub fn to_mut(&mut self) -> &mut BoundedVec<T, S> {
+		if let Self::Borrowed(slice) = *self {
+			// The slice is within the same bound, so no check is needed.
+			*self = Self::Owned(BoundedVec(slice.to_vec(), PhantomData));
+		}
+		match self {
+			Self::Owned(owned) => owned,
+			Self::Borrowed(_) => unreachable!("borrowed data was replaced by owned data above; qed"),
+		}
+	}
+
+	/// Extract the owned data.
+	///
+	/// Clones the data if it is not already owned.
+	pub fn into_owned(self) -> BoundedVec<T, S> {
+		match self {
+			Self::Borrowed(slice) => BoundedVec(slice.to_vec(), PhantomData),
+			Self::Owned(owned) => owned,
+		}
+	}
+}
+
+impl<'a, T, S> Deref for BoundedCow<'a, T, S> {
+	type Target = [T];
+
+	fn deref(&self) -> &Self::Target {
+		match self {
+			Self::Borrowed(slice) => slice,
+			Self::Owned(owned) => owned,
+		}
+	}
+}
+
+impl<'a, T, S> AsRef<[T]> for BoundedCow<'a, T, S> {
+	fn as_ref(&self) -> &[T] {
+		self
+	}
+}
+
+impl<'a, T: Clone, S> Clone for BoundedCow<'a, T, S> {
+	fn clone(&self) -> Self {
+		match self {
+			Self::Borrowed(slice) => Self::Borrowed(*slice),
+			Self::Owned(owned) => Self::Owned(owned.clone()),
+		}
+	}
+}
+
+impl<'a, T, S> core::fmt::Debug for BoundedCow<'a, T, S>
+where
+	T: core::fmt::Debug,
+	S: BoundValue,
+{
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			Self::Borrowed(slice) => f.debug_tuple("Borrowed").field(slice).finish(),
+			Self::Owned(owned) => f.debug_tuple("Owned").field(owned).finish(),
+		}
+	}
+}
+
+impl<'a, T, S> From<BoundedSlice<'a, T, S>> for BoundedCow<'a, T, S> {
+	fn from(slice: BoundedSlice<'a, T, S>) -> Self {
+		Self::Borrowed(slice)
+	}
+}
+
+impl<'a, T, S> From<BoundedVec<T, S>> for BoundedCow<'a, T, S> {
+	fn from(owned: BoundedVec<T, S>) -> Self {
+		Self::Owned(owned)
+	}
+}
+
+impl<'a, 'b, T, BoundSelf, BoundRhs> PartialEq<BoundedCow<'b, T, BoundRhs>> for BoundedCow<'a, T, BoundSelf>
+where
+	T: PartialEq,
+	BoundSelf: BoundValue,
+	BoundRhs: BoundValue,
+{
+	fn eq(&self, other: &BoundedCow<'b, T, BoundRhs>) -> bool {
+		**self == **other
+	}
+}
+
+impl<'a, 'b, T, BoundSelf, BoundRhs> PartialEq<BoundedSlice<'b, T, BoundRhs>> for BoundedCow<'a, T, BoundSelf>
+where
+	T: PartialEq,
+	BoundSelf: BoundValue,
+	BoundRhs: BoundValue,
+{
+	fn eq(&self, other: &BoundedSlice<'b, T, BoundRhs>) -> bool {
+		**self == **other
+	}
+}
+
+impl<'a, T, BoundSelf, BoundRhs> PartialEq<BoundedVec<T, BoundRhs>> for BoundedCow<'a, T, BoundSelf>
+where
+	T: PartialEq,
+	BoundSelf: BoundValue,
+	BoundRhs: BoundValue,
+{
+	fn eq(&self, other: &BoundedVec<T, BoundRhs>) -> bool {
+		**self == other[..]
+	}
+}
+
+impl<'a, T: Eq, S: BoundValue> Eq for BoundedCow<'a, T, S> {}
+
+#[cfg(feature = "serde")]
+impl<'a, T: Serialize, S> Serialize for BoundedCow<'a, T, S> {
+	fn serialize<Ser: Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+		// Transparent, just like `BoundedVec`.
+		(**self).serialize(serializer)
+	}
+}
+
+#[cfg(any(feature = "scale-codec", feature = "jam-codec"))]
+macro_rules! codec_impl {
+	($codec:ident) => {
+		use super::*;
+		use alloc::vec::Vec;
+
+		use $codec::{Decode, Encode, EncodeLike, Output};
+
+		// Encodes identically whether the data is borrowed or owned.
+		impl<'a, T: Encode, S> Encode for BoundedCow<'a, T, S> {
+			fn size_hint(&self) -> usize {
+				(**self).size_hint()
+			}
+
+			fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+				(**self).encode_to(dest)
+			}
+		}
+
+		// `BoundedCow`s encode to something which will always decode into a `BoundedVec` or a `Vec`.
+		impl<'a, T: Encode + Decode, S: BoundValue> EncodeLike<BoundedVec<T, S>> for BoundedCow<'a, T, S> {}
+
+		impl<'a, T: Encode + Decode, S: BoundValue> EncodeLike<Vec<T>> for BoundedCow<'a, T, S> {}
+	};
+}
+
+#[cfg(feature = "scale-codec")]
+mod scale_codec_impl {
+	codec_impl!(scale_codec);
+}
+
+#[cfg(feature = "jam-codec")]
+mod jam_codec_impl {
+	codec_impl!(jam_codec);
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test {
+	use super::*;
+	use crate::{bounded_vec, ConstU32};
+	use std::{cell::Cell, rc::Rc};
+
+	// Counts how many times it was cloned.
+	#[derive(Debug, PartialEq)]
+	struct CloneCounter(u32, Rc<Cell<usize>>);
+
+	impl Clone for CloneCounter {
+		fn clone(&self) -> Self {
+			self.1.set(self.1.get() + 1);
+			Self(self.0, self.1.clone())
+		}
+	}
+
+	fn items(clones: &Rc<Cell<usize>>) -> Vec<CloneCounter> {
+		(1..=3).map(|i| CloneCounter(i, clones.clone())).collect()
+	}
+
+	#[test]
+	fn read_only_path_does_not_clone() {
+		let clones = Rc::new(Cell::new(0));
+		let items = items(&clones);
+		let cow = BoundedCow::from(BoundedSlice::<_, ConstU32<4>>::truncate_from(&items));
+
+		assert!(cow.is_borrowed());
+		assert_eq!(cow.len(), 3);
+		assert_eq!(cow.iter().map(|i| i.0).sum::<u32>(), 6);
+		let _ = cow.clone();
+		assert_eq!(clones.get(), 0);
+	}
+
+	#[test]
+	fn to_mut_clones_once() {
+		let clones = Rc::new(Cell::new(0));
+		let items = items(&clones);
+		let mut cow = BoundedCow::from(BoundedSlice::<_, ConstU32<4>>::truncate_from(&items));
+
+		cow.to_mut().try_push(CloneCounter(4, clones.clone())).unwrap();
+		assert!(cow.is_owned());
+		assert_eq!(clones.get(), 3);
+		cow.to_mut().pop();
+		assert_eq!(clones.get(), 3);
+		assert_eq!(cow, BoundedSlice::<_, ConstU32<4>>::truncate_from(&items));
+	}
+
+	#[test]
+	fn into_owned_works() {
+		let clones = Rc::new(Cell::new(0));
+		let items = items(&clones);
+
+		let owned = BoundedCow::from(BoundedSlice::<_, ConstU32<4>>::truncate_from(&items)).into_owned();
+		assert_eq!(clones.get(), 3);
+		assert_eq!(owned, items);
+
+		let owned = BoundedCow::<_, ConstU32<4>>::from(owned).into_owned();
+		assert_eq!(clones.get(), 3);
+		assert_eq!(owned, items);
+	}
+
+	#[test]
+	fn eq_works() {
+		let v: BoundedVec<u32, ConstU32<4>> = bounded_vec![1, 2, 3];
+		let borrowed = BoundedCow::from(v.as_bounded_slice());
+		let owned = BoundedCow::<_, ConstU32<4>>::from(v.clone());
+
+		assert_eq!(borrowed, owned);
+		assert_eq!(borrowed, v);
+		assert_eq!(owned, v.as_bounded_slice());
+	}
+
+	#[test]
+	fn test_serializer() {
+		let v: BoundedVec<u32, ConstU32<4>> = bounded_vec![0, 1, 2];
+		let borrowed = BoundedCow::from(v.as_bounded_slice());
+		let owned = BoundedCow::<_, ConstU32<4>>::from(v.clone());
+
+		assert_eq!(serde_json::json!(&borrowed).to_string(), r#"[0,1,2]"#);
+		assert_eq!(serde_json::json!(&owned).to_string(), r#"[0,1,2]"#);
+	}
+
+	#[test]
+	#[cfg(feature = "scale-codec")]
+	fn encoding_same_as_bounded_vec() {
+		use scale_codec::Encode;
+
+		let v: BoundedVec<u32, ConstU32<4>> = bounded_vec![0, 1, 2];
+		let borrowed = BoundedCow::from(v.as_bounded_slice());
+		let owned = BoundedCow::<_, ConstU32<4>>::from(v.clone());
+
+		assert_eq!(borrowed.encode(), v.encode());
+		assert_eq!(owned.encode(), v.encode());
+	}
+}
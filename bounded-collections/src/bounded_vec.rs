@@ -19,11 +19,11 @@
 //! or a double map.
 
 use super::WeakBoundedVec;
-use crate::{Get, TryCollect};
+use crate::{BoundValue, OnOverflow, PushOutcome, TryCollect, TryExtend, TryFromIterator};
 use alloc::vec::Vec;
 use core::{
 	marker::PhantomData,
-	ops::{Deref, Index, IndexMut, RangeBounds},
+	ops::{Bound, Deref, Index, IndexMut, Range, RangeBounds},
 	slice::SliceIndex,
 };
 #[cfg(feature = "serde")]
@@ -40,11 +40,13 @@ use serde::{
 /// As the name suggests, the length of the queue is always bounded. All internal operations ensure
 /// this bound is respected.
 #[cfg_attr(feature = "serde", derive(Serialize), serde(transparent))]
-#[cfg_attr(feature = "jam-codec", derive(jam_codec::Encode))]
-#[cfg_attr(feature = "scale-codec", derive(scale_codec::Encode, scale_info::TypeInfo))]
+#[cfg_attr(feature = "scale-codec", derive(scale_info::TypeInfo))]
 #[cfg_attr(feature = "scale-codec", scale_info(skip_type_params(S)))]
 #[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
-pub struct BoundedVec<T, S>(pub(super) Vec<T>, #[cfg_attr(feature = "serde", serde(skip_serializing))] PhantomData<S>);
+pub struct BoundedVec<T, S>(
+	pub(super) Vec<T>,
+	#[cfg_attr(feature = "serde", serde(skip_serializing))] pub(super) PhantomData<S>,
+);
 
 /// Create an object through truncation.
 pub trait TruncateFrom<T> {
@@ -56,7 +58,7 @@ pub trait TruncateFrom<T> {
 mod serde_impl {
 	use super::*;
 
-	impl<'de, T, S: Get<u32>> Deserialize<'de> for BoundedVec<T, S>
+	impl<'de, T, S: BoundValue> Deserialize<'de> for BoundedVec<T, S>
 	where
 		T: Deserialize<'de>,
 	{
@@ -64,9 +66,9 @@ mod serde_impl {
 		where
 			D: Deserializer<'de>,
 		{
-			struct VecVisitor<T, S: Get<u32>>(PhantomData<(T, S)>);
+			struct VecVisitor<T, S: BoundValue>(PhantomData<(T, S)>);
 
-			impl<'de, T, S: Get<u32>> Visitor<'de> for VecVisitor<T, S>
+			impl<'de, T, S: BoundValue> Visitor<'de> for VecVisitor<T, S>
 			where
 				T: Deserialize<'de>,
 			{
@@ -81,10 +83,7 @@ mod serde_impl {
 					A: SeqAccess<'de>,
 				{
 					let size = seq.size_hint().unwrap_or(0);
-					let max = match usize::try_from(S::get()) {
-						Ok(n) => n,
-						Err(_) => return Err(A::Error::custom("can't convert to usize")),
-					};
+					let max = S::bound();
 					if size > max {
 						Err(A::Error::custom("out of bounds"))
 					} else {
@@ -113,15 +112,14 @@ mod serde_impl {
 /// A bounded slice.
 ///
 /// Similar to a `BoundedVec`, but not owned and cannot be decoded.
-#[cfg_attr(feature = "scale-codec", derive(scale_codec::Encode, scale_info::TypeInfo))]
-#[cfg_attr(feature = "jam-codec", derive(jam_codec::Encode))]
+#[cfg_attr(feature = "scale-codec", derive(scale_info::TypeInfo))]
 pub struct BoundedSlice<'a, T, S>(pub(super) &'a [T], PhantomData<S>);
 
 impl<'a, T, BoundSelf, BoundRhs> PartialEq<BoundedSlice<'a, T, BoundRhs>> for BoundedSlice<'a, T, BoundSelf>
 where
 	T: PartialEq,
-	BoundSelf: Get<u32>,
-	BoundRhs: Get<u32>,
+	BoundSelf: BoundValue,
+	BoundRhs: BoundValue,
 {
 	fn eq(&self, other: &BoundedSlice<'a, T, BoundRhs>) -> bool {
 		self.0 == other.0
@@ -131,8 +129,8 @@ where
 impl<'a, T, BoundSelf, BoundRhs> PartialEq<BoundedVec<T, BoundRhs>> for BoundedSlice<'a, T, BoundSelf>
 where
 	T: PartialEq,
-	BoundSelf: Get<u32>,
-	BoundRhs: Get<u32>,
+	BoundSelf: BoundValue,
+	BoundRhs: BoundValue,
 {
 	fn eq(&self, other: &BoundedVec<T, BoundRhs>) -> bool {
 		self.0 == other.0
@@ -142,21 +140,21 @@ where
 impl<'a, T, BoundSelf, BoundRhs> PartialEq<WeakBoundedVec<T, BoundRhs>> for BoundedSlice<'a, T, BoundSelf>
 where
 	T: PartialEq,
-	BoundSelf: Get<u32>,
-	BoundRhs: Get<u32>,
+	BoundSelf: BoundValue,
+	BoundRhs: BoundValue,
 {
 	fn eq(&self, other: &WeakBoundedVec<T, BoundRhs>) -> bool {
 		self.0 == other.0
 	}
 }
 
-impl<'a, T, S: Get<u32>> Eq for BoundedSlice<'a, T, S> where T: Eq {}
+impl<'a, T, S: BoundValue> Eq for BoundedSlice<'a, T, S> where T: Eq {}
 
 impl<'a, T, BoundSelf, BoundRhs> PartialOrd<BoundedSlice<'a, T, BoundRhs>> for BoundedSlice<'a, T, BoundSelf>
 where
 	T: PartialOrd,
-	BoundSelf: Get<u32>,
-	BoundRhs: Get<u32>,
+	BoundSelf: BoundValue,
+	BoundRhs: BoundValue,
 {
 	fn partial_cmp(&self, other: &BoundedSlice<'a, T, BoundRhs>) -> Option<core::cmp::Ordering> {
 		self.0.partial_cmp(other.0)
@@ -166,8 +164,8 @@ where
 impl<'a, T, BoundSelf, BoundRhs> PartialOrd<BoundedVec<T, BoundRhs>> for BoundedSlice<'a, T, BoundSelf>
 where
 	T: PartialOrd,
-	BoundSelf: Get<u32>,
-	BoundRhs: Get<u32>,
+	BoundSelf: BoundValue,
+	BoundRhs: BoundValue,
 {
 	fn partial_cmp(&self, other: &BoundedVec<T, BoundRhs>) -> Option<core::cmp::Ordering> {
 		self.0.partial_cmp(&*other.0)
@@ -177,24 +175,24 @@ where
 impl<'a, T, BoundSelf, BoundRhs> PartialOrd<WeakBoundedVec<T, BoundRhs>> for BoundedSlice<'a, T, BoundSelf>
 where
 	T: PartialOrd,
-	BoundSelf: Get<u32>,
-	BoundRhs: Get<u32>,
+	BoundSelf: BoundValue,
+	BoundRhs: BoundValue,
 {
 	fn partial_cmp(&self, other: &WeakBoundedVec<T, BoundRhs>) -> Option<core::cmp::Ordering> {
 		self.0.partial_cmp(&*other.0)
 	}
 }
 
-impl<'a, T: Ord, Bound: Get<u32>> Ord for BoundedSlice<'a, T, Bound> {
+impl<'a, T: Ord, Bound: BoundValue> Ord for BoundedSlice<'a, T, Bound> {
 	fn cmp(&self, other: &Self) -> core::cmp::Ordering {
 		self.0.cmp(&other.0)
 	}
 }
 
-impl<'a, T, S: Get<u32>> TryFrom<&'a [T]> for BoundedSlice<'a, T, S> {
+impl<'a, T, S: BoundValue> TryFrom<&'a [T]> for BoundedSlice<'a, T, S> {
 	type Error = &'a [T];
 	fn try_from(t: &'a [T]) -> Result<Self, Self::Error> {
-		if t.len() <= S::get() as usize {
+		if t.len() <= S::bound() {
 			Ok(BoundedSlice(t, PhantomData))
 		} else {
 			Err(t)
@@ -208,7 +206,7 @@ impl<'a, T, S> From<BoundedSlice<'a, T, S>> for &'a [T] {
 	}
 }
 
-impl<'a, T, S: Get<u32>> TruncateFrom<&'a [T]> for BoundedSlice<'a, T, S> {
+impl<'a, T, S: BoundValue> TruncateFrom<&'a [T]> for BoundedSlice<'a, T, S> {
 	fn truncate_from(unbound: &'a [T]) -> Self {
 		BoundedSlice::<T, S>::truncate_from(unbound)
 	}
@@ -223,10 +221,10 @@ impl<'a, T, S> Clone for BoundedSlice<'a, T, S> {
 impl<'a, T, S> core::fmt::Debug for BoundedSlice<'a, T, S>
 where
 	&'a [T]: core::fmt::Debug,
-	S: Get<u32>,
+	S: BoundValue,
 {
 	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-		f.debug_tuple("BoundedSlice").field(&self.0).field(&S::get()).finish()
+		f.debug_tuple("BoundedSlice").field(&self.0).field(&S::bound()).finish()
 	}
 }
 
@@ -259,11 +257,36 @@ impl<'a, T, S> core::iter::IntoIterator for BoundedSlice<'a, T, S> {
 	}
 }
 
-impl<'a, T, S: Get<u32>> BoundedSlice<'a, T, S> {
+impl<'a, T, S: BoundValue> BoundedSlice<'a, T, S> {
 	/// Create an instance from the first elements of the given slice (or all of it if it is smaller
 	/// than the length bound).
 	pub fn truncate_from(s: &'a [T]) -> Self {
-		Self(&s[0..(s.len().min(S::get() as usize))], PhantomData)
+		Self(&s[0..(s.len().min(S::bound()))], PhantomData)
+	}
+}
+
+impl<'a, T, S> BoundedSlice<'a, T, S> {
+	/// Splits the slice into a slice of `N`-element arrays, starting at the beginning of the slice,
+	/// and a remainder slice with length strictly less than `N`.
+	///
+	/// Same as `slice::as_chunks`, keeping the lifetime of the underlying slice.
+	///
+	/// # Panics
+	///
+	/// Panics if `N` is zero.
+	pub fn as_chunks<const N: usize>(&self) -> (&'a [[T; N]], &'a [T]) {
+		as_chunks(self.0)
+	}
+
+	/// Returns the slice as a slice of `N`-element arrays, or `None` if its length is not a
+	/// multiple of `N`.
+	///
+	/// # Panics
+	///
+	/// Panics if `N` is zero.
+	pub fn try_as_exact_chunks<const N: usize>(&self) -> Option<&'a [[T; N]]> {
+		let (chunks, remainder) = self.as_chunks();
+		remainder.is_empty().then_some(chunks)
 	}
 }
 
@@ -273,11 +296,6 @@ impl<T, S> BoundedVec<T, S> {
 		Self(Vec::new(), Default::default())
 	}
 
-	/// Create `Self` from `t` without any checks.
-	fn unchecked_from(t: Vec<T>) -> Self {
-		Self(t, Default::default())
-	}
-
 	/// Exactly the same semantics as `Vec::clear`.
 	pub fn clear(&mut self) {
 		self.0.clear()
@@ -383,15 +401,102 @@ impl<T, S> BoundedVec<T, S> {
 	{
 		self.0.drain(range)
 	}
+
+	/// Removes the elements in `range` from self and returns them in a new bounded vector.
+	///
+	/// Returns an `Err` (and is a noop) if the range is out of bounds or longer than the bound of
+	/// the returned vector. Unlike [`Self::drain`], this never panics.
+	pub fn drain_to_bounded<R, OutBound>(&mut self, range: R) -> Result<BoundedVec<T, OutBound>, ()>
+	where
+		R: RangeBounds<usize>,
+		OutBound: BoundValue,
+	{
+		let range = checked_range(range, self.len()).ok_or(())?;
+		if range.len() > BoundedVec::<T, OutBound>::bound() {
+			return Err(())
+		}
+		Ok(BoundedVec::unchecked_from(self.0.drain(range).collect()))
+	}
+
+	/// Removes all the elements from self and returns them in a new bounded vector with the same
+	/// bound.
+	///
+	/// The allocation is moved into the returned vector, leaving self empty without any capacity.
+	pub fn drain_all_bounded(&mut self) -> Self {
+		core::mem::take(self)
+	}
+
+	/// Splits the vector into a slice of `N`-element arrays, starting at the beginning of the
+	/// vector, and a remainder slice with length strictly less than `N`.
+	///
+	/// Same as `slice::as_chunks`.
+	///
+	/// # Panics
+	///
+	/// Panics if `N` is zero.
+	pub fn as_chunks<const N: usize>(&self) -> (&[[T; N]], &[T]) {
+		as_chunks(&self.0)
+	}
+
+	/// Returns the vector as a slice of `N`-element arrays, or `None` if its length is not a
+	/// multiple of `N`.
+	///
+	/// # Panics
+	///
+	/// Panics if `N` is zero.
+	pub fn try_as_exact_chunks<const N: usize>(&self) -> Option<&[[T; N]]> {
+		let (chunks, remainder) = self.as_chunks();
+		remainder.is_empty().then_some(chunks)
+	}
 }
 
-impl<T, S: Get<u32>> From<BoundedVec<T, S>> for Vec<T> {
+// Same as `slice::as_chunks`, which is not available with the minimum supported Rust version.
+fn as_chunks<T, const N: usize>(slice: &[T]) -> (&[[T; N]], &[T]) {
+	assert!(N != 0, "chunk size must be non-zero");
+	let len = slice.len() / N;
+	let (chunks, remainder) = slice.split_at(len * N);
+	// SAFETY: `chunks` holds exactly `len * N` items, and `[T; N]` has the same layout as `N`
+	// consecutive `T`s.
+	let chunks = unsafe { core::slice::from_raw_parts(chunks.as_ptr() as *const [T; N], len) };
+	(chunks, remainder)
+}
+
+// Resolve `range` into a range of indices into a slice of length `len`, or `None` if it is out of
+// bounds.
+fn checked_range(range: impl RangeBounds<usize>, len: usize) -> Option<Range<usize>> {
+	let start = match range.start_bound() {
+		Bound::Included(&start) => start,
+		Bound::Excluded(&start) => start.checked_add(1)?,
+		Bound::Unbounded => 0,
+	};
+	let end = match range.end_bound() {
+		Bound::Included(&end) => end.checked_add(1)?,
+		Bound::Excluded(&end) => end,
+		Bound::Unbounded => len,
+	};
+	(start <= end && end <= len).then_some(start..end)
+}
+
+impl<T, S: BoundValue> From<BoundedVec<T, S>> for Vec<T> {
 	fn from(x: BoundedVec<T, S>) -> Vec<T> {
 		x.0
 	}
 }
 
-impl<T, S: Get<u32>> BoundedVec<T, S> {
+// Truncates a vector to a bound when dropped, so it stays within the bound even if a mutation of
+// it panics.
+struct TruncateOnDrop<'a, T> {
+	vec: &'a mut Vec<T>,
+	bound: usize,
+}
+
+impl<'a, T> Drop for TruncateOnDrop<'a, T> {
+	fn drop(&mut self) {
+		self.vec.truncate(self.bound);
+	}
+}
+
+impl<T, S: BoundValue> BoundedVec<T, S> {
 	/// Pre-allocate `capacity` items in self.
 	///
 	/// If `capacity` is greater than [`Self::bound`], then the minimum of the two is used.
@@ -400,20 +505,43 @@ impl<T, S: Get<u32>> BoundedVec<T, S> {
 		Self(Vec::with_capacity(capacity), Default::default())
 	}
 
+	/// Create `Self` from `t` without any checks.
+	///
+	/// With the `defensive-checks` feature, the bound is still checked in debug builds.
+	pub(crate) fn unchecked_from(t: Vec<T>) -> Self {
+		let bounded = Self(t, Default::default());
+		bounded.check_invariant();
+		bounded
+	}
+
+	/// Check that the length of `self` is within the bound.
+	///
+	/// Called after every operation which could break this invariant. Does nothing unless the
+	/// `defensive-checks` feature is enabled and debug assertions are on.
+	#[inline(always)]
+	fn check_invariant(&self) {
+		#[cfg(feature = "defensive-checks")]
+		debug_assert!(self.0.len() <= Self::bound(), "BoundedVec exceeds its bound");
+	}
+
 	/// Allocate self with the maximum possible capacity.
 	pub fn with_max_capacity() -> Self {
 		Self::with_bounded_capacity(Self::bound())
 	}
 
 	/// Consume and truncate the vector `v` in order to create a new instance of `Self` from it.
+	///
+	/// With the `defensive-checks` feature, this panics if any item would be dropped.
 	pub fn truncate_from(mut v: Vec<T>) -> Self {
+		#[cfg(feature = "defensive-checks")]
+		assert!(v.len() <= Self::bound(), "BoundedVec::truncate_from drops items");
 		v.truncate(Self::bound());
 		Self::unchecked_from(v)
 	}
 
 	/// Get the bound of the type in `usize`.
 	pub fn bound() -> usize {
-		S::get() as usize
+		S::bound()
 	}
 
 	/// Returns true if this collection is full.
@@ -438,6 +566,7 @@ impl<T, S: Get<u32>> BoundedVec<T, S> {
 		} else if self.len() < Self::bound() {
 			// Cannot panic since self.len() >= index;
 			self.0.insert(index, element);
+			self.check_invariant();
 			Ok(None)
 		} else {
 			if index == 0 {
@@ -447,6 +576,7 @@ impl<T, S: Get<u32>> BoundedVec<T, S> {
 			// `[0..index] cannot panic since self.len() >= index.
 			// `rotate_left(1)` cannot panic because there is at least 1 element.
 			self[0..index].rotate_left(1);
+			self.check_invariant();
 			Ok(Some(element))
 		}
 	}
@@ -481,6 +611,7 @@ impl<T, S: Get<u32>> BoundedVec<T, S> {
 
 		// Cannot panic since `self.len() >= index`;
 		self.0.insert(index, element);
+		self.check_invariant();
 		Ok(maybe_removed)
 	}
 
@@ -537,11 +668,12 @@ impl<T, S: Get<u32>> BoundedVec<T, S> {
 	/// Forces the insertion of `s` into `self` truncating first if necessary.
 	///
 	/// Infallible, but if the bound is zero, then it's a no-op.
+	///
+	/// With the `defensive-checks` feature, this panics if any item would be dropped.
 	pub fn force_push(&mut self, element: T) {
-		if Self::bound() > 0 {
-			self.0.truncate(Self::bound() as usize - 1);
-			self.0.push(element);
-		}
+		#[cfg(feature = "defensive-checks")]
+		assert!(!self.is_full(), "BoundedVec::force_push drops items");
+		let _ = self.push_with_policy(element, OnOverflow::EvictLast);
 	}
 
 	/// Same as `Vec::resize`, but if `size` is more than [`Self::bound`], then [`Self::bound`] is
@@ -552,14 +684,16 @@ impl<T, S: Get<u32>> BoundedVec<T, S> {
 	{
 		let size = size.min(Self::bound());
 		self.0.resize(size, value);
+		self.check_invariant();
 	}
 
 	/// Exactly the same semantics as [`Vec::extend`], but returns an error and does nothing if the
 	/// length of the outcome is larger than the bound.
+	///
+	/// See [`TryExtend`] for extending with iterators of unknown length.
 	pub fn try_extend(&mut self, with: impl IntoIterator<Item = T> + ExactSizeIterator) -> Result<(), ()> {
 		if with.len().saturating_add(self.len()) <= Self::bound() {
-			self.0.extend(with);
-			Ok(())
+			TryExtend::try_extend(self, with)
 		} else {
 			Err(())
 		}
@@ -570,12 +704,72 @@ impl<T, S: Get<u32>> BoundedVec<T, S> {
 	pub fn try_append(&mut self, other: &mut Vec<T>) -> Result<(), ()> {
 		if other.len().saturating_add(self.len()) <= Self::bound() {
 			self.0.append(other);
+			self.check_invariant();
+			Ok(())
+		} else {
+			Err(())
+		}
+	}
+
+	/// Moves all the elements of `other` into `self`, like [`Self::try_append`] but taking another
+	/// bounded vector by value.
+	///
+	/// Returns `other` untouched (and is a noop) if the length of the outcome is larger than the
+	/// bound.
+	pub fn try_append_bounded<OtherBound>(
+		&mut self,
+		mut other: BoundedVec<T, OtherBound>,
+	) -> Result<(), BoundedVec<T, OtherBound>> {
+		if other.len().saturating_add(self.len()) <= Self::bound() {
+			self.0.append(&mut other.0);
+			self.check_invariant();
+			Ok(())
+		} else {
+			Err(other)
+		}
+	}
+
+	/// Exactly the same semantics as [`Vec::extend_from_slice`], but returns an error and does
+	/// nothing if the length of the outcome is larger than the bound.
+	pub fn try_append_from_slice(&mut self, other: &[T]) -> Result<(), ()>
+	where
+		T: Clone,
+	{
+		if other.len().saturating_add(self.len()) <= Self::bound() {
+			self.0.extend_from_slice(other);
+			self.check_invariant();
 			Ok(())
 		} else {
 			Err(())
 		}
 	}
 
+	/// Exchanges the contents of self and `other`, which may have a different bound.
+	///
+	/// Returns an `Err` (and is a noop) if the contents of either vector would exceed the bound of
+	/// the other one. No elements are copied.
+	pub fn try_swap_with<OtherBound: BoundValue>(&mut self, other: &mut BoundedVec<T, OtherBound>) -> Result<(), ()> {
+		if self.len() <= BoundedVec::<T, OtherBound>::bound() && other.len() <= Self::bound() {
+			core::mem::swap(&mut self.0, &mut other.0);
+			Ok(())
+		} else {
+			Err(())
+		}
+	}
+
+	/// Exchanges the contents of self and `other`. No elements are copied.
+	pub fn swap_with(&mut self, other: &mut Self) {
+		core::mem::swap(self, other)
+	}
+
+	/// Returns an [`Extend`] adapter which pushes items into self until it is full.
+	///
+	/// **Items beyond the bound are silently dropped.** Check [`SaturatingExtender::dropped`] after
+	/// extending to detect data loss, or prefer [`TryExtend`] if it isn't acceptable.
+	pub fn saturating_extender(&mut self) -> SaturatingExtender<'_, T, S> {
+		SaturatingExtender { vec: self, dropped: 0 }
+	}
+
 	/// Consumes self and mutates self via the given `mutate` function.
 	///
 	/// If the outcome of mutation is within bounds, `Some(Self)` is returned. Else, `None` is
@@ -588,6 +782,46 @@ impl<T, S: Get<u32>> BoundedVec<T, S> {
 		(self.0.len() <= Self::bound()).then(move || self)
 	}
 
+	/// Mutates self in place via the given `mutate` function, returning its result.
+	///
+	/// `mutate` may temporarily exceed the bound. If the outcome of the mutation is within bounds,
+	/// `Ok` with the result of `mutate` is returned. Else, `Err` is returned and self is restored to
+	/// its contents before the mutation.
+	///
+	/// The contents are cloned up front to be able to restore them. Use
+	/// [`Self::try_mutate_in_place_truncating`] if truncating on failure is good enough.
+	///
+	/// If `mutate` panics, self is truncated to the bound.
+	pub fn try_mutate_in_place<R>(&mut self, mutate: impl FnOnce(&mut Vec<T>) -> R) -> Result<R, ()>
+	where
+		T: Clone,
+	{
+		let original = self.0.clone();
+		match self.try_mutate_in_place_truncating(mutate) {
+			Ok(result) => Ok(result),
+			Err(()) => {
+				self.0 = original;
+				Err(())
+			},
+		}
+	}
+
+	/// Mutates self in place via the given `mutate` function, returning its result.
+	///
+	/// `mutate` may temporarily exceed the bound. If the outcome of the mutation is within bounds,
+	/// `Ok` with the result of `mutate` is returned. Else, `Err` is returned and self is truncated
+	/// to the bound, keeping all other changes done by `mutate`.
+	///
+	/// If `mutate` panics, self is truncated to the bound as well.
+	pub fn try_mutate_in_place_truncating<R>(&mut self, mutate: impl FnOnce(&mut Vec<T>) -> R) -> Result<R, ()> {
+		let guard = TruncateOnDrop { vec: &mut self.0, bound: Self::bound() };
+		let result = mutate(guard.vec);
+		let within_bound = guard.vec.len() <= guard.bound;
+		drop(guard);
+		self.check_invariant();
+		within_bound.then_some(result).ok_or(())
+	}
+
 	/// Exactly the same semantics as [`Vec::insert`], but returns an `Err` (and is a noop) if the
 	/// new length of the vector exceeds `S`.
 	///
@@ -597,6 +831,7 @@ impl<T, S: Get<u32>> BoundedVec<T, S> {
 	pub fn try_insert(&mut self, index: usize, element: T) -> Result<(), T> {
 		if self.len() < Self::bound() {
 			self.0.insert(index, element);
+			self.check_invariant();
 			Ok(())
 		} else {
 			Err(element)
@@ -610,11 +845,39 @@ impl<T, S: Get<u32>> BoundedVec<T, S> {
 	///
 	/// Panics if the new capacity exceeds isize::MAX bytes.
 	pub fn try_push(&mut self, element: T) -> Result<(), T> {
+		match self.push_with_policy(element, OnOverflow::Error) {
+			PushOutcome::Rejected(element) => Err(element),
+			_ => Ok(()),
+		}
+	}
+
+	/// Push `element` to the end of the vector, handling a full vector according to `policy`.
+	///
+	/// With a zero bound, nothing can be evicted to make room, so the element is dropped unless
+	/// `policy` is [`OnOverflow::Error`].
+	///
+	/// # Panics
+	///
+	/// Panics if the new capacity exceeds isize::MAX bytes.
+	pub fn push_with_policy(&mut self, element: T, policy: OnOverflow) -> PushOutcome<T> {
 		if self.len() < Self::bound() {
 			self.0.push(element);
-			Ok(())
-		} else {
-			Err(element)
+			self.check_invariant();
+			return PushOutcome::Inserted
+		}
+		match policy {
+			OnOverflow::Error => PushOutcome::Rejected(element),
+			_ if self.is_empty() => PushOutcome::Dropped,
+			OnOverflow::DropNewest => PushOutcome::Dropped,
+			OnOverflow::EvictOldest => {
+				let evicted = self.0.remove(0);
+				self.0.push(element);
+				PushOutcome::Evicted(evicted)
+			},
+			OnOverflow::EvictLast => {
+				let last = self.0.last_mut().expect("the vector is full and not empty; qed");
+				PushOutcome::Evicted(core::mem::replace(last, element))
+			},
 		}
 	}
 
@@ -639,6 +902,82 @@ impl<T, S: Get<u32>> BoundedVec<T, S> {
 	}
 }
 
+// The number of bytes printed by `HexDebug` before truncating.
+const HEX_DEBUG_MAX_BYTES: usize = 64;
+
+// Formats bytes as `name(0x<hex>, <len>)`, truncating after `HEX_DEBUG_MAX_BYTES` bytes.
+pub(crate) struct HexDebug<'a> {
+	pub(crate) name: &'static str,
+	pub(crate) bytes: &'a [u8],
+}
+
+impl<'a> core::fmt::Debug for HexDebug<'a> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "{}(0x", self.name)?;
+		for byte in self.bytes.iter().take(HEX_DEBUG_MAX_BYTES) {
+			write!(f, "{:02x}", byte)?;
+		}
+		if self.bytes.len() > HEX_DEBUG_MAX_BYTES {
+			f.write_str("…")?;
+		}
+		write!(f, ", {})", self.bytes.len())
+	}
+}
+
+impl<S> BoundedVec<u8, S> {
+	/// Return an adapter which formats the bytes as hex with [`Debug`](core::fmt::Debug).
+	///
+	/// Prints `BoundedVec(0x<hex>, <len>)`, only including the first 64 bytes followed by `…` for
+	/// longer vectors.
+	pub fn hex_debug(&self) -> impl core::fmt::Debug + '_ {
+		HexDebug { name: "BoundedVec", bytes: &self.0 }
+	}
+}
+
+impl<'a, S> BoundedSlice<'a, u8, S> {
+	/// Return an adapter which formats the bytes as hex with [`Debug`](core::fmt::Debug).
+	///
+	/// Prints `BoundedSlice(0x<hex>, <len>)`, only including the first 64 bytes followed by `…` for
+	/// longer slices.
+	pub fn hex_debug(&self) -> impl core::fmt::Debug + 'a {
+		HexDebug { name: "BoundedSlice", bytes: self.0 }
+	}
+}
+
+/// Create a bounded collection from `N` elements.
+///
+/// Used by [`const_bounded_vec`](crate::const_bounded_vec), which checks the number of elements
+/// against the bound at compile time.
+#[doc(hidden)]
+pub trait FromConstLen<T, const N: usize>: Sized {
+	/// Create `Self` from `items`, which has length `N`.
+	fn from_const_len(items: Vec<T>) -> Self;
+}
+
+// Check that `N` elements fit within the bound `S`, which must be known at compile time.
+struct CheckConstLen<S, const N: usize>(PhantomData<S>);
+
+impl<S: BoundValue, const N: usize> CheckConstLen<S, N> {
+	const ASSERTION: () = match S::CONST_BOUND {
+		Some(bound) => assert!(N as u64 <= bound, "too many elements for the bound of the bounded vec"),
+		None => panic!("the bound of the bounded vec is not known at compile time"),
+	};
+}
+
+impl<T, S: BoundValue, const N: usize> FromConstLen<T, N> for BoundedVec<T, S> {
+	fn from_const_len(items: Vec<T>) -> Self {
+		let () = CheckConstLen::<S, N>::ASSERTION;
+		Self::try_from(items).unwrap_or_else(|_| panic!("too many elements for the bound of the bounded vec"))
+	}
+}
+
+impl<T, S: BoundValue, const N: usize> FromConstLen<T, N> for WeakBoundedVec<T, S> {
+	fn from_const_len(items: Vec<T>) -> Self {
+		let () = CheckConstLen::<S, N>::ASSERTION;
+		Self::try_from(items).unwrap_or_else(|_| panic!("too many elements for the bound of the bounded vec"))
+	}
+}
+
 impl<T, S> BoundedVec<T, S> {
 	/// Return a [`BoundedSlice`] with the content and bound of [`Self`].
 	pub fn as_bounded_slice(&self) -> BoundedSlice<T, S> {
@@ -646,17 +985,63 @@ impl<T, S> BoundedVec<T, S> {
 	}
 }
 
+/// An [`Extend`] adapter for a [`BoundedVec`] which drops all items beyond the bound.
+///
+/// Returned by [`BoundedVec::saturating_extender`]. This allows using a bounded vector with generic
+/// code requiring an [`Extend`] sink.
+///
+/// **This loses data** if more items are given than fit into the vector. The number of dropped
+/// items is counted, see [`Self::dropped`].
+///
+/// ```
+/// # use bounded_collections::{BoundedVec, ConstU32};
+/// let mut evens = BoundedVec::<u32, ConstU32<2>>::new();
+/// let mut odds = BoundedVec::<u32, ConstU32<2>>::new();
+/// let mut sinks = (evens.saturating_extender(), odds.saturating_extender());
+/// sinks.extend((0..5).map(|i| (2 * i, 2 * i + 1)));
+/// assert_eq!(sinks.0.dropped(), 3);
+/// assert_eq!(evens, vec![0, 2]);
+/// ```
+pub struct SaturatingExtender<'a, T, S> {
+	vec: &'a mut BoundedVec<T, S>,
+	dropped: usize,
+}
+
+impl<'a, T, S> SaturatingExtender<'a, T, S> {
+	/// The number of items dropped so far because the vector was full.
+	pub fn dropped(&self) -> usize {
+		self.dropped
+	}
+}
+
+impl<'a, T, S: BoundValue> Extend<T> for SaturatingExtender<'a, T, S> {
+	fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+		let bound = BoundedVec::<T, S>::bound();
+		let iter = iter.into_iter();
+		let remaining = bound.saturating_sub(self.vec.len());
+		self.vec.0.reserve(iter.size_hint().0.min(remaining));
+		for item in iter {
+			if self.vec.len() < bound {
+				self.vec.0.push(item);
+			} else {
+				self.dropped = self.dropped.saturating_add(1);
+			}
+		}
+		self.vec.check_invariant();
+	}
+}
+
 impl<T, S> Default for BoundedVec<T, S> {
 	fn default() -> Self {
 		// the bound cannot be below 0, which is satisfied by an empty vector
-		Self::unchecked_from(Vec::default())
+		Self::new()
 	}
 }
 
 impl<T, S> core::fmt::Debug for BoundedVec<T, S>
 where
 	Vec<T>: core::fmt::Debug,
-	S: Get<u32>,
+	S: BoundValue,
 {
 	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 		f.debug_tuple("BoundedVec").field(&self.0).field(&Self::bound()).finish()
@@ -669,11 +1054,11 @@ where
 {
 	fn clone(&self) -> Self {
 		// bound is retained
-		Self::unchecked_from(self.0.clone())
+		Self(self.0.clone(), PhantomData)
 	}
 }
 
-impl<T, S: Get<u32>> TryFrom<Vec<T>> for BoundedVec<T, S> {
+impl<T, S: BoundValue> TryFrom<Vec<T>> for BoundedVec<T, S> {
 	type Error = Vec<T>;
 	fn try_from(t: Vec<T>) -> Result<Self, Self::Error> {
 		if t.len() <= Self::bound() {
@@ -685,7 +1070,7 @@ impl<T, S: Get<u32>> TryFrom<Vec<T>> for BoundedVec<T, S> {
 	}
 }
 
-impl<T, S: Get<u32>> TruncateFrom<Vec<T>> for BoundedVec<T, S> {
+impl<T, S: BoundValue> TruncateFrom<Vec<T>> for BoundedVec<T, S> {
 	fn truncate_from(unbound: Vec<T>) -> Self {
 		BoundedVec::<T, S>::truncate_from(unbound)
 	}
@@ -778,8 +1163,8 @@ impl<'a, T, S> core::iter::IntoIterator for &'a mut BoundedVec<T, S> {
 impl<T, BoundSelf, BoundRhs> PartialEq<BoundedVec<T, BoundRhs>> for BoundedVec<T, BoundSelf>
 where
 	T: PartialEq,
-	BoundSelf: Get<u32>,
-	BoundRhs: Get<u32>,
+	BoundSelf: BoundValue,
+	BoundRhs: BoundValue,
 {
 	fn eq(&self, rhs: &BoundedVec<T, BoundRhs>) -> bool {
 		self.0 == rhs.0
@@ -789,8 +1174,8 @@ where
 impl<T, BoundSelf, BoundRhs> PartialEq<WeakBoundedVec<T, BoundRhs>> for BoundedVec<T, BoundSelf>
 where
 	T: PartialEq,
-	BoundSelf: Get<u32>,
-	BoundRhs: Get<u32>,
+	BoundSelf: BoundValue,
+	BoundRhs: BoundValue,
 {
 	fn eq(&self, rhs: &WeakBoundedVec<T, BoundRhs>) -> bool {
 		self.0 == rhs.0
@@ -800,33 +1185,33 @@ where
 impl<'a, T, BoundSelf, BoundRhs> PartialEq<BoundedSlice<'a, T, BoundRhs>> for BoundedVec<T, BoundSelf>
 where
 	T: PartialEq,
-	BoundSelf: Get<u32>,
-	BoundRhs: Get<u32>,
+	BoundSelf: BoundValue,
+	BoundRhs: BoundValue,
 {
 	fn eq(&self, rhs: &BoundedSlice<'a, T, BoundRhs>) -> bool {
 		self.0 == rhs.0
 	}
 }
 
-impl<'a, T: PartialEq, S: Get<u32>> PartialEq<&'a [T]> for BoundedSlice<'a, T, S> {
+impl<'a, T: PartialEq, S: BoundValue> PartialEq<&'a [T]> for BoundedSlice<'a, T, S> {
 	fn eq(&self, other: &&'a [T]) -> bool {
 		&self.0 == other
 	}
 }
 
-impl<T: PartialEq, S: Get<u32>> PartialEq<Vec<T>> for BoundedVec<T, S> {
+impl<T: PartialEq, S: BoundValue> PartialEq<Vec<T>> for BoundedVec<T, S> {
 	fn eq(&self, other: &Vec<T>) -> bool {
 		&self.0 == other
 	}
 }
 
-impl<T, S: Get<u32>> Eq for BoundedVec<T, S> where T: Eq {}
+impl<T, S: BoundValue> Eq for BoundedVec<T, S> where T: Eq {}
 
 impl<T, BoundSelf, BoundRhs> PartialOrd<BoundedVec<T, BoundRhs>> for BoundedVec<T, BoundSelf>
 where
 	T: PartialOrd,
-	BoundSelf: Get<u32>,
-	BoundRhs: Get<u32>,
+	BoundSelf: BoundValue,
+	BoundRhs: BoundValue,
 {
 	fn partial_cmp(&self, other: &BoundedVec<T, BoundRhs>) -> Option<core::cmp::Ordering> {
 		self.0.partial_cmp(&other.0)
@@ -836,8 +1221,8 @@ where
 impl<T, BoundSelf, BoundRhs> PartialOrd<WeakBoundedVec<T, BoundRhs>> for BoundedVec<T, BoundSelf>
 where
 	T: PartialOrd,
-	BoundSelf: Get<u32>,
-	BoundRhs: Get<u32>,
+	BoundSelf: BoundValue,
+	BoundRhs: BoundValue,
 {
 	fn partial_cmp(&self, other: &WeakBoundedVec<T, BoundRhs>) -> Option<core::cmp::Ordering> {
 		self.0.partial_cmp(&other.0)
@@ -847,29 +1232,66 @@ where
 impl<'a, T, BoundSelf, BoundRhs> PartialOrd<BoundedSlice<'a, T, BoundRhs>> for BoundedVec<T, BoundSelf>
 where
 	T: PartialOrd,
-	BoundSelf: Get<u32>,
-	BoundRhs: Get<u32>,
+	BoundSelf: BoundValue,
+	BoundRhs: BoundValue,
 {
 	fn partial_cmp(&self, other: &BoundedSlice<'a, T, BoundRhs>) -> Option<core::cmp::Ordering> {
 		(&*self.0).partial_cmp(other.0)
 	}
 }
 
-impl<T: Ord, Bound: Get<u32>> Ord for BoundedVec<T, Bound> {
+impl<T: Ord, Bound: BoundValue> Ord for BoundedVec<T, Bound> {
 	fn cmp(&self, other: &Self) -> core::cmp::Ordering {
 		self.0.cmp(&other.0)
 	}
 }
 
+// Extends `vec` with `iter`, unless the outcome is longer than `bound`.
+//
+// Stops consuming `iter` and restores `vec` as soon as the bound is exceeded.
+pub(crate) fn try_extend_vec<T>(vec: &mut Vec<T>, bound: usize, iter: impl IntoIterator<Item = T>) -> Result<(), ()> {
+	let len = vec.len();
+	let iter = iter.into_iter();
+	vec.reserve(iter.size_hint().0.min(bound.saturating_sub(len)));
+	for item in iter {
+		if vec.len() >= bound {
+			vec.truncate(len);
+			return Err(())
+		}
+		vec.push(item);
+	}
+	Ok(())
+}
+
+impl<T, S: BoundValue> TryExtend<T> for BoundedVec<T, S> {
+	type Error = ();
+
+	fn try_extend<I: IntoIterator<Item = T>>(&mut self, iter: I) -> Result<(), ()> {
+		let result = try_extend_vec(&mut self.0, Self::bound(), iter);
+		self.check_invariant();
+		result
+	}
+}
+
+impl<T, S: BoundValue> TryFromIterator<T> for BoundedVec<T, S> {
+	type Error = ();
+
+	fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<Self, ()> {
+		let mut bounded = Self::default();
+		TryExtend::try_extend(&mut bounded, iter)?;
+		Ok(bounded)
+	}
+}
+
 impl<I, T, Bound> TryCollect<BoundedVec<T, Bound>> for I
 where
 	I: ExactSizeIterator + Iterator<Item = T>,
-	Bound: Get<u32>,
+	Bound: BoundValue,
 {
 	type Error = &'static str;
 
 	fn try_collect(self) -> Result<BoundedVec<T, Bound>, Self::Error> {
-		if self.len() > Bound::get() as usize {
+		if self.len() > Bound::bound() {
 			Err("iterator length too big")
 		} else {
 			Ok(BoundedVec::<T, Bound>::unchecked_from(self.collect::<Vec<T>>()))
@@ -877,6 +1299,66 @@ where
 	}
 }
 
+#[cfg(feature = "rayon")]
+mod rayon_impl {
+	use super::*;
+	use crate::ParallelTryCollect;
+	use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+	impl<T: Send, S> IntoParallelIterator for BoundedVec<T, S> {
+		type Iter = rayon::vec::IntoIter<T>;
+		type Item = T;
+
+		fn into_par_iter(self) -> Self::Iter {
+			self.0.into_par_iter()
+		}
+	}
+
+	impl<'a, T: Sync, S> IntoParallelIterator for &'a BoundedVec<T, S> {
+		type Iter = rayon::slice::Iter<'a, T>;
+		type Item = &'a T;
+
+		fn into_par_iter(self) -> Self::Iter {
+			self.0.as_slice().into_par_iter()
+		}
+	}
+
+	impl<'a, T: Send, S> IntoParallelIterator for &'a mut BoundedVec<T, S> {
+		type Iter = rayon::slice::IterMut<'a, T>;
+		type Item = &'a mut T;
+
+		fn into_par_iter(self) -> Self::Iter {
+			self.0.as_mut_slice().into_par_iter()
+		}
+	}
+
+	impl<'a, T: Sync, S> IntoParallelIterator for BoundedSlice<'a, T, S> {
+		type Iter = rayon::slice::Iter<'a, T>;
+		type Item = &'a T;
+
+		fn into_par_iter(self) -> Self::Iter {
+			self.0.into_par_iter()
+		}
+	}
+
+	impl<I, T, Bound> ParallelTryCollect<BoundedVec<T, Bound>> for I
+	where
+		I: ParallelIterator<Item = T>,
+		T: Send,
+		Bound: BoundValue,
+	{
+		type Error = &'static str;
+
+		fn try_collect_bounded(self) -> Result<BoundedVec<T, Bound>, Self::Error> {
+			// Bail out early if the length is known up front.
+			if self.opt_len().is_some_and(|len| len > Bound::bound()) {
+				return Err("iterator length too big")
+			}
+			BoundedVec::try_from(self.collect::<Vec<T>>()).map_err(|_| "iterator length too big")
+		}
+	}
+}
+
 #[cfg(any(feature = "scale-codec", feature = "jam-codec"))]
 macro_rules! codec_impl {
 	($codec:ident) => {
@@ -884,19 +1366,69 @@ macro_rules! codec_impl {
 
 		use $codec::{
 			decode_vec_with_len, Compact, Decode, DecodeLength, DecodeWithMemTracking, Encode, EncodeLike, Error,
-			Input, MaxEncodedLen,
+			Input, MaxEncodedLen, Output,
 		};
 
-		impl<T: Decode, S: Get<u32>> Decode for BoundedVec<T, S> {
+		// `BoundedVec` encodes exactly like the inner `Vec`, so forward everything to it. This lets
+		// byte vectors use the specialized `Vec<u8>` implementation instead of a per-element loop.
+		impl<T: Encode, S> Encode for BoundedVec<T, S> {
+			fn size_hint(&self) -> usize {
+				self.0.size_hint()
+			}
+
+			fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+				self.0.encode_to(dest)
+			}
+
+			fn encode(&self) -> Vec<u8> {
+				self.0.encode()
+			}
+
+			fn using_encoded<R, F: FnOnce(&[u8]) -> R>(&self, f: F) -> R {
+				self.0.using_encoded(f)
+			}
+
+			fn encoded_size(&self) -> usize {
+				self.0.encoded_size()
+			}
+		}
+
+		impl<T: Encode, S> EncodeLike for BoundedVec<T, S> {}
+
+		impl<'a, T: Encode, S> Encode for BoundedSlice<'a, T, S> {
+			fn size_hint(&self) -> usize {
+				self.0.size_hint()
+			}
+
+			fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+				self.0.encode_to(dest)
+			}
+
+			fn encode(&self) -> Vec<u8> {
+				self.0.encode()
+			}
+
+			fn using_encoded<R, F: FnOnce(&[u8]) -> R>(&self, f: F) -> R {
+				self.0.using_encoded(f)
+			}
+
+			fn encoded_size(&self) -> usize {
+				self.0.encoded_size()
+			}
+		}
+
+		impl<'a, T: Encode, S> EncodeLike for BoundedSlice<'a, T, S> {}
+
+		impl<T: Decode, S: BoundValue> Decode for BoundedVec<T, S> {
 			fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
 				// Same as the underlying implementation for `Decode` on `Vec`, except we fail early if the
 				// len is too big.
 				let len: u32 = <Compact<u32>>::decode(input)?.into();
-				if len > S::get() {
+				if u64::from(len) > S::bound_u64() {
 					return Err("BoundedVec exceeds its limit".into());
 				}
 				let inner = decode_vec_with_len(input, len as usize)?;
-				Ok(Self(inner, PhantomData))
+				Ok(Self::unchecked_from(inner))
 			}
 
 			fn skip<I: Input>(input: &mut I) -> Result<(), Error> {
@@ -904,22 +1436,22 @@ macro_rules! codec_impl {
 			}
 		}
 
-		impl<T: DecodeWithMemTracking, S: Get<u32>> DecodeWithMemTracking for BoundedVec<T, S> {}
+		impl<T: DecodeWithMemTracking, S: BoundValue> DecodeWithMemTracking for BoundedVec<T, S> {}
 
 		// `BoundedVec`s encode to something which will always decode as a `Vec`.
-		impl<T: Encode + Decode, S: Get<u32>> EncodeLike<Vec<T>> for BoundedVec<T, S> {}
+		impl<T: Encode + Decode, S: BoundValue> EncodeLike<Vec<T>> for BoundedVec<T, S> {}
 
 		impl<T, S> MaxEncodedLen for BoundedVec<T, S>
 		where
 			T: MaxEncodedLen,
-			S: Get<u32>,
+			S: BoundValue,
 			BoundedVec<T, S>: Encode,
 		{
 			fn max_encoded_len() -> usize {
 				// BoundedVec<T, S> encodes like Vec<T> which encodes like [T], which is a compact u32
 				// plus each item in the slice:
 				// See: https://docs.substrate.io/reference/scale-codec/
-				Compact(S::get())
+				Compact(crate::saturated_bound_u32::<S>())
 					.encoded_size()
 					.saturating_add(Self::bound().saturating_mul(T::max_encoded_len()))
 			}
@@ -935,11 +1467,11 @@ macro_rules! codec_impl {
 
 		// `BoundedSlice`s encode to something which will always decode into a `BoundedVec`,
 		// `WeakBoundedVec`, or a `Vec`.
-		impl<'a, T: Encode + Decode, S: Get<u32>> EncodeLike<BoundedVec<T, S>> for BoundedSlice<'a, T, S> {}
+		impl<'a, T: Encode + Decode, S: BoundValue> EncodeLike<BoundedVec<T, S>> for BoundedSlice<'a, T, S> {}
 
-		impl<'a, T: Encode + Decode, S: Get<u32>> EncodeLike<WeakBoundedVec<T, S>> for BoundedSlice<'a, T, S> {}
+		impl<'a, T: Encode + Decode, S: BoundValue> EncodeLike<WeakBoundedVec<T, S>> for BoundedSlice<'a, T, S> {}
 
-		impl<'a, T: Encode + Decode, S: Get<u32>> EncodeLike<Vec<T>> for BoundedSlice<'a, T, S> {}
+		impl<'a, T: Encode + Decode, S: BoundValue> EncodeLike<Vec<T>> for BoundedSlice<'a, T, S> {}
 	};
 }
 
@@ -953,6 +1485,37 @@ mod jam_codec_impl {
 	codec_impl!(jam_codec);
 }
 
+/// RLP encoding of `BoundedVec` and `BoundedSlice` as a list of their items, the same as
+/// `rlp::encode_list` of the items. Note that a `Vec<u8>` encodes as a byte string instead.
+#[cfg(feature = "rlp")]
+mod rlp_impl {
+	use super::*;
+	use rlp::{Decodable, DecoderError, Encodable, Rlp, RlpStream};
+
+	impl<T: Encodable, S> Encodable for BoundedVec<T, S> {
+		fn rlp_append(&self, s: &mut RlpStream) {
+			s.append_list::<T, T>(&self.0);
+		}
+	}
+
+	impl<'a, T: Encodable, S> Encodable for BoundedSlice<'a, T, S> {
+		fn rlp_append(&self, s: &mut RlpStream) {
+			s.append_list::<T, T>(self.0);
+		}
+	}
+
+	impl<T: Decodable, S: BoundValue> Decodable for BoundedVec<T, S> {
+		fn decode(rlp: &Rlp) -> Result<Self, DecoderError> {
+			// Only the headers of the items are read to count them, so none are decoded if there
+			// are too many.
+			if rlp.item_count()? > S::bound() {
+				return Err(DecoderError::RlpListTooLong)
+			}
+			rlp.as_list().map(Self::unchecked_from)
+		}
+	}
+}
+
 #[cfg(all(test, feature = "std"))]
 mod test {
 	use super::*;
@@ -969,6 +1532,68 @@ mod test {
 		assert_eq!(b.encode(), v.encode());
 	}
 
+	#[test]
+	#[cfg(feature = "scale-codec")]
+	fn byte_encoding_same_as_unbounded_vec() {
+		let v: Vec<u8> = (0..=255).collect();
+		let b = BoundedVec::<u8, ConstU32<256>>::try_from(v.clone()).unwrap();
+		let mut dest = Vec::new();
+		b.encode_to(&mut dest);
+
+		assert_eq!(dest, v.encode());
+		assert_eq!(b.encode(), v.encode());
+		assert_eq!(b.using_encoded(|e| e.to_vec()), v.encode());
+		assert_eq!(b.encoded_size(), v.encoded_size());
+		assert_eq!(b.as_bounded_slice().encode(), v.encode());
+	}
+
+	#[test]
+	#[cfg(feature = "rlp")]
+	fn rlp_encoding_same_as_unbounded_list() {
+		let v: Vec<u64> = vec![0, 1, 0x7f, 0x80, 1 << 40];
+		let b = BoundedVec::<u64, ConstU32<5>>::try_from(v.clone()).unwrap();
+		let expected = rlp::encode_list::<u64, u64>(&v);
+
+		assert_eq!(rlp::encode(&b), expected);
+		assert_eq!(rlp::encode(&b.as_bounded_slice()), expected);
+		assert_eq!(rlp::decode::<BoundedVec<u64, ConstU32<5>>>(&expected), Ok(b));
+		assert_eq!(rlp::decode::<BoundedVec<u64, ConstU32<4>>>(&expected), Err(rlp::DecoderError::RlpListTooLong));
+	}
+
+	#[test]
+	#[cfg(feature = "rlp")]
+	fn rlp_nested_bounded_vecs() {
+		type Inner = BoundedVec<String, ConstU32<2>>;
+		type Outer = BoundedVec<Inner, ConstU32<3>>;
+
+		let inner = |items: &[&str]| Inner::try_from(items.iter().map(|s| s.to_string()).collect::<Vec<_>>()).unwrap();
+		let outer = Outer::try_from(vec![inner(&["cat", "dog"]), inner(&[]), inner(&["cow"])]).unwrap();
+		let encoded = rlp::encode(&outer);
+		assert_eq!(rlp::decode::<Outer>(&encoded), Ok(outer));
+
+		let mut stream = rlp::RlpStream::new_list(4);
+		for _ in 0..4 {
+			stream.begin_list(0);
+		}
+		assert_eq!(rlp::decode::<Outer>(&stream.out()), Err(rlp::DecoderError::RlpListTooLong));
+
+		let mut stream = rlp::RlpStream::new_list(1);
+		stream.append_list::<&str, &str>(&["cat", "dog", "cow"]);
+		assert_eq!(rlp::decode::<Outer>(&stream.out()), Err(rlp::DecoderError::RlpListTooLong));
+	}
+
+	#[test]
+	#[cfg(feature = "rlp")]
+	fn rlp_excess_items_are_not_decoded() {
+		// The third item isn't a valid `u64`, but the list is rejected for its length first.
+		let mut stream = rlp::RlpStream::new_list(3);
+		stream.append(&1u64).append(&2u64).begin_list(0);
+		let encoded = stream.out();
+
+		assert_eq!(rlp::decode::<BoundedVec<u64, ConstU32<2>>>(&encoded), Err(rlp::DecoderError::RlpListTooLong));
+		assert_eq!(rlp::decode::<BoundedVec<u64, ConstU32<3>>>(&encoded), Err(rlp::DecoderError::RlpExpectedToBeData));
+	}
+
 	#[test]
 	fn slice_truncate_from_works() {
 		let bounded = BoundedSlice::<u32, ConstU32<4>>::truncate_from(&[1, 2, 3, 4, 5]);
@@ -1144,6 +1769,67 @@ mod test {
 		assert!(bounded.try_mutate(|v| v.push(8)).is_none());
 	}
 
+	#[test]
+	fn try_mutate_in_place_works() {
+		let mut bounded: BoundedVec<u32, ConstU32<4>> = bounded_vec![1, 2, 3];
+
+		// the bound may be exceeded temporarily.
+		let removed = bounded
+			.try_mutate_in_place(|v| {
+				v.extend([4, 5]);
+				v.remove(0)
+			})
+			.unwrap();
+		assert_eq!(removed, 1);
+		assert_eq!(bounded, vec![2, 3, 4, 5]);
+
+		// exceeding the bound restores the original contents.
+		let result = bounded.try_mutate_in_place(|v| {
+			v[0] = 0;
+			v.push(6);
+			v.len()
+		});
+		assert_eq!(result, Err(()));
+		assert_eq!(bounded, vec![2, 3, 4, 5]);
+	}
+
+	#[test]
+	fn try_mutate_in_place_truncating_works() {
+		let mut bounded: BoundedVec<u32, ConstU32<4>> = bounded_vec![1, 2, 3];
+
+		let removed = bounded
+			.try_mutate_in_place_truncating(|v| {
+				v.extend([4, 5]);
+				v.remove(0)
+			})
+			.unwrap();
+		assert_eq!(removed, 1);
+		assert_eq!(bounded, vec![2, 3, 4, 5]);
+
+		// exceeding the bound truncates, but keeps the other changes.
+		let result = bounded.try_mutate_in_place_truncating(|v| {
+			v[0] = 0;
+			v.push(6);
+			v.len()
+		});
+		assert_eq!(result, Err(()));
+		assert_eq!(bounded, vec![0, 3, 4, 5]);
+	}
+
+	#[test]
+	fn try_mutate_in_place_panic_keeps_bound() {
+		let mut bounded: BoundedVec<u32, ConstU32<4>> = bounded_vec![1, 2, 3];
+
+		let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+			bounded.try_mutate_in_place(|v| {
+				v.extend([4, 5, 6]);
+				panic!("mutation failed");
+			})
+		}));
+		assert!(result.is_err());
+		assert_eq!(bounded, vec![1, 2, 3, 4]);
+	}
+
 	#[test]
 	fn slice_indexing_works() {
 		let bounded: BoundedVec<u32, ConstU32<7>> = bounded_vec![1, 2, 3, 4, 5, 6];
@@ -1227,6 +1913,201 @@ mod test {
 		assert_eq!(*b, vec![1, 2, 3]);
 	}
 
+	#[test]
+	fn try_extend_trait_works() {
+		let mut b: BoundedVec<u32, ConstU32<5>> = bounded_vec![1, 2, 3];
+
+		// works with iterators of unknown length.
+		assert!(TryExtend::try_extend(&mut b, (4..10).filter(|i| i % 2 == 0)).is_err());
+		assert_eq!(*b, vec![1, 2, 3]);
+		assert!(TryExtend::try_extend(&mut b, (4..8).filter(|i| i % 2 == 0)).is_ok());
+		assert_eq!(*b, vec![1, 2, 3, 4, 6]);
+
+		// the iterator is not consumed beyond the first item exceeding the bound.
+		let mut b: BoundedVec<u32, ConstU32<5>> = bounded_vec![1, 2, 3];
+		let mut iter = 4..;
+		assert!(TryExtend::try_extend(&mut b, iter.by_ref()).is_err());
+		assert_eq!(*b, vec![1, 2, 3]);
+		assert_eq!(iter.next(), Some(7));
+	}
+
+	#[test]
+	fn try_append_bounded_works() {
+		let mut b: BoundedVec<u32, ConstU32<5>> = bounded_vec![1, 2, 3];
+
+		// exactly up to the bound.
+		let other: BoundedVec<u32, ConstU32<2>> = bounded_vec![4, 5];
+		assert!(b.try_append_bounded(other).is_ok());
+		assert_eq!(*b, vec![1, 2, 3, 4, 5]);
+
+		// the donor is returned untouched on failure.
+		let other: BoundedVec<u32, ConstU32<10>> = bounded_vec![6, 7];
+		let other = b.try_append_bounded(other).unwrap_err();
+		assert_eq!(*other, vec![6, 7]);
+		assert_eq!(*b, vec![1, 2, 3, 4, 5]);
+
+		assert!(b.try_append_bounded(BoundedVec::<u32, ConstU32<0>>::new()).is_ok());
+		assert_eq!(*b, vec![1, 2, 3, 4, 5]);
+	}
+
+	#[test]
+	fn try_append_from_slice_works() {
+		let mut b: BoundedVec<u32, ConstU32<5>> = bounded_vec![1, 2, 3];
+
+		assert!(b.try_append_from_slice(&[4, 5, 6]).is_err());
+		assert_eq!(*b, vec![1, 2, 3]);
+		assert!(b.try_append_from_slice(&[4, 5]).is_ok());
+		assert_eq!(*b, vec![1, 2, 3, 4, 5]);
+		assert!(b.try_append_from_slice(&[]).is_ok());
+		assert!(b.try_append_from_slice(&[6]).is_err());
+		assert_eq!(*b, vec![1, 2, 3, 4, 5]);
+	}
+
+	#[test]
+	fn drain_to_bounded_works() {
+		let mut b: BoundedVec<u32, ConstU32<6>> = bounded_vec![1, 2, 3, 4, 5, 6];
+
+		let drained = b.drain_to_bounded::<_, ConstU32<2>>(1..3).unwrap();
+		assert_eq!(*drained, vec![2, 3]);
+		assert_eq!(*b, vec![1, 4, 5, 6]);
+
+		// too long for the output bound.
+		assert!(b.drain_to_bounded::<_, ConstU32<2>>(1..).is_err());
+		assert_eq!(*b, vec![1, 4, 5, 6]);
+
+		let drained = b.drain_to_bounded::<_, ConstU32<3>>(1..=3).unwrap();
+		assert_eq!(*drained, vec![4, 5, 6]);
+		assert_eq!(*b, vec![1]);
+
+		let drained = b.drain_to_bounded::<_, ConstU32<0>>(1..1).unwrap();
+		assert!(drained.is_empty());
+		assert_eq!(*b, vec![1]);
+	}
+
+	#[test]
+	fn drain_to_bounded_does_not_panic_on_bad_ranges() {
+		let mut b: BoundedVec<u32, ConstU32<6>> = bounded_vec![1, 2, 3];
+
+		assert!(b.drain_to_bounded::<_, ConstU32<6>>(2..4).is_err());
+		assert!(b.drain_to_bounded::<_, ConstU32<6>>(4..).is_err());
+		#[allow(clippy::reversed_empty_ranges)]
+		let reversed = 2..1;
+		assert!(b.drain_to_bounded::<_, ConstU32<6>>(reversed).is_err());
+		assert!(b.drain_to_bounded::<_, ConstU32<6>>(..=usize::MAX).is_err());
+		assert!(b
+			.drain_to_bounded::<_, ConstU32<6>>((Bound::Excluded(usize::MAX), Bound::Unbounded))
+			.is_err());
+		assert_eq!(*b, vec![1, 2, 3]);
+	}
+
+	#[test]
+	fn drain_all_bounded_works() {
+		let mut b: BoundedVec<u32, ConstU32<6>> = bounded_vec![1, 2, 3];
+		let drained = b.drain_all_bounded();
+		assert_eq!(*drained, vec![1, 2, 3]);
+		assert!(b.is_empty());
+
+		// moving items between two bounded queues.
+		let mut other: BoundedVec<u32, ConstU32<6>> = bounded_vec![4];
+		assert!(other.try_append_bounded(drained).is_ok());
+		assert_eq!(*other, vec![4, 1, 2, 3]);
+	}
+
+	#[test]
+	fn saturating_extender_counts_dropped_items() {
+		let mut b: BoundedVec<u32, ConstU32<4>> = bounded_vec![1, 2];
+
+		let mut extender = b.saturating_extender();
+		extender.extend([3]);
+		assert_eq!(extender.dropped(), 0);
+		extender.extend(4..8);
+		assert_eq!(extender.dropped(), 3);
+		extender.extend(core::iter::empty());
+		assert_eq!(extender.dropped(), 3);
+		extender.extend([8, 9]);
+		assert_eq!(extender.dropped(), 5);
+		assert_eq!(*b, vec![1, 2, 3, 4]);
+
+		let mut b = BoundedVec::<u32, ConstU32<0>>::new();
+		let mut extender = b.saturating_extender();
+		extender.extend([1]);
+		assert_eq!(extender.dropped(), 1);
+		assert!(b.is_empty());
+	}
+
+	#[test]
+	fn as_chunks_works() {
+		let b: BoundedVec<u8, ConstU32<8>> = bounded_vec![1, 2, 3, 4, 5, 6, 7];
+
+		let (chunks, remainder) = b.as_chunks::<2>();
+		assert_eq!(chunks, &[[1, 2], [3, 4], [5, 6]]);
+		assert_eq!(remainder, &[7]);
+		let (chunks, remainder) = b.as_chunks::<8>();
+		assert!(chunks.is_empty());
+		assert_eq!(remainder, &[1, 2, 3, 4, 5, 6, 7]);
+
+		assert_eq!(b.try_as_exact_chunks::<2>(), None);
+		assert_eq!(b.try_as_exact_chunks::<7>(), Some(&[[1, 2, 3, 4, 5, 6, 7]][..]));
+		assert_eq!(BoundedVec::<u8, ConstU32<8>>::new().try_as_exact_chunks::<3>(), Some(&[][..]));
+	}
+
+	#[test]
+	fn slice_as_chunks_keeps_lifetime() {
+		let data = [1u8, 2, 3, 4, 5, 6];
+		let chunks = {
+			let slice = BoundedSlice::<u8, ConstU32<8>>::try_from(&data[..]).unwrap();
+			slice.try_as_exact_chunks::<3>().unwrap()
+		};
+		assert_eq!(chunks, &[[1, 2, 3], [4, 5, 6]]);
+
+		let slice = BoundedSlice::<u8, ConstU32<8>>::try_from(&data[..]).unwrap();
+		assert_eq!(slice.try_as_exact_chunks::<4>(), None);
+		assert_eq!(slice.as_chunks::<4>(), (&[[1, 2, 3, 4]][..], &[5, 6][..]));
+	}
+
+	#[test]
+	#[should_panic(expected = "chunk size must be non-zero")]
+	fn as_chunks_of_zero_panics() {
+		let b: BoundedVec<u8, ConstU32<8>> = bounded_vec![1, 2];
+		let _ = b.as_chunks::<0>();
+	}
+
+	#[test]
+	#[should_panic(expected = "chunk size must be non-zero")]
+	fn try_as_exact_chunks_of_zero_panics() {
+		let b = BoundedVec::<u8, ConstU32<8>>::new();
+		let _ = b.as_bounded_slice().try_as_exact_chunks::<0>();
+	}
+
+	#[test]
+	fn try_swap_with_works() {
+		let mut small: BoundedVec<u32, ConstU32<2>> = bounded_vec![1];
+		let mut large: BoundedVec<u32, ConstU32<4>> = bounded_vec![2, 3, 4];
+
+		// `small` fits into `large`, but not the other way around.
+		assert!(small.try_swap_with(&mut large).is_err());
+		assert!(large.try_swap_with(&mut small).is_err());
+		assert_eq!(*small, vec![1]);
+		assert_eq!(*large, vec![2, 3, 4]);
+
+		large.pop();
+		assert!(large.try_swap_with(&mut small).is_ok());
+		assert_eq!(*small, vec![2, 3]);
+		assert_eq!(*large, vec![1]);
+		assert!(small.try_swap_with(&mut large).is_ok());
+		assert_eq!(*small, vec![1]);
+		assert_eq!(*large, vec![2, 3]);
+	}
+
+	#[test]
+	fn swap_with_works() {
+		let mut a: BoundedVec<u32, ConstU32<4>> = bounded_vec![1, 2, 3, 4];
+		let mut b: BoundedVec<u32, ConstU32<4>> = bounded_vec![];
+		a.swap_with(&mut b);
+		assert!(a.is_empty());
+		assert_eq!(*b, vec![1, 2, 3, 4]);
+	}
+
 	#[test]
 	fn test_serializer() {
 		let c: BoundedVec<u32, ConstU32<6>> = bounded_vec![0, 1, 2];
@@ -1330,12 +2211,132 @@ mod test {
 	}
 
 	#[test]
+	#[cfg(not(feature = "defensive-checks"))]
 	fn bounded_vec_truncate_from_works() {
 		let unbound = vec![1, 2, 3, 4, 5];
 		let bound = BoundedVec::<u32, ConstU32<3>>::truncate_from(unbound.clone());
 		assert_eq!(bound, vec![1, 2, 3]);
 	}
 
+	#[test]
+	#[cfg(feature = "defensive-checks")]
+	#[should_panic(expected = "BoundedVec::truncate_from drops items")]
+	fn bounded_vec_truncate_from_panics_with_defensive_checks() {
+		let _ = BoundedVec::<u32, ConstU32<3>>::truncate_from(vec![1, 2, 3, 4, 5]);
+	}
+
+	#[test]
+	fn hex_debug_works() {
+		let v: BoundedVec<u8, ConstU32<100>> = bounded_vec![0xde, 0xad, 0xbe, 0xef];
+		assert_eq!(format!("{:?}", v.hex_debug()), "BoundedVec(0xdeadbeef, 4)");
+		assert_eq!(format!("{:?}", v.as_bounded_slice().hex_debug()), "BoundedSlice(0xdeadbeef, 4)");
+
+		let v = BoundedVec::<u8, ConstU32<100>>::new();
+		assert_eq!(format!("{:?}", v.hex_debug()), "BoundedVec(0x, 0)");
+	}
+
+	#[test]
+	fn hex_debug_truncates() {
+		// 64 bytes are printed in full.
+		let v: BoundedVec<u8, ConstU32<100>> = vec![0xab; 64].try_into().unwrap();
+		assert_eq!(format!("{:?}", v.hex_debug()), format!("BoundedVec(0x{}, 64)", "ab".repeat(64)));
+
+		// anything longer is truncated.
+		let mut bytes = vec![0xab; 64];
+		bytes.push(0xcd);
+		let v: BoundedVec<u8, ConstU32<100>> = bytes.try_into().unwrap();
+		assert_eq!(format!("{:?}", v.hex_debug()), format!("BoundedVec(0x{}…, 65)", "ab".repeat(64)));
+		assert_eq!(
+			format!("{:?}", v.as_bounded_slice().hex_debug()),
+			format!("BoundedSlice(0x{}…, 65)", "ab".repeat(64))
+		);
+	}
+
+	#[test]
+	fn force_push_works() {
+		let mut bounded: BoundedVec<u32, ConstU32<3>> = bounded_vec![1, 2];
+		bounded.force_push(3);
+		assert_eq!(bounded, vec![1, 2, 3]);
+	}
+
+	#[test]
+	#[cfg(not(feature = "defensive-checks"))]
+	fn force_push_drops_last_item_when_full() {
+		let mut bounded: BoundedVec<u32, ConstU32<3>> = bounded_vec![1, 2, 3];
+		bounded.force_push(4);
+		assert_eq!(bounded, vec![1, 2, 4]);
+
+		let mut bounded: BoundedVec<u32, ConstU32<0>> = bounded_vec![];
+		bounded.force_push(1);
+		assert!(bounded.is_empty());
+	}
+
+	#[test]
+	#[cfg(feature = "defensive-checks")]
+	#[should_panic(expected = "BoundedVec::force_push drops items")]
+	fn force_push_panics_with_defensive_checks() {
+		let mut bounded: BoundedVec<u32, ConstU32<3>> = bounded_vec![1, 2, 3];
+		bounded.force_push(4);
+	}
+
+	#[test]
+	fn push_with_policy_works() {
+		let mut bounded: BoundedVec<u32, ConstU32<3>> = bounded_vec![1, 2];
+		assert_eq!(bounded.push_with_policy(3, OnOverflow::Error), PushOutcome::Inserted);
+		assert_eq!(bounded, vec![1, 2, 3]);
+
+		assert_eq!(bounded.push_with_policy(4, OnOverflow::Error), PushOutcome::Rejected(4));
+		assert_eq!(bounded, vec![1, 2, 3]);
+		assert_eq!(bounded.push_with_policy(4, OnOverflow::DropNewest), PushOutcome::Dropped);
+		assert_eq!(bounded, vec![1, 2, 3]);
+		assert_eq!(bounded.push_with_policy(4, OnOverflow::EvictOldest), PushOutcome::Evicted(1));
+		assert_eq!(bounded, vec![2, 3, 4]);
+		assert_eq!(bounded.push_with_policy(5, OnOverflow::EvictLast), PushOutcome::Evicted(4));
+		assert_eq!(bounded, vec![2, 3, 5]);
+	}
+
+	#[test]
+	fn push_with_policy_with_zero_bound() {
+		let mut bounded = BoundedVec::<u32, ConstU32<0>>::new();
+		assert_eq!(bounded.push_with_policy(1, OnOverflow::Error), PushOutcome::Rejected(1));
+		assert_eq!(bounded.push_with_policy(1, OnOverflow::DropNewest), PushOutcome::Dropped);
+		assert_eq!(bounded.push_with_policy(1, OnOverflow::EvictOldest), PushOutcome::Dropped);
+		assert_eq!(bounded.push_with_policy(1, OnOverflow::EvictLast), PushOutcome::Dropped);
+		assert!(bounded.is_empty());
+	}
+
+	#[test]
+	fn push_with_policy_can_be_parameterized() {
+		fn enqueue_all<S: BoundValue>(queue: &mut BoundedVec<u32, S>, items: &[u32], policy: OnOverflow) -> usize {
+			items
+				.iter()
+				.filter(|item| !matches!(queue.push_with_policy(**item, policy), PushOutcome::Inserted))
+				.count()
+		}
+
+		let mut queue = BoundedVec::<u32, ConstU32<2>>::new();
+		assert_eq!(enqueue_all(&mut queue, &[1, 2, 3, 4], OnOverflow::EvictOldest), 2);
+		assert_eq!(queue, vec![3, 4]);
+
+		let mut queue = BoundedVec::<u32, ConstU32<2>>::new();
+		assert_eq!(enqueue_all(&mut queue, &[1, 2, 3, 4], OnOverflow::DropNewest), 2);
+		assert_eq!(queue, vec![1, 2]);
+	}
+
+	#[test]
+	#[cfg(not(feature = "defensive-checks"))]
+	fn invariant_is_not_checked_without_defensive_checks() {
+		let bounded = BoundedVec::<u32, ConstU32<1>>::unchecked_from(vec![1, 2]);
+		assert_eq!(bounded.len(), 2);
+	}
+
+	#[test]
+	#[cfg(all(feature = "defensive-checks", debug_assertions))]
+	#[should_panic(expected = "BoundedVec exceeds its bound")]
+	fn invariant_is_checked_with_defensive_checks() {
+		let _ = BoundedVec::<u32, ConstU32<1>>::unchecked_from(vec![1, 2]);
+	}
+
 	#[test]
 	fn bounded_slice_truncate_from_works() {
 		let unbound = [1, 2, 3, 4, 5];
@@ -1404,6 +2405,44 @@ mod test {
 		let _foo = Foo { bar: 42, slice: BoundedSlice::truncate_from(&[0, 1][..]), map: BoundedVec::default() };
 	}
 
+	#[test]
+	#[cfg(target_pointer_width = "64")]
+	fn bound_larger_than_u32_works() {
+		type Bound = crate::ConstU64<{ u32::MAX as u64 + 10 }>;
+		assert_eq!(BoundedVec::<u8, Bound>::bound(), u32::MAX as usize + 10);
+
+		let mut b: BoundedVec<u8, Bound> = bounded_vec![1, 2, 3];
+		b.try_push(4).unwrap();
+		assert_eq!(b, vec![1, 2, 3, 4]);
+		assert_eq!(BoundedSlice::<u8, Bound>::truncate_from(&b).len(), 4);
+	}
+
+	#[test]
+	fn wide_bound_works() {
+		crate::parameter_types! {
+			pub const U64Bound: u64 = 3;
+			pub const UsizeBound: usize = 3;
+		}
+		assert_eq!(BoundedVec::<u32, crate::WideBound<U64Bound>>::bound(), 3);
+		assert_eq!(BoundedVec::<u32, crate::WideBound<UsizeBound, usize>>::bound(), 3);
+
+		let mut b: BoundedVec<u32, crate::WideBound<U64Bound>> = bounded_vec![1, 2, 3];
+		assert_eq!(b.try_push(4), Err(4));
+	}
+
+	#[test]
+	#[cfg(all(feature = "scale-codec", target_pointer_width = "64"))]
+	fn bound_larger_than_u32_codec_works() {
+		use scale_codec::MaxEncodedLen;
+
+		type Bound = crate::ConstU64<{ u32::MAX as u64 + 10 }>;
+		// The length prefix saturates at `Compact(u32::MAX)`, which is 5 bytes.
+		assert_eq!(BoundedVec::<u8, Bound>::max_encoded_len(), 5 + u32::MAX as usize + 10);
+
+		let v: Vec<u8> = vec![1, 2, 3];
+		assert_eq!(BoundedVec::<u8, Bound>::decode(&mut &v.encode()[..]).unwrap(), v);
+	}
+
 	#[test]
 	fn is_full_works() {
 		let mut bounded: BoundedVec<u32, ConstU32<4>> = bounded_vec![1, 2, 3];
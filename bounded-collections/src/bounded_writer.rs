@@ -0,0 +1,145 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A [`core::fmt::Write`] adapter for bounded byte buffers.
+
+use crate::{BoundValue, BoundedVec};
+use core::fmt;
+
+/// Formats text into a [`BoundedVec<u8, S>`].
+///
+/// Appends as much of the text as fits into the buffer, without ever splitting a multi-byte UTF-8
+/// character. Once some text didn't fit, this and every later write returns [`fmt::Error`], even if
+/// it would fit into the remaining space, so the written text is always a prefix of the formatted one.
+///
+/// ```
+/// # use bounded_collections::{BoundedVec, BoundedWriter, ConstU32};
+/// use core::fmt::Write;
+///
+/// let mut buf = BoundedVec::<u8, ConstU32<8>>::new();
+/// let mut writer = BoundedWriter::new(&mut buf);
+/// assert!(write!(writer, "code {}", 42).is_ok());
+/// assert!(write!(writer, "!!!").is_err());
+/// assert_eq!(writer.written(), "code 42!");
+/// ```
+pub struct BoundedWriter<'a, S> {
+	buf: &'a mut BoundedVec<u8, S>,
+	// Where the text written by this writer starts in `buf`.
+	start: usize,
+	// Whether some text didn't fit.
+	truncated: bool,
+}
+
+impl<'a, S: BoundValue> BoundedWriter<'a, S> {
+	/// Create a writer appending to `buf`.
+	pub fn new(buf: &'a mut BoundedVec<u8, S>) -> Self {
+		let start = buf.len();
+		Self { buf, start, truncated: false }
+	}
+
+	/// Whether some text didn't fit into the buffer.
+	pub fn is_truncated(&self) -> bool {
+		self.truncated
+	}
+
+	/// The text written so far by this writer.
+	///
+	/// Anything that was in the buffer before the writer was created is not included.
+	pub fn written(&self) -> &str {
+		core::str::from_utf8(&self.buf[self.start..]).expect("only complete characters are ever written; qed")
+	}
+}
+
+impl<'a, S: BoundValue> fmt::Write for BoundedWriter<'a, S> {
+	fn write_str(&mut self, s: &str) -> fmt::Result {
+		if self.truncated {
+			return Err(fmt::Error)
+		}
+		let remaining = BoundedVec::<u8, S>::bound().saturating_sub(self.buf.len());
+		if s.len() <= remaining {
+			self.buf.0.extend_from_slice(s.as_bytes());
+			return Ok(())
+		}
+
+		// `0` is always a char boundary, so this terminates.
+		let mut end = remaining;
+		while !s.is_char_boundary(end) {
+			end -= 1;
+		}
+		self.buf.0.extend_from_slice(&s.as_bytes()[..end]);
+		self.truncated = true;
+		Err(fmt::Error)
+	}
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test {
+	use super::*;
+	use crate::{bounded_vec, ConstU32};
+	use core::fmt::Write;
+
+	#[test]
+	fn write_within_bound_works() {
+		let mut buf = BoundedVec::<u8, ConstU32<16>>::new();
+		let mut writer = BoundedWriter::new(&mut buf);
+		write!(writer, "a-{}", 1).unwrap();
+		writer.write_str("🦀").unwrap();
+		assert_eq!(writer.written(), "a-1🦀");
+		assert_eq!(buf, "a-1🦀".as_bytes().to_vec());
+	}
+
+	#[test]
+	fn write_truncates_at_bound() {
+		let mut buf = BoundedVec::<u8, ConstU32<4>>::new();
+		let mut writer = BoundedWriter::new(&mut buf);
+		assert_eq!(writer.write_str("abcdef"), Err(fmt::Error));
+		assert_eq!(writer.written(), "abcd");
+		assert!(writer.is_truncated());
+		// once full, nothing more is written.
+		assert_eq!(writer.write_str("g"), Err(fmt::Error));
+		assert_eq!(writer.written(), "abcd");
+	}
+
+	#[test]
+	fn write_fails_after_overflow() {
+		// the crab takes four bytes, but only two are left.
+		let mut buf = BoundedVec::<u8, ConstU32<4>>::new();
+		let mut writer = BoundedWriter::new(&mut buf);
+		assert!(!writer.is_truncated());
+		assert_eq!(writer.write_str("ab🦀"), Err(fmt::Error));
+		// a shorter character would fit, but text after the truncated one is not written.
+		assert_eq!(writer.write_str("é"), Err(fmt::Error));
+		assert_eq!(writer.write_str(""), Err(fmt::Error));
+		assert_eq!(writer.written(), "ab");
+		assert!(writer.is_truncated());
+	}
+
+	#[test]
+	fn write_does_not_split_characters() {
+		// the crab takes four bytes, but only two are left.
+		let mut buf = BoundedVec::<u8, ConstU32<4>>::new();
+		let mut writer = BoundedWriter::new(&mut buf);
+		assert_eq!(writer.write_str("ab🦀"), Err(fmt::Error));
+		assert_eq!(writer.written(), "ab");
+
+		// a character which exactly fills the buffer is written.
+		let mut buf = BoundedVec::<u8, ConstU32<6>>::new();
+		let mut writer = BoundedWriter::new(&mut buf);
+		assert!(writer.write_str("ab🦀").is_ok());
+		assert_eq!(writer.written(), "ab🦀");
+	}
+
+	#[test]
+	fn written_skips_existing_contents() {
+		let mut buf: BoundedVec<u8, ConstU32<4>> = bounded_vec![0xff, 0xfe];
+		let mut writer = BoundedWriter::new(&mut buf);
+		assert_eq!(writer.write_fmt(format_args!("{}", 100 + 23)), Err(fmt::Error));
+		assert_eq!(writer.written(), "12");
+		assert_eq!(buf, vec![0xff, 0xfe, b'1', b'2']);
+	}
+}
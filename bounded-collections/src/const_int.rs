@@ -16,10 +16,18 @@ trait ConstBounded<T> {
 }
 
 macro_rules! impl_const_bounded {
-	($bound:ty, $t:ty) => {
-		impl ConstBounded<$bound> for $t {
-			const MIN: $bound = <$t>::MIN as $bound;
-			const MAX: $bound = <$t>::MAX as $bound;
+	(u128, $t:ty) => {
+		impl ConstBounded<u128> for $t {
+			// Negative values can never be reached from an unsigned integer.
+			const MIN: u128 = if (<$t>::MIN as i128) < 0 { 0 } else { <$t>::MIN as u128 };
+			const MAX: u128 = <$t>::MAX as u128;
+		}
+	};
+	(i128, $t:ty) => {
+		impl ConstBounded<i128> for $t {
+			const MIN: i128 = <$t>::MIN as i128;
+			// Values above `i128::MAX` can never be reached from a signed integer.
+			const MAX: i128 = if (<$t>::MAX as u128) > (i128::MAX as u128) { i128::MAX } else { <$t>::MAX as i128 };
 		}
 	};
 }
@@ -30,12 +38,25 @@ impl_const_bounded!(u128, u32);
 impl_const_bounded!(u128, u64);
 impl_const_bounded!(u128, u128);
 impl_const_bounded!(u128, usize);
+impl_const_bounded!(u128, i8);
+impl_const_bounded!(u128, i16);
+impl_const_bounded!(u128, i32);
+impl_const_bounded!(u128, i64);
+impl_const_bounded!(u128, i128);
+impl_const_bounded!(u128, isize);
 
 impl_const_bounded!(i128, i8);
 impl_const_bounded!(i128, i16);
 impl_const_bounded!(i128, i32);
 impl_const_bounded!(i128, i64);
 impl_const_bounded!(i128, i128);
+impl_const_bounded!(i128, isize);
+impl_const_bounded!(i128, u8);
+impl_const_bounded!(i128, u16);
+impl_const_bounded!(i128, u32);
+impl_const_bounded!(i128, u64);
+impl_const_bounded!(i128, u128);
+impl_const_bounded!(i128, usize);
 
 // Check whether a unsigned integer is within the bounds of a type.
 struct CheckOverflowU128<T: ConstBounded<u128>, const N: u128>(PhantomData<T>);
@@ -53,6 +74,25 @@ impl<T: ConstBounded<i128>, const N: i128> CheckOverflowI128<T, N> {
 
 /// Const getter for unsigned integers.
 ///
+/// Implements [`Get`] for every integer type, so the same constant can be used wherever any
+/// integer width is expected:
+///
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// # use bounded_collections::{BoundedVec, ConstUint, Get};
+/// type MaxItems = ConstUint<16>;
+///
+/// fn max_weight<W: Get<u64>>() -> u64 {
+///     W::get() * 1_000
+/// }
+///
+/// // As the `Get<u32>` bound of a bounded vec.
+/// assert_eq!(BoundedVec::<u8, MaxItems>::bound(), 16);
+/// // As a `Get<u64>` parameter.
+/// assert_eq!(max_weight::<MaxItems>(), 16_000);
+/// # }
+/// ```
+///
 /// # Compile-time checks
 ///
 /// ```compile_fail
@@ -60,33 +100,46 @@ impl<T: ConstBounded<i128>, const N: i128> CheckOverflowI128<T, N> {
 /// let _ = <ConstUint<256> as Get<u8>>::get();
 /// ```
 #[derive(Default, Clone)]
+#[cfg_attr(feature = "scale-codec", derive(scale_info::TypeInfo))]
 pub struct ConstUint<const N: u128>;
 
 impl<const N: u128> core::fmt::Debug for ConstUint<N> {
 	fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
-		fmt.write_str(&alloc::format!("ConstUint<{}>", N))
+		write!(fmt, "ConstUint<{}>", N)
 	}
 }
 
 impl<const N: u128> TypedGet for ConstUint<N> {
 	type Type = u128;
+	const CONST_VALUE: Option<u128> = Some(N);
 	fn get() -> u128 {
 		N
 	}
 }
 
 /// Const getter for signed integers.
+///
+/// Like [`ConstUint`], implements [`Get`] for every integer type the value fits in.
+///
+/// # Compile-time checks
+///
+/// ```compile_fail
+/// # use bounded_collections::{ConstInt, Get};
+/// let _ = <ConstInt<-1> as Get<u32>>::get();
+/// ```
 #[derive(Default, Clone)]
+#[cfg_attr(feature = "scale-codec", derive(scale_info::TypeInfo))]
 pub struct ConstInt<const N: i128>;
 
 impl<const N: i128> core::fmt::Debug for ConstInt<N> {
 	fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
-		fmt.write_str(&alloc::format!("ConstInt<{}>", N))
+		write!(fmt, "ConstInt<{}>", N)
 	}
 }
 
 impl<const N: i128> TypedGet for ConstInt<N> {
 	type Type = i128;
+	const CONST_VALUE: Option<i128> = Some(N);
 	fn get() -> i128 {
 		N
 	}
@@ -95,6 +148,11 @@ impl<const N: i128> TypedGet for ConstInt<N> {
 macro_rules! impl_const_int {
 	($t:ident, $check:ident, $bound:ty, $target:ty) => {
 		impl<const N: $bound> Get<$target> for $t<N> {
+			const CONST_VALUE: Option<$target> = {
+				let _ = <$check<$target, N>>::ASSERTION;
+				Some(N as $target)
+			};
+
 			fn get() -> $target {
 				let _ = <$check<$target, N>>::ASSERTION;
 				N as $target
@@ -115,12 +173,25 @@ impl_const_int!(ConstUint, CheckOverflowU128, u128, u32);
 impl_const_int!(ConstUint, CheckOverflowU128, u128, u64);
 impl_const_int!(ConstUint, CheckOverflowU128, u128, u128);
 impl_const_int!(ConstUint, CheckOverflowU128, u128, usize);
+impl_const_int!(ConstUint, CheckOverflowU128, u128, i8);
+impl_const_int!(ConstUint, CheckOverflowU128, u128, i16);
+impl_const_int!(ConstUint, CheckOverflowU128, u128, i32);
+impl_const_int!(ConstUint, CheckOverflowU128, u128, i64);
+impl_const_int!(ConstUint, CheckOverflowU128, u128, i128);
+impl_const_int!(ConstUint, CheckOverflowU128, u128, isize);
 
 impl_const_int!(ConstInt, CheckOverflowI128, i128, i8);
 impl_const_int!(ConstInt, CheckOverflowI128, i128, i16);
 impl_const_int!(ConstInt, CheckOverflowI128, i128, i32);
 impl_const_int!(ConstInt, CheckOverflowI128, i128, i64);
 impl_const_int!(ConstInt, CheckOverflowI128, i128, i128);
+impl_const_int!(ConstInt, CheckOverflowI128, i128, isize);
+impl_const_int!(ConstInt, CheckOverflowI128, i128, u8);
+impl_const_int!(ConstInt, CheckOverflowI128, i128, u16);
+impl_const_int!(ConstInt, CheckOverflowI128, i128, u32);
+impl_const_int!(ConstInt, CheckOverflowI128, i128, u64);
+impl_const_int!(ConstInt, CheckOverflowI128, i128, u128);
+impl_const_int!(ConstInt, CheckOverflowI128, i128, usize);
 
 #[cfg(test)]
 mod tests {
@@ -135,11 +206,31 @@ mod tests {
 		assert_eq!(<ConstUint<42> as Get<u64>>::get(), 42);
 		assert_eq!(<ConstUint<42> as Get<u128>>::get(), 42);
 		assert_eq!(<ConstUint<42> as Get<usize>>::get(), 42);
+		assert_eq!(<ConstUint<42> as Get<i8>>::get(), 42);
+		assert_eq!(<ConstUint<42> as Get<i128>>::get(), 42);
+		assert_eq!(<ConstUint<42> as Get<isize>>::get(), 42);
+		assert_eq!(<ConstUint<{ u128::MAX }> as Get<u128>>::get(), u128::MAX);
 		assert_eq!(<ConstUint<42> as TypedGet>::get(), 42);
 		// compile-time error
 		// assert_eq!(<ConstUint<256> as Get<u8>>::get() as u128, 256);
 	}
 
+	#[test]
+	fn same_constant_works_for_every_width() {
+		fn as_u32<S: Get<u32>>() -> u32 {
+			S::get()
+		}
+		fn as_u64<S: Get<u64>>() -> u64 {
+			S::get()
+		}
+
+		assert_eq!(as_u32::<ConstUint<7>>(), 7);
+		assert_eq!(as_u64::<ConstUint<7>>(), 7);
+		assert_eq!(as_u32::<ConstInt<7>>(), 7);
+		assert_eq!(as_u64::<ConstInt<7>>(), 7);
+		assert_eq!(<ConstUint<7> as Get<u32>>::CONST_VALUE, Some(7));
+	}
+
 	#[test]
 	fn const_int_works() {
 		assert_eq!(<ConstInt<-42> as Get<i8>>::get(), -42);
@@ -148,6 +239,21 @@ mod tests {
 		assert_eq!(<ConstInt<-42> as Get<i32>>::get(), -42);
 		assert_eq!(<ConstInt<-42> as Get<i64>>::get(), -42);
 		assert_eq!(<ConstInt<-42> as Get<i128>>::get(), -42);
+		assert_eq!(<ConstInt<-42> as Get<isize>>::get(), -42);
+		assert_eq!(<ConstInt<42> as Get<u8>>::get(), 42);
+		assert_eq!(<ConstInt<42> as Get<u128>>::get(), 42);
+		assert_eq!(<ConstInt<42> as Get<usize>>::get(), 42);
+		assert_eq!(<ConstInt<{ i128::MIN }> as Get<i128>>::get(), i128::MIN);
 		assert_eq!(<ConstInt<-42> as TypedGet>::get(), -42);
 	}
+
+	#[test]
+	#[cfg(feature = "scale-codec")]
+	fn type_info_works() {
+		use scale_info::TypeInfo;
+
+		assert_eq!(ConstUint::<42>::type_info().path.ident(), Some("ConstUint"));
+		assert_eq!(ConstInt::<-42>::type_info().path.ident(), Some("ConstInt"));
+		assert_eq!(crate::ConstUsize::<42>::type_info().path.ident(), Some("ConstUsize"));
+	}
 }
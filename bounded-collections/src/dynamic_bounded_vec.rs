@@ -0,0 +1,396 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A bounded vector whose bound is stored in the instance rather than in its type.
+
+use crate::{BoundValue, BoundedVec, TryExtend};
+use alloc::vec::Vec;
+use core::ops::{Deref, Index, IndexMut};
+
+/// A bounded vector whose bound is chosen at runtime.
+///
+/// This is useful for limits which are not known at compile time, such as values that can be
+/// changed by governance. Unlike [`BoundedVec`], reading the bound never has to consult anything
+/// outside of the instance.
+///
+/// The bound is part of the encoding: a `Compact<u32>` bound is followed by the items, encoded like a
+/// `Vec<T>`. Since the bound is not known up front, this type does not implement `MaxEncodedLen`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "scale-codec", derive(scale_info::TypeInfo))]
+pub struct DynamicBoundedVec<T> {
+	#[cfg_attr(feature = "scale-codec", codec(compact))]
+	bound: u32,
+	items: Vec<T>,
+}
+
+impl<T> DynamicBoundedVec<T> {
+	/// Create `Self` with no items and the given `bound`.
+	pub fn new(bound: u32) -> Self {
+		Self { bound, items: Vec::new() }
+	}
+
+	/// Create `Self` from `items` if there are no more than `bound` of them, otherwise return
+	/// `items` back.
+	pub fn try_from_vec(items: Vec<T>, bound: u32) -> Result<Self, Vec<T>> {
+		if items.len() <= bound as usize {
+			Ok(Self { bound, items })
+		} else {
+			Err(items)
+		}
+	}
+
+	/// Consume and truncate the vector `items` in order to create a new instance of `Self` with the
+	/// given `bound`.
+	pub fn truncate_from(mut items: Vec<T>, bound: u32) -> Self {
+		items.truncate(bound as usize);
+		Self { bound, items }
+	}
+
+	/// Get the bound of this instance.
+	pub fn bound(&self) -> u32 {
+		self.bound
+	}
+
+	/// Returns true if this collection is full.
+	pub fn is_full(&self) -> bool {
+		self.items.len() >= self.bound as usize
+	}
+
+	/// Change the bound of this instance to `new_bound`.
+	///
+	/// Returns an `Err` (and is a noop) if the current length exceeds `new_bound`.
+	pub fn try_rebind(&mut self, new_bound: u32) -> Result<(), ()> {
+		if self.items.len() <= new_bound as usize {
+			self.bound = new_bound;
+			Ok(())
+		} else {
+			Err(())
+		}
+	}
+
+	/// Consume self, and return the inner `Vec`.
+	pub fn into_inner(self) -> Vec<T> {
+		self.items
+	}
+
+	/// Exactly the same semantics as `Vec::clear`.
+	pub fn clear(&mut self) {
+		self.items.clear()
+	}
+
+	/// Exactly the same semantics as `Vec::remove`.
+	///
+	/// # Panics
+	///
+	/// Panics if `index` is out of bounds.
+	pub fn remove(&mut self, index: usize) -> T {
+		self.items.remove(index)
+	}
+
+	/// Exactly the same semantics as `Vec::retain`.
+	pub fn retain<F: FnMut(&T) -> bool>(&mut self, f: F) {
+		self.items.retain(f)
+	}
+
+	/// Exactly the same semantics as `Vec::truncate`.
+	pub fn truncate(&mut self, s: usize) {
+		self.items.truncate(s);
+	}
+
+	/// Exactly the same semantics as `Vec::pop`.
+	pub fn pop(&mut self) -> Option<T> {
+		self.items.pop()
+	}
+
+	/// Exactly the same semantics as [`slice::iter_mut`].
+	pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, T> {
+		self.items.iter_mut()
+	}
+
+	/// Exactly the same semantics as [`Vec::push`], but returns an `Err` (and is a noop) if the
+	/// new length of the vector exceeds the bound.
+	pub fn try_push(&mut self, element: T) -> Result<(), T> {
+		if self.is_full() {
+			Err(element)
+		} else {
+			self.items.push(element);
+			Ok(())
+		}
+	}
+
+	/// Exactly the same semantics as [`Vec::insert`], but returns an `Err` (and is a noop) if the
+	/// new length of the vector exceeds the bound.
+	///
+	/// # Panics
+	///
+	/// Panics if `index > len`.
+	pub fn try_insert(&mut self, index: usize, element: T) -> Result<(), T> {
+		if self.is_full() {
+			Err(element)
+		} else {
+			self.items.insert(index, element);
+			Ok(())
+		}
+	}
+
+	/// Exactly the same semantics as [`Vec::extend`], but returns an error and does nothing if the
+	/// length of the outcome is larger than the bound.
+	///
+	/// See [`TryExtend`] for extending with iterators of unknown length.
+	pub fn try_extend(&mut self, with: impl IntoIterator<Item = T> + ExactSizeIterator) -> Result<(), ()> {
+		if with.len().saturating_add(self.items.len()) <= self.bound as usize {
+			TryExtend::try_extend(self, with)
+		} else {
+			Err(())
+		}
+	}
+}
+
+impl<T> TryExtend<T> for DynamicBoundedVec<T> {
+	type Error = ();
+
+	fn try_extend<I: IntoIterator<Item = T>>(&mut self, iter: I) -> Result<(), ()> {
+		crate::bounded_vec::try_extend_vec(&mut self.items, self.bound as usize, iter)
+	}
+}
+
+// Will allow for all immutable operations of `Vec<T>` on `DynamicBoundedVec<T>`.
+impl<T> Deref for DynamicBoundedVec<T> {
+	type Target = Vec<T>;
+
+	fn deref(&self) -> &Self::Target {
+		&self.items
+	}
+}
+
+impl<T> AsRef<[T]> for DynamicBoundedVec<T> {
+	fn as_ref(&self) -> &[T] {
+		&self.items
+	}
+}
+
+impl<T> AsMut<[T]> for DynamicBoundedVec<T> {
+	fn as_mut(&mut self) -> &mut [T] {
+		&mut self.items
+	}
+}
+
+// Allows for indexing similar to a normal `Vec`. Can panic if out of bound.
+impl<T, I> Index<I> for DynamicBoundedVec<T>
+where
+	I: core::slice::SliceIndex<[T]>,
+{
+	type Output = I::Output;
+
+	#[inline]
+	fn index(&self, index: I) -> &Self::Output {
+		self.items.index(index)
+	}
+}
+
+impl<T, I> IndexMut<I> for DynamicBoundedVec<T>
+where
+	I: core::slice::SliceIndex<[T]>,
+{
+	#[inline]
+	fn index_mut(&mut self, index: I) -> &mut Self::Output {
+		self.items.index_mut(index)
+	}
+}
+
+impl<T> core::iter::IntoIterator for DynamicBoundedVec<T> {
+	type Item = T;
+	type IntoIter = alloc::vec::IntoIter<T>;
+	fn into_iter(self) -> Self::IntoIter {
+		self.items.into_iter()
+	}
+}
+
+impl<'a, T> core::iter::IntoIterator for &'a DynamicBoundedVec<T> {
+	type Item = &'a T;
+	type IntoIter = core::slice::Iter<'a, T>;
+	fn into_iter(self) -> Self::IntoIter {
+		self.items.iter()
+	}
+}
+
+impl<T> From<DynamicBoundedVec<T>> for Vec<T> {
+	fn from(x: DynamicBoundedVec<T>) -> Vec<T> {
+		x.items
+	}
+}
+
+impl<T, S: BoundValue> TryFrom<BoundedVec<T, S>> for DynamicBoundedVec<T> {
+	type Error = BoundedVec<T, S>;
+
+	/// Fails if the bound `S` does not fit in a `u32`.
+	fn try_from(t: BoundedVec<T, S>) -> Result<Self, Self::Error> {
+		match u32::try_from(S::bound_u64()) {
+			Ok(bound) => Ok(Self { bound, items: t.into_inner() }),
+			Err(_) => Err(t),
+		}
+	}
+}
+
+impl<T, S: BoundValue> TryFrom<DynamicBoundedVec<T>> for BoundedVec<T, S> {
+	type Error = DynamicBoundedVec<T>;
+
+	/// Fails if the bound of `t` is not the same as `S`.
+	fn try_from(t: DynamicBoundedVec<T>) -> Result<Self, Self::Error> {
+		if u64::from(t.bound) == S::bound_u64() {
+			// The length is within the bound, which is the same as `S`.
+			Ok(BoundedVec::unchecked_from(t.items))
+		} else {
+			Err(t)
+		}
+	}
+}
+
+impl<T: PartialEq, S: BoundValue> PartialEq<BoundedVec<T, S>> for DynamicBoundedVec<T> {
+	fn eq(&self, other: &BoundedVec<T, S>) -> bool {
+		self.items == **other
+	}
+}
+
+impl<T: PartialEq> PartialEq<Vec<T>> for DynamicBoundedVec<T> {
+	fn eq(&self, other: &Vec<T>) -> bool {
+		&self.items == other
+	}
+}
+
+#[cfg(any(feature = "scale-codec", feature = "jam-codec"))]
+macro_rules! codec_impl {
+	($codec:ident) => {
+		use super::*;
+
+		use $codec::{decode_vec_with_len, Compact, Decode, DecodeWithMemTracking, Encode, Error, Input, Output};
+
+		impl<T: Encode> Encode for DynamicBoundedVec<T> {
+			fn size_hint(&self) -> usize {
+				Compact(self.bound).size_hint().saturating_add(self.items.size_hint())
+			}
+
+			fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+				Compact(self.bound).encode_to(dest);
+				self.items.encode_to(dest);
+			}
+		}
+
+		impl<T: Decode> Decode for DynamicBoundedVec<T> {
+			fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+				let bound: u32 = <Compact<u32>>::decode(input)?.into();
+				// Same as the underlying implementation for `Decode` on `Vec`, except we fail early if
+				// the len is too big.
+				let len: u32 = <Compact<u32>>::decode(input)?.into();
+				if len > bound {
+					return Err("DynamicBoundedVec exceeds its limit".into());
+				}
+				let items = decode_vec_with_len(input, len as usize)?;
+				Ok(Self { bound, items })
+			}
+		}
+
+		impl<T: DecodeWithMemTracking> DecodeWithMemTracking for DynamicBoundedVec<T> {}
+	};
+}
+
+#[cfg(feature = "scale-codec")]
+mod scale_codec_impl {
+	codec_impl!(scale_codec);
+}
+
+#[cfg(feature = "jam-codec")]
+mod jam_codec_impl {
+	codec_impl!(jam_codec);
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test {
+	use super::*;
+	use crate::{bounded_vec, ConstU32};
+
+	#[test]
+	fn try_push_and_insert_respect_bound() {
+		let mut v = DynamicBoundedVec::new(3);
+		v.try_push(1).unwrap();
+		v.try_push(3).unwrap();
+		v.try_insert(1, 2).unwrap();
+		assert!(v.is_full());
+		assert_eq!(v.try_push(4), Err(4));
+		assert_eq!(v.try_insert(0, 0), Err(0));
+		assert_eq!(v, vec![1, 2, 3]);
+	}
+
+	#[test]
+	fn try_extend_respects_bound() {
+		let mut v = DynamicBoundedVec::new(3);
+		assert!(v.try_extend(vec![1, 2].into_iter()).is_ok());
+		assert!(v.try_extend(vec![3, 4].into_iter()).is_err());
+		assert_eq!(v, vec![1, 2]);
+		assert!(v.try_extend(vec![3].into_iter()).is_ok());
+		assert_eq!(v, vec![1, 2, 3]);
+	}
+
+	#[test]
+	fn try_extend_trait_works() {
+		let mut v = DynamicBoundedVec::new(3);
+		assert!(TryExtend::try_extend(&mut v, (1..).take_while(|i| *i < 5)).is_err());
+		assert!(v.is_empty());
+		assert!(TryExtend::try_extend(&mut v, (1..).take_while(|i| *i < 4)).is_ok());
+		assert_eq!(v, vec![1, 2, 3]);
+	}
+
+	#[test]
+	fn try_rebind_works() {
+		let mut v = DynamicBoundedVec::try_from_vec(vec![1, 2, 3], 4).unwrap();
+		assert_eq!(v.try_rebind(2), Err(()));
+		assert_eq!(v.bound(), 4);
+		assert_eq!(v.try_rebind(3), Ok(()));
+		assert_eq!(v.bound(), 3);
+		assert!(v.is_full());
+		assert_eq!(v.try_rebind(10), Ok(()));
+		v.try_push(4).unwrap();
+		assert_eq!(v, vec![1, 2, 3, 4]);
+	}
+
+	#[test]
+	fn construction_works() {
+		assert_eq!(DynamicBoundedVec::try_from_vec(vec![1, 2, 3], 2), Err(vec![1, 2, 3]));
+		let v = DynamicBoundedVec::truncate_from(vec![1, 2, 3], 2);
+		assert_eq!(v, vec![1, 2]);
+		assert_eq!(v.bound(), 2);
+	}
+
+	#[test]
+	fn bounded_vec_conversions_work() {
+		let b: BoundedVec<u32, ConstU32<4>> = bounded_vec![1, 2, 3];
+		let d = DynamicBoundedVec::try_from(b.clone()).unwrap();
+		assert_eq!(d.bound(), 4);
+		assert_eq!(d, b);
+
+		assert_eq!(BoundedVec::<u32, ConstU32<4>>::try_from(d.clone()).unwrap(), b);
+		assert_eq!(BoundedVec::<u32, ConstU32<5>>::try_from(d.clone()), Err(d));
+	}
+
+	#[test]
+	#[cfg(feature = "scale-codec")]
+	fn codec_works() {
+		use scale_codec::{Compact, Decode, Encode};
+
+		let v = DynamicBoundedVec::try_from_vec(vec![1u32, 2, 3], 5).unwrap();
+		let encoded = v.encode();
+		assert_eq!(encoded, (Compact(5u32), vec![1u32, 2, 3]).encode());
+		assert_eq!(DynamicBoundedVec::<u32>::decode(&mut &encoded[..]).unwrap(), v);
+
+		let too_long = (Compact(2u32), vec![1u32, 2, 3]).encode();
+		assert_eq!(
+			DynamicBoundedVec::<u32>::decode(&mut &too_long[..]),
+			Err("DynamicBoundedVec exceeds its limit".into()),
+		);
+	}
+}
@@ -0,0 +1,165 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Estimating the heap memory used by bounded collections.
+
+use crate::{BoundedBTreeMap, BoundedBTreeSet, BoundedVec, WeakBoundedVec};
+use alloc::{
+	boxed::Box,
+	collections::{BTreeMap, BTreeSet},
+	string::String,
+	vec::Vec,
+};
+use core::mem::size_of;
+
+/// Estimate the heap memory owned by a value, excluding the value itself.
+///
+/// Vectors account for their allocated capacity rather than their length, and recurse into their
+/// elements. B-tree collections don't expose their allocations, so they are estimated from the
+/// size of their entries.
+pub trait EstimateHeapSize {
+	/// The estimated number of heap bytes owned by self.
+	fn estimate_heap_size(&self) -> usize;
+}
+
+macro_rules! impl_no_heap {
+	($($t:ty),*) => {
+		$(
+			impl EstimateHeapSize for $t {
+				fn estimate_heap_size(&self) -> usize {
+					0
+				}
+			}
+		)*
+	};
+}
+
+impl_no_heap!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, bool, char, ());
+
+impl<T: EstimateHeapSize, const N: usize> EstimateHeapSize for [T; N] {
+	fn estimate_heap_size(&self) -> usize {
+		elements_heap_size(self.iter())
+	}
+}
+
+impl<T: EstimateHeapSize> EstimateHeapSize for Option<T> {
+	fn estimate_heap_size(&self) -> usize {
+		self.as_ref().map_or(0, T::estimate_heap_size)
+	}
+}
+
+impl<A: EstimateHeapSize, B: EstimateHeapSize> EstimateHeapSize for (A, B) {
+	fn estimate_heap_size(&self) -> usize {
+		self.0.estimate_heap_size().saturating_add(self.1.estimate_heap_size())
+	}
+}
+
+impl<T: EstimateHeapSize> EstimateHeapSize for Box<T> {
+	fn estimate_heap_size(&self) -> usize {
+		size_of::<T>().saturating_add((**self).estimate_heap_size())
+	}
+}
+
+impl EstimateHeapSize for String {
+	fn estimate_heap_size(&self) -> usize {
+		self.capacity()
+	}
+}
+
+impl<T: EstimateHeapSize> EstimateHeapSize for Vec<T> {
+	fn estimate_heap_size(&self) -> usize {
+		self.capacity()
+			.saturating_mul(size_of::<T>())
+			.saturating_add(elements_heap_size(self.iter()))
+	}
+}
+
+impl<K: EstimateHeapSize, V: EstimateHeapSize> EstimateHeapSize for BTreeMap<K, V> {
+	fn estimate_heap_size(&self) -> usize {
+		self.len()
+			.saturating_mul(size_of::<K>().saturating_add(size_of::<V>()))
+			.saturating_add(elements_heap_size(self.keys()))
+			.saturating_add(elements_heap_size(self.values()))
+	}
+}
+
+impl<T: EstimateHeapSize> EstimateHeapSize for BTreeSet<T> {
+	fn estimate_heap_size(&self) -> usize {
+		self.len()
+			.saturating_mul(size_of::<T>())
+			.saturating_add(elements_heap_size(self.iter()))
+	}
+}
+
+impl<T: EstimateHeapSize, S> EstimateHeapSize for BoundedVec<T, S> {
+	fn estimate_heap_size(&self) -> usize {
+		(**self).estimate_heap_size()
+	}
+}
+
+impl<T: EstimateHeapSize, S> EstimateHeapSize for WeakBoundedVec<T, S> {
+	fn estimate_heap_size(&self) -> usize {
+		(**self).estimate_heap_size()
+	}
+}
+
+impl<K: Ord + EstimateHeapSize, V: EstimateHeapSize, S> EstimateHeapSize for BoundedBTreeMap<K, V, S> {
+	fn estimate_heap_size(&self) -> usize {
+		(**self).estimate_heap_size()
+	}
+}
+
+impl<T: Ord + EstimateHeapSize, S> EstimateHeapSize for BoundedBTreeSet<T, S> {
+	fn estimate_heap_size(&self) -> usize {
+		(**self).estimate_heap_size()
+	}
+}
+
+fn elements_heap_size<'a, T: EstimateHeapSize + 'a>(elements: impl Iterator<Item = &'a T>) -> usize {
+	elements.fold(0, |size, element| size.saturating_add(element.estimate_heap_size()))
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test {
+	use super::*;
+	use crate::{bounded_vec, ConstU32};
+
+	#[test]
+	fn capacity_drives_vec_size() {
+		let mut v = BoundedVec::<u32, ConstU32<16>>::with_bounded_capacity(10);
+		assert_eq!(v.estimate_heap_size(), 40);
+		v.try_push(1).unwrap();
+		v.try_push(2).unwrap();
+		assert_eq!(v.estimate_heap_size(), 40);
+
+		let v: WeakBoundedVec<u64, ConstU32<16>> = Vec::with_capacity(3).try_into().unwrap();
+		assert_eq!(v.estimate_heap_size(), 24);
+	}
+
+	#[test]
+	fn elements_are_included() {
+		let mut inner = Vec::<u8>::with_capacity(8);
+		inner.push(1);
+		let v: BoundedVec<Vec<u8>, ConstU32<4>> = bounded_vec![inner, Vec::new()];
+		assert_eq!(v.estimate_heap_size(), v.capacity() * size_of::<Vec<u8>>() + 8);
+	}
+
+	#[test]
+	fn btree_collections_are_estimated_from_entries() {
+		let mut map = BoundedBTreeMap::<u32, Vec<u8>, ConstU32<4>>::new();
+		map.try_insert(1, Vec::with_capacity(5)).unwrap();
+		map.try_insert(2, Vec::new()).unwrap();
+		assert_eq!(map.estimate_heap_size(), 2 * (4 + size_of::<Vec<u8>>()) + 5);
+
+		let mut set = BoundedBTreeSet::<u16, ConstU32<4>>::new();
+		set.try_insert(1).unwrap();
+		set.try_insert(2).unwrap();
+		set.try_insert(3).unwrap();
+		assert_eq!(set.estimate_heap_size(), 6);
+	}
+}
@@ -11,20 +11,48 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(feature = "alloc")]
 pub extern crate alloc;
 
+pub mod bounded_array_vec;
+#[cfg(feature = "alloc")]
 pub mod bounded_btree_map;
+#[cfg(feature = "alloc")]
 pub mod bounded_btree_set;
+#[cfg(feature = "alloc")]
+pub mod bounded_cow;
+#[cfg(feature = "alloc")]
 pub mod bounded_vec;
+#[cfg(feature = "alloc")]
+pub mod bounded_writer;
 pub mod const_int;
+#[cfg(feature = "alloc")]
+pub mod dynamic_bounded_vec;
+#[cfg(feature = "heap-size")]
+pub mod heap_size;
+#[cfg(feature = "alloc")]
 pub mod weak_bounded_vec;
 
+#[cfg(feature = "alloc")]
 mod test;
 
+pub use bounded_array_vec::BoundedArrayVec;
+#[cfg(feature = "alloc")]
 pub use bounded_btree_map::BoundedBTreeMap;
+#[cfg(feature = "alloc")]
 pub use bounded_btree_set::BoundedBTreeSet;
-pub use bounded_vec::{BoundedSlice, BoundedVec};
+#[cfg(feature = "alloc")]
+pub use bounded_cow::BoundedCow;
+#[cfg(feature = "alloc")]
+pub use bounded_vec::{BoundedSlice, BoundedVec, SaturatingExtender};
+#[cfg(feature = "alloc")]
+pub use bounded_writer::BoundedWriter;
 pub use const_int::{ConstInt, ConstUint};
+#[cfg(feature = "alloc")]
+pub use dynamic_bounded_vec::DynamicBoundedVec;
+#[cfg(feature = "heap-size")]
+pub use heap_size::EstimateHeapSize;
+#[cfg(feature = "alloc")]
 pub use weak_bounded_vec::WeakBoundedVec;
 
 /// A trait for querying a single value from a type defined in the trait.
@@ -33,6 +61,8 @@ pub use weak_bounded_vec::WeakBoundedVec;
 pub trait TypedGet {
 	/// The type which is returned.
 	type Type;
+	/// The value, if it is known at compile time.
+	const CONST_VALUE: Option<Self::Type> = None;
 	/// Return the current value.
 	fn get() -> Self::Type;
 }
@@ -41,6 +71,12 @@ pub trait TypedGet {
 ///
 /// It is not required that the value is constant.
 pub trait Get<T> {
+	/// The value, if it is known at compile time.
+	///
+	/// This allows some checks, like the length check of [`const_bounded_vec`], to happen at compile
+	/// time.
+	const CONST_VALUE: Option<T> = None;
+
 	/// Return the current value.
 	fn get() -> T;
 }
@@ -51,6 +87,99 @@ impl<T: Default> Get<T> for () {
 	}
 }
 
+/// The upper limit on the number of elements of a bounded collection.
+///
+/// Implemented for every [`Get<u32>`], so any such type can be used as a bound. Bounds larger than
+/// `u32::MAX` can be expressed with [`ConstU64`], or with any [`Get<u64>`] or [`Get<usize>`] through
+/// [`WideBound`].
+pub trait BoundValue {
+	/// The bound as `u64`, if it is known at compile time.
+	const CONST_BOUND: Option<u64> = None;
+
+	/// Return the bound as `u64`.
+	///
+	/// This is lossless for every bound type.
+	fn bound_u64() -> u64;
+
+	/// Return the bound as `usize`, saturating at `usize::MAX` if it doesn't fit.
+	fn bound() -> usize {
+		usize::try_from(Self::bound_u64()).unwrap_or(usize::MAX)
+	}
+}
+
+impl<S: Get<u32>> BoundValue for S {
+	const CONST_BOUND: Option<u64> = match S::CONST_VALUE {
+		Some(bound) => Some(bound as u64),
+		None => None,
+	};
+
+	fn bound_u64() -> u64 {
+		S::get().into()
+	}
+
+	fn bound() -> usize {
+		S::get() as usize
+	}
+}
+
+impl<const N: u64> BoundValue for ConstU64<N> {
+	const CONST_BOUND: Option<u64> = Some(N);
+
+	fn bound_u64() -> u64 {
+		N
+	}
+}
+
+/// Use a [`Get<u64>`] or [`Get<usize>`] implementation as a [`BoundValue`].
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use bounded_collections::{parameter_types, BoundValue, BoundedVec, WideBound};
+///
+/// parameter_types! {
+///    pub const MaxBytes: u64 = 1 << 33;
+/// }
+///
+/// let bytes = BoundedVec::<u8, WideBound<MaxBytes>>::new();
+/// assert!(bytes.is_empty());
+/// assert_eq!(<WideBound<MaxBytes> as BoundValue>::bound_u64(), 1 << 33);
+/// # }
+/// ```
+pub struct WideBound<S, I = u64>(core::marker::PhantomData<(S, I)>);
+
+impl<S: Get<u64>> BoundValue for WideBound<S, u64> {
+	const CONST_BOUND: Option<u64> = S::CONST_VALUE;
+
+	fn bound_u64() -> u64 {
+		S::get()
+	}
+}
+
+impl<S: Get<usize>> BoundValue for WideBound<S, usize> {
+	const CONST_BOUND: Option<u64> = match S::CONST_VALUE {
+		Some(bound) => Some(bound as u64),
+		None => None,
+	};
+
+	fn bound_u64() -> u64 {
+		S::get() as u64
+	}
+
+	fn bound() -> usize {
+		S::get()
+	}
+}
+
+/// The bound of `S` as `u32`, saturating at `u32::MAX`.
+///
+/// Encoded lengths are prefixed by a `Compact<u32>`, so this is the largest length prefix a bounded
+/// collection can be encoded with.
+#[cfg(all(feature = "alloc", any(feature = "scale-codec", feature = "jam-codec")))]
+pub(crate) fn saturated_bound_u32<S: BoundValue>() -> u32 {
+	u32::try_from(S::bound_u64()).unwrap_or(u32::MAX)
+}
+
 /// Converts [`Get<I>`] to [`Get<R>`] using [`Into`].
 ///
 /// Acts as a type-safe bridge between `Get` implementations where `I: Into<R>`.
@@ -95,6 +224,7 @@ macro_rules! impl_const_get {
 	($name:ident, $t:ty) => {
 		/// Const getter for a basic type.
 		#[derive(Default, Clone)]
+		#[cfg_attr(feature = "scale-codec", derive(scale_info::TypeInfo))]
 		pub struct $name<const T: $t>;
 
 		#[cfg(feature = "std")]
@@ -110,6 +240,8 @@ macro_rules! impl_const_get {
 			}
 		}
 		impl<const T: $t> Get<$t> for $name<T> {
+			const CONST_VALUE: Option<$t> = Some(T);
+
 			fn get() -> $t {
 				T
 			}
@@ -121,6 +253,7 @@ macro_rules! impl_const_get {
 		}
 		impl<const T: $t> TypedGet for $name<T> {
 			type Type = $t;
+			const CONST_VALUE: Option<$t> = Some(T);
 			fn get() -> $t {
 				T
 			}
@@ -134,11 +267,13 @@ impl_const_get!(ConstU16, u16);
 impl_const_get!(ConstU32, u32);
 impl_const_get!(ConstU64, u64);
 impl_const_get!(ConstU128, u128);
+impl_const_get!(ConstUsize, usize);
 impl_const_get!(ConstI8, i8);
 impl_const_get!(ConstI16, i16);
 impl_const_get!(ConstI32, i32);
 impl_const_get!(ConstI64, i64);
 impl_const_get!(ConstI128, i128);
+impl_const_get!(ConstIsize, isize);
 
 /// Try and collect into a collection `C`.
 pub trait TryCollect<C> {
@@ -151,6 +286,155 @@ pub trait TryCollect<C> {
 	fn try_collect(self) -> Result<C, Self::Error>;
 }
 
+/// Try and collect a parallel iterator into a collection `C`.
+///
+/// This is the counterpart of [`TryCollect`] for [`rayon`] parallel iterators. The items are
+/// collected first and the bound is checked afterwards, unless the length is known up front.
+#[cfg(feature = "rayon")]
+pub trait ParallelTryCollect<C> {
+	/// The error type that gets returned when a collection can't be made from `self`.
+	type Error;
+	/// Consume self and try to collect the results into `C`.
+	fn try_collect_bounded(self) -> Result<C, Self::Error>;
+}
+
+/// Try to extend a bounded collection with the contents of an iterator.
+///
+/// This allows writing code which is generic over the bounded collection being extended. All
+/// implementations in this crate are atomic: if the outcome would exceed the bound, an error is
+/// returned and the collection is left unchanged. The iterator is not consumed any further once
+/// that is known.
+pub trait TryExtend<A> {
+	/// The error type that gets returned when the collection can't be extended.
+	type Error;
+	/// Try to extend the collection with the contents of `iter`.
+	fn try_extend<I: IntoIterator<Item = A>>(&mut self, iter: I) -> Result<(), Self::Error>;
+}
+
+/// Try to create a bounded collection from an iterator of unknown length.
+///
+/// Unlike [`TryCollect`], this does not require an [`ExactSizeIterator`]. The iterator is consumed
+/// element by element and no further elements are consumed once the bound is exceeded, so passing
+/// [`Iterator::by_ref`] allows inspecting how far it got.
+pub trait TryFromIterator<A>: Sized {
+	/// The error type that gets returned when the collection can't be created.
+	type Error;
+	/// Try to create the collection from the contents of `iter`.
+	fn try_from_iter<I: IntoIterator<Item = A>>(iter: I) -> Result<Self, Self::Error>;
+}
+
+/// Try and collect an iterator of unknown length into a collection `C`.
+///
+/// This is the counterpart of [`TryCollect`] for iterators which are not an [`ExactSizeIterator`],
+/// such as the result of [`Iterator::filter`].
+pub trait TryCollectUnboundedLen<C> {
+	/// The error type that gets returned when a collection can't be made from `self`.
+	type Error;
+	/// Consume self and try to collect the results into `C`.
+	///
+	/// See [`TryFromIterator`] for how much of `self` is consumed on failure.
+	fn try_collect_unbounded_len(self) -> Result<C, Self::Error>;
+}
+
+impl<I: Iterator, C: TryFromIterator<I::Item>> TryCollectUnboundedLen<C> for I {
+	type Error = C::Error;
+
+	fn try_collect_unbounded_len(self) -> Result<C, Self::Error> {
+		C::try_from_iter(self)
+	}
+}
+
+/// The error of [`TryCollectResults`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CollectResultsError<E> {
+	/// An element of the iterator was an error.
+	Element(E),
+	/// The iterator has more elements than the bound allows.
+	Length,
+}
+
+/// Try and collect an iterator of [`Result`]s into a collection `C`.
+///
+/// The bounded counterpart of collecting into `Result<Vec<T>, E>`: stops at the first element
+/// error or as soon as the bound is exceeded, whichever comes first, without collecting into an
+/// intermediate `Vec`.
+pub trait TryCollectResults<C> {
+	/// The error type of the elements.
+	type Error;
+	/// Consume self and try to collect the results into `C`.
+	fn try_collect_results(self) -> Result<C, CollectResultsError<Self::Error>>;
+}
+
+impl<I, T, E, C> TryCollectResults<C> for I
+where
+	I: Iterator<Item = Result<T, E>>,
+	C: TryFromIterator<T>,
+{
+	type Error = E;
+
+	fn try_collect_results(self) -> Result<C, CollectResultsError<E>> {
+		let mut error = None;
+		let collected = C::try_from_iter(UntilErr { iter: self, error: &mut error });
+		match (collected, error) {
+			(_, Some(error)) => Err(CollectResultsError::Element(error)),
+			(Ok(collected), None) => Ok(collected),
+			(Err(_), None) => Err(CollectResultsError::Length),
+		}
+	}
+}
+
+// Yields the `Ok` values of an iterator until the first error, which is stored in `error`.
+struct UntilErr<'a, I, E> {
+	iter: I,
+	error: &'a mut Option<E>,
+}
+
+impl<'a, I, T, E> Iterator for UntilErr<'a, I, E>
+where
+	I: Iterator<Item = Result<T, E>>,
+{
+	type Item = T;
+
+	fn next(&mut self) -> Option<T> {
+		if self.error.is_some() {
+			return None
+		}
+		match self.iter.next()? {
+			Ok(item) => Some(item),
+			Err(error) => {
+				*self.error = Some(error);
+				None
+			},
+		}
+	}
+}
+
+/// What to do when inserting into a full bounded collection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OnOverflow {
+	/// Reject the new element and hand it back, like [`BoundedVec::try_push`].
+	Error,
+	/// Drop the new element, like [`BoundedVec::truncate_from`].
+	DropNewest,
+	/// Remove the first element to make room for the new one.
+	EvictOldest,
+	/// Replace the last element with the new one, like [`BoundedVec::force_push`].
+	EvictLast,
+}
+
+/// The outcome of inserting an element with an [`OnOverflow`] policy.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PushOutcome<T> {
+	/// The element was inserted without removing anything.
+	Inserted,
+	/// The collection was full and the element is handed back. Only with [`OnOverflow::Error`].
+	Rejected(T),
+	/// The collection was full and the element was dropped.
+	Dropped,
+	/// The element was inserted after removing the given element to make room.
+	Evicted(T),
+}
+
 /// Create new implementations of the [`Get`](crate::Get) trait.
 ///
 /// The so-called parameter type can be created in four different ways:
@@ -160,6 +444,10 @@ pub trait TryCollect<C> {
 ///
 /// - Declare the parameter type without `const` to have more freedom when creating the value.
 ///
+/// - Using `static` to create a parameter type whose value can be changed with an additional `set`
+///   function. The value is stored in a thread-local, so this is only available with the `std`
+///   feature and is intended for testing ONLY.
+///
 /// NOTE: A more substantial version of this macro is available in `frame_support` crate which
 /// allows mutable and persistant variants.
 ///
@@ -190,6 +478,22 @@ pub trait TryCollect<C> {
 /// }
 /// ```
 ///
+/// Using `static` to vary a parameter between test cases:
+///
+/// ```
+/// # #[cfg(feature = "std")] {
+/// # use bounded_collections::{parameter_types, BoundedVec};
+/// parameter_types! {
+///    pub static MaxLen: u32 = 2;
+/// }
+///
+/// assert_eq!(MaxLen::get(), 2);
+/// assert_eq!(BoundedVec::<u8, MaxLen>::bound(), 2);
+/// MaxLen::set(5);
+/// assert_eq!(BoundedVec::<u8, MaxLen>::bound(), 5);
+/// # }
+/// ```
+///
 /// # Invalid example:
 ///
 /// ```compile_fail
@@ -224,6 +528,16 @@ macro_rules! parameter_types {
 		$crate::parameter_types!(@IMPL $name, $type, $value);
 		$crate::parameter_types!( $( $rest )* );
 	);
+	(
+		$( #[ $attr:meta ] )*
+		$vis:vis static $name:ident: $type:ty = $value:expr;
+		$( $rest:tt )*
+	) => (
+		$( #[ $attr ] )*
+		$vis struct $name;
+		$crate::parameter_types_impl_thread_local!($name, $type, $value);
+		$crate::parameter_types!( $( $rest )* );
+	);
 	() => ();
 	(@IMPL_CONST $name:ident, $type:ty, $value:expr) => {
 		impl $name {
@@ -241,6 +555,7 @@ macro_rules! parameter_types {
 
 		impl $crate::TypedGet for $name {
 			type Type = $type;
+			const CONST_VALUE: Option<$type> = Some($name::get());
 			fn get() -> $type {
 				Self::get()
 			}
@@ -269,6 +584,46 @@ macro_rules! parameter_types {
 	};
 }
 
+#[doc(hidden)]
+#[cfg(feature = "std")]
+pub mod __private {
+	pub use std::{cell::RefCell, thread::LocalKey, thread_local};
+}
+
+/// Implement a `static` parameter type of [`parameter_types`], backed by a thread-local.
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "std")]
+macro_rules! parameter_types_impl_thread_local {
+	($name:ident, $type:ty, $value:expr) => {
+		impl $name {
+			fn storage() -> &'static $crate::__private::LocalKey<$crate::__private::RefCell<$type>> {
+				$crate::__private::thread_local! {
+					static VALUE: $crate::__private::RefCell<$type> = $crate::__private::RefCell::new($value);
+				}
+				&VALUE
+			}
+
+			/// Set the value of this parameter type for the current thread.
+			pub fn set(value: $type) {
+				Self::storage().with(|v| *v.borrow_mut() = value);
+			}
+		}
+
+		$crate::parameter_types!(@IMPL $name, $type, Self::storage().with(|v| v.borrow().clone()));
+	};
+}
+
+/// Implement a `static` parameter type of [`parameter_types`], backed by a thread-local.
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "std"))]
+macro_rules! parameter_types_impl_thread_local {
+	($name:ident, $type:ty, $value:expr) => {
+		compile_error!("`static` parameter types are only available with the `std` feature of `bounded-collections`");
+	};
+}
+
 /// Build a bounded vec from the given literals.
 ///
 /// The type of the outcome must be known.
@@ -290,6 +645,35 @@ macro_rules! bounded_vec {
 	}
 }
 
+/// Build a bounded vec from the given elements, checking their number against the bound at compile
+/// time.
+///
+/// The outcome must be a [`BoundedVec`], [`WeakBoundedVec`] or [`BoundedArrayVec`] whose bound is
+/// known at compile time, like [`ConstU32`], [`ConstU64`] or [`ConstUint`]. Unlike [`bounded_vec`],
+/// too many elements are a compile error rather than a panic, and so is any other bound, including
+/// the parameter types of [`parameter_types`].
+///
+/// ```
+/// # use bounded_collections::{const_bounded_vec, BoundedVec, ConstU32};
+/// let v: BoundedVec<u32, ConstU32<3>> = const_bounded_vec![1, 2, 3];
+/// assert_eq!(v, vec![1, 2, 3]);
+/// ```
+///
+/// ```compile_fail
+/// # use bounded_collections::{const_bounded_vec, BoundedVec, ConstU32};
+/// let v: BoundedVec<u32, ConstU32<2>> = const_bounded_vec![1, 2, 3];
+/// ```
+#[macro_export]
+#[cfg(feature = "std")]
+macro_rules! const_bounded_vec {
+	($ ($values:expr),* $(,)?) => {
+		{
+			const LEN: usize = <[&str]>::len(&[$(stringify!($values)),*]);
+			$crate::bounded_vec::FromConstLen::<_, LEN>::from_const_len($crate::alloc::vec![$($values),*])
+		}
+	};
+}
+
 /// Build a bounded btree-map from the given literals.
 ///
 /// The type of the outcome must be known.
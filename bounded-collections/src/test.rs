@@ -48,3 +48,192 @@ fn const_debug_fmt() {
 	assert_eq!(format!("{:?}", ConstI64::<-99> {}), "ConstI64<-99>");
 	assert_eq!(format!("{:?}", ConstI128::<-100> {}), "ConstI128<-100>");
 }
+
+#[cfg(feature = "std")]
+parameter_types! {
+	static TestMaxLen: u32 = 2;
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn static_parameter_type_defaults_to_declared_value() {
+	assert_eq!(TestMaxLen::get(), 2);
+	let mut v = BoundedVec::<u32, TestMaxLen>::new();
+	assert!(v.try_push(1).is_ok());
+	assert!(v.try_push(2).is_ok());
+	assert!(v.try_push(3).is_err());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn static_parameter_type_can_be_set() {
+	TestMaxLen::set(0);
+	assert_eq!(<TestMaxLen as Get<u32>>::get(), 0);
+	assert!(BoundedVec::<u32, TestMaxLen>::new().try_push(1).is_err());
+
+	TestMaxLen::set(1000);
+	assert_eq!(<TestMaxLen as Get<u64>>::get(), 1000);
+	assert_eq!(BoundedVec::<u32, TestMaxLen>::bound(), 1000);
+}
+
+parameter_types! {
+	const TestConstLen: u32 = 3;
+	const TestConstName: &'static str = "name";
+}
+
+#[test]
+fn const_parameter_type_value_is_known_at_compile_time() {
+	assert_eq!(<TestConstLen as TypedGet>::CONST_VALUE, Some(3));
+	assert_eq!(<TestConstName as TypedGet>::CONST_VALUE, Some("name"));
+}
+
+#[test]
+fn const_getters_are_const_bounds() {
+	assert_eq!(<ConstU32<3> as BoundValue>::CONST_BOUND, Some(3));
+	assert_eq!(<ConstU64<{ 1 << 33 }> as BoundValue>::CONST_BOUND, Some(1 << 33));
+	assert_eq!(<ConstUint<3> as BoundValue>::CONST_BOUND, Some(3));
+	assert_eq!(<WideBound<ConstU64<3>> as BoundValue>::CONST_BOUND, Some(3));
+	assert_eq!(<WideBound<ConstUsize<3>, usize> as BoundValue>::CONST_BOUND, Some(3));
+	// Parameter types implement `Get` for any type their value converts into, so they can't tell.
+	assert_eq!(<TestConstLen as BoundValue>::CONST_BOUND, None);
+	#[cfg(feature = "std")]
+	assert_eq!(<TestMaxLen as BoundValue>::CONST_BOUND, None);
+}
+
+#[test]
+fn try_extend_is_generic_over_collections() {
+	fn extend_with_evens<C: TryExtend<u32>>(collection: &mut C, up_to: u32) -> Result<(), C::Error> {
+		collection.try_extend((0..up_to).filter(|i| i % 2 == 0))
+	}
+
+	let mut vec = BoundedVec::<u32, ConstU32<3>>::new();
+	assert!(extend_with_evens(&mut vec, 6).is_ok());
+	assert!(extend_with_evens(&mut vec, 2).is_err());
+	assert_eq!(vec, alloc::vec![0, 2, 4]);
+
+	let mut set = BoundedBTreeSet::<u32, ConstU32<3>>::new();
+	assert!(extend_with_evens(&mut set, 6).is_ok());
+	assert!(extend_with_evens(&mut set, 6).is_ok());
+	assert!(extend_with_evens(&mut set, 8).is_err());
+	assert_eq!(set.len(), 3);
+}
+
+// An iterator reporting a wrong size hint.
+struct WrongSizeHint<I> {
+	inner: I,
+	hint: usize,
+}
+
+impl<I: Iterator> Iterator for WrongSizeHint<I> {
+	type Item = I::Item;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.inner.next()
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(self.hint, Some(self.hint))
+	}
+}
+
+#[test]
+fn try_from_iter_ignores_wrong_size_hint() {
+	// reporting too few elements.
+	let iter = WrongSizeHint { inner: 0..4u32, hint: 1 };
+	assert!(BoundedVec::<u32, ConstU32<3>>::try_from_iter(iter).is_err());
+	let iter = WrongSizeHint { inner: 0..3u32, hint: 1 };
+	assert_eq!(BoundedVec::<u32, ConstU32<3>>::try_from_iter(iter).unwrap(), alloc::vec![0, 1, 2]);
+
+	// reporting too many elements.
+	let iter = WrongSizeHint { inner: 0..3u32, hint: usize::MAX };
+	assert_eq!(BoundedVec::<u32, ConstU32<3>>::try_from_iter(iter).unwrap(), alloc::vec![0, 1, 2]);
+	let iter = WrongSizeHint { inner: 0..2u32, hint: 100 };
+	assert_eq!(BoundedArrayVec::<u32, 3>::try_from_iter(iter).unwrap().as_slice(), &[0, 1]);
+	let iter = WrongSizeHint { inner: 0..2u32, hint: 100 };
+	assert_eq!(BoundedBTreeSet::<u32, ConstU32<3>>::try_from_iter(iter).unwrap().len(), 2);
+}
+
+#[test]
+fn try_from_iter_stops_at_bound() {
+	let mut iter = 0u32..;
+	assert!(WeakBoundedVec::<u32, ConstU32<3>>::try_from_iter(iter.by_ref()).is_err());
+	// only the first element exceeding the bound was consumed.
+	assert_eq!(iter.next(), Some(4));
+
+	let mut iter = (0u32..).map(|i| (i, i));
+	assert!(BoundedBTreeMap::<u32, u32, ConstU32<3>>::try_from_iter(iter.by_ref()).is_err());
+	assert_eq!(iter.next(), Some((4, 4)));
+}
+
+#[test]
+fn try_collect_unbounded_len_works() {
+	let v: BoundedVec<u32, ConstU32<3>> = (0..10).filter(|i| i % 4 == 0).try_collect_unbounded_len().unwrap();
+	assert_eq!(v, alloc::vec![0, 4, 8]);
+
+	let v: Result<BoundedVec<u32, ConstU32<3>>, _> = (0..20).filter(|i| i % 4 == 0).try_collect_unbounded_len();
+	assert!(v.is_err());
+
+	let m: BoundedBTreeMap<u32, u32, ConstU32<3>> =
+		(0..3).flat_map(|i| [(i, i), (i, i * 10)]).try_collect_unbounded_len().unwrap();
+	assert_eq!(m.get(&2), Some(&20));
+}
+
+#[test]
+#[cfg(feature = "rayon")]
+fn parallel_and_serial_iteration_agree() {
+	use rayon::prelude::*;
+
+	let mut v: BoundedVec<u64, ConstU32<1000>> = (0..1000).collect::<alloc::vec::Vec<_>>().try_into().unwrap();
+
+	let serial: alloc::vec::Vec<u64> = v.iter().map(|i| i * i).collect();
+	let parallel: BoundedVec<u64, ConstU32<1000>> = (&v).into_par_iter().map(|i| i * i).try_collect_bounded().unwrap();
+	assert_eq!(parallel, serial);
+	assert_eq!(v.as_bounded_slice().into_par_iter().sum::<u64>(), v.iter().sum::<u64>());
+
+	(&mut v).into_par_iter().for_each(|i| *i += 1);
+	assert_eq!(v.clone().into_par_iter().max(), Some(1000));
+
+	// the bound is checked, whether the length is known up front or not.
+	let too_long: Result<BoundedVec<u64, ConstU32<999>>, _> = (&v).into_par_iter().copied().try_collect_bounded();
+	assert_eq!(too_long, Err("iterator length too big"));
+	let too_long: Result<BoundedVec<u64, ConstU32<10>>, _> =
+		(&v).into_par_iter().copied().filter(|i| i % 2 == 0).try_collect_bounded();
+	assert_eq!(too_long, Err("iterator length too big"));
+	let filtered: BoundedVec<u64, ConstU32<500>> = (&v)
+		.into_par_iter()
+		.copied()
+		.filter(|i| i % 2 == 0)
+		.try_collect_bounded()
+		.unwrap();
+	assert_eq!(filtered.len(), 500);
+}
+
+#[test]
+fn try_collect_results_works() {
+	fn parse(items: &[&str]) -> Result<BoundedVec<u32, ConstU32<3>>, CollectResultsError<core::num::ParseIntError>> {
+		items.iter().map(|s| s.parse::<u32>()).try_collect_results()
+	}
+
+	// exact fit.
+	assert_eq!(parse(&["1", "2", "3"]).unwrap(), alloc::vec![1, 2, 3]);
+	// element error before the bound is exceeded.
+	assert!(matches!(parse(&["1", "x", "3", "4"]), Err(CollectResultsError::Element(_))));
+	// bound exceeded before any element error.
+	assert_eq!(parse(&["1", "2", "3", "4", "x"]), Err(CollectResultsError::Length));
+}
+
+#[test]
+fn try_collect_results_short_circuits() {
+	let mut consumed = 0;
+	let items = [Ok(1), Err("first"), Ok(2), Err("second")];
+	let result: Result<BoundedBTreeSet<u32, ConstU32<3>>, _> =
+		items.into_iter().inspect(|_| consumed += 1).try_collect_results();
+	assert_eq!(result, Err(CollectResultsError::Element("first")));
+	assert_eq!(consumed, 2);
+
+	let mut consumed = 0;
+	let result: Result<BoundedVec<u32, ConstU32<2>>, CollectResultsError<()>> =
+		(0..).map(Ok).inspect(|_| consumed += 1).try_collect_results();
+	assert_eq!(result, Err(CollectResultsError::Length));
+	assert_eq!(consumed, 3);
+}
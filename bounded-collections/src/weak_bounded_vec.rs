@@ -19,7 +19,7 @@
 //! or a double map.
 
 use super::{BoundedSlice, BoundedVec};
-use crate::Get;
+use crate::{BoundValue, TryExtend, TryFromIterator};
 use alloc::vec::Vec;
 use core::{
 	marker::PhantomData,
@@ -49,7 +49,7 @@ pub struct WeakBoundedVec<T, S>(
 );
 
 #[cfg(feature = "serde")]
-impl<'de, T, S: Get<u32>> Deserialize<'de> for WeakBoundedVec<T, S>
+impl<'de, T, S: BoundValue> Deserialize<'de> for WeakBoundedVec<T, S>
 where
 	T: Deserialize<'de>,
 {
@@ -57,9 +57,9 @@ where
 	where
 		D: Deserializer<'de>,
 	{
-		struct VecVisitor<T, S: Get<u32>>(PhantomData<(T, S)>);
+		struct VecVisitor<T, S: BoundValue>(PhantomData<(T, S)>);
 
-		impl<'de, T, S: Get<u32>> Visitor<'de> for VecVisitor<T, S>
+		impl<'de, T, S: BoundValue> Visitor<'de> for VecVisitor<T, S>
 		where
 			T: Deserialize<'de>,
 		{
@@ -74,10 +74,7 @@ where
 				A: SeqAccess<'de>,
 			{
 				let size = seq.size_hint().unwrap_or(0);
-				let max = match usize::try_from(S::get()) {
-					Ok(n) => n,
-					Err(_) => return Err(A::Error::custom("can't convert to usize")),
-				};
+				let max = S::bound();
 				if size > max {
 					log::warn!(
 						target: "runtime",
@@ -108,11 +105,6 @@ where
 }
 
 impl<T, S> WeakBoundedVec<T, S> {
-	/// Create `Self` from `t` without any checks.
-	fn unchecked_from(t: Vec<T>) -> Self {
-		Self(t, Default::default())
-	}
-
 	/// Consume self, and return the inner `Vec`. Henceforth, the `Vec<_>` can be altered in an
 	/// arbitrary way. At some point, if the reverse conversion is required, `TryFrom<Vec<_>>` can
 	/// be used.
@@ -152,17 +144,42 @@ impl<T, S> WeakBoundedVec<T, S> {
 	}
 }
 
-impl<T, S: Get<u32>> WeakBoundedVec<T, S> {
+impl<T, S: BoundValue> WeakBoundedVec<T, S> {
 	/// Get the bound of the type in `usize`.
 	pub fn bound() -> usize {
-		S::get() as usize
+		S::bound()
+	}
+
+	/// Create `Self` from `t` without any checks.
+	///
+	/// With the `defensive-checks` feature, the bound is still checked in debug builds.
+	fn unchecked_from(t: Vec<T>) -> Self {
+		let bounded = Self(t, Default::default());
+		bounded.check_invariant();
+		bounded
+	}
+
+	/// Check that the length of `self` is within the bound.
+	///
+	/// Called after every operation which could break this invariant. The bound is only checked if
+	/// the `defensive-checks` feature is enabled and debug assertions are on, in which case
+	/// [`Self::force_from`] can't exceed it either.
+	#[inline(always)]
+	fn check_invariant(&self) {
+		#[cfg(feature = "defensive-checks")]
+		debug_assert!(self.0.len() <= Self::bound(), "WeakBoundedVec exceeds its bound");
 	}
 
 	/// Create `Self` from `t` without any checks. Logs warnings if the bound is not being
 	/// respected. The additional scope can be used to indicate where a potential overflow is
 	/// happening.
+	///
+	/// With the `defensive-checks` feature, this panics instead of logging.
 	pub fn force_from(t: Vec<T>, scope: Option<&'static str>) -> Self {
 		if t.len() > Self::bound() {
+			#[cfg(feature = "defensive-checks")]
+			panic!("length of a bounded vector in scope {} is not respected.", scope.unwrap_or("UNKNOWN"));
+			#[cfg(not(feature = "defensive-checks"))]
 			log::warn!(
 				target: "runtime",
 				"length of a bounded vector in scope {} is not respected.",
@@ -194,6 +211,7 @@ impl<T, S: Get<u32>> WeakBoundedVec<T, S> {
 	pub fn try_insert(&mut self, index: usize, element: T) -> Result<(), ()> {
 		if self.len() < Self::bound() {
 			self.0.insert(index, element);
+			self.check_invariant();
 			Ok(())
 		} else {
 			Err(())
@@ -209,6 +227,7 @@ impl<T, S: Get<u32>> WeakBoundedVec<T, S> {
 	pub fn try_push(&mut self, element: T) -> Result<(), ()> {
 		if self.len() < Self::bound() {
 			self.0.push(element);
+			self.check_invariant();
 			Ok(())
 		} else {
 			Err(())
@@ -221,17 +240,47 @@ impl<T, S: Get<u32>> WeakBoundedVec<T, S> {
 	}
 }
 
+impl<T, S: BoundValue> TryExtend<T> for WeakBoundedVec<T, S> {
+	type Error = ();
+
+	fn try_extend<I: IntoIterator<Item = T>>(&mut self, iter: I) -> Result<(), ()> {
+		let result = super::bounded_vec::try_extend_vec(&mut self.0, Self::bound(), iter);
+		self.check_invariant();
+		result
+	}
+}
+
+impl<T, S: BoundValue> TryFromIterator<T> for WeakBoundedVec<T, S> {
+	type Error = ();
+
+	fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<Self, ()> {
+		let mut bounded = Self::default();
+		TryExtend::try_extend(&mut bounded, iter)?;
+		Ok(bounded)
+	}
+}
+
+impl<S> WeakBoundedVec<u8, S> {
+	/// Return an adapter which formats the bytes as hex with [`Debug`](core::fmt::Debug).
+	///
+	/// Prints `WeakBoundedVec(0x<hex>, <len>)`, only including the first 64 bytes followed by `…`
+	/// for longer vectors.
+	pub fn hex_debug(&self) -> impl core::fmt::Debug + '_ {
+		super::bounded_vec::HexDebug { name: "WeakBoundedVec", bytes: &self.0 }
+	}
+}
+
 impl<T, S> Default for WeakBoundedVec<T, S> {
 	fn default() -> Self {
 		// the bound cannot be below 0, which is satisfied by an empty vector
-		Self::unchecked_from(Vec::default())
+		Self(Vec::default(), PhantomData)
 	}
 }
 
 impl<T, S> core::fmt::Debug for WeakBoundedVec<T, S>
 where
 	Vec<T>: core::fmt::Debug,
-	S: Get<u32>,
+	S: BoundValue,
 {
 	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 		f.debug_tuple("WeakBoundedVec").field(&self.0).field(&Self::bound()).finish()
@@ -244,11 +293,11 @@ where
 {
 	fn clone(&self) -> Self {
 		// bound is retained
-		Self::unchecked_from(self.0.clone())
+		Self(self.0.clone(), PhantomData)
 	}
 }
 
-impl<T, S: Get<u32>> TryFrom<Vec<T>> for WeakBoundedVec<T, S> {
+impl<T, S: BoundValue> TryFrom<Vec<T>> for WeakBoundedVec<T, S> {
 	type Error = ();
 	fn try_from(t: Vec<T>) -> Result<Self, Self::Error> {
 		if t.len() <= Self::bound() {
@@ -338,8 +387,8 @@ impl<'a, T, S> core::iter::IntoIterator for &'a mut WeakBoundedVec<T, S> {
 impl<T, BoundSelf, BoundRhs> PartialEq<WeakBoundedVec<T, BoundRhs>> for WeakBoundedVec<T, BoundSelf>
 where
 	T: PartialEq,
-	BoundSelf: Get<u32>,
-	BoundRhs: Get<u32>,
+	BoundSelf: BoundValue,
+	BoundRhs: BoundValue,
 {
 	fn eq(&self, rhs: &WeakBoundedVec<T, BoundRhs>) -> bool {
 		self.0 == rhs.0
@@ -349,8 +398,8 @@ where
 impl<T, BoundSelf, BoundRhs> PartialEq<BoundedVec<T, BoundRhs>> for WeakBoundedVec<T, BoundSelf>
 where
 	T: PartialEq,
-	BoundSelf: Get<u32>,
-	BoundRhs: Get<u32>,
+	BoundSelf: BoundValue,
+	BoundRhs: BoundValue,
 {
 	fn eq(&self, rhs: &BoundedVec<T, BoundRhs>) -> bool {
 		self.0 == rhs.0
@@ -360,27 +409,27 @@ where
 impl<'a, T, BoundSelf, BoundRhs> PartialEq<BoundedSlice<'a, T, BoundRhs>> for WeakBoundedVec<T, BoundSelf>
 where
 	T: PartialEq,
-	BoundSelf: Get<u32>,
-	BoundRhs: Get<u32>,
+	BoundSelf: BoundValue,
+	BoundRhs: BoundValue,
 {
 	fn eq(&self, rhs: &BoundedSlice<'a, T, BoundRhs>) -> bool {
 		self.0 == rhs.0
 	}
 }
 
-impl<T: PartialEq, S: Get<u32>> PartialEq<Vec<T>> for WeakBoundedVec<T, S> {
+impl<T: PartialEq, S: BoundValue> PartialEq<Vec<T>> for WeakBoundedVec<T, S> {
 	fn eq(&self, other: &Vec<T>) -> bool {
 		&self.0 == other
 	}
 }
 
-impl<T, S: Get<u32>> Eq for WeakBoundedVec<T, S> where T: Eq {}
+impl<T, S: BoundValue> Eq for WeakBoundedVec<T, S> where T: Eq {}
 
 impl<T, BoundSelf, BoundRhs> PartialOrd<WeakBoundedVec<T, BoundRhs>> for WeakBoundedVec<T, BoundSelf>
 where
 	T: PartialOrd,
-	BoundSelf: Get<u32>,
-	BoundRhs: Get<u32>,
+	BoundSelf: BoundValue,
+	BoundRhs: BoundValue,
 {
 	fn partial_cmp(&self, other: &WeakBoundedVec<T, BoundRhs>) -> Option<core::cmp::Ordering> {
 		self.0.partial_cmp(&other.0)
@@ -390,8 +439,8 @@ where
 impl<T, BoundSelf, BoundRhs> PartialOrd<BoundedVec<T, BoundRhs>> for WeakBoundedVec<T, BoundSelf>
 where
 	T: PartialOrd,
-	BoundSelf: Get<u32>,
-	BoundRhs: Get<u32>,
+	BoundSelf: BoundValue,
+	BoundRhs: BoundValue,
 {
 	fn partial_cmp(&self, other: &BoundedVec<T, BoundRhs>) -> Option<core::cmp::Ordering> {
 		self.0.partial_cmp(&other.0)
@@ -401,15 +450,15 @@ where
 impl<'a, T, BoundSelf, BoundRhs> PartialOrd<BoundedSlice<'a, T, BoundRhs>> for WeakBoundedVec<T, BoundSelf>
 where
 	T: PartialOrd,
-	BoundSelf: Get<u32>,
-	BoundRhs: Get<u32>,
+	BoundSelf: BoundValue,
+	BoundRhs: BoundValue,
 {
 	fn partial_cmp(&self, other: &BoundedSlice<'a, T, BoundRhs>) -> Option<core::cmp::Ordering> {
 		(&*self.0).partial_cmp(other.0)
 	}
 }
 
-impl<T: Ord, S: Get<u32>> Ord for WeakBoundedVec<T, S> {
+impl<T: Ord, S: BoundValue> Ord for WeakBoundedVec<T, S> {
 	fn cmp(&self, other: &Self) -> core::cmp::Ordering {
 		self.0.cmp(&other.0)
 	}
@@ -424,20 +473,20 @@ macro_rules! codec_impl {
 		impl<T, S> MaxEncodedLen for WeakBoundedVec<T, S>
 		where
 			T: MaxEncodedLen,
-			S: Get<u32>,
+			S: BoundValue,
 			WeakBoundedVec<T, S>: Encode,
 		{
 			fn max_encoded_len() -> usize {
 				// WeakBoundedVec<T, S> encodes like Vec<T> which encodes like [T], which is a compact u32
 				// plus each item in the slice:
 				// See: https://docs.polkadot.com/polkadot-protocol/basics/data-encoding/#scale-codec-libraries
-				Compact(S::get())
+				Compact(crate::saturated_bound_u32::<S>())
 					.encoded_size()
 					.saturating_add(Self::bound().saturating_mul(T::max_encoded_len()))
 			}
 		}
 
-		impl<T: Decode, S: Get<u32>> Decode for WeakBoundedVec<T, S> {
+		impl<T: Decode, S: BoundValue> Decode for WeakBoundedVec<T, S> {
 			fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
 				let inner = Vec::<T>::decode(input)?;
 				Ok(Self::force_from(inner, Some("decode")))
@@ -448,7 +497,7 @@ macro_rules! codec_impl {
 			}
 		}
 
-		impl<T: DecodeWithMemTracking, S: Get<u32>> DecodeWithMemTracking for WeakBoundedVec<T, S> {}
+		impl<T: DecodeWithMemTracking, S: BoundValue> DecodeWithMemTracking for WeakBoundedVec<T, S> {}
 
 		impl<T, S> DecodeLength for WeakBoundedVec<T, S> {
 			fn len(self_encoded: &[u8]) -> Result<usize, Error> {
@@ -474,7 +523,7 @@ mod jam_impl {
 mod test {
 	use super::*;
 	use crate::ConstU32;
-	use alloc::vec;
+	use alloc::{format, vec};
 	#[cfg(feature = "scale-codec")]
 	use scale_codec::{Decode, Encode};
 
@@ -493,6 +542,55 @@ mod test {
 		assert_eq!(*bounded, vec![1, 0, 2, 3]);
 	}
 
+	#[test]
+	fn hex_debug_works() {
+		let v: WeakBoundedVec<u8, ConstU32<100>> = vec![0x01, 0x23].try_into().unwrap();
+		assert_eq!(format!("{:?}", v.hex_debug()), "WeakBoundedVec(0x0123, 2)");
+
+		let v: WeakBoundedVec<u8, ConstU32<100>> = vec![0xff; 65].try_into().unwrap();
+		assert_eq!(format!("{:?}", v.hex_debug()), format!("WeakBoundedVec(0x{}…, 65)", "ff".repeat(64)));
+	}
+
+	#[test]
+	fn try_extend_works() {
+		let mut bounded: WeakBoundedVec<u32, ConstU32<4>> = vec![1, 2].try_into().unwrap();
+		assert!(bounded.try_extend(vec![3, 4, 5]).is_err());
+		assert_eq!(*bounded, vec![1, 2]);
+		assert!(bounded.try_extend(vec![3, 4]).is_ok());
+		assert_eq!(*bounded, vec![1, 2, 3, 4]);
+	}
+
+	#[test]
+	#[cfg(not(feature = "defensive-checks"))]
+	fn try_extend_overflowing_works() {
+		// an overflowing vec can only be extended with nothing.
+		let mut bounded = WeakBoundedVec::<u32, ConstU32<1>>::force_from(vec![1, 2], None);
+		assert!(bounded.try_extend(vec![]).is_ok());
+		assert!(bounded.try_extend(vec![3]).is_err());
+		assert_eq!(*bounded, vec![1, 2]);
+	}
+
+	#[test]
+	#[cfg(feature = "defensive-checks")]
+	#[should_panic(expected = "length of a bounded vector in scope UNKNOWN is not respected.")]
+	fn force_from_panics_with_defensive_checks() {
+		let _ = WeakBoundedVec::<u32, ConstU32<1>>::force_from(vec![1, 2], None);
+	}
+
+	#[test]
+	#[cfg(not(feature = "defensive-checks"))]
+	fn invariant_is_not_checked_without_defensive_checks() {
+		let bounded = WeakBoundedVec::<u32, ConstU32<1>>::unchecked_from(vec![1, 2]);
+		assert_eq!(bounded.len(), 2);
+	}
+
+	#[test]
+	#[cfg(all(feature = "defensive-checks", debug_assertions))]
+	#[should_panic(expected = "WeakBoundedVec exceeds its bound")]
+	fn invariant_is_checked_with_defensive_checks() {
+		let _ = WeakBoundedVec::<u32, ConstU32<1>>::unchecked_from(vec![1, 2]);
+	}
+
 	#[test]
 	#[should_panic(expected = "insertion index (is 9) should be <= len (is 3)")]
 	fn try_inert_panics_if_oob() {
@@ -540,6 +638,10 @@ mod test {
 
 	#[test]
 	#[cfg(feature = "scale-codec")]
+	#[cfg_attr(
+		feature = "defensive-checks",
+		should_panic(expected = "length of a bounded vector in scope decode is not respected.")
+	)]
 	fn too_big_succeed_to_decode() {
 		let v: Vec<u32> = vec![1, 2, 3, 4, 5];
 		let w = WeakBoundedVec::<u32, ConstU32<4>>::decode(&mut &v.encode()[..]).unwrap();
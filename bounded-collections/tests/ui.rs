@@ -0,0 +1,16 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[test]
+#[cfg(feature = "std")]
+fn ui() {
+	let t = trybuild::TestCases::new();
+	// The pass cases make trybuild do a full build, which is needed to evaluate the const assertions.
+	t.pass("tests/ui/pass/*.rs");
+	t.compile_fail("tests/ui/fail/*.rs");
+}
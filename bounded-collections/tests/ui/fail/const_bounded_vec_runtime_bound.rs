@@ -0,0 +1,9 @@
+use bounded_collections::{const_bounded_vec, parameter_types, BoundedVec};
+
+parameter_types! {
+	pub static MaxLen: u32 = 3;
+}
+
+fn main() {
+	let _v: BoundedVec<u32, MaxLen> = const_bounded_vec![1, 2, 3];
+}
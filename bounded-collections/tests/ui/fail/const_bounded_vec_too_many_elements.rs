@@ -0,0 +1,5 @@
+use bounded_collections::{const_bounded_vec, BoundedVec, ConstU32};
+
+fn main() {
+	let _v: BoundedVec<u32, ConstU32<2>> = const_bounded_vec![1, 2, 3];
+}
@@ -0,0 +1,39 @@
+use bounded_collections::{
+	bounded_vec, const_bounded_vec, parameter_types, BoundedArrayVec, BoundedVec, ConstU32, ConstU64, WeakBoundedVec,
+};
+
+parameter_types! {
+	pub const MaxLen: u32 = 2;
+	pub static RuntimeMaxLen: u32 = 2;
+}
+
+// Any type converting from a `Vec` can be built with `bounded_vec!`, whatever its elements are.
+struct Items(Vec<u32>);
+
+impl TryFrom<Vec<u32>> for Items {
+	type Error = ();
+	fn try_from(items: Vec<u32>) -> Result<Self, ()> {
+		Ok(Items(items))
+	}
+}
+
+fn main() {
+	let v: BoundedVec<u32, ConstU32<3>> = const_bounded_vec![1, 2, 3];
+	assert_eq!(v, vec![1, 2, 3]);
+	let v: BoundedVec<u32, ConstU64<2>> = const_bounded_vec![1, 2];
+	assert_eq!(v, vec![1, 2]);
+	let v: WeakBoundedVec<u32, ConstU32<2>> = const_bounded_vec![];
+	assert!(v.is_empty());
+	let one = 1;
+	let v: BoundedArrayVec<u32, 2> = const_bounded_vec![one, one + 1];
+	assert_eq!(&v[..], &[1, 2]);
+
+	let v: BoundedVec<u32, MaxLen> = bounded_vec![1, 2];
+	assert_eq!(v, vec![1, 2]);
+	let v: BoundedVec<u32, RuntimeMaxLen> = bounded_vec![1, 2];
+	assert_eq!(v, vec![1, 2]);
+	let v: Items = bounded_vec![1, 2, 3];
+	assert_eq!(v.0, vec![1, 2, 3]);
+	let v: Items = bounded_vec![one, one + 1, 3];
+	assert_eq!(v.0, vec![1, 2, 3]);
+}
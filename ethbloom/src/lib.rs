@@ -48,7 +48,7 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use core::{mem, ops};
+use core::{iter::FromIterator, mem, ops};
 
 use crunchy::unroll;
 use fixed_hash::*;
@@ -60,6 +60,9 @@ use impl_rlp::impl_fixed_hash_rlp;
 use impl_serde::impl_fixed_hash_serde;
 use tiny_keccak::{Hasher, Keccak};
 
+#[cfg(feature = "serialize")]
+pub mod serde_compact;
+
 // 3 according to yellowpaper
 const BLOOM_BITS: u32 = 3;
 const BLOOM_SIZE: usize = 256;
@@ -86,11 +89,24 @@ fn log2(x: usize) -> u32 {
 	mem::size_of::<usize>() as u32 * 8 - n
 }
 
+#[derive(Debug, Clone, Copy)]
 pub enum Input<'a> {
 	Raw(&'a [u8]),
 	Hash(&'a [u8; 32]),
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Input<'a> {
+	fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+		if u.arbitrary()? {
+			let hash = u.bytes(32)?.try_into().map_err(|_| arbitrary::Error::NotEnoughData)?;
+			Ok(Input::Hash(hash))
+		} else {
+			Ok(Input::Raw(u.arbitrary()?))
+		}
+	}
+}
+
 enum Hash<'a> {
 	Ref(&'a [u8; 32]),
 	Owned([u8; 32]),
@@ -147,7 +163,21 @@ impl<'a> From<Input<'a>> for Bloom {
 	}
 }
 
+impl<'a> FromIterator<Input<'a>> for Bloom {
+	fn from_iter<I: IntoIterator<Item = Input<'a>>>(inputs: I) -> Bloom {
+		let mut bloom = Bloom::default();
+		bloom.accrue_iter(inputs);
+		bloom
+	}
+}
+
 impl Bloom {
+	/// Creates a bloom with all of the given raw inputs accrued, such as the addresses and topics
+	/// of a log.
+	pub fn from_inputs(inputs: &[&[u8]]) -> Bloom {
+		inputs.iter().map(|input| Input::Raw(input)).collect()
+	}
+
 	pub fn is_empty(&self) -> bool {
 		self.0.iter().all(|x| *x == 0)
 	}
@@ -171,6 +201,18 @@ impl Bloom {
 		self_ref.contains_bloom(bloom)
 	}
 
+	/// Returns `true` if every one of the inputs may have been accrued, checking their combined
+	/// bloom at once.
+	pub fn contains_all<'a>(&self, inputs: impl IntoIterator<Item = Input<'a>>) -> bool {
+		let bloom: Bloom = inputs.into_iter().collect();
+		self.contains_bloom(&bloom)
+	}
+
+	/// Returns `true` if at least one of the inputs may have been accrued.
+	pub fn contains_any<'a>(&self, inputs: impl IntoIterator<Item = Input<'a>>) -> bool {
+		inputs.into_iter().any(|input| self.contains_input(input))
+	}
+
 	pub fn accrue(&mut self, input: Input<'_>) {
 		let p = BLOOM_BITS;
 
@@ -203,6 +245,13 @@ impl Bloom {
 		}
 	}
 
+	/// Accrues each of the inputs, like calling `accrue` for every one of them.
+	pub fn accrue_iter<'a>(&mut self, inputs: impl IntoIterator<Item = Input<'a>>) {
+		for input in inputs {
+			self.accrue(input);
+		}
+	}
+
 	pub fn accrue_bloom<'a, B>(&mut self, bloom: B)
 	where
 		BloomRef<'a>: From<B>,
@@ -218,6 +267,29 @@ impl Bloom {
 	pub fn data(&self) -> &[u8; BLOOM_SIZE] {
 		&self.0
 	}
+
+	/// Returns the number of bits set in both `self` and `other`.
+	pub fn overlap(&self, other: &Self) -> u32 {
+		self.0.iter().zip(other.0.iter()).map(|(a, b)| (a & b).count_ones()).sum()
+	}
+
+	/// Returns the fraction of the bits which are set, from `0.0` for an empty bloom to `1.0`.
+	pub fn fill_ratio(&self) -> f64 {
+		self.count_ones() as f64 / (BLOOM_SIZE * 8) as f64
+	}
+
+	/// Estimates the probability that `contains_input` returns `true` for an input which wasn't
+	/// accrued, as the chance that all of its bits are among the set ones.
+	pub fn estimated_false_positive_rate(&self) -> f64 {
+		let ratio = self.fill_ratio();
+		(0..BLOOM_BITS).fold(1.0, |rate, _| rate * ratio)
+	}
+
+	/// Returns `true` if the fill ratio is at least `threshold`, so that the bloom matches too much
+	/// to be worth querying.
+	pub fn is_saturated(&self, threshold: f64) -> bool {
+		self.fill_ratio() >= threshold
+	}
 }
 
 #[derive(Clone, Copy)]
@@ -314,4 +386,243 @@ mod tests {
 		assert!(my_bloom.contains_input(Input::Raw(&topic)));
 		assert_eq!(my_bloom, bloom);
 	}
+
+	fn log_bloom() -> Bloom {
+		Bloom::from_str(concat!(
+			"00000000000000000000000000000000",
+			"00000000100000000000000000000000",
+			"00000000000000000000000000000000",
+			"00000000000000000000000000000000",
+			"00000000000000000000000000000000",
+			"00000000000000000000000000000000",
+			"00000002020000000000000000000000",
+			"00000000000000000000000800000000",
+			"10000000000000000000000000000000",
+			"00000000000000000000001000000000",
+			"00000000000000000000000000000000",
+			"00000000000000000000000000000000",
+			"00000000000000000000000000000000",
+			"00000000000000000000000000000000",
+			"00000000000000000000000000000000",
+			"00000000000000000000000000000000",
+		))
+		.unwrap()
+	}
+
+	const ADDRESS: [u8; 20] = hex!("ef2d6d194084c2de36e0dabfce45d046b37d1106");
+	const TOPIC: [u8; 32] = hex!("02c69be41d0b7e40352fc85be1cd65eb03d40ef8427a0ca4596b1ead9a00e9fc");
+	const OTHER_TOPIC: [u8; 32] = hex!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef");
+
+	#[test]
+	fn from_iter_matches_accrue() {
+		let bloom: Bloom = [Input::Raw(&ADDRESS), Input::Raw(&TOPIC)].into_iter().collect();
+		assert_eq!(bloom, log_bloom());
+		assert_eq!(Bloom::from_inputs(&[&ADDRESS, &TOPIC]), log_bloom());
+		assert_eq!(Bloom::from_inputs(&[]), Bloom::default());
+
+		let mut accrued = Bloom::from(Input::Raw(&ADDRESS));
+		accrued.accrue_iter([Input::Raw(&TOPIC)]);
+		assert_eq!(accrued, log_bloom());
+	}
+
+	#[test]
+	fn accrue_iter_takes_hashes() {
+		let mut hashed = [0u8; 32];
+		let mut keccak256 = tiny_keccak::Keccak::v256();
+		tiny_keccak::Hasher::update(&mut keccak256, &TOPIC);
+		tiny_keccak::Hasher::finalize(keccak256, &mut hashed);
+
+		let mut bloom = Bloom::from_inputs(&[&ADDRESS]);
+		bloom.accrue_iter([Input::Hash(&hashed)]);
+		assert_eq!(bloom, log_bloom());
+	}
+
+	#[test]
+	fn contains_all_and_any() {
+		let bloom = log_bloom();
+		assert!(bloom.contains_all([Input::Raw(&ADDRESS), Input::Raw(&TOPIC)]));
+		assert!(!bloom.contains_all([Input::Raw(&ADDRESS), Input::Raw(&OTHER_TOPIC)]));
+		assert!(bloom.contains_all([]));
+
+		assert!(bloom.contains_any([Input::Raw(&OTHER_TOPIC), Input::Raw(&TOPIC)]));
+		assert!(!bloom.contains_any([Input::Raw(&OTHER_TOPIC)]));
+		assert!(!bloom.contains_any([]));
+	}
+
+	fn blooms() -> Vec<Bloom> {
+		let mut blooms = vec![Bloom::default(), Bloom::repeat_byte(0xff), log_bloom()];
+		for n in 0u64..8 {
+			let inputs: Vec<[u8; 8]> = (0..n * 3).map(|i| (i * 31 + n).to_be_bytes()).collect();
+			blooms.push(inputs.iter().map(|input| Input::Raw(input)).collect());
+		}
+		blooms.push(Bloom::from_inputs(&[&ADDRESS]));
+		blooms.push(Bloom::from_inputs(&[&TOPIC]));
+		blooms
+	}
+
+	#[test]
+	fn set_operations_agree_with_contains_bloom() {
+		let blooms = blooms();
+		for a in &blooms {
+			for b in &blooms {
+				assert_eq!(a.contains_bloom(b), &(a & b) == b);
+				assert_eq!(a.contains_bloom(b), (a | b) == *a);
+				assert_eq!(a.contains_bloom(b), a.overlap(b) == b.count_ones());
+
+				let mut union = *a;
+				union |= b;
+				assert!(union.contains_bloom(a) && union.contains_bloom(b));
+				let mut intersection = *a;
+				intersection &= b;
+				assert!(a.contains_bloom(&intersection) && b.contains_bloom(&intersection));
+
+				assert_eq!(a.overlap(b), (a & b).count_ones());
+				assert_eq!((a ^ b).count_ones(), a.count_ones() + b.count_ones() - 2 * a.overlap(b));
+				assert_eq!((*a & !b) | (a & b), *a);
+			}
+		}
+	}
+
+	#[test]
+	fn overlap() {
+		let address = Bloom::from_inputs(&[&ADDRESS]);
+		let topic = Bloom::from_inputs(&[&TOPIC]);
+		assert_eq!(address.overlap(&topic), 0);
+		assert_eq!(address.overlap(&log_bloom()), 3);
+		assert_eq!(log_bloom().overlap(&Bloom::repeat_byte(0xff)), 6);
+		assert_eq!(address | topic, log_bloom());
+	}
+
+	#[test]
+	fn saturation_of_empty_and_full_blooms() {
+		let empty = Bloom::default();
+		assert_eq!(empty.count_ones(), 0);
+		assert_eq!(empty.fill_ratio(), 0.0);
+		assert_eq!(empty.estimated_false_positive_rate(), 0.0);
+		assert!(!empty.is_saturated(0.5));
+		assert!(empty.is_saturated(0.0));
+
+		let full = Bloom::repeat_byte(0xff);
+		assert_eq!(full.count_ones(), 2048);
+		assert_eq!(full.fill_ratio(), 1.0);
+		assert_eq!(full.estimated_false_positive_rate(), 1.0);
+		assert!(full.is_saturated(1.0));
+	}
+
+	#[test]
+	fn saturation_of_log_bloom() {
+		// The address and the topic each set 3 bits.
+		let bloom = log_bloom();
+		assert_eq!(bloom.count_ones(), 6);
+		assert_eq!(bloom.fill_ratio(), 6.0 / 2048.0);
+		assert_eq!(bloom.estimated_false_positive_rate(), (6.0f64 / 2048.0).powi(3));
+	}
+
+	#[test]
+	fn saturation_grows_with_inputs() {
+		let inputs: Vec<[u8; 8]> = (0u64..200).map(u64::to_be_bytes).collect();
+		let bloom: Bloom = inputs.iter().map(|input| Input::Raw(input)).collect();
+		// 600 bits are set, less the expected collisions, which leave about 1 - e^(-600/2048)
+		// of the bloom filled.
+		let ratio = bloom.fill_ratio();
+		assert!((0.23..0.27).contains(&ratio), "fill ratio {}", ratio);
+		let rate = bloom.estimated_false_positive_rate();
+		assert!((0.012..0.020).contains(&rate), "false positive rate {}", rate);
+		assert!(bloom.is_saturated(0.2));
+		assert!(!bloom.is_saturated(0.3));
+	}
+
+	// An independent implementation of the m3:2048 bloom of the yellow paper: the low 11 bits of
+	// each of the first three big-endian byte pairs of the keccak hash select a bit, counted from the
+	// least significant bit of the last byte.
+	fn reference_bits(input: &[u8]) -> [usize; 3] {
+		let mut hash = [0u8; 32];
+		let mut keccak256 = tiny_keccak::Keccak::v256();
+		tiny_keccak::Hasher::update(&mut keccak256, input);
+		tiny_keccak::Hasher::finalize(keccak256, &mut hash);
+		core::array::from_fn(|i| u16::from_be_bytes([hash[2 * i], hash[2 * i + 1]]) as usize % 2048)
+	}
+
+	fn reference_bloom(input: &[u8]) -> Bloom {
+		let mut bloom = Bloom::zero();
+		for bit in reference_bits(input) {
+			bloom.0[255 - bit / 8] |= 1 << (bit % 8);
+		}
+		bloom
+	}
+
+	fn random_inputs(seed: u64) -> Vec<Vec<u8>> {
+		use rand::{rngs::StdRng, Rng, SeedableRng};
+
+		let mut rng = StdRng::seed_from_u64(seed);
+		(0..200)
+			.map(|_| {
+				let len = rng.gen_range(0..100);
+				(0..len).map(|_| rng.gen()).collect()
+			})
+			.collect()
+	}
+
+	#[test]
+	fn bits_match_reference_implementation() {
+		assert_eq!(reference_bloom(&ADDRESS) | reference_bloom(&TOPIC), log_bloom());
+		for input in random_inputs(0) {
+			let bloom = Bloom::from(Input::Raw(&input));
+			assert_eq!(bloom, reference_bloom(&input), "input {:?}", input);
+			// the `bit` numbering counts from the most significant bit of the first byte
+			assert!(reference_bits(&input).iter().all(|bit| bloom.bit(2047 - bit)));
+			assert!((1..=3).contains(&bloom.count_ones()));
+		}
+	}
+
+	#[test]
+	fn accrued_inputs_are_contained() {
+		let inputs = random_inputs(1);
+		let mut bloom = Bloom::zero();
+		for (i, input) in inputs.iter().enumerate() {
+			bloom.accrue(Input::Raw(input));
+			assert!(inputs[..=i].iter().all(|input| bloom.contains_input(Input::Raw(input))));
+		}
+	}
+
+	#[test]
+	fn contains_bloom_is_reflexive_and_monotone() {
+		let mut bloom = Bloom::zero();
+		assert!(bloom.contains_bloom(&bloom));
+		for input in random_inputs(2) {
+			let before = bloom;
+			bloom.accrue(Input::Raw(&input));
+			assert!(bloom.contains_bloom(&bloom));
+			assert!(bloom.contains_bloom(&before));
+			assert!(bloom.contains_bloom(&Bloom::from(Input::Raw(&input))));
+		}
+	}
+
+	#[test]
+	#[cfg(feature = "arbitrary")]
+	fn arbitrary_inputs() {
+		use arbitrary::{Arbitrary, Unstructured};
+
+		let data: Vec<u8> = random_inputs(3).concat();
+		let mut u = Unstructured::new(&data);
+		let mut bloom = Bloom::arbitrary(&mut u).unwrap();
+		let (mut raw, mut hashes) = (0, 0);
+		while let Ok(input) = Input::arbitrary(&mut u) {
+			match input {
+				Input::Raw(bytes) => {
+					raw += 1;
+					assert_eq!(Bloom::from(input), reference_bloom(bytes));
+				},
+				Input::Hash(_) => hashes += 1,
+			}
+			let before = bloom;
+			bloom.accrue(input);
+			assert!(bloom.contains_input(input));
+			assert!(bloom.contains_bloom(&before));
+			if u.is_empty() {
+				break
+			}
+		}
+		assert!(raw > 0 && hashes > 0, "{} raw inputs and {} hashes", raw, hashes);
+	}
 }
@@ -0,0 +1,194 @@
+// Copyright 2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Compact serde serialization of `Bloom`, for human-readable formats such as JSON.
+//!
+//! Use with `#[serde(with = "ethbloom::serde_compact")]`. An empty bloom is serialized as `"0x0"`,
+//! a bloom with at most `MAX_SPARSE_BITS` bits set as the list of their indices, numbered as in
+//! `Bloom::bit`, and any other bloom as the usual `0x` prefixed hex string. All three forms can be
+//! deserialized.
+//!
+//! Formats which aren't human-readable use the default representation of `Bloom` instead.
+
+use crate::{Bloom, BLOOM_SIZE};
+use core::fmt;
+use impl_serde::{
+	serde::{
+		de,
+		ser::{self, SerializeSeq},
+		Deserialize, Deserializer, Serialize, Serializer,
+	},
+	serialize::{from_hex, serialize_raw},
+};
+
+/// The largest number of set bits which are serialized as a list of indices.
+pub const MAX_SPARSE_BITS: u32 = 64;
+
+const BLOOM_BITS: usize = BLOOM_SIZE * 8;
+
+/// Serialize `bloom` in its most compact form.
+pub fn serialize<S: Serializer>(bloom: &Bloom, serializer: S) -> Result<S::Ok, S::Error> {
+	if !serializer.is_human_readable() {
+		return bloom.serialize(serializer)
+	}
+
+	let count = bloom.count_ones();
+	if count == 0 {
+		serializer.serialize_str("0x0")
+	} else if count <= MAX_SPARSE_BITS {
+		let mut seq = serializer.serialize_seq(Some(count as usize))?;
+		for index in (0..BLOOM_BITS).filter(|&index| bloom.bit(index)) {
+			seq.serialize_element(&(index as u16))?;
+		}
+		seq.end()
+	} else {
+		let mut slice = [0u8; 2 + 2 * BLOOM_SIZE];
+		serialize_raw(&mut slice, bloom.as_bytes(), serializer).map_err(ser::Error::custom)
+	}
+}
+
+/// Deserialize a bloom from `"0x0"`, a list of set bit indices or a full hex string.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Bloom, D::Error> {
+	if !deserializer.is_human_readable() {
+		return Bloom::deserialize(deserializer)
+	}
+
+	deserializer.deserialize_any(Visitor)
+}
+
+struct Visitor;
+
+impl<'de> de::Visitor<'de> for Visitor {
+	type Value = Bloom;
+
+	fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+		write!(formatter, "\"0x0\", a list of set bit indices or a hex string of {} bytes", BLOOM_SIZE)
+	}
+
+	fn visit_str<E: de::Error>(self, value: &str) -> Result<Bloom, E> {
+		if value == "0x0" {
+			return Ok(Bloom::zero())
+		}
+		let bytes = from_hex(value).map_err(E::custom)?;
+		if bytes.len() != BLOOM_SIZE {
+			return Err(E::invalid_length(bytes.len(), &self))
+		}
+		Ok(Bloom::from_slice(&bytes))
+	}
+
+	fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Bloom, A::Error> {
+		let mut bloom = Bloom::zero();
+		while let Some(index) = seq.next_element::<u64>()? {
+			if index >= BLOOM_BITS as u64 {
+				return Err(de::Error::invalid_value(de::Unexpected::Unsigned(index), &"a bit index below 2048"))
+			}
+			bloom.set_bit(index as usize, true);
+		}
+		Ok(bloom)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::Input;
+	use serde_derive::{Deserialize, Serialize};
+
+	#[derive(Debug, PartialEq, Serialize, Deserialize)]
+	#[serde(crate = "impl_serde::serde")]
+	struct Compact(#[serde(with = "super")] Bloom);
+
+	fn round_trip(bloom: Bloom) -> String {
+		let json = serde_json::to_string(&Compact(bloom)).unwrap();
+		assert_eq!(serde_json::from_str::<Compact>(&json).unwrap(), Compact(bloom));
+		json
+	}
+
+	fn full_length() -> usize {
+		serde_json::to_string(&Bloom::zero()).unwrap().len()
+	}
+
+	#[test]
+	fn empty() {
+		assert_eq!(round_trip(Bloom::zero()), r#""0x0""#);
+		assert_eq!(
+			serde_json::from_str::<Compact>(&serde_json::to_string(&Bloom::zero()).unwrap())
+				.unwrap()
+				.0,
+			Bloom::zero()
+		);
+		assert_eq!(serde_json::from_str::<Compact>("[]").unwrap().0, Bloom::zero());
+	}
+
+	#[test]
+	fn sparse() {
+		let mut bloom = Bloom::zero();
+		bloom.set_bit(0, true);
+		bloom.set_bit(9, true);
+		bloom.set_bit(2047, true);
+		assert_eq!(round_trip(bloom), "[0,9,2047]");
+		assert_eq!(bloom.as_bytes()[..2], [0x80, 0x40]);
+
+		let log: Bloom = [Input::Raw(b"address"), Input::Raw(b"topic")].into_iter().collect();
+		let json = round_trip(log);
+		assert!(json.starts_with('['));
+		println!("log bloom: {} bytes compact, {} bytes in full", json.len(), full_length());
+
+		// The default representation is accepted too.
+		assert_eq!(
+			serde_json::from_str::<Compact>(&serde_json::to_string(&log).unwrap())
+				.unwrap()
+				.0,
+			log
+		);
+	}
+
+	#[test]
+	fn full() {
+		let inputs: Vec<[u8; 8]> = (0u64..100).map(u64::to_be_bytes).collect();
+		let bloom: Bloom = inputs.iter().map(|input| Input::Raw(input)).collect();
+		assert!(bloom.count_ones() > MAX_SPARSE_BITS);
+		let json = round_trip(bloom);
+		assert_eq!(json, serde_json::to_string(&bloom).unwrap());
+		assert_eq!(json.len(), full_length());
+	}
+
+	#[test]
+	fn largest_sparse() {
+		let mut bloom = Bloom::zero();
+		for index in 0..MAX_SPARSE_BITS as usize {
+			bloom.set_bit(index * 32, true);
+		}
+		let json = round_trip(bloom);
+		assert!(json.starts_with('['));
+		println!("{} set bits: {} bytes compact, {} bytes in full", MAX_SPARSE_BITS, json.len(), full_length());
+		assert!(json.len() < full_length());
+
+		bloom.set_bit(1, true);
+		assert!(round_trip(bloom).starts_with("\"0x"));
+	}
+
+	#[test]
+	fn invalid() {
+		assert!(serde_json::from_str::<Compact>("[2048]").is_err());
+		assert!(serde_json::from_str::<Compact>(r#""0x00""#).is_err());
+		assert!(serde_json::from_str::<Compact>(r#""0xzz""#).is_err());
+		assert!(serde_json::from_str::<Compact>("0").is_err());
+	}
+
+	#[test]
+	fn binary_uses_the_default_representation() {
+		let bloom: Bloom = [Input::Raw(b"address")].into_iter().collect();
+		#[derive(Serialize)]
+		#[serde(crate = "impl_serde::serde")]
+		struct Default(Bloom);
+		let encoded = bincode::serialize(&Compact(bloom)).unwrap();
+		assert_eq!(encoded, bincode::serialize(&Default(bloom)).unwrap());
+		assert_eq!(bincode::deserialize::<Compact>(&encoded).unwrap(), Compact(bloom));
+	}
+}
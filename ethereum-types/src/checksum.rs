@@ -0,0 +1,131 @@
+// Copyright 2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [EIP-55](https://eips.ethereum.org/EIPS/eip-55) checksummed addresses.
+
+use crate::Address;
+use core::fmt;
+use tiny_keccak::{Hasher, Keccak};
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+/// Error parsing a checksummed address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChecksumError {
+	/// The address doesn't have 40 hex digits after the optional `0x` prefix.
+	InvalidLength,
+	/// The address contains a character which isn't a hex digit.
+	InvalidCharacter { character: char, index: usize },
+	/// The address has both lowercase and uppercase letters, but their case doesn't match the checksum.
+	ChecksumMismatch,
+}
+
+impl fmt::Display for ChecksumError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match *self {
+			Self::InvalidLength => write!(f, "invalid length: an address has 40 hex digits"),
+			Self::InvalidCharacter { character, index } =>
+				write!(f, "invalid hex character: {}, at {}", character, index),
+			Self::ChecksumMismatch => write!(f, "the case of the address doesn't match its checksum"),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ChecksumError {}
+
+/// EIP-55 checksummed formatting and parsing of addresses.
+///
+/// The checksum is encoded in the case of the hex letters of the address, uppercase where the
+/// matching hex digit of the keccak hash of the lowercase address is at least 8.
+pub trait ChecksumAddress: Sized {
+	/// Returns the `0x` prefixed checksummed form of the address.
+	#[cfg(feature = "std")]
+	fn to_checksum_string(&self) -> String;
+
+	/// Parses an address, with or without the `0x` prefix, verifying its checksum.
+	///
+	/// As in EIP-55, an address with letters in only one case is accepted without verification, as it
+	/// carries no checksum.
+	fn from_checksum_str(value: &str) -> Result<Self, ChecksumError>;
+}
+
+impl ChecksumAddress for Address {
+	#[cfg(feature = "std")]
+	fn to_checksum_string(&self) -> String {
+		Checksummed(self).to_string()
+	}
+
+	fn from_checksum_str(value: &str) -> Result<Self, ChecksumError> {
+		let (digits, prefix_len) = match value.strip_prefix("0x") {
+			Some(digits) => (digits, 2),
+			None => (value, 0),
+		};
+		if digits.len() != 40 {
+			return Err(ChecksumError::InvalidLength)
+		}
+
+		let mut address = Address::zero();
+		for (index, character) in digits.char_indices() {
+			let nibble = character
+				.to_digit(16)
+				.ok_or(ChecksumError::InvalidCharacter { character, index: index + prefix_len })?;
+			// hex digits are ASCII, so the byte index is also the digit index
+			address.0[index / 2] |= (nibble as u8) << if index % 2 == 0 { 4 } else { 0 };
+		}
+
+		let has_lower = digits.bytes().any(|b| b.is_ascii_lowercase());
+		let has_upper = digits.bytes().any(|b| b.is_ascii_uppercase());
+		if has_lower && has_upper && checksum_digits(&address) != digits.as_bytes() {
+			return Err(ChecksumError::ChecksumMismatch)
+		}
+		Ok(address)
+	}
+}
+
+/// Formats an address in its `0x` prefixed EIP-55 checksummed form.
+///
+/// ```
+/// use ethereum_types::{Address, Checksummed};
+///
+/// let address: Address = "5aaeb6053f3e94c9b9a09f33669435e7ef1beaed".parse().unwrap();
+/// assert_eq!(Checksummed(&address).to_string(), "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Checksummed<'a>(pub &'a Address);
+
+impl fmt::Display for Checksummed<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		let mut buf = [0u8; 42];
+		buf[..2].copy_from_slice(b"0x");
+		buf[2..].copy_from_slice(&checksum_digits(self.0));
+		f.pad(core::str::from_utf8(&buf).map_err(|_| fmt::Error)?)
+	}
+}
+
+// Returns the checksummed hex digits of the address, without prefix.
+fn checksum_digits(address: &Address) -> [u8; 40] {
+	let mut digits = [0u8; 40];
+	for (i, byte) in address.as_bytes().iter().enumerate() {
+		digits[2 * i] = HEX_DIGITS[(byte >> 4) as usize];
+		digits[2 * i + 1] = HEX_DIGITS[(byte & 0xf) as usize];
+	}
+
+	let mut hash = [0u8; 32];
+	let mut keccak256 = Keccak::v256();
+	keccak256.update(&digits);
+	keccak256.finalize(&mut hash);
+
+	for (i, digit) in digits.iter_mut().enumerate() {
+		let nibble = if i % 2 == 0 { hash[i / 2] >> 4 } else { hash[i / 2] & 0xf };
+		if nibble >= 8 {
+			digit.make_ascii_uppercase();
+		}
+	}
+	digits
+}
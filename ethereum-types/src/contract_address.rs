@@ -0,0 +1,66 @@
+// Copyright 2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Addresses of contracts created with `CREATE` and
+//! [`CREATE2`](https://eips.ethereum.org/EIPS/eip-1014).
+
+use crate::{Address, H256, U256};
+use tiny_keccak::{Hasher, Keccak};
+
+/// Derivation of the addresses of created contracts.
+pub trait ContractAddress {
+	/// Returns the address of the contract created by `sender` with `CREATE`, the last 20 bytes of
+	/// the keccak hash of the RLP encoding of `[sender, nonce]`.
+	fn from_create(sender: &Address, nonce: U256) -> Self;
+
+	/// Returns the address of the contract created by `sender` with `CREATE2`, the last 20 bytes of
+	/// `keccak256(0xff ++ sender ++ salt ++ code_hash)`, where `code_hash` is the keccak hash of the
+	/// init code.
+	fn from_create2(sender: &Address, salt: H256, code_hash: H256) -> Self;
+}
+
+impl ContractAddress for Address {
+	fn from_create(sender: &Address, nonce: U256) -> Self {
+		let nonce = nonce.to_big_endian();
+		let nonce = &nonce[nonce.iter().take_while(|b| **b == 0).count()..];
+
+		// The list is at most 1 + 21 + 33 bytes long, so its header is a single byte.
+		let mut stream = [0u8; 55];
+		stream[1] = 0x80 + 20;
+		stream[2..22].copy_from_slice(sender.as_bytes());
+		let mut len = 22;
+		match nonce {
+			[byte] if *byte < 0x80 => {
+				stream[len] = *byte;
+				len += 1;
+			},
+			_ => {
+				stream[len] = 0x80 + nonce.len() as u8;
+				stream[len + 1..len + 1 + nonce.len()].copy_from_slice(nonce);
+				len += 1 + nonce.len();
+			},
+		}
+		stream[0] = 0xc0 + (len - 1) as u8;
+
+		address_of_hash(&[&stream[..len]])
+	}
+
+	fn from_create2(sender: &Address, salt: H256, code_hash: H256) -> Self {
+		address_of_hash(&[&[0xff], sender.as_bytes(), salt.as_bytes(), code_hash.as_bytes()])
+	}
+}
+
+fn address_of_hash(input: &[&[u8]]) -> Address {
+	let mut hash = [0u8; 32];
+	let mut keccak = Keccak::v256();
+	for bytes in input {
+		keccak.update(bytes);
+	}
+	keccak.finalize(&mut hash);
+	Address::from_slice(&hash[12..])
+}
@@ -8,12 +8,24 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(feature = "keccak")]
+mod checksum;
+#[cfg(feature = "keccak")]
+mod contract_address;
 mod hash;
+#[cfg(feature = "keccak")]
+mod public_key;
 mod uint;
 
+#[cfg(feature = "keccak")]
+pub use checksum::{ChecksumAddress, ChecksumError, Checksummed};
+#[cfg(feature = "keccak")]
+pub use contract_address::ContractAddress;
 #[cfg(feature = "ethbloom")]
 pub use ethbloom::{Bloom, BloomRef, Input as BloomInput};
 pub use hash::{BigEndianHash, H128, H160, H256, H264, H32, H512, H520, H64};
+#[cfg(feature = "keccak")]
+pub use public_key::{PublicKeyAddress, PublicKeyError};
 pub use uint::{FromDecStrErr, FromStrRadixErr, FromStrRadixErrKind, U128, U256, U512, U64};
 
 pub type Address = H160;
@@ -0,0 +1,77 @@
+// Copyright 2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Addresses of secp256k1 public keys.
+
+use crate::Address;
+use core::fmt;
+use tiny_keccak::{Hasher, Keccak};
+
+/// Tag of an uncompressed SEC1 encoded public key.
+const UNCOMPRESSED_TAG: u8 = 0x04;
+
+/// Error deriving an address from an encoded public key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PublicKeyError {
+	/// The key is neither 64 nor 65 bytes long.
+	InvalidLength(usize),
+	/// The key is 65 bytes long, but doesn't start with the `0x04` tag of uncompressed keys.
+	InvalidTag(u8),
+	/// The key is a compressed SEC1 key, which can't be hashed without decompressing it first.
+	Compressed,
+}
+
+impl fmt::Display for PublicKeyError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match *self {
+			Self::InvalidLength(len) => write!(f, "invalid public key length: expected 64 or 65 bytes, got {}", len),
+			Self::InvalidTag(tag) => write!(f, "invalid public key tag: expected 0x04, got {:#04x}", tag),
+			Self::Compressed => write!(f, "compressed public keys are not supported"),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PublicKeyError {}
+
+/// Derivation of the address of a secp256k1 public key, the last 20 bytes of the keccak hash of its
+/// 64 byte uncompressed encoding without the SEC1 tag.
+///
+/// This only hashes the key, it doesn't check that the key is a point on the curve.
+pub trait PublicKeyAddress: Sized {
+	/// Returns the address of the public key with the 64 byte encoding `public`, the concatenated
+	/// big endian `x` and `y` coordinates, such as the bytes of a `Public`.
+	fn from_public_key_bytes(public: &[u8; 64]) -> Self;
+
+	/// Returns the address of a 65 byte uncompressed SEC1 encoded public key, starting with the
+	/// `0x04` tag, or of a 64 byte encoding without the tag.
+	fn try_from_sec1_bytes(bytes: &[u8]) -> Result<Self, PublicKeyError>;
+}
+
+impl PublicKeyAddress for Address {
+	fn from_public_key_bytes(public: &[u8; 64]) -> Self {
+		let mut hash = [0u8; 32];
+		let mut keccak = Keccak::v256();
+		keccak.update(public);
+		keccak.finalize(&mut hash);
+		Address::from_slice(&hash[12..])
+	}
+
+	fn try_from_sec1_bytes(bytes: &[u8]) -> Result<Self, PublicKeyError> {
+		let public = match bytes.len() {
+			64 => bytes,
+			65 if bytes[0] == UNCOMPRESSED_TAG => &bytes[1..],
+			65 => return Err(PublicKeyError::InvalidTag(bytes[0])),
+			33 if bytes[0] == 0x02 || bytes[0] == 0x03 => return Err(PublicKeyError::Compressed),
+			len => return Err(PublicKeyError::InvalidLength(len)),
+		};
+		let mut buf = [0u8; 64];
+		buf.copy_from_slice(public);
+		Ok(Self::from_public_key_bytes(&buf))
+	}
+}
@@ -0,0 +1,93 @@
+// Copyright 2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use ethereum_types::{Address, ChecksumAddress, ChecksumError, Checksummed};
+
+// The test vectors from EIP-55.
+const VECTORS: [&str; 8] = [
+	// all caps
+	"0x52908400098527886E0F7030069857D2E4169EE7",
+	"0x8617E340B3D01FA5F11F306F4090FD50E238070D",
+	// all lower
+	"0xde709f2102306220921060314715629080e2fb77",
+	"0x27b1fdb04752bbc536007a920d24acb045561c26",
+	// normal
+	"0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+	"0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+	"0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+	"0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+];
+
+fn address(value: &str) -> Address {
+	value.trim_start_matches("0x").to_lowercase().parse().unwrap()
+}
+
+#[test]
+fn eip55_vectors() {
+	for vector in VECTORS {
+		let address = address(vector);
+		assert_eq!(address.to_checksum_string(), vector);
+		assert_eq!(Checksummed(&address).to_string(), vector);
+		assert_eq!(Address::from_checksum_str(vector), Ok(address));
+		assert_eq!(Address::from_checksum_str(&vector[2..]), Ok(address));
+	}
+}
+
+#[test]
+fn single_case_is_not_verified() {
+	for vector in VECTORS {
+		let address = address(vector);
+		let digits = &vector[2..];
+		assert_eq!(Address::from_checksum_str(&digits.to_lowercase()), Ok(address));
+		assert_eq!(Address::from_checksum_str(&format!("0x{}", digits.to_uppercase())), Ok(address));
+	}
+}
+
+#[test]
+fn checksum_mismatch() {
+	// the case of one letter flipped
+	assert_eq!(
+		Address::from_checksum_str("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD"),
+		Err(ChecksumError::ChecksumMismatch)
+	);
+	assert_eq!(
+		Address::from_checksum_str("0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9adb"),
+		Err(ChecksumError::ChecksumMismatch)
+	);
+}
+
+#[test]
+fn invalid_hex() {
+	assert_eq!(
+		Address::from_checksum_str("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeA"),
+		Err(ChecksumError::InvalidLength)
+	);
+	assert_eq!(Address::from_checksum_str(""), Err(ChecksumError::InvalidLength));
+	assert_eq!(
+		Address::from_checksum_str("0X5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"),
+		Err(ChecksumError::InvalidLength)
+	);
+	assert_eq!(
+		Address::from_checksum_str("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeg"),
+		Err(ChecksumError::InvalidCharacter { character: 'g', index: 41 })
+	);
+	assert_eq!(
+		Address::from_checksum_str("5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAé"),
+		Err(ChecksumError::InvalidCharacter { character: 'é', index: 38 })
+	);
+	assert_eq!(
+		ChecksumError::InvalidCharacter { character: 'g', index: 41 }.to_string(),
+		"invalid hex character: g, at 41"
+	);
+}
+
+#[test]
+fn padding() {
+	let address = address(VECTORS[4]);
+	assert_eq!(format!("{:>44}", Checksummed(&address)), format!("  {}", VECTORS[4]));
+}
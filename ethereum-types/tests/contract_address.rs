@@ -0,0 +1,112 @@
+// Copyright 2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use ethereum_types::{Address, ContractAddress, H256, U256};
+use tiny_keccak::{Hasher, Keccak};
+
+fn keccak(bytes: &[u8]) -> H256 {
+	let mut hash = H256::zero();
+	let mut keccak = Keccak::v256();
+	keccak.update(bytes);
+	keccak.finalize(hash.as_bytes_mut());
+	hash
+}
+
+fn address(value: &str) -> Address {
+	value.to_lowercase().parse().unwrap()
+}
+
+fn h256(value: &str) -> H256 {
+	value.parse().unwrap()
+}
+
+#[test]
+fn create() {
+	let sender = address("0x6ac7ea33f8831ea9dcc53393aaa88b25a785dbf0");
+	let expected = [
+		"0xcd234a471b72ba2f1ccf0a70fcaba648a5eecd8d",
+		"0x343c43a37d37dff08ae8c4a11544c718abb4fcf8",
+		"0xf778b86fa74e846c4f0a1fbd1335fe81c00a0c91",
+		"0xfffd933a0bc612844eaf0c6fe3e5b8e9b6c1d19c",
+	];
+	for (nonce, expected) in expected.iter().enumerate() {
+		assert_eq!(Address::from_create(&sender, U256::from(nonce)), address(expected), "nonce {}", nonce);
+	}
+}
+
+#[test]
+fn create_matches_rlp() {
+	let sender = address("0xde0b295669a9fd93d5f28d9ec85e40f4cb697bae");
+	let nonces = (0u64..300)
+		.map(U256::from)
+		.chain([U256::from(u64::MAX), U256::from(u64::MAX) + 1, U256::MAX]);
+	for nonce in nonces {
+		let mut stream = rlp::RlpStream::new_list(2);
+		stream.append(&sender);
+		stream.append(&nonce);
+		let expected = Address::from_slice(&keccak(&stream.out())[12..]);
+		assert_eq!(Address::from_create(&sender, nonce), expected, "nonce {}", nonce);
+	}
+}
+
+// The examples from EIP-1014.
+#[test]
+fn create2() {
+	let vectors = [
+		(
+			"0x0000000000000000000000000000000000000000",
+			"0x0000000000000000000000000000000000000000000000000000000000000000",
+			&[0x00][..],
+			"0x4D1A2e2bB4F88F0250f26Ffff098B0b30B26BF38",
+		),
+		(
+			"0xdeadbeef00000000000000000000000000000000",
+			"0x0000000000000000000000000000000000000000000000000000000000000000",
+			&[0x00],
+			"0xB928f69Bb1D91Cd65274e3c79d8986362984fDA3",
+		),
+		(
+			"0xdeadbeef00000000000000000000000000000000",
+			"0x000000000000000000000000feed000000000000000000000000000000000000",
+			&[0x00],
+			"0xD04116cDd17beBE565EB2422F2497E06cC1C9833",
+		),
+		(
+			"0x0000000000000000000000000000000000000000",
+			"0x0000000000000000000000000000000000000000000000000000000000000000",
+			&[0xde, 0xad, 0xbe, 0xef],
+			"0x70f2b2914A2a4b783FaEFb75f459A580616Fcb5e",
+		),
+		(
+			"0x00000000000000000000000000000000deadbeef",
+			"0x00000000000000000000000000000000000000000000000000000000cafebabe",
+			&[0xde, 0xad, 0xbe, 0xef],
+			"0x60f3f640a8508fC6a86d45DF051962668E1e8AC7",
+		),
+		(
+			"0x00000000000000000000000000000000deadbeef",
+			"0x00000000000000000000000000000000000000000000000000000000cafebabe",
+			&[0xde, 0xad, 0xbe, 0xef].repeat(11),
+			"0x1d8bfDC5D46DC4f61D6b6115972536eBE6A8854C",
+		),
+		(
+			"0x0000000000000000000000000000000000000000",
+			"0x0000000000000000000000000000000000000000000000000000000000000000",
+			&[],
+			"0xE33C0C7F7df4809055C3ebA6c09CFe4BaF1BD9e0",
+		),
+	];
+	for (sender, salt, init_code, expected) in vectors {
+		assert_eq!(
+			Address::from_create2(&address(sender), h256(salt), keccak(init_code)),
+			address(expected),
+			"init code {:?}",
+			init_code
+		);
+	}
+}
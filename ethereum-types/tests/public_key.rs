@@ -0,0 +1,57 @@
+// Copyright 2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use ethereum_types::{Address, Public, PublicKeyAddress, PublicKeyError};
+
+// The public keys of the private keys 1 and 2, the generator point and its double.
+const PUBLIC_1: &str = "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798\
+	483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8";
+const ADDRESS_1: &str = "7e5f4552091a69125d5dfcb7b8c2659029395bdf";
+const PUBLIC_2: &str = "c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5\
+	1ae168fea63dc339a3c58419466ceaeef7f632653266d0e1236431a950cfe52a";
+const ADDRESS_2: &str = "2b5ad5c4795c026514f8317c7a215e218dccd6cf";
+
+fn public(value: &str) -> Public {
+	value.parse().unwrap()
+}
+
+fn address(value: &str) -> Address {
+	value.parse().unwrap()
+}
+
+fn sec1(public: &Public) -> [u8; 65] {
+	let mut bytes = [0x04; 65];
+	bytes[1..].copy_from_slice(public.as_bytes());
+	bytes
+}
+
+#[test]
+fn from_public_key_bytes() {
+	for (key, expected) in [(PUBLIC_1, ADDRESS_1), (PUBLIC_2, ADDRESS_2)] {
+		assert_eq!(Address::from_public_key_bytes(public(key).as_fixed_bytes()), address(expected));
+	}
+}
+
+#[test]
+fn try_from_sec1_bytes() {
+	for (key, expected) in [(PUBLIC_1, ADDRESS_1), (PUBLIC_2, ADDRESS_2)] {
+		let key = public(key);
+		assert_eq!(Address::try_from_sec1_bytes(&sec1(&key)), Ok(address(expected)));
+		assert_eq!(Address::try_from_sec1_bytes(key.as_bytes()), Ok(address(expected)));
+	}
+}
+
+#[test]
+fn try_from_sec1_bytes_rejects_other_encodings() {
+	let mut key = sec1(&public(PUBLIC_1));
+	key[0] = 0x02;
+	assert_eq!(Address::try_from_sec1_bytes(&key), Err(PublicKeyError::InvalidTag(0x02)));
+	assert_eq!(Address::try_from_sec1_bytes(&key[..33]), Err(PublicKeyError::Compressed));
+	assert_eq!(Address::try_from_sec1_bytes(&key[..32]), Err(PublicKeyError::InvalidLength(32)));
+	assert_eq!(Address::try_from_sec1_bytes(&[]), Err(PublicKeyError::InvalidLength(0)));
+}
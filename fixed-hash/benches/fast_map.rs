@@ -0,0 +1,56 @@
+// Copyright 2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Benchmarks comparing `PlainHasher` maps of hashes with the default `HashMap`.
+
+use std::collections::HashMap;
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use rand::{rngs::StdRng, SeedableRng};
+
+use fixed_hash::construct_fixed_hash;
+
+construct_fixed_hash! { pub struct H256(32); }
+
+criterion_group!(fast_map, insert, lookup);
+criterion_main!(fast_map);
+
+fn keys(count: usize) -> Vec<H256> {
+	let mut rng = StdRng::seed_from_u64(0);
+	(0..count).map(|_| H256::random_using(&mut rng)).collect()
+}
+
+fn insert(c: &mut Criterion) {
+	let mut group = c.benchmark_group("insert");
+	for count in [100, 10_000] {
+		let keys = keys(count);
+		group.bench_with_input(BenchmarkId::new("default", count), &keys, |b, keys| {
+			b.iter(|| keys.iter().map(|key| (*key, ())).collect::<HashMap<_, _>>())
+		});
+		group.bench_with_input(BenchmarkId::new("plain", count), &keys, |b, keys| {
+			b.iter(|| keys.iter().map(|key| (*key, ())).collect::<H256FastMap<_>>())
+		});
+	}
+	group.finish();
+}
+
+fn lookup(c: &mut Criterion) {
+	let mut group = c.benchmark_group("lookup");
+	for count in [100, 10_000] {
+		let keys = keys(count);
+		let default: HashMap<_, _> = keys.iter().map(|key| (*key, ())).collect();
+		let plain: H256FastMap<_> = keys.iter().map(|key| (*key, ())).collect();
+		group.bench_with_input(BenchmarkId::new("default", count), &keys, |b, keys| {
+			b.iter(|| keys.iter().filter(|key| default.contains_key(black_box(*key))).count())
+		});
+		group.bench_with_input(BenchmarkId::new("plain", count), &keys, |b, keys| {
+			b.iter(|| keys.iter().filter(|key| plain.contains_key(black_box(*key))).count())
+		});
+	}
+	group.finish();
+}
@@ -0,0 +1,53 @@
+// Copyright 2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Abbreviated formatting of hash types.
+
+use core::fmt;
+
+/// Number of leading bytes printed by `ShortDisplay`.
+const HEAD_BYTES: usize = 4;
+/// Number of trailing bytes printed by `ShortDisplay`.
+const TAIL_BYTES: usize = 2;
+
+/// Abbreviated display of a hash for log lines, returned by the `short` method of hash types.
+///
+/// Prints the first 4 and last 2 bytes as `0x12345678…abcd`. Hashes of at most 8 bytes, which
+/// the abbreviation would hardly shorten, are printed in full.
+#[derive(Clone, Copy)]
+pub struct ShortDisplay<'a>(&'a [u8]);
+
+impl<'a> ShortDisplay<'a> {
+	#[doc(hidden)]
+	pub fn new(bytes: &'a [u8]) -> Self {
+		ShortDisplay(bytes)
+	}
+}
+
+impl fmt::Display for ShortDisplay<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "0x")?;
+		if self.0.len() <= 8 {
+			return self.0.iter().try_for_each(|byte| write!(f, "{:02x}", byte))
+		}
+		for byte in &self.0[..HEAD_BYTES] {
+			write!(f, "{:02x}", byte)?;
+		}
+		write!(f, "…")?;
+		for byte in &self.0[self.0.len() - TAIL_BYTES..] {
+			write!(f, "{:02x}", byte)?;
+		}
+		Ok(())
+	}
+}
+
+impl fmt::Debug for ShortDisplay<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		fmt::Display::fmt(self, f)
+	}
+}
@@ -106,6 +106,12 @@ macro_rules! construct_fixed_hash {
 		}
 
 		impl $name {
+			/// Returns a new fixed hash wrapping the given bytes.
+			#[inline]
+			pub const fn from_array(bytes: [u8; $n_bytes]) -> $name {
+				$name(bytes)
+			}
+
 			/// Returns a new fixed hash where all bits are set to the given byte.
 			#[inline]
 			pub const fn repeat_byte(byte: u8) -> $name {
@@ -207,6 +213,166 @@ macro_rules! construct_fixed_hash {
 			pub fn is_zero(&self) -> bool {
 				self.as_bytes().iter().all(|&byte| byte == 0u8)
 			}
+
+			/// Returns the bit at `index`.
+			///
+			/// Bits are numbered from the most significant bit of the first byte, which is index
+			/// `0`, to the least significant bit of the last byte, so comparing hashes compares
+			/// their bits in index order.
+			///
+			/// # Panics
+			///
+			/// If `index` is not less than the number of bits in the hash.
+			#[inline]
+			pub fn bit(&self, index: usize) -> bool {
+				match self.checked_bit(index) {
+					Some(bit) => bit,
+					None => $crate::core_::panic!(
+						"bit index {} out of range for {}-bit hash",
+						index,
+						Self::len_bytes() * 8
+					),
+				}
+			}
+
+			/// Returns the bit at `index`, numbered as in `bit`, or `None` if `index` is out of
+			/// range.
+			#[inline]
+			pub fn checked_bit(&self, index: usize) -> Option<bool> {
+				let byte = self.0.get(index / 8)?;
+				Some(byte & (0x80 >> (index % 8)) != 0)
+			}
+
+			/// Sets the bit at `index`, numbered as in `bit`, to `value`.
+			///
+			/// # Panics
+			///
+			/// If `index` is not less than the number of bits in the hash.
+			#[inline]
+			pub fn set_bit(&mut self, index: usize, value: bool) {
+				let byte = match self.0.get_mut(index / 8) {
+					Some(byte) => byte,
+					None => $crate::core_::panic!(
+						"bit index {} out of range for {}-bit hash",
+						index,
+						Self::len_bytes() * 8
+					),
+				};
+				let mask = 0x80 >> (index % 8);
+				if value {
+					*byte |= mask;
+				} else {
+					*byte &= !mask;
+				}
+			}
+
+			/// Returns the number of zero bits before the first set bit, which is the index of
+			/// that bit as numbered in `bit`.
+			#[inline]
+			pub fn leading_zeros(&self) -> u32 {
+				match self.0.iter().position(|&byte| byte != 0) {
+					Some(i) => i as u32 * 8 + self.0[i].leading_zeros(),
+					None => (Self::len_bytes() * 8) as u32,
+				}
+			}
+
+			/// Returns the number of zero bits after the last set bit.
+			#[inline]
+			pub fn trailing_zeros(&self) -> u32 {
+				match self.0.iter().rposition(|&byte| byte != 0) {
+					Some(i) => (Self::len_bytes() - 1 - i) as u32 * 8 + self.0[i].trailing_zeros(),
+					None => (Self::len_bytes() * 8) as u32,
+				}
+			}
+
+			/// Returns the number of set bits.
+			#[inline]
+			pub fn count_ones(&self) -> u32 {
+				self.0.iter().map(|byte| byte.count_ones()).sum()
+			}
+
+			/// Returns the nibble at `index`.
+			///
+			/// Nibbles are numbered like bits in `bit`, from the high nibble of the first byte,
+			/// which is index `0`, to the low nibble of the last byte.
+			///
+			/// # Panics
+			///
+			/// If `index` is not less than twice the number of bytes in the hash.
+			#[inline]
+			pub fn nibble_at(&self, index: usize) -> u8 {
+				let byte = match self.0.get(index / 2) {
+					Some(byte) => byte,
+					None => $crate::core_::panic!(
+						"nibble index {} out of range for {}-nibble hash",
+						index,
+						Self::len_bytes() * 2
+					),
+				};
+				if index % 2 == 0 {
+					byte >> 4
+				} else {
+					byte & 0x0f
+				}
+			}
+
+			/// Returns an iterator over the nibbles, in the order of `nibble_at`.
+			#[inline]
+			pub fn iter_nibbles(
+				&self,
+			) -> impl $crate::core_::iter::DoubleEndedIterator<Item = u8> + $crate::core_::iter::ExactSizeIterator + '_ {
+				(0..Self::len_bytes() * 2).map(move |index| self.nibble_at(index))
+			}
+
+			/// Returns the number of leading nibbles `self` and `other` have in common.
+			#[inline]
+			pub fn common_prefix_nibbles(&self, other: &Self) -> usize {
+				match self.0.iter().zip(other.0.iter()).position(|(a, b)| a != b) {
+					Some(i) => i * 2 + ((self.0[i] ^ other.0[i]).leading_zeros() / 4) as usize,
+					None => Self::len_bytes() * 2,
+				}
+			}
+
+			/// Returns an abbreviated display of the hash for log lines, such as `0x12345678…abcd`.
+			///
+			/// Unlike `Display`, which shows the first and last 2 bytes, this shows the first 4
+			/// and last 2 bytes, and hashes of at most 8 bytes in full.
+			#[inline]
+			pub fn short(&self) -> $crate::ShortDisplay<'_> {
+				$crate::ShortDisplay::new(&self.0)
+			}
+
+			/// Returns the XOR distance between `self` and `other`, as used by Kademlia.
+			#[inline]
+			pub fn xor_distance(&self, other: &Self) -> Self {
+				self ^ other
+			}
+
+			/// Returns the base 2 logarithm of the XOR distance between `self` and `other`, rounded
+			/// down, or `None` if they are equal.
+			///
+			/// This is the position of the highest differing bit counted from the least significant
+			/// bit of the last byte, the reverse of the numbering in `bit`, so it equals the number
+			/// of bits minus one minus the `leading_zeros` of the distance.
+			#[inline]
+			pub fn log2_distance(&self, other: &Self) -> Option<u32> {
+				let i = self.0.iter().zip(other.0.iter()).position(|(a, b)| a != b)?;
+				let byte = self.0[i] ^ other.0[i];
+				Some((Self::len_bytes() - i) as u32 * 8 - 1 - byte.leading_zeros())
+			}
+
+			/// Compares the XOR distances from `self` to `a` and to `b`, returning `Less` if `a` is
+			/// closer.
+			#[inline]
+			pub fn cmp_distance(&self, a: &Self, b: &Self) -> $crate::core_::cmp::Ordering {
+				for ((s, a), b) in self.0.iter().zip(a.0.iter()).zip(b.0.iter()) {
+					let ordering = (s ^ a).cmp(&(s ^ b));
+					if ordering != $crate::core_::cmp::Ordering::Equal {
+						return ordering
+					}
+				}
+				$crate::core_::cmp::Ordering::Equal
+			}
 		}
 
 		impl $crate::core_::fmt::Debug for $name {
@@ -253,6 +419,28 @@ macro_rules! construct_fixed_hash {
 			}
 		}
 
+		impl $crate::core_::str::FromStr for $name {
+			type Err = $crate::FromHexError;
+
+			/// Creates a hash type instance from the given string.
+			///
+			/// # Note
+			///
+			/// The given input string is interpreted in big endian. It may have a `0x` or `0X`
+			/// prefix, and surrounding ASCII whitespace is ignored.
+			///
+			/// # Errors
+			///
+			/// - `InvalidCharacter` when encountering a non hex-digit
+			/// - `InvalidLength` when the number of digits is not twice the size of the hash,
+			///   including odd and empty input
+			fn from_str(input: &str) -> $crate::core_::result::Result<$name, $crate::FromHexError> {
+				let mut result = Self::zero();
+				$crate::decode_hex(input, result.as_bytes_mut())?;
+				Ok(result)
+			}
+		}
+
 		impl $crate::core_::marker::Copy for $name {}
 
 		impl $crate::core_::clone::Clone for $name {
@@ -314,12 +502,34 @@ macro_rules! construct_fixed_hash {
 		impl_bit_ops_for_fixed_hash!($name, BitAnd, bitand, BitAndAssign, bitand_assign, &, &=);
 		impl_bit_ops_for_fixed_hash!($name, BitXor, bitxor, BitXorAssign, bitxor_assign, ^, ^=);
 
+		impl<'a> $crate::core_::ops::Not for &'a $name {
+			type Output = $name;
+
+			fn not(self) -> Self::Output {
+				let mut ret = *self;
+				for byte in ret.as_bytes_mut() {
+					*byte = !*byte;
+				}
+				ret
+			}
+		}
+
+		impl $crate::core_::ops::Not for $name {
+			type Output = $name;
+
+			#[inline]
+			fn not(self) -> Self::Output {
+				!&self
+			}
+		}
+
 		impl_byteorder_for_fixed_hash!($name);
 
 		impl_rand_for_fixed_hash!($name);
-		impl_rustc_hex_for_fixed_hash!($name);
 		impl_quickcheck_for_fixed_hash!($name);
 		impl_arbitrary_for_fixed_hash!($name);
+		impl_bytemuck_for_fixed_hash!($name);
+		impl_fast_map_for_fixed_hash!($visibility $name);
 	}
 }
 
@@ -427,12 +637,15 @@ macro_rules! impl_byteorder_for_fixed_hash {
 				self.to_low_u64_with_fn(u64::from_ne_bytes)
 			}
 
-			fn from_low_u64_with_fn(val: u64, to_bytes: fn(u64) -> [u8; 8]) -> Self {
-				let buf = to_bytes(val);
-				let capped = $crate::core_::cmp::min(Self::len_bytes(), 8);
+			const fn from_low_u64_bytes(buf: [u8; 8]) -> Self {
+				let capped = if Self::len_bytes() < 8 { Self::len_bytes() } else { 8 };
 				let mut bytes = [0x0; $crate::core_::mem::size_of::<Self>()];
-				bytes[(Self::len_bytes() - capped)..].copy_from_slice(&buf[..capped]);
-				Self::from_slice(&bytes)
+				let mut i = 0;
+				while i < capped {
+					bytes[Self::len_bytes() - capped + i] = buf[i];
+					i += 1;
+				}
+				$name(bytes)
 			}
 
 			/// Creates a new hash type from the given `u64` value.
@@ -443,8 +656,8 @@ macro_rules! impl_byteorder_for_fixed_hash {
 			/// - Ignores the most significant bits of the given value
 			///   if the hash type has less than 8 bytes.
 			#[inline]
-			pub fn from_low_u64_be(val: u64) -> Self {
-				Self::from_low_u64_with_fn(val, u64::to_be_bytes)
+			pub const fn from_low_u64_be(val: u64) -> Self {
+				Self::from_low_u64_bytes(val.to_be_bytes())
 			}
 
 			/// Creates a new hash type from the given `u64` value.
@@ -455,8 +668,8 @@ macro_rules! impl_byteorder_for_fixed_hash {
 			/// - Ignores the most significant bits of the given value
 			///   if the hash type has less than 8 bytes.
 			#[inline]
-			pub fn from_low_u64_le(val: u64) -> Self {
-				Self::from_low_u64_with_fn(val, u64::to_le_bytes)
+			pub const fn from_low_u64_le(val: u64) -> Self {
+				Self::from_low_u64_bytes(val.to_le_bytes())
 			}
 
 			/// Creates a new hash type from the given `u64` value.
@@ -467,8 +680,8 @@ macro_rules! impl_byteorder_for_fixed_hash {
 			/// - Ignores the most significant bits of the given value
 			///   if the hash type has less than 8 bytes.
 			#[inline]
-			pub fn from_low_u64_ne(val: u64) -> Self {
-				Self::from_low_u64_with_fn(val, u64::to_ne_bytes)
+			pub const fn from_low_u64_ne(val: u64) -> Self {
+				Self::from_low_u64_bytes(val.to_ne_bytes())
 			}
 		}
 	};
@@ -545,60 +758,26 @@ macro_rules! impl_rand_for_fixed_hash {
 				hash.randomize();
 				hash
 			}
-		}
-	};
-}
-
-// Implementation for disabled rustc-hex crate support.
-//
-// # Note
-//
-// Feature guarded macro definitions instead of feature guarded impl blocks
-// to work around the problems of introducing `rustc-hex` crate feature in
-// a user crate.
-#[cfg(not(feature = "rustc-hex"))]
-#[macro_export]
-#[doc(hidden)]
-macro_rules! impl_rustc_hex_for_fixed_hash {
-	( $name:ident ) => {};
-}
 
-// Implementation for enabled rustc-hex crate support.
-//
-// # Note
-//
-// Feature guarded macro definitions instead of feature guarded impl blocks
-// to work around the problems of introducing `rustc-hex` crate feature in
-// a user crate.
-#[cfg(feature = "rustc-hex")]
-#[macro_export]
-#[doc(hidden)]
-macro_rules! impl_rustc_hex_for_fixed_hash {
-	( $name:ident ) => {
-		impl $crate::core_::str::FromStr for $name {
-			type Err = $crate::rustc_hex::FromHexError;
-
-			/// Creates a hash type instance from the given string.
+			/// Create a new hash with content derived from `seed`, which is the same on every run
+			/// and platform.
 			///
 			/// # Note
 			///
-			/// The given input string is interpreted in big endian.
-			///
-			/// # Errors
-			///
-			/// - When encountering invalid non hex-digits
-			/// - Upon empty string input or invalid input length in general
-			fn from_str(input: &str) -> $crate::core_::result::Result<$name, $crate::rustc_hex::FromHexError> {
-				let input = input.strip_prefix("0x").unwrap_or(input);
-				let mut iter = $crate::rustc_hex::FromHexIter::new(input);
-				let mut result = Self::zero();
-				for byte in result.as_mut() {
-					*byte = iter.next().ok_or(Self::Err::InvalidHexLength)??;
-				}
-				if iter.next().is_some() {
-					return Err(Self::Err::InvalidHexLength)
+			/// The content is generated with SplitMix64, which is only fit for reproducible test
+			/// fixtures. It is predictable from the seed and not cryptographically random.
+			pub fn random_seeded(seed: u64) -> Self {
+				let mut state = seed;
+				let mut ret = Self::zero();
+				for chunk in ret.as_bytes_mut().chunks_mut(8) {
+					state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+					let mut z = state;
+					z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+					z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+					z ^= z >> 31;
+					chunk.copy_from_slice(&z.to_be_bytes()[..chunk.len()]);
 				}
-				Ok(result)
+				ret
 			}
 		}
 	};
@@ -632,6 +811,20 @@ macro_rules! impl_quickcheck_for_fixed_hash {
 	( $name:ident ) => {
 		impl $crate::quickcheck::Arbitrary for $name {
 			fn arbitrary(g: &mut $crate::quickcheck::Gen) -> Self {
+				// 20% chance to generate an edge case, which random bytes are unlikely to hit
+				if u8::arbitrary(g) % 5 == 0 {
+					let mut res = Self::zero();
+					match u8::arbitrary(g) % 3 {
+						0 => {},
+						1 => res = Self::repeat_byte(0xff),
+						// a single bit set
+						_ => {
+							let bit = usize::arbitrary(g) % (Self::len_bytes() * 8);
+							res.0[bit / 8] = 1 << (bit % 8);
+						},
+					}
+					return res
+				}
 				let res: [u8; Self::len_bytes()] = $crate::core_::array::from_fn(|_| u8::arbitrary(g));
 				Self::from(res)
 			}
@@ -671,11 +864,115 @@ macro_rules! impl_arbitrary_for_fixed_hash {
 				u.fill_buffer(&mut res.0)?;
 				Ok(Self::from(res))
 			}
+
+			fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+				(Self::len_bytes(), Some(Self::len_bytes()))
+			}
 		}
 	};
 }
 
-/// Implements lossy conversions between the given types.
+// When the `bytemuck` feature is disabled.
+//
+// # Note
+//
+// Feature guarded macro definitions instead of feature guarded impl blocks
+// to work around the problems of introducing `bytemuck` crate feature in
+// a user crate.
+#[cfg(not(feature = "bytemuck"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_bytemuck_for_fixed_hash {
+	( $name:ident ) => {};
+}
+
+// When the `bytemuck` feature is enabled.
+//
+// # Note
+//
+// Feature guarded macro definitions instead of feature guarded impl blocks
+// to work around the problems of introducing `bytemuck` crate feature in
+// a user crate.
+#[cfg(feature = "bytemuck")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_bytemuck_for_fixed_hash {
+	( $name:ident ) => {
+		// SAFETY: the hash type is `#[repr(C)]` with a single `[u8; N]` field, so it has no padding,
+		// an alignment of 1, and every bit pattern, including all zeros, is a valid value.
+		unsafe impl $crate::bytemuck::Zeroable for $name {}
+		unsafe impl $crate::bytemuck::Pod for $name {}
+
+		impl $name {
+			/// Views a slice of hashes as their concatenated bytes, without copying.
+			#[inline]
+			pub fn as_byte_slice(hashes: &[Self]) -> &[u8] {
+				$crate::bytemuck::cast_slice(hashes)
+			}
+
+			/// Views a byte slice as a slice of hashes, without copying.
+			///
+			/// Returns `None` if the length of `bytes` is not a multiple of the length of the hash.
+			#[inline]
+			pub fn from_byte_slice(bytes: &[u8]) -> Option<&[Self]> {
+				$crate::bytemuck::try_cast_slice(bytes).ok()
+			}
+		}
+	};
+}
+
+// When the `std` feature is disabled.
+#[cfg(not(feature = "std"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_fast_map_for_fixed_hash {
+	( $visibility:vis $name:ident ) => {};
+}
+
+// When the `std` feature is enabled.
+//
+// # Note
+//
+// Feature guarded macro definitions instead of feature guarded impl blocks
+// to work around the problems of introducing `std` crate feature in
+// a user crate.
+#[cfg(feature = "std")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_fast_map_for_fixed_hash {
+	( $visibility:vis $name:ident ) => {
+		$crate::paste::paste! {
+			#[doc = "`HashMap` with `" $name "` keys, hashed with `fixed_hash::PlainHasher`."]
+			///
+			/// Only use it for keys which are uniformly distributed, such as hashes, and not chosen
+			/// by an attacker.
+			#[allow(dead_code)]
+			$visibility type [<$name FastMap>]<V> = $crate::FastMap<$name, V>;
+
+			#[doc = "`HashSet` of `" $name "`, hashed with `fixed_hash::PlainHasher`."]
+			///
+			/// Only use it for values which are uniformly distributed, such as hashes, and not
+			/// chosen by an attacker.
+			#[allow(dead_code)]
+			$visibility type [<$name FastSet>] = $crate::FastSet<$name>;
+		}
+	};
+}
+
+/// Implements conversions between the given types.
+///
+/// Generates methods which spell out where the bytes of the smaller type go, named after the
+/// types. For `impl_fixed_hash_conversions!(H256, H160)` those are:
+///
+/// - `H160::to_h256_left_padded`, prepending zero bytes, so the value is the least significant
+///   bytes in big-endian order.
+/// - `H160::to_h256_right_padded`, appending zero bytes.
+/// - `H256::to_h160_lossy_low`, keeping the last bytes, which inverts `to_h256_left_padded`.
+/// - `H256::to_h160_lossy_high`, keeping the first bytes, which inverts `to_h256_right_padded`.
+/// - `H256::try_into_h160`, which keeps the last bytes like `to_h160_lossy_low`, but returns `None`
+///   if any of the discarded bytes are nonzero.
+///
+/// The `From` implementations between the types left pad and keep the low bytes.
 ///
 /// # Note
 ///
@@ -684,8 +981,7 @@ macro_rules! impl_arbitrary_for_fixed_hash {
 ///
 /// # Panics
 ///
-/// Both `From` implementations will panic if sizes of the given types
-/// do not meet the requirements stated above.
+/// Compilation fails if sizes of the given types do not meet the requirements stated above.
 ///
 /// # Example
 ///
@@ -697,6 +993,12 @@ macro_rules! impl_arbitrary_for_fixed_hash {
 /// // now use it!
 /// assert_eq!(H256::from(H160::zero()), H256::zero());
 /// assert_eq!(H160::from(H256::zero()), H160::zero());
+///
+/// let small = H160::repeat_byte(0xff);
+/// assert_eq!(small.to_h256_left_padded().as_bytes()[..12], [0; 12]);
+/// assert_eq!(small.to_h256_right_padded().as_bytes()[20..], [0; 12]);
+/// assert_eq!(small.to_h256_left_padded().try_into_h160(), Some(small));
+/// assert_eq!(small.to_h256_right_padded().try_into_h160(), None);
 /// ```
 #[macro_export(local_inner_macros)]
 macro_rules! impl_fixed_hash_conversions {
@@ -705,35 +1007,175 @@ macro_rules! impl_fixed_hash_conversions {
 			$crate::core_::mem::size_of::<$small_ty>() < $crate::core_::mem::size_of::<$large_ty>()
 		);
 
-		impl From<$small_ty> for $large_ty {
-			fn from(value: $small_ty) -> $large_ty {
-				let large_ty_size = $large_ty::len_bytes();
-				let small_ty_size = $small_ty::len_bytes();
+		$crate::paste::paste! {
+			impl $small_ty {
+				#[doc = "Converts to `" $large_ty "` by prepending zero bytes, so the value is in its last, least significant bytes."]
+				#[inline]
+				pub fn [<to_ $large_ty:lower _left_padded>](self) -> $large_ty {
+					let mut ret = $large_ty::zero();
+					let padding = $large_ty::len_bytes() - $small_ty::len_bytes();
+					ret.as_bytes_mut()[padding..].copy_from_slice(self.as_bytes());
+					ret
+				}
+
+				#[doc = "Converts to `" $large_ty "` by appending zero bytes, so the value is in its first, most significant bytes."]
+				#[inline]
+				pub fn [<to_ $large_ty:lower _right_padded>](self) -> $large_ty {
+					let mut ret = $large_ty::zero();
+					ret.as_bytes_mut()[..$small_ty::len_bytes()].copy_from_slice(self.as_bytes());
+					ret
+				}
+			}
 
-				$crate::core_::debug_assert!(
-					large_ty_size > small_ty_size && large_ty_size % 2 == 0 && small_ty_size % 2 == 0
-				);
+			impl $large_ty {
+				#[doc = "Converts to `" $small_ty "` by keeping the last, least significant bytes."]
+				#[inline]
+				pub fn [<to_ $small_ty:lower _lossy_low>](self) -> $small_ty {
+					let padding = $large_ty::len_bytes() - $small_ty::len_bytes();
+					$small_ty::from_slice(&self.as_bytes()[padding..])
+				}
 
-				let mut ret = $large_ty::zero();
-				ret.as_bytes_mut()[(large_ty_size - small_ty_size)..large_ty_size].copy_from_slice(value.as_bytes());
-				ret
+				#[doc = "Converts to `" $small_ty "` by keeping the first, most significant bytes."]
+				#[inline]
+				pub fn [<to_ $small_ty:lower _lossy_high>](self) -> $small_ty {
+					$small_ty::from_slice(&self.as_bytes()[..$small_ty::len_bytes()])
+				}
+
+				#[doc = "Converts to `" $small_ty "` by keeping the last bytes, or returns `None` if any of the first bytes, which would be discarded, are nonzero."]
+				#[inline]
+				pub fn [<try_into_ $small_ty:lower>](self) -> Option<$small_ty> {
+					let padding = $large_ty::len_bytes() - $small_ty::len_bytes();
+					if self.as_bytes()[..padding].iter().all(|b| *b == 0) {
+						Some(self.[<to_ $small_ty:lower _lossy_low>]())
+					} else {
+						None
+					}
+				}
+			}
+
+			impl From<$small_ty> for $large_ty {
+				#[doc = "Left pads with zero bytes, like `" $small_ty "::to_" $large_ty:lower "_left_padded`."]
+				fn from(value: $small_ty) -> $large_ty {
+					value.[<to_ $large_ty:lower _left_padded>]()
+				}
+			}
+
+			impl From<$large_ty> for $small_ty {
+				#[doc = "Keeps the last bytes, like `" $large_ty "::to_" $small_ty:lower "_lossy_low`."]
+				fn from(value: $large_ty) -> $small_ty {
+					value.[<to_ $small_ty:lower _lossy_low>]()
+				}
 			}
 		}
+	};
+}
+
+/// Implements splitting a hash type into two halves of another and joining them back.
+///
+/// For `impl_fixed_hash_split!(H256, H128)` this generates:
+///
+/// - `H256::split`, returning the first and the last 16 bytes as `(high, low)`.
+/// - `H256::from_parts`, joining `high` and `low` back, which inverts `split`.
+/// - `H128::concat`, joining `self` as the first bytes with another `H128` as the last bytes.
+///
+/// All of them take their arguments by value and are `const fn`.
+///
+/// # Note
+///
+/// Type `whole_ty` must be exactly twice the size of `half_ty`.
+///
+/// # Panics
+///
+/// Compilation fails if sizes of the given types do not meet the requirement stated above.
+///
+/// # Example
+///
+/// ```
+/// use fixed_hash::{construct_fixed_hash, impl_fixed_hash_split};
+/// construct_fixed_hash!{ struct H128(16); }
+/// construct_fixed_hash!{ struct H256(32); }
+/// impl_fixed_hash_split!(H256, H128);
+/// // now use it!
+/// let (r, s) = (H128::repeat_byte(0x11), H128::repeat_byte(0x22));
+/// let signature = r.concat(s);
+/// assert_eq!(signature, H256::from_parts(r, s));
+/// assert_eq!(signature.as_bytes()[..16], [0x11; 16]);
+/// assert_eq!(signature.split(), (r, s));
+/// ```
+#[macro_export(local_inner_macros)]
+macro_rules! impl_fixed_hash_split {
+	($whole_ty:ident, $half_ty:ident) => {
+		$crate::static_assertions::const_assert!(
+			2 * $crate::core_::mem::size_of::<$half_ty>() == $crate::core_::mem::size_of::<$whole_ty>()
+		);
 
-		impl From<$large_ty> for $small_ty {
-			fn from(value: $large_ty) -> $small_ty {
-				let large_ty_size = $large_ty::len_bytes();
-				let small_ty_size = $small_ty::len_bytes();
+		impl $whole_ty {
+			/// Splits into the first and the last half of the bytes, as `(high, low)`.
+			#[inline]
+			pub const fn split(self) -> ($half_ty, $half_ty) {
+				let bytes = self.to_fixed_bytes();
+				let mut high = [0u8; $half_ty::len_bytes()];
+				let mut low = [0u8; $half_ty::len_bytes()];
+				let mut i = 0;
+				while i < $half_ty::len_bytes() {
+					high[i] = bytes[i];
+					low[i] = bytes[$half_ty::len_bytes() + i];
+					i += 1;
+				}
+				($half_ty::from_array(high), $half_ty::from_array(low))
+			}
 
-				$crate::core_::debug_assert!(
-					large_ty_size > small_ty_size && large_ty_size % 2 == 0 && small_ty_size % 2 == 0
-				);
+			/// Joins `high` as the first half of the bytes and `low` as the last half.
+			#[inline]
+			pub const fn from_parts(high: $half_ty, low: $half_ty) -> Self {
+				let high = high.to_fixed_bytes();
+				let low = low.to_fixed_bytes();
+				let mut bytes = [0u8; $whole_ty::len_bytes()];
+				let mut i = 0;
+				while i < $half_ty::len_bytes() {
+					bytes[i] = high[i];
+					bytes[$half_ty::len_bytes() + i] = low[i];
+					i += 1;
+				}
+				$whole_ty::from_array(bytes)
+			}
+		}
 
-				let mut ret = $small_ty::zero();
-				ret.as_bytes_mut()
-					.copy_from_slice(&value[(large_ty_size - small_ty_size)..large_ty_size]);
-				ret
+		impl $half_ty {
+			/// Joins `self` as the first half of the bytes and `low` as the last half, like
+			/// `from_parts`.
+			#[inline]
+			pub const fn concat(self, low: Self) -> $whole_ty {
+				$whole_ty::from_parts(self, low)
 			}
 		}
 	};
 }
+
+/// Creates a hash of type `hash_ty` from a hex string literal at compile time.
+///
+/// The literal may start with `0x`. A literal with a character which isn't a hex digit, or with a
+/// number of digits not matching the size of the hash, fails to compile. The result is a constant,
+/// so the macro can be used to initialize a `const` or `static`.
+///
+/// # Example
+///
+/// ```
+/// use fixed_hash::{construct_fixed_hash, hash_literal};
+/// construct_fixed_hash!{ struct H32(4); }
+/// const DEADBEEF: H32 = hash_literal!(H32, "0xdeadbeef");
+/// assert_eq!(DEADBEEF, H32::from([0xde, 0xad, 0xbe, 0xef]));
+/// ```
+///
+/// ```compile_fail
+/// use fixed_hash::{construct_fixed_hash, hash_literal};
+/// construct_fixed_hash!{ struct H32(4); }
+/// let _ = hash_literal!(H32, "0xdeadbe");
+/// ```
+#[macro_export]
+macro_rules! hash_literal {
+	($hash_ty:ty, $hex:expr) => {{
+		const VALUE: $hash_ty = <$hash_ty>::from_array($crate::decode_hex_literal($hex));
+		VALUE
+	}};
+}
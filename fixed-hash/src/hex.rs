@@ -0,0 +1,107 @@
+// Copyright 2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Parsing of hash types from hex strings.
+
+use core::fmt;
+
+/// Error parsing a hash type from a hex string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FromHexError {
+	/// The number of hex digits doesn't match the size of the hash.
+	InvalidLength {
+		/// Number of digits the hash type requires.
+		expected: usize,
+		/// Number of digits found, not counting the prefix and surrounding whitespace.
+		got: usize,
+	},
+	/// A non hex-digit character was found.
+	InvalidCharacter {
+		/// Byte offset of the character in the input string.
+		at: usize,
+	},
+}
+
+impl fmt::Display for FromHexError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match *self {
+			FromHexError::InvalidLength { expected, got } => {
+				write!(f, "invalid hex length: expected {} digits, got {}", expected, got)
+			},
+			FromHexError::InvalidCharacter { at } => write!(f, "invalid hex character at {}", at),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FromHexError {}
+
+/// Decodes `input` into `bytes`, which must be filled exactly.
+///
+/// Accepts an optional `0x` or `0X` prefix and ignores surrounding ASCII whitespace.
+#[doc(hidden)]
+pub fn decode_hex(input: &str, bytes: &mut [u8]) -> Result<(), FromHexError> {
+	let trimmed = input.trim_start_matches(|c: char| c.is_ascii_whitespace());
+	let offset = input.len() - trimmed.len();
+	let trimmed = trimmed.trim_end_matches(|c: char| c.is_ascii_whitespace());
+	let (digits, offset) = match trimmed.strip_prefix("0x").or_else(|| trimmed.strip_prefix("0X")) {
+		Some(digits) => (digits, offset + 2),
+		None => (trimmed, offset),
+	};
+
+	if let Some(index) = digits.bytes().position(|b| !b.is_ascii_hexdigit()) {
+		return Err(FromHexError::InvalidCharacter { at: offset + index })
+	}
+	if digits.len() != bytes.len() * 2 {
+		return Err(FromHexError::InvalidLength { expected: bytes.len() * 2, got: digits.len() })
+	}
+
+	for (byte, pair) in bytes.iter_mut().zip(digits.as_bytes().chunks_exact(2)) {
+		*byte = (hex_value(pair[0]) << 4) | hex_value(pair[1]);
+	}
+	Ok(())
+}
+
+const fn hex_value(digit: u8) -> u8 {
+	match digit {
+		b'0'..=b'9' => digit - b'0',
+		b'a'..=b'f' => digit - b'a' + 10,
+		_ => digit - b'A' + 10,
+	}
+}
+
+/// Decodes a hex string literal, with an optional `0x` prefix, into `N` bytes in a `const` context,
+/// for `hash_literal!`.
+///
+/// # Panics
+///
+/// If the literal has a character which isn't a hex digit or doesn't have exactly `2 * N` digits,
+/// which is a compile error when evaluated as a constant.
+#[doc(hidden)]
+pub const fn decode_hex_literal<const N: usize>(input: &str) -> [u8; N] {
+	let input = input.as_bytes();
+	let prefix = if input.len() >= 2 && input[0] == b'0' && (input[1] == b'x' || input[1] == b'X') { 2 } else { 0 };
+	let mut i = prefix;
+	while i < input.len() {
+		if !input[i].is_ascii_hexdigit() {
+			panic!("invalid hex character in hash literal");
+		}
+		i += 1;
+	}
+	if input.len() - prefix != N * 2 {
+		panic!("hash literal has the wrong number of hex digits for the hash type");
+	}
+
+	let mut bytes = [0u8; N];
+	let mut i = 0;
+	while i < N {
+		bytes[i] = (hex_value(input[prefix + 2 * i]) << 4) | hex_value(input[prefix + 2 * i + 1]);
+		i += 1;
+	}
+	bytes
+}
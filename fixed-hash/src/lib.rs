@@ -30,6 +30,10 @@ pub use static_assertions;
 #[doc(hidden)]
 pub use static_assertions::const_assert;
 
+// Re-export `paste` for generating the names of the conversion methods.
+#[doc(hidden)]
+pub use paste;
+
 #[cfg(feature = "rustc-hex")]
 #[doc(hidden)]
 pub use rustc_hex;
@@ -46,8 +50,24 @@ pub use quickcheck;
 #[doc(hidden)]
 pub use arbitrary;
 
+#[cfg(feature = "bytemuck")]
+#[doc(hidden)]
+pub use bytemuck;
+
 #[macro_use]
 mod hash;
+mod display;
+mod hex;
+mod plain_hasher;
+
+pub use display::ShortDisplay;
+pub use hex::FromHexError;
+pub use plain_hasher::{BuildPlainHasher, PlainHasher};
+#[cfg(feature = "std")]
+pub use plain_hasher::{FastMap, FastSet};
+
+#[doc(hidden)]
+pub use hex::{decode_hex, decode_hex_literal};
 
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,58 @@
+// Copyright 2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A `Hasher` for keys which are already uniformly distributed, such as hashes.
+
+use core::hash::{BuildHasherDefault, Hasher};
+
+/// A `Hasher` which uses the bytes of the key as the hash, instead of hashing them again.
+///
+/// Keys of at most 8 bytes are used as is, longer keys are reduced to their first 8 bytes XORed
+/// with their last 8 bytes.
+///
+/// # Note
+///
+/// This is only suitable for keys which are themselves uniformly distributed, such as the output
+/// of a cryptographic hash function. Keys which are chosen by an attacker, for example to share
+/// their first and last bytes, all end up in the same bucket and degrade the map to a list.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PlainHasher(u64);
+
+impl Hasher for PlainHasher {
+	#[inline]
+	fn finish(&self) -> u64 {
+		self.0
+	}
+
+	#[inline]
+	fn write(&mut self, bytes: &[u8]) {
+		let len = bytes.len().min(8);
+		let mut head = [0u8; 8];
+		head[..len].copy_from_slice(&bytes[..len]);
+		let mut value = u64::from_ne_bytes(head);
+		if bytes.len() > 8 {
+			let mut tail = [0u8; 8];
+			tail.copy_from_slice(&bytes[bytes.len() - 8..]);
+			value ^= u64::from_ne_bytes(tail);
+		}
+		self.0 = self.0.rotate_left(32) ^ value;
+	}
+}
+
+/// `BuildHasher` creating a `PlainHasher`.
+pub type BuildPlainHasher = BuildHasherDefault<PlainHasher>;
+
+/// `HashMap` hashing its keys with `PlainHasher`, which is only suitable for uniformly distributed
+/// keys.
+#[cfg(feature = "std")]
+pub type FastMap<K, V> = std::collections::HashMap<K, V, BuildPlainHasher>;
+
+/// `HashSet` hashing its values with `PlainHasher`, which is only suitable for uniformly
+/// distributed values.
+#[cfg(feature = "std")]
+pub type FastSet<T> = std::collections::HashSet<T, BuildPlainHasher>;
@@ -13,6 +13,8 @@ construct_fixed_hash! { pub struct H160(20); }
 construct_fixed_hash! { pub struct H256(32); }
 
 impl_fixed_hash_conversions!(H256, H160);
+impl_fixed_hash_split!(H256, H128);
+impl_fixed_hash_split!(H128, H64);
 
 mod repeat_byte {
 	use super::*;
@@ -45,6 +47,160 @@ fn as_bytes() {
 	assert_eq!(H32::from([0x42; 4]).as_bytes_mut(), &mut [0x42; 4]);
 }
 
+mod bits {
+	use super::*;
+
+	#[test]
+	fn bit_ordering() {
+		// Index 0 is the most significant bit of the first byte.
+		let hash = H32::from([0b1000_0000, 0b0000_0001, 0b0010_0000, 0b0000_0011]);
+		let set: [usize; 5] = [0, 15, 18, 30, 31];
+		for index in 0..32 {
+			assert_eq!(hash.bit(index), set.contains(&index), "bit {}", index);
+		}
+		assert!(H256::from_low_u64_be(1).bit(255));
+		assert!(!H256::from_low_u64_be(1).bit(0));
+		assert!(H256::from_low_u64_be(1 << 63).bit(192));
+	}
+
+	#[test]
+	fn bit_ordering_matches_comparison() {
+		let mut low = H64::zero();
+		low.set_bit(63, true);
+		let mut high = H64::zero();
+		high.set_bit(62, true);
+		assert!(low < high);
+	}
+
+	#[test]
+	fn set_bit() {
+		let mut hash = H32::zero();
+		hash.set_bit(0, true);
+		hash.set_bit(9, true);
+		hash.set_bit(31, true);
+		assert_eq!(hash, H32::from([0b1000_0000, 0b0100_0000, 0b0000_0000, 0b0000_0001]));
+		hash.set_bit(0, false);
+		hash.set_bit(9, true);
+		hash.set_bit(30, false);
+		assert_eq!(hash, H32::from([0b0000_0000, 0b0100_0000, 0b0000_0000, 0b0000_0001]));
+	}
+
+	#[test]
+	fn checked_bit() {
+		let hash = H32::repeat_byte(0xff);
+		assert_eq!(hash.checked_bit(31), Some(true));
+		assert_eq!(hash.checked_bit(32), None);
+		assert_eq!(H32::zero().checked_bit(0), Some(false));
+	}
+
+	#[test]
+	#[should_panic(expected = "bit index 32 out of range for 32-bit hash")]
+	fn bit_out_of_range() {
+		H32::zero().bit(32);
+	}
+
+	#[test]
+	#[should_panic(expected = "bit index 256 out of range for 256-bit hash")]
+	fn set_bit_out_of_range() {
+		H256::zero().set_bit(256, true);
+	}
+
+	#[test]
+	fn leading_and_trailing_zeros() {
+		let hash = H32::from([0x00, 0b0001_0000, 0b0000_0100, 0x00]);
+		assert_eq!(hash.leading_zeros(), 11);
+		assert_eq!(hash.trailing_zeros(), 10);
+		assert_eq!(H256::zero().leading_zeros(), 256);
+		assert_eq!(H256::zero().trailing_zeros(), 256);
+		assert_eq!(H256::repeat_byte(0xff).leading_zeros(), 0);
+		assert_eq!(H256::repeat_byte(0xff).trailing_zeros(), 0);
+		assert_eq!(H256::from_low_u64_be(1).leading_zeros(), 255);
+		assert_eq!(H256::from_low_u64_be(1).trailing_zeros(), 0);
+	}
+
+	#[test]
+	fn leading_zeros_is_the_first_set_bit() {
+		let mut hash = H160::zero();
+		hash.set_bit(77, true);
+		hash.set_bit(100, true);
+		assert_eq!(hash.leading_zeros(), 77);
+		assert_eq!(hash.trailing_zeros(), 159 - 100);
+	}
+
+	#[test]
+	fn count_ones() {
+		assert_eq!(H32::from([0b1000_0000, 0b0000_0001, 0b0010_0000, 0b0000_0011]).count_ones(), 5);
+		assert_eq!(H256::zero().count_ones(), 0);
+		assert_eq!(H256::repeat_byte(0xff).count_ones(), 256);
+	}
+}
+
+mod nibbles {
+	use super::*;
+
+	#[test]
+	fn nibble_ordering() {
+		let hash = H32::from([0x12, 0x34, 0xab, 0xcd]);
+		let expected = [0x1, 0x2, 0x3, 0x4, 0xa, 0xb, 0xc, 0xd];
+		for (index, &nibble) in expected.iter().enumerate() {
+			assert_eq!(hash.nibble_at(index), nibble, "nibble {}", index);
+		}
+		assert_eq!(H256::from_low_u64_be(0xf).nibble_at(63), 0xf);
+		assert_eq!(H256::from_low_u64_be(0xf).nibble_at(62), 0x0);
+	}
+
+	#[test]
+	fn iter_agrees_with_nibble_at() {
+		let hash = H256::from([
+			0x7f, 0x86, 0x4e, 0x18, 0xe3, 0xdd, 0x8b, 0x58, 0x38, 0x63, 0x10, 0xd2, 0xfe, 0x09, 0x19, 0xee, 0xf2, 0x7c,
+			0x6e, 0x55, 0x85, 0x64, 0xb7, 0xf6, 0x7f, 0x22, 0xd9, 0x9d, 0x20, 0xf5, 0x87, 0xb4,
+		]);
+		let mut iter = hash.iter_nibbles();
+		assert_eq!(iter.len(), 64);
+		for index in 0..64 {
+			assert_eq!(iter.next(), Some(hash.nibble_at(index)));
+			assert_eq!(iter.len(), 63 - index);
+		}
+		assert_eq!(iter.next(), None);
+
+		for (index, nibble) in hash.iter_nibbles().enumerate().rev() {
+			assert_eq!(nibble, hash.nibble_at(index));
+		}
+		assert_eq!(hash.iter_nibbles().next_back(), Some(0x4));
+	}
+
+	#[test]
+	#[should_panic(expected = "nibble index 8 out of range for 8-nibble hash")]
+	fn nibble_out_of_range() {
+		H32::zero().nibble_at(8);
+	}
+
+	#[test]
+	fn common_prefix_nibbles() {
+		let hash = H32::from([0x12, 0x34, 0x56, 0x78]);
+		assert_eq!(hash.common_prefix_nibbles(&hash), 8);
+		assert_eq!(hash.common_prefix_nibbles(&H32::from([0x12, 0x34, 0x56, 0x79])), 7);
+		assert_eq!(hash.common_prefix_nibbles(&H32::from([0x12, 0x34, 0x57, 0x78])), 5);
+		assert_eq!(hash.common_prefix_nibbles(&H32::from([0x12, 0x35, 0x56, 0x78])), 3);
+		assert_eq!(hash.common_prefix_nibbles(&H32::from([0x12, 0x44, 0x56, 0x78])), 2);
+		assert_eq!(hash.common_prefix_nibbles(&H32::from([0x02, 0x34, 0x56, 0x78])), 0);
+		assert_eq!(hash.common_prefix_nibbles(&H32::from([0x1f, 0x34, 0x56, 0x78])), 1);
+		assert_eq!(H256::zero().common_prefix_nibbles(&H256::from_low_u64_be(1)), 63);
+	}
+
+	#[test]
+	fn common_prefix_agrees_with_iter() {
+		let a = H64::from([0xab, 0xcd, 0xef, 0x01, 0x23, 0x45, 0x67, 0x89]);
+		for index in 0..16 {
+			let mut b = a;
+			b.as_bytes_mut()[index / 2] ^= if index % 2 == 0 { 0x10 } else { 0x01 };
+			let prefix = a.iter_nibbles().zip(b.iter_nibbles()).take_while(|(x, y)| x == y).count();
+			assert_eq!(a.common_prefix_nibbles(&b), prefix);
+			assert_eq!(prefix, index);
+		}
+	}
+}
+
 mod assign_from_slice {
 	use super::*;
 
@@ -235,48 +391,394 @@ mod from_low_u64 {
 	}
 }
 
+mod split {
+	use super::*;
+
+	fn whole() -> H256 {
+		H256(core::array::from_fn(|i| i as u8))
+	}
+
+	#[test]
+	fn halves_are_in_byte_order() {
+		let (high, low) = whole().split();
+		assert_eq!(high, H128(core::array::from_fn(|i| i as u8)));
+		assert_eq!(low, H128(core::array::from_fn(|i| 16 + i as u8)));
+
+		let (high, low) = H256::from_low_u64_be(1).split();
+		assert_eq!(high, H128::zero());
+		assert_eq!(low, H128::from_low_u64_be(1));
+	}
+
+	#[test]
+	fn from_parts_inverts_split() {
+		let (high, low) = whole().split();
+		assert_eq!(H256::from_parts(high, low), whole());
+		assert_eq!(high.concat(low), whole());
+		assert_eq!(H256::from_parts(low, high).split(), (low, high));
+
+		let small =
+			H128([0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef, 0xfe, 0xdc, 0xba, 0x98, 0x76, 0x54, 0x32, 0x10]);
+		let (high, low) = small.split();
+		assert_eq!(high, H64([0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef]));
+		assert_eq!(low, H64([0xfe, 0xdc, 0xba, 0x98, 0x76, 0x54, 0x32, 0x10]));
+		assert_eq!(H128::from_parts(high, low), small);
+	}
+
+	#[test]
+	fn const_split_and_join() {
+		const WHOLE: H256 = H128::repeat_byte(0xaa).concat(H128::repeat_byte(0xbb));
+		const HALVES: (H128, H128) = WHOLE.split();
+		assert_eq!(WHOLE.as_bytes()[..16], [0xaa; 16]);
+		assert_eq!(WHOLE.as_bytes()[16..], [0xbb; 16]);
+		assert_eq!(HALVES, (H128::repeat_byte(0xaa), H128::repeat_byte(0xbb)));
+	}
+}
+
+mod const_constructors {
+	use super::*;
+
+	// Declaring these as constants makes the constructors fail to compile if they stop being `const`.
+	const FROM_ARRAY: H32 = H32::from_array([0x01, 0x23, 0x45, 0x67]);
+	const ZERO: H64 = H64::zero();
+	const REPEAT_BYTE: H128 = H128::repeat_byte(0xAA);
+	const LOW_U64_BE: H128 = H128::from_low_u64_be(0x0123_4567_89AB_CDEF);
+	const LOW_U64_LE: H32 = H32::from_low_u64_le(0x0123_4567_89AB_CDEF);
+	const LOW_U64_NE: H256 = H256::from_low_u64_ne(0x0123_4567_89AB_CDEF);
+
+	#[test]
+	fn match_runtime_constructors() {
+		assert_eq!(FROM_ARRAY, H32::from([0x01, 0x23, 0x45, 0x67]));
+		assert_eq!(ZERO, H64::from([0x00; 8]));
+		assert_eq!(REPEAT_BYTE, H128::from([0xAA; 16]));
+		assert_eq!(LOW_U64_BE, H128::from_low_u64_be(0x0123_4567_89AB_CDEF));
+		assert_eq!(LOW_U64_LE, H32::from_low_u64_le(0x0123_4567_89AB_CDEF));
+		assert_eq!(LOW_U64_NE, H256::from_low_u64_ne(0x0123_4567_89AB_CDEF));
+		assert_eq!(LOW_U64_NE.to_low_u64_ne(), 0x0123_4567_89AB_CDEF);
+	}
+}
+
+mod conversions {
+	use super::*;
+
+	fn small() -> H160 {
+		H160(core::array::from_fn(|i| i as u8 + 1))
+	}
+
+	fn large() -> H256 {
+		H256(core::array::from_fn(|i| i as u8 + 101))
+	}
+
+	#[test]
+	fn widening() {
+		let mut left = [0u8; 32];
+		left[12..].copy_from_slice(small().as_bytes());
+		assert_eq!(small().to_h256_left_padded(), H256(left));
+		assert_eq!(H256::from(small()), H256(left));
+
+		let mut right = [0u8; 32];
+		right[..20].copy_from_slice(small().as_bytes());
+		assert_eq!(small().to_h256_right_padded(), H256(right));
+	}
+
+	#[test]
+	fn narrowing() {
+		assert_eq!(large().to_h160_lossy_low(), H160(core::array::from_fn(|i| i as u8 + 113)));
+		assert_eq!(H160::from(large()), large().to_h160_lossy_low());
+		assert_eq!(large().to_h160_lossy_high(), H160(core::array::from_fn(|i| i as u8 + 101)));
+	}
+
+	#[test]
+	fn round_trips() {
+		assert_eq!(small().to_h256_left_padded().to_h160_lossy_low(), small());
+		assert_eq!(small().to_h256_right_padded().to_h160_lossy_high(), small());
+		assert_eq!(small().to_h256_left_padded().try_into_h160(), Some(small()));
+	}
+
+	#[test]
+	fn checked_narrowing() {
+		assert_eq!(large().try_into_h160(), None);
+		assert_eq!(small().to_h256_right_padded().try_into_h160(), None);
+		assert_eq!(H256::zero().try_into_h160(), Some(H160::zero()));
+
+		let mut last_discarded = small().to_h256_left_padded();
+		last_discarded.0[11] = 1;
+		assert_eq!(last_discarded.try_into_h160(), None);
+	}
+}
+
+mod distance {
+	use super::*;
+	use core::cmp::Ordering;
+
+	fn hashes() -> [H256; 6] {
+		let mut high = H256::zero();
+		high.set_bit(0, true);
+		let mut low = H256::zero();
+		low.set_bit(255, true);
+		[H256::zero(), H256::repeat_byte(0xff), high, low, H256::from_low_u64_be(0x1234), H256::repeat_byte(0x5a)]
+	}
+
+	#[test]
+	fn xor_distance() {
+		let a = H32::from([0xff, 0x00, 0x0f, 0x12]);
+		let b = H32::from([0x0f, 0x00, 0xff, 0x12]);
+		assert_eq!(a.xor_distance(&b), H32::from([0xf0, 0x00, 0xf0, 0x00]));
+		assert_eq!(a.xor_distance(&b), b.xor_distance(&a));
+		assert!(a.xor_distance(&a).is_zero());
+	}
+
+	#[test]
+	fn log2_distance_numbers_bits_from_the_least_significant() {
+		let zero = H256::zero();
+		assert_eq!(zero.log2_distance(&zero), None);
+		assert_eq!(zero.log2_distance(&H256::from_low_u64_be(1)), Some(0));
+		assert_eq!(zero.log2_distance(&H256::from_low_u64_be(0x1234)), Some(12));
+
+		let mut high = H256::zero();
+		high.set_bit(0, true);
+		assert_eq!(zero.log2_distance(&high), Some(255));
+		assert_eq!(H256::repeat_byte(0xff).log2_distance(&high), Some(254));
+		assert_eq!(H32::zero().log2_distance(&H32::from([0, 0x10, 0, 0])), Some(20));
+	}
+
+	#[test]
+	fn log2_distance_agrees_with_leading_zeros() {
+		for a in hashes().iter() {
+			for b in hashes().iter() {
+				let leading_zeros = a.xor_distance(b).leading_zeros();
+				let expected = if leading_zeros == 256 { None } else { Some(255 - leading_zeros) };
+				assert_eq!(a.log2_distance(b), expected);
+			}
+		}
+	}
+
+	#[test]
+	fn cmp_distance() {
+		let origin = H256::from_low_u64_be(0b1000);
+		let near = H256::from_low_u64_be(0b1001);
+		let far = H256::from_low_u64_be(0b0000);
+		assert_eq!(origin.cmp_distance(&near, &far), Ordering::Less);
+		assert_eq!(origin.cmp_distance(&far, &near), Ordering::Greater);
+		assert_eq!(origin.cmp_distance(&near, &near), Ordering::Equal);
+		assert_eq!(origin.cmp_distance(&origin, &near), Ordering::Less);
+
+		for s in hashes().iter() {
+			for a in hashes().iter() {
+				for b in hashes().iter() {
+					assert_eq!(s.cmp_distance(a, b), s.xor_distance(a).cmp(&s.xor_distance(b)));
+				}
+			}
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+mod plain_hasher {
+	use super::*;
+	use crate::{BuildPlainHasher, FastMap};
+	use core::hash::{BuildHasher, Hash, Hasher};
+
+	fn plain_hash<T: Hash>(value: &T) -> u64 {
+		let mut hasher = BuildPlainHasher::default().build_hasher();
+		value.hash(&mut hasher);
+		hasher.finish()
+	}
+
+	#[test]
+	fn uses_first_and_last_bytes() {
+		let hash = H256::from_slice(&core::array::from_fn::<u8, 32, _>(|i| i as u8));
+		let first = u64::from_ne_bytes([0, 1, 2, 3, 4, 5, 6, 7]);
+		let last = u64::from_ne_bytes([24, 25, 26, 27, 28, 29, 30, 31]);
+		assert_eq!(plain_hash(&hash), first ^ last);
+
+		let mut middle = hash;
+		middle.0[16] ^= 0xff;
+		assert_eq!(plain_hash(&middle), plain_hash(&hash));
+	}
+
+	#[test]
+	fn short_keys() {
+		assert_eq!(plain_hash(&H64::from_low_u64_ne(0x0123_4567_89ab_cdef)), 0x0123_4567_89ab_cdef);
+		assert_eq!(plain_hash(&H32::from([1, 2, 3, 4])), u64::from_ne_bytes([1, 2, 3, 4, 0, 0, 0, 0]));
+		assert_ne!(plain_hash(&H32::zero()), plain_hash(&H32::repeat_byte(1)));
+	}
+
+	#[test]
+	fn generated_aliases() {
+		let mut map = H256FastMap::default();
+		let mut set = H160FastSet::default();
+		for i in 0..100u64 {
+			map.insert(H256::from_low_u64_be(i), i);
+			set.insert(H160::from_low_u64_be(i));
+		}
+		assert_eq!(map.len(), 100);
+		assert_eq!(map.get(&H256::from_low_u64_be(42)), Some(&42));
+		assert_eq!(map.get(&H256::from_low_u64_be(100)), None);
+		assert!(set.contains(&H160::from_low_u64_be(7)));
+
+		let mut small: H32FastMap<&str> = FastMap::default();
+		small.insert(H32::repeat_byte(1), "one");
+		assert_eq!(small[&H32::repeat_byte(1)], "one");
+	}
+}
+
 #[cfg(feature = "rand")]
 mod rand {
 	use super::*;
-	use ::rand::{rngs::StdRng, SeedableRng};
+	use ::rand::{distributions::Standard, rngs::StdRng, Rng, SeedableRng};
 
 	#[test]
 	fn random() {
 		let mut rng = StdRng::seed_from_u64(123);
 		assert_eq!(H32::random_using(&mut rng), H32::from([0xeb, 0x96, 0xaf, 0x1c]));
 	}
+
+	#[test]
+	fn standard_distribution() {
+		let mut rng = StdRng::seed_from_u64(123);
+		let hash: H256 = rng.gen();
+		assert_eq!(hash, H256::random_using(&mut StdRng::seed_from_u64(123)));
+
+		let hashes: [H32; 4] = core::array::from_fn(|_| rng.sample(Standard));
+		assert!(hashes.iter().all(|h| !h.is_zero()));
+	}
+
+	#[test]
+	fn random_seeded() {
+		assert_eq!(H32::random_seeded(0), H32::from([0xe2, 0x20, 0xa8, 0x39]));
+		assert_eq!(
+			H256::random_seeded(0),
+			H256::from([
+				0xe2, 0x20, 0xa8, 0x39, 0x7b, 0x1d, 0xcd, 0xaf, 0x6e, 0x78, 0x9e, 0x6a, 0xa1, 0xb9, 0x65, 0xf4, 0x06,
+				0xc4, 0x5d, 0x18, 0x80, 0x09, 0x45, 0x4f, 0xf8, 0x8b, 0xb8, 0xa8, 0x72, 0x4c, 0x81, 0xec
+			])
+		);
+		assert_eq!(
+			H160::random_seeded(42),
+			H160::from([
+				0xbd, 0xd7, 0x32, 0x26, 0x2f, 0xeb, 0x6e, 0x95, 0x28, 0xef, 0xe3, 0x33, 0xb2, 0x66, 0xf1, 0x03, 0x47,
+				0x52, 0x67, 0x57
+			])
+		);
+		assert_eq!(H64::random_seeded(1234567), H64::from_low_u64_be(0x599e_d017_fb08_fc85));
+		assert_ne!(H256::random_seeded(1), H256::random_seeded(2));
+	}
+}
+
+#[cfg(feature = "quickcheck")]
+mod quickcheck {
+	use super::*;
+	use ::quickcheck::{Arbitrary, Gen};
+
+	#[test]
+	fn arbitrary_generates_edge_cases() {
+		let mut g = Gen::new(100);
+		let values: Vec<H256> = (0..1000).map(|_| H256::arbitrary(&mut g)).collect();
+		assert!(values.contains(&H256::zero()));
+		assert!(values.contains(&H256::repeat_byte(0xff)));
+		assert!(values.iter().any(|h| h.0.iter().map(|b| b.count_ones()).sum::<u32>() == 1));
+	}
+}
+
+#[cfg(feature = "bytemuck")]
+mod bytemuck {
+	use super::*;
+
+	#[test]
+	#[cfg(feature = "std")]
+	fn cast_vec_to_bytes_and_back() {
+		let hashes: Vec<H256> = (0..4u8).map(H256::repeat_byte).collect();
+		let bytes = H256::as_byte_slice(&hashes);
+		assert_eq!(bytes.len(), 4 * 32);
+		assert_eq!(&bytes[32..64], &[1; 32]);
+		assert_eq!(&bytes[96..], &[3; 32]);
+
+		let owned = bytes.to_vec();
+		assert_eq!(H256::from_byte_slice(&owned), Some(&hashes[..]));
+	}
+
+	#[test]
+	fn from_byte_slice_checks_length() {
+		assert_eq!(H32::from_byte_slice(&[]), Some(&[][..]));
+		assert_eq!(H32::from_byte_slice(&[1, 2, 3, 4, 5, 6, 7, 8]), Some(&[H32([1, 2, 3, 4]), H32([5, 6, 7, 8])][..]));
+		assert_eq!(H32::from_byte_slice(&[0; 5]), None);
+	}
+
+	#[test]
+	fn pod() {
+		assert_eq!(<H160 as ::bytemuck::Zeroable>::zeroed(), H160::zero());
+		let hash: H64 = ::bytemuck::cast(0x0102_0304_0506_0708u64.to_be_bytes());
+		assert_eq!(hash, H64([1, 2, 3, 4, 5, 6, 7, 8]));
+	}
 }
 
-#[cfg(feature = "rustc-hex")]
 mod from_str {
 	use super::*;
+	use crate::{core_::str::FromStr, FromHexError};
+
+	fn expected() -> H64 {
+		H64::from([0x01, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF])
+	}
 
 	#[test]
 	fn valid() {
-		use crate::core_::str::FromStr;
+		assert_eq!(H64::from_str("0123456789ABCDEF").unwrap(), expected());
+		assert_eq!(H64::from_str("0123456789abcdef").unwrap(), expected());
+	}
 
-		assert_eq!(
-			H64::from_str("0123456789ABCDEF").unwrap(),
-			H64::from([0x01, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF])
-		)
+	#[test]
+	fn prefixed() {
+		assert_eq!(H64::from_str("0x0123456789ABCDEF").unwrap(), expected());
+		assert_eq!(H64::from_str("0X0123456789abcdef").unwrap(), expected());
+	}
+
+	#[test]
+	fn surrounding_whitespace() {
+		assert_eq!(H64::from_str("  0x0123456789abcdef\n").unwrap(), expected());
+		assert_eq!(H64::from_str("\t0123456789abcdef ").unwrap(), expected());
+		assert_eq!(H64::from_str("0x 0123456789abcdef"), Err(FromHexError::InvalidCharacter { at: 2 }));
 	}
 
 	#[test]
 	fn empty_str() {
-		use crate::core_::str::FromStr;
-		assert!(H64::from_str("").is_err())
+		assert_eq!(H64::from_str(""), Err(FromHexError::InvalidLength { expected: 16, got: 0 }));
+		assert_eq!(H64::from_str("0x"), Err(FromHexError::InvalidLength { expected: 16, got: 0 }));
+		assert_eq!(H64::from_str("  "), Err(FromHexError::InvalidLength { expected: 16, got: 0 }));
 	}
 
 	#[test]
 	fn invalid_digits() {
-		use crate::core_::str::FromStr;
-		assert!(H64::from_str("Hello, World!").is_err())
+		assert_eq!(H64::from_str("Hello, World!"), Err(FromHexError::InvalidCharacter { at: 0 }));
+		assert_eq!(H64::from_str("0123456789ABCDEG"), Err(FromHexError::InvalidCharacter { at: 15 }));
+		assert_eq!(H64::from_str(" 0x0123456789ABCDEG"), Err(FromHexError::InvalidCharacter { at: 18 }));
+		assert_eq!(H64::from_str("0x0123456789ABCDé"), Err(FromHexError::InvalidCharacter { at: 16 }));
+		assert_eq!(H64::from_str("0x0x0123456789ABCD"), Err(FromHexError::InvalidCharacter { at: 3 }));
+	}
+
+	#[test]
+	fn odd_length() {
+		assert_eq!(H64::from_str("0123456789ABCDE"), Err(FromHexError::InvalidLength { expected: 16, got: 15 }));
+		assert_eq!(H64::from_str("0x0123456789ABCDEF0"), Err(FromHexError::InvalidLength { expected: 16, got: 17 }));
 	}
 
 	#[test]
 	fn too_many_digits() {
-		use crate::core_::str::FromStr;
-		assert!(H64::from_str("0123456789ABCDEF0").is_err())
+		assert_eq!(H64::from_str("0123456789ABCDEF01"), Err(FromHexError::InvalidLength { expected: 16, got: 18 }));
+	}
+
+	#[test]
+	fn too_few_digits() {
+		assert_eq!(H64::from_str("0x01234567"), Err(FromHexError::InvalidLength { expected: 16, got: 8 }));
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn error_display() {
+		assert_eq!(
+			FromHexError::InvalidLength { expected: 16, got: 15 }.to_string(),
+			"invalid hex length: expected 16 digits, got 15"
+		);
+		assert_eq!(FromHexError::InvalidCharacter { at: 3 }.to_string(), "invalid hex character at 3");
 	}
 }
 
@@ -347,6 +849,31 @@ fn display_and_debug() {
 	test_for(0x1000, "0000000000001000", "0000…1000");
 }
 
+#[test]
+#[cfg(feature = "std")]
+fn short_display() {
+	let h256 = H256::from_slice(&core::array::from_fn::<u8, 32, _>(|i| i as u8 * 7));
+	assert_eq!(format!("{}", h256.short()), "0x00070e15…d2d9");
+	assert_eq!(format!("{:?}", h256.short()), "0x00070e15…d2d9");
+	assert_eq!(format!("{}", H160::repeat_byte(0xab).short()), "0xabababab…abab");
+	assert_eq!(format!("{}", H128::from_low_u64_be(0x1234).short()), "0x00000000…1234");
+
+	assert_eq!(format!("{}", H64::from_low_u64_be(0x0123_4567_89ab_cdef).short()), "0x0123456789abcdef");
+	assert_eq!(format!("{}", H32::from([0xde, 0xad, 0xbe, 0xef]).short()), "0xdeadbeef");
+
+	// `Display` is unchanged.
+	assert_eq!(format!("{}", h256), "0x0007…d2d9");
+}
+
+#[test]
+fn hash_literal() {
+	const H32_LITERAL: H32 = hash_literal!(H32, "0xdeadBEEF");
+	static H64_LITERAL: H64 = hash_literal!(H64, "0X0123456789abcdef");
+	assert_eq!(H32_LITERAL, H32::from([0xde, 0xad, 0xbe, 0xef]));
+	assert_eq!(H64_LITERAL, H64::from_low_u64_be(0x0123_4567_89ab_cdef));
+	assert_eq!(hash_literal!(H128, "00000000000000000000000000001234"), H128::from_low_u64_be(0x1234));
+}
+
 #[test]
 fn const_matching_works() {
 	const ONES: H32 = H32::repeat_byte(1);
@@ -393,6 +920,14 @@ mod ops {
 		)
 	}
 
+	#[test]
+	fn not() {
+		assert_eq!(!lhs(), H32::from([!0b0011_0110, !0b0001_0011, !0b1010_1010, !0b0001_0010]));
+		assert_eq!(!&lhs(), !lhs());
+		assert_eq!(!H256::zero(), H256::repeat_byte(0xff));
+		assert_eq!(!!lhs(), lhs());
+	}
+
 	#[test]
 	fn bitxor() {
 		assert_eq!(
@@ -7,9 +7,15 @@
 // except according to those terms.
 
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use keccak_hash::keccak;
+use keccak_hash::{keccak, keccak_batch, H256};
 
-criterion_group!(keccak_256, keccak_256_with_empty_input, keccak_256_with_typical_input, keccak_256_with_large_input,);
+criterion_group!(
+	keccak_256,
+	keccak_256_with_empty_input,
+	keccak_256_with_typical_input,
+	keccak_256_with_large_input,
+	keccak_256_batch,
+);
 criterion_main!(keccak_256);
 
 pub fn keccak_256_with_empty_input(c: &mut Criterion) {
@@ -53,3 +59,24 @@ pub fn keccak_256_with_large_input(c: &mut Criterion) {
 		})
 	});
 }
+
+/// Run with `--features rayon` to compare with the parallel batch.
+pub fn keccak_256_batch(c: &mut Criterion) {
+	let data: Vec<u8> = (0..10_000 * 512).map(|i| (i * 7 % 251) as u8).collect();
+	let inputs: Vec<&[u8]> = data.chunks(512).collect();
+	let mut out = vec![H256::zero(); inputs.len()];
+	let mut group = c.benchmark_group("keccak_256_batch_10k_512_bytes");
+	group.bench_function("one_by_one", |b| {
+		b.iter(|| {
+			for (input, out) in inputs.iter().zip(&mut out) {
+				*out = keccak(black_box(input));
+			}
+		})
+	});
+	group.bench_function("keccak_batch", |b| {
+		b.iter(|| {
+			keccak_batch(black_box(&inputs), &mut out);
+		})
+	});
+	group.finish();
+}
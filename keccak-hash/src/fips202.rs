@@ -0,0 +1,142 @@
+// Copyright 2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The SHA3 hashes of FIPS-202, which differ from the Keccak hashes used by Ethereum.
+
+use primitive_types::{H256, H512};
+use tiny_keccak::{Hasher, Sha3};
+
+/// Returns the FIPS-202 SHA3-256 hash of `data`, which is not the keccak256 hash of `keccak`.
+///
+/// ```
+/// use keccak_hash::{keccak, sha3_256};
+/// assert_ne!(sha3_256(b"cat"), keccak(b"cat"));
+/// ```
+pub fn sha3_256(data: &[u8]) -> H256 {
+	let mut hasher = Sha3Hasher::new();
+	hasher.update(data);
+	hasher.finalize()
+}
+
+/// Returns the FIPS-202 SHA3-512 hash of `data`, which is not the keccak512 hash of
+/// `keccak512_hash`.
+pub fn sha3_512(data: &[u8]) -> H512 {
+	let mut hasher = Sha3_512Hasher::new();
+	hasher.update(data);
+	hasher.finalize()
+}
+
+/// Incremental FIPS-202 SHA3-256 hasher, like `KeccakHasher` for keccak256.
+#[derive(Clone)]
+pub struct Sha3Hasher(Sha3);
+
+impl Sha3Hasher {
+	/// Creates a hasher of the empty input.
+	pub fn new() -> Self {
+		Sha3Hasher(Sha3::v256())
+	}
+
+	/// Appends `data` to the input.
+	pub fn update(&mut self, data: &[u8]) {
+		self.0.update(data);
+	}
+
+	/// Returns the hash of the input.
+	pub fn finalize(self) -> H256 {
+		let mut output = H256::zero();
+		self.0.finalize(output.as_bytes_mut());
+		output
+	}
+}
+
+impl Default for Sha3Hasher {
+	fn default() -> Self {
+		Sha3Hasher::new()
+	}
+}
+
+/// Incremental FIPS-202 SHA3-512 hasher, like `Keccak512Hasher` for keccak512.
+#[derive(Clone)]
+pub struct Sha3_512Hasher(Sha3);
+
+impl Sha3_512Hasher {
+	/// Creates a hasher of the empty input.
+	pub fn new() -> Self {
+		Sha3_512Hasher(Sha3::v512())
+	}
+
+	/// Appends `data` to the input.
+	pub fn update(&mut self, data: &[u8]) {
+		self.0.update(data);
+	}
+
+	/// Returns the hash of the input.
+	pub fn finalize(self) -> H512 {
+		let mut output = H512::zero();
+		self.0.finalize(output.as_bytes_mut());
+		output
+	}
+}
+
+impl Default for Sha3_512Hasher {
+	fn default() -> Self {
+		Sha3_512Hasher::new()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const ALPHABET: &[u8] = b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq";
+
+	/// The test vectors of the NIST examples of SHA3-256.
+	#[test]
+	fn sha3_256_test_vectors() {
+		let vectors = [
+			(&b""[..], primitive_types::h256!("a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a")),
+			(&b"abc"[..], primitive_types::h256!("3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532")),
+			(ALPHABET, primitive_types::h256!("41c0dba2a9d6240849100376a8235e2c82e1b9998a999e21db32dd97496d3376")),
+		];
+		for (input, expected) in vectors {
+			assert_eq!(sha3_256(input), expected);
+			let mut hasher = Sha3Hasher::default();
+			for chunk in input.chunks(5) {
+				hasher.update(chunk);
+			}
+			assert_eq!(hasher.finalize(), expected);
+		}
+	}
+
+	/// The test vectors of the NIST examples of SHA3-512.
+	#[test]
+	fn sha3_512_test_vectors() {
+		let vectors = [
+			(
+				&b""[..],
+				primitive_types::h512!("a69f73cca23a9ac5c8b567dc185a756e97c982164fe25859e0d1dcc1475c80a615b2123af1f5f94c11e3e9402c3ac558f500199d95b6d3e301758586281dcd26"),
+			),
+			(
+				&b"abc"[..],
+				primitive_types::h512!("b751850b1a57168a5693cd924b6b096e08f621827444f70d884f5d0240d2712e10e116e9192af3c91a7ec57647e3934057340b4cf408d5a56592f8274eec53f0"),
+			),
+			(
+				ALPHABET,
+				primitive_types::h512!("04a371e84ecfb5b8b77cb48610fca8182dd457ce6f326a0fd3d7ec2f1e91636dee691fbe0c985302ba1b0d8dc78c086346b533b49c030d99a27daf1139d6e75e"),
+			),
+		];
+		for (input, expected) in vectors {
+			assert_eq!(sha3_512(input), expected);
+			let mut hasher = Sha3_512Hasher::default();
+			for chunk in input.chunks(5) {
+				hasher.update(chunk);
+			}
+			assert_eq!(hasher.finalize(), expected);
+		}
+	}
+}
@@ -6,14 +6,41 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+//! Keccak hashes as used by Ethereum, and optionally the FIPS-202 SHA3 hashes.
+//!
+//! Ethereum's "SHA3" is the original Keccak submission, which pads the input differently from the
+//! SHA3 standardized in FIPS-202, so the two give different hashes of the same input:
+//!
+//! | Hash | Functions | Use for |
+//! |------|-----------|---------|
+//! | Keccak-256 | `keccak`, `KeccakHasher`, ... | Ethereum: addresses, tries, the `SHA3` opcode, Solidity's `keccak256` |
+//! | Keccak-512 | `keccak512_hash`, `Keccak512Hasher`, ... | Ethereum: ethash |
+//! | SHA3-256 | `sha3_256`, `Sha3Hasher`, with the `fips202` feature | Other systems following FIPS-202 |
+//! | SHA3-512 | `sha3_512`, `Sha3_512Hasher`, with the `fips202` feature | Other systems following FIPS-202 |
+
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(feature = "fips202")]
+mod fips202;
+
 #[cfg(feature = "std")]
 use std::io;
 
-pub use primitive_types::H256;
+use core::hash;
+
+pub use primitive_types::{H256, H512};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
 use tiny_keccak::{Hasher, Keccak};
 
+#[cfg(feature = "fips202")]
+pub use self::fips202::{sha3_256, sha3_512, Sha3Hasher, Sha3_512Hasher};
+
+/// The number of items hashed by each task of the parallel batch functions, enough to outweigh the
+/// cost of a task.
+#[cfg(feature = "rayon")]
+const BATCH_CHUNK_SIZE: usize = 64;
+
 /// Get the KECCAK (i.e. Keccak) hash of the empty bytes string.
 pub const KECCAK_EMPTY: H256 = H256([
 	0xc5, 0xd2, 0x46, 0x01, 0x86, 0xf7, 0x23, 0x3c, 0x92, 0x7e, 0x7d, 0xb2, 0xdc, 0xc7, 0x03, 0xc0, 0xe5, 0x00, 0xb6,
@@ -38,6 +65,116 @@ pub fn keccak<T: AsRef<[u8]>>(s: T) -> H256 {
 	H256(result)
 }
 
+/// Returns the keccak512 hash of `data`, like `keccak` for keccak256.
+///
+/// The in-place `keccak512` predates it, hence the name.
+///
+/// ```
+/// use keccak_hash::{keccak512_hash, keccak_512};
+/// let mut output = [0u8; 64];
+/// keccak_512(b"cat", &mut output);
+/// assert_eq!(keccak512_hash(b"cat").0, output);
+/// ```
+pub fn keccak512_hash(data: &[u8]) -> H512 {
+	let mut dest = H512::zero();
+	keccak512_into(data, &mut dest);
+	dest
+}
+
+/// Writes the keccak256 hash of `data` to `dest`, to reuse it in loops instead of returning a new
+/// hash.
+pub fn keccak256_into(data: &[u8], dest: &mut H256) {
+	let mut keccak256 = Keccak::v256();
+	keccak256.update(data);
+	keccak256.finalize(dest.as_bytes_mut());
+}
+
+/// Writes the keccak512 hash of `data` to `dest`, like `keccak256_into`.
+pub fn keccak512_into(data: &[u8], dest: &mut H512) {
+	let mut keccak512 = Keccak::v512();
+	keccak512.update(data);
+	keccak512.finalize(dest.as_bytes_mut());
+}
+
+/// Returns the keccak256 hash of the concatenation of `parts`, without concatenating them.
+///
+/// ```
+/// use keccak_hash::{keccak, keccak_concat};
+/// let parts: [&[u8]; 3] = [b"domain", b"payload", b"salt"];
+/// assert_eq!(keccak_concat(parts), keccak(parts.concat()));
+/// ```
+pub fn keccak_concat<'a>(parts: impl IntoIterator<Item = &'a [u8]>) -> H256 {
+	let mut hasher = KeccakHasher::new();
+	for part in parts {
+		hasher.update(part);
+	}
+	hasher.finalize()
+}
+
+/// Returns the keccak256 hash of the concatenation of `a` and `b`, the same as `keccak_concat`.
+///
+/// ```
+/// use keccak_hash::{keccak, keccak2};
+/// assert_eq!(keccak2(b"left", b"right"), keccak(b"leftright"));
+/// ```
+pub fn keccak2(a: &[u8], b: &[u8]) -> H256 {
+	let mut hasher = KeccakHasher::new();
+	hasher.update(a);
+	hasher.update(b);
+	hasher.finalize()
+}
+
+/// Writes the keccak256 hash of each of `inputs` to the same index of `out`.
+///
+/// With the `rayon` feature, the inputs are hashed in parallel.
+///
+/// # Panics
+///
+/// If `inputs` and `out` don't have the same length.
+///
+/// ```
+/// use keccak_hash::{keccak, keccak_batch, H256};
+/// let inputs: [&[u8]; 2] = [b"cat", b"dog"];
+/// let mut out = [H256::zero(); 2];
+/// keccak_batch(&inputs, &mut out);
+/// assert_eq!(out, [keccak(b"cat"), keccak(b"dog")]);
+/// ```
+pub fn keccak_batch(inputs: &[&[u8]], out: &mut [H256]) {
+	assert_eq!(inputs.len(), out.len(), "keccak_batch needs as many outputs as inputs");
+	#[cfg(feature = "rayon")]
+	inputs
+		.par_chunks(BATCH_CHUNK_SIZE)
+		.zip(out.par_chunks_mut(BATCH_CHUNK_SIZE))
+		.for_each(|(inputs, out)| keccak_batch_sequential(inputs, out));
+	#[cfg(not(feature = "rayon"))]
+	keccak_batch_sequential(inputs, out);
+}
+
+fn keccak_batch_sequential(inputs: &[&[u8]], out: &mut [H256]) {
+	for (input, out) in inputs.iter().zip(out) {
+		write_keccak(input, out.as_bytes_mut());
+	}
+}
+
+/// Computes in-place keccak256 hash of each of `data`, like `keccak256`.
+///
+/// With the `rayon` feature, the buffers are hashed in parallel.
+///
+/// ```
+/// let mut cat = *b"cat, padded to 32 bytes.........";
+/// let mut dog = *b"dog, padded to 32 bytes.........";
+/// keccak_hash::keccak256_batch(&mut [&mut cat, &mut dog]);
+/// assert_eq!(cat, keccak_hash::keccak(b"cat, padded to 32 bytes.........").0);
+/// assert_eq!(dog, keccak_hash::keccak(b"dog, padded to 32 bytes.........").0);
+/// ```
+pub fn keccak256_batch(data: &mut [&mut [u8]]) {
+	#[cfg(feature = "rayon")]
+	data.par_chunks_mut(BATCH_CHUNK_SIZE)
+		.for_each(|data| data.iter_mut().for_each(|data| keccak256(data)));
+	#[cfg(not(feature = "rayon"))]
+	data.iter_mut().for_each(|data| keccak256(data));
+}
+
 /// Computes in-place keccak256 hash of `data`.
 pub fn keccak256(data: &mut [u8]) {
 	let mut keccak256 = Keccak::v256();
@@ -103,6 +240,11 @@ pub fn keccak512_range(data: &mut [u8], range: core::ops::Range<usize>) {
 	keccak512.finalize(data);
 }
 
+/// Writes the keccak256 hash of `input` to `output`, like `write_keccak`.
+///
+/// # Panics
+///
+/// If `output` isn't 32 bytes long.
 pub fn keccak_256(input: &[u8], output: &mut [u8]) {
 	write_keccak(input, output);
 }
@@ -113,7 +255,14 @@ pub fn keccak_512(input: &[u8], output: &mut [u8]) {
 	keccak512.finalize(output);
 }
 
+/// Writes the keccak256 hash of `s` to `dest`.
+///
+/// # Panics
+///
+/// If `dest` isn't 32 bytes long, rather than writing part of the hash or more output of the sponge
+/// than the hash.
 pub fn write_keccak<T: AsRef<[u8]>>(s: T, dest: &mut [u8]) {
+	assert_eq!(dest.len(), 32, "the destination of a keccak256 hash must be 32 bytes long");
 	let mut keccak256 = Keccak::v256();
 	keccak256.update(s.as_ref());
 	keccak256.finalize(dest);
@@ -144,6 +293,103 @@ pub fn keccak_buffer(r: &mut dyn io::BufRead) -> Result<H256, io::Error> {
 	keccak_pipe(r, &mut io::sink())
 }
 
+/// Incremental keccak256 hasher, for input which isn't contiguous in memory.
+///
+/// ```
+/// use keccak_hash::{keccak, KeccakHasher};
+/// let mut hasher = KeccakHasher::new();
+/// hasher.update(b"hello");
+/// hasher.update(b" world");
+/// assert_eq!(hasher.finalize(), keccak(b"hello world"));
+/// ```
+///
+/// It also implements `core::hash::Hasher`, so it can be used with hash maps through
+/// `BuildHasherDefault`, with the last 8 bytes of the hash as the `u64` hash.
+///
+/// ```
+/// use std::{collections::HashMap, hash::BuildHasherDefault};
+/// use keccak_hash::KeccakHasher;
+/// let mut map: HashMap<&str, u32, BuildHasherDefault<KeccakHasher>> = HashMap::default();
+/// map.insert("cat", 1);
+/// assert_eq!(map.get("cat"), Some(&1));
+/// ```
+#[derive(Clone)]
+pub struct KeccakHasher(Keccak);
+
+impl KeccakHasher {
+	/// Creates a hasher of the empty input.
+	pub fn new() -> Self {
+		KeccakHasher(Keccak::v256())
+	}
+
+	/// Appends `data` to the input.
+	pub fn update(&mut self, data: &[u8]) {
+		self.0.update(data);
+	}
+
+	/// Returns the hash of the input.
+	pub fn finalize(self) -> H256 {
+		let mut output = [0u8; 32];
+		self.0.finalize(&mut output);
+		H256(output)
+	}
+}
+
+impl Default for KeccakHasher {
+	fn default() -> Self {
+		KeccakHasher::new()
+	}
+}
+
+impl hash::Hasher for KeccakHasher {
+	fn write(&mut self, bytes: &[u8]) {
+		self.update(bytes);
+	}
+
+	fn finish(&self) -> u64 {
+		self.clone().finalize().to_low_u64_be()
+	}
+}
+
+/// Incremental keccak512 hasher, for input which isn't contiguous in memory.
+///
+/// ```
+/// use keccak_hash::Keccak512Hasher;
+/// let mut data = [1u8; 64];
+/// let mut hasher = Keccak512Hasher::new();
+/// hasher.update(&data[..3]);
+/// hasher.update(&data[3..8]);
+/// keccak_hash::keccak512_range(&mut data, 0..8);
+/// assert_eq!(hasher.finalize().as_bytes(), &data[..]);
+/// ```
+#[derive(Clone)]
+pub struct Keccak512Hasher(Keccak);
+
+impl Keccak512Hasher {
+	/// Creates a hasher of the empty input.
+	pub fn new() -> Self {
+		Keccak512Hasher(Keccak::v512())
+	}
+
+	/// Appends `data` to the input.
+	pub fn update(&mut self, data: &[u8]) {
+		self.0.update(data);
+	}
+
+	/// Returns the hash of the input.
+	pub fn finalize(self) -> H512 {
+		let mut output = [0u8; 64];
+		self.0.finalize(&mut output);
+		H512(output)
+	}
+}
+
+impl Default for Keccak512Hasher {
+	fn default() -> Self {
+		Keccak512Hasher::new()
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	#[cfg(not(feature = "std"))]
@@ -158,6 +404,67 @@ mod tests {
 		assert_eq!(keccak([0u8; 0]), KECCAK_EMPTY);
 	}
 
+	#[test]
+	fn keccak_test_vectors() {
+		let fox = b"The quick brown fox jumps over the lazy dog";
+		let vectors_256 = [
+			(&b""[..], primitive_types::h256!("c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470")),
+			(&b"abc"[..], primitive_types::h256!("4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45")),
+			(&fox[..], primitive_types::h256!("4d741b6f1eb29cb2a9b9911c82f56fa8d73b04959d3d9d222895df6c0b28aa15")),
+		];
+		let mut dest = H256::zero();
+		for (input, expected) in vectors_256 {
+			assert_eq!(keccak(input), expected);
+			keccak256_into(input, &mut dest);
+			assert_eq!(dest, expected);
+		}
+
+		let vectors_512 = [
+			(
+				&b""[..],
+				primitive_types::h512!("0eab42de4c3ceb9235fc91acffe746b29c29a8c366b7c60e4e67c466f36a4304c00fa9caf9d87976ba469bcbe06713b435f091ef2769fb160cdab33d3670680e"),
+			),
+			(
+				&b"abc"[..],
+				primitive_types::h512!("18587dc2ea106b9a1563e32b3312421ca164c7f1f07bc922a9c83d77cea3a1e5d0c69910739025372dc14ac9642629379540c17e2a65b19d77aa511a9d00bb96"),
+			),
+			(
+				&fox[..],
+				primitive_types::h512!("d135bb84d0439dbac432247ee573a23ea7d3c9deb2a968eb31d47c4fb45f1ef4422d6c531b5b9bd6f449ebcc449ea94d0a8f05f62130fda612da53c79659f609"),
+			),
+		];
+		let mut dest = H512::zero();
+		for (input, expected) in vectors_512 {
+			assert_eq!(keccak512_hash(input), expected);
+			keccak512_into(input, &mut dest);
+			assert_eq!(dest, expected);
+			let mut output = [0u8; 64];
+			keccak_512(input, &mut output);
+			assert_eq!(H512(output), expected);
+		}
+	}
+
+	#[test]
+	#[should_panic(expected = "the destination of a keccak256 hash must be 32 bytes long")]
+	fn write_keccak_to_short_dest() {
+		write_keccak(b"cat", &mut [0u8; 31]);
+	}
+
+	#[test]
+	#[should_panic(expected = "the destination of a keccak256 hash must be 32 bytes long")]
+	fn write_keccak_to_long_dest() {
+		keccak_256(b"cat", &mut [0u8; 64]);
+	}
+
+	#[cfg(feature = "fips202")]
+	#[test]
+	fn keccak_and_sha3_differ() {
+		for input in [&b""[..], b"abc", &[0u8; 200]] {
+			assert_ne!(keccak(input), sha3_256(input));
+			assert_ne!(keccak512_hash(input), sha3_512(input));
+		}
+	}
+
 	#[test]
 	fn keccak_as() {
 		assert_eq!(
@@ -207,4 +514,148 @@ mod tests {
 		// then
 		assert_eq!(format!("{:x}", hash), "68371d7e884c168ae2022c82bd837d51837718a7f7dfb7aa3f753074a35e1d87");
 	}
+
+	/// Returns the pseudo-random sequence of an xorshift generator.
+	fn xorshift(mut state: u64) -> impl Iterator<Item = u64> {
+		core::iter::repeat_with(move || {
+			state ^= state << 13;
+			state ^= state >> 7;
+			state ^= state << 17;
+			state
+		})
+	}
+
+	#[test]
+	fn hasher_matches_one_shot() {
+		let data: Vec<u8> = xorshift(1).take(1000).map(|n| n as u8).collect();
+		let mut expected_512 = data.clone();
+		expected_512.resize(data.len().max(64), 0);
+		let end = data.len();
+		keccak512_range(&mut expected_512, 0..end);
+
+		let mut random = xorshift(2);
+		for _ in 0..100 {
+			let mut splits: Vec<_> = random.by_ref().take(5).map(|n| n as usize % (data.len() + 1)).collect();
+			splits.push(0);
+			splits.push(data.len());
+			splits.sort_unstable();
+
+			let mut hasher = KeccakHasher::new();
+			let mut hasher_512 = Keccak512Hasher::new();
+			// Repeated split points give empty updates.
+			for range in splits.windows(2) {
+				hasher.update(&data[range[0]..range[1]]);
+				hasher_512.update(&data[range[0]..range[1]]);
+			}
+			assert_eq!(hasher.finalize(), keccak(&data), "splits {:?}", splits);
+			assert_eq!(hasher_512.finalize().as_bytes(), &expected_512[..64]);
+		}
+	}
+
+	#[test]
+	fn keccak_concat_matches_concatenation() {
+		let data: Vec<u8> = xorshift(3).take(500).map(|n| n as u8).collect();
+		let parts: [&[u8]; 5] = [&[], &data[..100], &[], &data[100..101], &data[101..]];
+		assert_eq!(keccak_concat(parts), keccak(&data));
+		assert_eq!(keccak_concat(parts.iter().copied()), keccak(&data));
+		assert_eq!(keccak_concat([]), KECCAK_EMPTY);
+		assert_eq!(keccak_concat([&[][..], &[]]), KECCAK_EMPTY);
+
+		// Many tiny parts, crossing the 136 byte blocks of the sponge.
+		assert_eq!(keccak_concat(data.chunks(1)), keccak(&data));
+		assert_eq!(keccak_concat(data.chunks(3)), keccak(&data));
+		let mut random = xorshift(4);
+		let tiny = data.chunks(7).flat_map(|chunk| {
+			let split = random.next().unwrap() as usize % (chunk.len() + 1);
+			[&chunk[..split], &chunk[split..]]
+		});
+		assert_eq!(keccak_concat(tiny), keccak(&data));
+	}
+
+	#[test]
+	fn keccak2_matches_concatenation() {
+		let data: Vec<u8> = xorshift(5).take(300).map(|n| n as u8).collect();
+		for split in [0, 1, 64, 135, 136, 137, 299, 300] {
+			assert_eq!(keccak2(&data[..split], &data[split..]), keccak(&data), "split at {}", split);
+		}
+		assert_eq!(keccak2(&[], &[]), KECCAK_EMPTY);
+	}
+
+	#[test]
+	fn keccak_batch_matches_keccak() {
+		let data: Vec<u8> = xorshift(6).take(100_000).map(|n| n as u8).collect();
+		let mut random = xorshift(7);
+		// Enough inputs for several parallel tasks, of sizes from 0 to 300 bytes.
+		let mut inputs = Vec::new();
+		let mut rest = &data[..];
+		while rest.len() > 300 {
+			let (input, tail) = rest.split_at(random.next().unwrap() as usize % 301);
+			inputs.push(input);
+			rest = tail;
+		}
+		assert!(inputs.len() > 500);
+
+		let mut out = vec![H256::zero(); inputs.len()];
+		keccak_batch(&inputs, &mut out);
+		for (input, hash) in inputs.iter().zip(&out) {
+			assert_eq!(*hash, keccak(input));
+		}
+
+		let mut buffers: Vec<Vec<u8>> = inputs.iter().map(|input| input.to_vec()).collect();
+		// The hash is written over the first 32 bytes of the buffers.
+		for buffer in &mut buffers {
+			buffer.resize(buffer.len().max(32), 0);
+		}
+		let mut expected = buffers.clone();
+		let mut slices: Vec<&mut [u8]> = buffers.iter_mut().map(|buffer| &mut buffer[..]).collect();
+		keccak256_batch(&mut slices);
+		for buffer in &mut expected {
+			keccak256(buffer);
+		}
+		assert_eq!(buffers, expected);
+
+		keccak_batch(&[], &mut []);
+		keccak256_batch(&mut []);
+	}
+
+	#[test]
+	#[should_panic(expected = "keccak_batch needs as many outputs as inputs")]
+	fn keccak_batch_wrong_output_len() {
+		keccak_batch(&[b"cat"], &mut [H256::zero(); 2]);
+	}
+
+	#[test]
+	fn hasher_of_empty_input() {
+		assert_eq!(KeccakHasher::new().finalize(), KECCAK_EMPTY);
+		let mut hasher = KeccakHasher::default();
+		hasher.update(&[]);
+		hasher.update(&[]);
+		assert_eq!(hasher.finalize(), KECCAK_EMPTY);
+
+		let mut data = [0u8; 64];
+		keccak512_range(&mut data, 0..0);
+		assert_eq!(Keccak512Hasher::default().finalize(), H512(data));
+	}
+
+	#[test]
+	fn hasher_as_core_hasher() {
+		use core::hash::{Hash, Hasher as _};
+
+		let mut hasher = KeccakHasher::new();
+		hasher.write(b"cat");
+		let finish = hasher.finish();
+		// `finish` doesn't consume the state, so more can be written.
+		assert_eq!(finish, hasher.finish());
+		assert_eq!(finish, keccak(b"cat").to_low_u64_be());
+		hasher.write(b"dog");
+		assert_eq!(hasher.finish(), keccak(b"catdog").to_low_u64_be());
+
+		let hash = |value: &str| {
+			let mut hasher = KeccakHasher::new();
+			value.hash(&mut hasher);
+			hasher.finish()
+		};
+		assert_eq!(hash("cat"), hash("cat"));
+		assert_ne!(hash("cat"), hash("dog"));
+	}
 }
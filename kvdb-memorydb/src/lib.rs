@@ -45,6 +45,14 @@ impl KeyValueDB for InMemory {
 		}
 	}
 
+	fn get_many(&self, col: u32, keys: &[&[u8]]) -> io::Result<Vec<Option<DBValue>>> {
+		let columns = self.columns.read();
+		match columns.get(&col) {
+			None => Err(invalid_column(col)),
+			Some(map) => Ok(keys.iter().map(|key| map.get(*key).cloned()).collect()),
+		}
+	}
+
 	fn get_by_prefix(&self, col: u32, prefix: &[u8]) -> io::Result<Option<DBValue>> {
 		let columns = self.columns.read();
 		match columns.get(&col) {
@@ -115,6 +123,37 @@ impl KeyValueDB for InMemory {
 			None => Box::new(std::iter::once(Err(invalid_column(col)))),
 		}
 	}
+
+	fn iter_rev<'a>(&'a self, col: u32) -> Box<dyn Iterator<Item = io::Result<DBKeyValue>> + 'a> {
+		match self.columns.read().get(&col) {
+			Some(map) => Box::new(map.clone().into_iter().rev().map(|(k, v)| Ok((k.into(), v)))),
+			None => Box::new(std::iter::once(Err(invalid_column(col)))),
+		}
+	}
+
+	fn iter_with_prefix_rev<'a>(
+		&'a self,
+		col: u32,
+		prefix: &'a [u8],
+	) -> Box<dyn Iterator<Item = io::Result<DBKeyValue>> + 'a> {
+		use std::ops::Bound;
+		match self.columns.read().get(&col) {
+			Some(map) => {
+				// Without an end prefix, every key from the prefix on starts with it.
+				let end_range = match kvdb::end_prefix(prefix) {
+					Some(end_range) => Bound::Excluded(end_range),
+					None => Bound::Unbounded,
+				};
+				let items: Vec<_> = map
+					.range((Bound::Included(prefix.to_vec()), end_range))
+					.rev()
+					.map(|(k, v)| Ok((k.as_slice().into(), v.clone())))
+					.collect();
+				Box::new(items.into_iter())
+			},
+			None => Box::new(std::iter::once(Err(invalid_column(col)))),
+		}
+	}
 }
 
 #[cfg(test)]
@@ -135,6 +174,12 @@ mod tests {
 		st::test_put_and_get(&db)
 	}
 
+	#[test]
+	fn get_many() -> io::Result<()> {
+		let db = create(1);
+		st::test_get_many(&db)
+	}
+
 	#[test]
 	fn delete_and_get() -> io::Result<()> {
 		let db = create(1);
@@ -159,6 +204,18 @@ mod tests {
 		st::test_iter_with_prefix(&db)
 	}
 
+	#[test]
+	fn iter_rev() -> io::Result<()> {
+		let db = create(1);
+		st::test_iter_rev(&db)
+	}
+
+	#[test]
+	fn iter_with_prefix_rev() -> io::Result<()> {
+		let db = create(1);
+		st::test_iter_with_prefix_rev(&db)
+	}
+
 	#[test]
 	fn complex() -> io::Result<()> {
 		let db = create(1);
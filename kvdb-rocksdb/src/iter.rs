@@ -32,6 +32,10 @@ pub trait IterationHandler {
 	/// https://github.com/facebook/rocksdb/blob/master/include/rocksdb/options.h#L1169).
 	/// The `Iterator` iterates over keys which start with the provided `prefix`.
 	fn iter_with_prefix(self, col: u32, prefix: &[u8], read_opts: ReadOptions) -> Self::Iterator;
+	/// Create an `Iterator` over a `ColumnFamily` corresponding to the passed index, from the last
+	/// key to the first. Iteration starts at the last key below the upper bound of `read_opts`, so
+	/// it can be limited to a prefix with the iterate bounds.
+	fn iter_rev(self, col: u32, read_opts: ReadOptions) -> Self::Iterator;
 }
 
 impl<'a> IterationHandler for &'a DBAndColumns {
@@ -54,6 +58,13 @@ impl<'a> IterationHandler for &'a DBAndColumns {
 			Err(e) => EitherIter::B(std::iter::once(Err(e))),
 		}
 	}
+
+	fn iter_rev(self, col: u32, read_opts: ReadOptions) -> Self::Iterator {
+		match self.cf(col as usize) {
+			Ok(cf) => EitherIter::A(KvdbAdapter(self.db.iterator_cf_opt(cf, read_opts, IteratorMode::End))),
+			Err(e) => EitherIter::B(std::iter::once(Err(e))),
+		}
+	}
 }
 
 /// Small enum to avoid boxing iterators.
@@ -483,6 +483,28 @@ impl Database {
 		value
 	}
 
+	/// Get the values of many keys of a column, in the order of the keys, with RocksDB's `MultiGet`.
+	pub fn get_many(&self, col: u32, keys: &[&[u8]]) -> io::Result<Vec<Option<DBValue>>> {
+		let cfs = &self.inner;
+		let cf = cfs.cf(col as usize)?;
+		self.stats.tally_reads(keys.len() as u64);
+		let values = cfs
+			.db
+			.batched_multi_get_cf_opt(cf, keys, false, &self.read_opts)
+			.into_iter()
+			.map(|value| value.map(|v| v.map(|v| v.to_vec())).map_err(other_io_err))
+			.collect::<io::Result<Vec<_>>>()?;
+
+		let bytes_read: usize = keys
+			.iter()
+			.zip(&values)
+			.map(|(key, value)| key.len() + value.as_ref().map_or(0, |v| v.len()))
+			.sum();
+		self.stats.tally_bytes_read(bytes_read as u64);
+
+		Ok(values)
+	}
+
 	/// Get value by partial key. Prefix size should match configured prefix size.
 	pub fn get_by_prefix(&self, col: u32, prefix: &[u8]) -> io::Result<Option<DBValue>> {
 		self.iter_with_prefix(col, prefix)
@@ -511,6 +533,36 @@ impl Database {
 		iter::IterationHandler::iter_with_prefix(&self.inner, col, prefix, read_opts)
 	}
 
+	/// Iterator over the data in the given database column index, from the last key to the first.
+	/// Will hold a lock until the iterator is dropped
+	/// preventing the database from being closed.
+	pub fn iter_rev<'a>(&'a self, col: u32) -> impl Iterator<Item = io::Result<DBKeyValue>> + 'a {
+		let read_opts = generate_read_options();
+		iter::IterationHandler::iter_rev(&self.inner, col, read_opts)
+	}
+
+	/// Iterator over data in the `col` database column index matching the given prefix, from the
+	/// last key to the first.
+	/// Will hold a lock until the iterator is dropped
+	/// preventing the database from being closed.
+	fn iter_with_prefix_rev<'a>(
+		&'a self,
+		col: u32,
+		prefix: &'a [u8],
+	) -> impl Iterator<Item = io::Result<DBKeyValue>> + 'a {
+		let mut read_opts = generate_read_options();
+		// Iteration starts from the last key below the upper bound and stops at the lower bound.
+		// Without an end prefix, every key from the prefix on starts with it, so the last key of
+		// the column is the start.
+		if !prefix.is_empty() {
+			read_opts.set_iterate_lower_bound(prefix);
+		}
+		if let Some(end_prefix) = kvdb::end_prefix(prefix) {
+			read_opts.set_iterate_upper_bound(end_prefix);
+		}
+		iter::IterationHandler::iter_rev(&self.inner, col, read_opts)
+	}
+
 	/// The number of column families in the db.
 	pub fn num_columns(&self) -> u32 {
 		self.inner.column_names.len() as u32
@@ -588,6 +640,10 @@ impl KeyValueDB for Database {
 		Database::get(self, col, key)
 	}
 
+	fn get_many(&self, col: u32, keys: &[&[u8]]) -> io::Result<Vec<Option<DBValue>>> {
+		Database::get_many(self, col, keys)
+	}
+
 	fn get_by_prefix(&self, col: u32, prefix: &[u8]) -> io::Result<Option<DBValue>> {
 		Database::get_by_prefix(self, col, prefix)
 	}
@@ -610,6 +666,20 @@ impl KeyValueDB for Database {
 		Box::new(unboxed.into_iter())
 	}
 
+	fn iter_rev<'a>(&'a self, col: u32) -> Box<dyn Iterator<Item = io::Result<DBKeyValue>> + 'a> {
+		let unboxed = Database::iter_rev(self, col);
+		Box::new(unboxed.into_iter())
+	}
+
+	fn iter_with_prefix_rev<'a>(
+		&'a self,
+		col: u32,
+		prefix: &'a [u8],
+	) -> Box<dyn Iterator<Item = io::Result<DBKeyValue>> + 'a> {
+		let unboxed = Database::iter_with_prefix_rev(self, col, prefix);
+		Box::new(unboxed.into_iter())
+	}
+
 	fn io_stats(&self, kind: kvdb::IoStatsKind) -> kvdb::IoStats {
 		let rocksdb_stats = self.get_statistics();
 		let cache_hit_count = rocksdb_stats.get("block.cache.hit").map(|s| s.count).unwrap_or(0u64);
@@ -663,6 +733,12 @@ mod tests {
 		st::test_put_and_get(&db)
 	}
 
+	#[test]
+	fn get_many() -> io::Result<()> {
+		let db = create(1)?;
+		st::test_get_many(&db)
+	}
+
 	#[test]
 	fn delete_and_get() -> io::Result<()> {
 		let db = create(1)?;
@@ -687,6 +763,18 @@ mod tests {
 		st::test_iter_with_prefix(&db)
 	}
 
+	#[test]
+	fn iter_rev() -> io::Result<()> {
+		let db = create(1)?;
+		st::test_iter_rev(&db)
+	}
+
+	#[test]
+	fn iter_with_prefix_rev() -> io::Result<()> {
+		let db = create(1)?;
+		st::test_iter_with_prefix_rev(&db)
+	}
+
 	#[test]
 	fn complex() -> io::Result<()> {
 		let db = create(1)?;
@@ -38,6 +38,36 @@ pub fn test_delete_and_get(db: &dyn KeyValueDB) -> io::Result<()> {
 	Ok(())
 }
 
+/// A test for `KeyValueDB::get_many`.
+/// Assumes the `db` has only 1 column.
+pub fn test_get_many(db: &dyn KeyValueDB) -> io::Result<()> {
+	let mut transaction = db.transaction();
+	for i in 0..200u32 {
+		transaction.put(0, &i.to_be_bytes(), &[i as u8; 3]);
+	}
+	db.write(transaction)?;
+
+	// The values are in the order of the keys, which are neither sorted nor unique.
+	let keys: Vec<[u8; 4]> = [150u32, 3, 1000, 3, 0, 199, 200].iter().map(|i| i.to_be_bytes()).collect();
+	let keys: Vec<&[u8]> = keys.iter().map(|key| &key[..]).collect();
+	let values = db.get_many(0, &keys)?;
+	let expected: Vec<_> = keys.iter().map(|key| db.get(0, key)).collect::<io::Result<_>>()?;
+	assert_eq!(values, expected);
+	assert_eq!(values[0], Some(vec![150; 3]));
+	assert_eq!(values[1], Some(vec![3; 3]));
+	assert_eq!(values[2], None);
+	assert_eq!(values[6], None);
+
+	let keys: Vec<[u8; 4]> = (0..200u32).rev().map(|i| i.to_be_bytes()).collect();
+	let keys: Vec<&[u8]> = keys.iter().map(|key| &key[..]).collect();
+	let values = db.get_many(0, &keys)?;
+	assert_eq!(values, (0..200u32).rev().map(|i| Some(vec![i as u8; 3])).collect::<Vec<_>>());
+
+	assert_eq!(db.get_many(0, &[])?, Vec::<Option<Vec<u8>>>::new());
+	assert!(db.get_many(1, &[&b"key"[..]]).is_err());
+	Ok(())
+}
+
 /// A test for `KeyValueDB::get`.
 /// Assumes the `db` has only 1 column.
 pub fn test_get_fails_with_non_existing_column(db: &dyn KeyValueDB) -> io::Result<()> {
@@ -126,6 +156,89 @@ pub fn test_iter_with_prefix(db: &dyn KeyValueDB) -> io::Result<()> {
 	Ok(())
 }
 
+/// A test for `KeyValueDB::iter_rev`.
+pub fn test_iter_rev(db: &dyn KeyValueDB) -> io::Result<()> {
+	let keys: [&[u8]; 5] = [b"", b"\x00", b"key1", b"key2", b"\xff\xff"];
+
+	let mut transaction = db.transaction();
+	// Written out of order, to check the order comes from the keys.
+	for key in keys.iter().rev() {
+		transaction.put(0, key, key);
+	}
+	db.write(transaction)?;
+
+	let contents: Vec<_> = db.iter_rev(0).map(Result::unwrap).collect();
+	assert_eq!(contents.len(), keys.len());
+	for ((key, value), expected) in contents.iter().zip(keys.iter().rev()) {
+		assert_eq!(&**key, *expected);
+		assert_eq!(&**value, *expected);
+	}
+
+	let mut forward: Vec<_> = db.iter(0).map(Result::unwrap).collect();
+	forward.reverse();
+	assert_eq!(contents, forward);
+
+	assert!(db.iter_rev(1).next().unwrap().is_err());
+	Ok(())
+}
+
+/// A test for `KeyValueDB::iter_with_prefix_rev`, including prefixes of `0xff` bytes, which
+/// have no upper bound.
+pub fn test_iter_with_prefix_rev(db: &dyn KeyValueDB) -> io::Result<()> {
+	let keys: [&[u8]; 12] = [
+		b"0",
+		b"ab",
+		b"abc",
+		b"abcd",
+		b"ab\xff",
+		b"ab\xff\x00",
+		b"ac",
+		b"\xfe\xff",
+		b"\xff",
+		b"\xff\x00",
+		b"\xff\xff",
+		b"\xff\xff\x00",
+	];
+
+	let mut batch = db.transaction();
+	for key in keys.iter() {
+		batch.put(0, key, key);
+	}
+	db.write(batch)?;
+
+	let check = |prefix: &[u8], expected: &[&[u8]]| {
+		let contents: Vec<_> = db.iter_with_prefix_rev(0, prefix).map(Result::unwrap).collect();
+		let contents: Vec<&[u8]> = contents.iter().map(|(key, _)| &**key).collect();
+		assert_eq!(contents, expected, "prefix {:?}", prefix);
+
+		let mut forward: Vec<_> = db.iter_with_prefix(0, prefix).map(Result::unwrap).collect();
+		forward.reverse();
+		let forward: Vec<&[u8]> = forward.iter().map(|(key, _)| &**key).collect();
+		assert_eq!(contents, forward, "prefix {:?}", prefix);
+	};
+
+	// empty prefix
+	let mut all = keys.to_vec();
+	all.sort();
+	all.reverse();
+	check(b"", &all);
+
+	check(b"a", &[b"ac", b"ab\xff\x00", b"ab\xff", b"abcd", b"abc", b"ab"]);
+	check(b"abc", &[b"abcd", b"abc"]);
+	check(b"abcde", &[]);
+	check(b"0", &[b"0"]);
+	// The upper bound of a prefix ending with `0xff` is past the `0xff` bytes.
+	check(b"ab\xff", &[b"ab\xff\x00", b"ab\xff"]);
+	check(b"\xfe", &[b"\xfe\xff"]);
+	// Prefixes of only `0xff` bytes have no upper bound.
+	check(b"\xff", &[b"\xff\xff\x00", b"\xff\xff", b"\xff\x00", b"\xff"]);
+	check(b"\xff\xff", &[b"\xff\xff\x00", b"\xff\xff"]);
+	check(b"\xff\xff\xff", &[]);
+
+	assert!(db.iter_with_prefix_rev(1, b"a").next().unwrap().is_err());
+	Ok(())
+}
+
 /// The number of columns required to run `test_io_stats`.
 pub const IO_STATS_NUM_COLUMNS: u32 = 3;
 
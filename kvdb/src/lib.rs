@@ -113,6 +113,14 @@ pub trait KeyValueDB: Sync + Send {
 	/// Get a value by key.
 	fn get(&self, col: u32, key: &[u8]) -> io::Result<Option<DBValue>>;
 
+	/// Get the values of many keys of a column, in the order of the keys.
+	///
+	/// The default implementation calls `get` for each key, backends override it to look the keys
+	/// up together.
+	fn get_many(&self, col: u32, keys: &[&[u8]]) -> io::Result<Vec<Option<DBValue>>> {
+		keys.iter().map(|key| self.get(col, key)).collect()
+	}
+
 	/// Get the first value matching the given prefix.
 	fn get_by_prefix(&self, col: u32, prefix: &[u8]) -> io::Result<Option<DBValue>>;
 
@@ -130,6 +138,31 @@ pub trait KeyValueDB: Sync + Send {
 		prefix: &'a [u8],
 	) -> Box<dyn Iterator<Item = io::Result<DBKeyValue>> + 'a>;
 
+	/// Iterate over the data for a given column, from the last key to the first.
+	///
+	/// The default implementation collects `iter` and reverses it, backends override it to
+	/// iterate backwards natively.
+	fn iter_rev<'a>(&'a self, col: u32) -> Box<dyn Iterator<Item = io::Result<DBKeyValue>> + 'a> {
+		let mut items: Vec<_> = self.iter(col).collect();
+		items.reverse();
+		Box::new(items.into_iter())
+	}
+
+	/// Iterate over the data for a given column, returning all key/value pairs
+	/// where the key starts with the given prefix, from the last key to the first.
+	///
+	/// The default implementation collects `iter_with_prefix` and reverses it, backends
+	/// override it to iterate backwards natively.
+	fn iter_with_prefix_rev<'a>(
+		&'a self,
+		col: u32,
+		prefix: &'a [u8],
+	) -> Box<dyn Iterator<Item = io::Result<DBKeyValue>> + 'a> {
+		let mut items: Vec<_> = self.iter_with_prefix(col, prefix).collect();
+		items.reverse();
+		Box::new(items.into_iter())
+	}
+
 	/// Query statistics.
 	///
 	/// Not all kvdb implementations are able or expected to implement this, so by
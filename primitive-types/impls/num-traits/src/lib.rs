@@ -19,10 +19,79 @@ pub use integer_sqrt;
 #[doc(hidden)]
 pub use uint;
 
+/// The [`TypeId`](core::any::TypeId) of `T`, which unlike [`TypeId::of`](core::any::TypeId::of)
+/// doesn't need to be `'static`. Lifetimes are erased, so `&'a T` and `&'static T` are the same.
+#[doc(hidden)]
+pub fn type_id<T: ?Sized>() -> core::any::TypeId {
+	trait NonStaticAny {
+		fn type_id(&self) -> core::any::TypeId
+		where
+			Self: 'static;
+	}
+
+	impl<T: ?Sized> NonStaticAny for core::marker::PhantomData<T> {
+		fn type_id(&self) -> core::any::TypeId
+		where
+			Self: 'static,
+		{
+			core::any::TypeId::of::<T>()
+		}
+	}
+
+	let phantom = core::marker::PhantomData::<T>;
+	// SAFETY: only the lifetime of the trait object is extended, and `type_id` never uses it.
+	NonStaticAny::type_id(unsafe { core::mem::transmute::<&dyn NonStaticAny, &(dyn NonStaticAny + 'static)>(&phantom) })
+}
+
 /// Add num-traits support to an integer created by `construct_uint!`.
+///
+/// This lets the integer be used in code which is generic over `num-traits`:
+///
+/// ```
+/// use impl_num_traits::{impl_uint_num_traits, uint::construct_uint};
+/// use num_traits::{CheckedAdd, FromPrimitive, Num, ToPrimitive};
+///
+/// construct_uint! {
+///     pub struct U256(4);
+/// }
+/// impl_uint_num_traits!(U256, 4);
+///
+/// fn mean<T: Num + CheckedAdd + FromPrimitive>(values: &[T]) -> Option<T> {
+///     let sum = values.iter().try_fold(T::zero(), |sum, value| sum.checked_add(value))?;
+///     Some(sum / T::from_usize(values.len()).filter(|len| !len.is_zero())?)
+/// }
+///
+/// let balances = [U256::from(10), U256::from(20), U256::MAX / 2];
+/// assert_eq!(mean(&balances), Some(U256::MAX / 6 + 10));
+/// assert_eq!(mean::<U256>(&[]), None);
+/// assert_eq!(mean(&[U256::MAX, U256::one()]), None);
+/// assert_eq!(mean(&balances[..2]).and_then(|mean| mean.to_u64()), Some(15));
+/// ```
+///
+/// `NumCast` converts through `u128`, which other integers created by `construct_uint!` may not fit
+/// in. Those listed after the number of words are converted word by word instead:
+///
+/// ```
+/// use impl_num_traits::{impl_uint_num_traits, uint::construct_uint};
+///
+/// construct_uint! {
+///     pub struct U256(4);
+/// }
+/// construct_uint! {
+///     pub struct U512(8);
+/// }
+/// impl_uint_num_traits!(U256, 4, [U256, U512]);
+/// impl_uint_num_traits!(U512, 8, [U256, U512]);
+///
+/// assert_eq!(num_traits::cast::<_, U512>(U256::MAX), Some(U512::MAX >> 256));
+/// assert_eq!(num_traits::cast::<_, U256>(U512::MAX), None);
+/// ```
 #[macro_export]
 macro_rules! impl_uint_num_traits {
 	($name: ident, $len: expr) => {
+		$crate::impl_uint_num_traits!($name, $len, []);
+	};
+	($name: ident, $len: expr, [$($wide: ty),* $(,)?]) => {
 		impl $crate::num_traits::bounds::Bounded for $name {
 			#[inline]
 			fn min_value() -> Self {
@@ -184,5 +253,107 @@ macro_rules! impl_uint_num_traits {
 				Self::pow(self, rhs)
 			}
 		}
+
+		impl $crate::num_traits::cast::ToPrimitive for $name {
+			fn to_i64(&self) -> Option<i64> {
+				self.to_u64()
+					.filter(|value| *value <= i64::MAX as u64)
+					.map(|value| value as i64)
+			}
+
+			fn to_u64(&self) -> Option<u64> {
+				if self.bits() <= 64 {
+					Some(self.low_u64())
+				} else {
+					None
+				}
+			}
+
+			fn to_i128(&self) -> Option<i128> {
+				self.to_u128()
+					.filter(|value| *value <= i128::MAX as u128)
+					.map(|value| value as i128)
+			}
+
+			fn to_u128(&self) -> Option<u128> {
+				if self.bits() <= 128 {
+					let words: &[u64] = self.as_ref();
+					let high = words.get(1).copied().unwrap_or(0);
+					Some((high as u128) << 64 | words[0] as u128)
+				} else {
+					None
+				}
+			}
+
+			fn to_f64(&self) -> Option<f64> {
+				Some(self.to_f64_lossy())
+			}
+		}
+
+		impl $crate::num_traits::cast::FromPrimitive for $name {
+			fn from_i64(n: i64) -> Option<Self> {
+				if n < 0 {
+					None
+				} else {
+					Some(Self::from(n as u64))
+				}
+			}
+
+			fn from_u64(n: u64) -> Option<Self> {
+				Some(Self::from(n))
+			}
+
+			fn from_i128(n: i128) -> Option<Self> {
+				if n < 0 {
+					None
+				} else {
+					<Self as $crate::num_traits::cast::FromPrimitive>::from_u128(n as u128)
+				}
+			}
+
+			fn from_u128(n: u128) -> Option<Self> {
+				let mut words = [0u64; $len];
+				words[0] = n as u64;
+				let high = (n >> 64) as u64;
+				match words.get_mut(1) {
+					Some(word) => *word = high,
+					None if high != 0 => return None,
+					None => {},
+				}
+				Some($name(words))
+			}
+
+			fn from_f64(n: f64) -> Option<Self> {
+				// `MAX` rounds up to the first power of two above it. NaN fails both comparisons.
+				if n > -1.0 && n < Self::MAX.to_f64_lossy() {
+					Some(Self::from_f64_lossy(n))
+				} else {
+					None
+				}
+			}
+		}
+
+		impl $crate::num_traits::cast::NumCast for $name {
+			fn from<T: $crate::num_traits::cast::ToPrimitive>(n: T) -> Option<Self> {
+				$(
+					if $crate::type_id::<T>() == core::any::TypeId::of::<$wide>() {
+						// SAFETY: `T` is `$wide`, which is `Copy`.
+						let n = unsafe { core::mem::transmute_copy::<T, $wide>(&n) };
+						let words: &[u64] = n.as_ref();
+						let mut result = [0u64; $len];
+						for (i, word) in words.iter().enumerate() {
+							match result.get_mut(i) {
+								Some(limb) => *limb = *word,
+								None if *word != 0 => return None,
+								None => {},
+							}
+						}
+						return Some($name(result))
+					}
+				)*
+				n.to_u128()
+					.and_then(<Self as $crate::num_traits::cast::FromPrimitive>::from_u128)
+			}
+		}
 	};
 }
@@ -26,13 +26,22 @@ macro_rules! impl_uint_rlp {
 				let buffer = self.to_big_endian();
 				s.encoder().encode_value(&buffer[leading_empty_bytes..]);
 			}
+
+			fn rlp_size_hint(&self) -> usize {
+				let leading_empty_bytes = $size * 8 - (self.bits() + 7) / 8;
+				$crate::rlp::data_size(&self.to_big_endian()[leading_empty_bytes..])
+			}
 		}
 
 		impl $crate::rlp::Decodable for $name {
 			fn decode(rlp: &$crate::rlp::Rlp) -> Result<Self, $crate::rlp::DecoderError> {
 				rlp.decoder().decode_value(|bytes| {
 					if !bytes.is_empty() && bytes[0] == 0 {
-						Err($crate::rlp::DecoderError::RlpInvalidIndirection)
+						if rlp.is_strict() {
+							Err($crate::rlp::DecoderError::RlpNonCanonicalInteger)
+						} else {
+							Err($crate::rlp::DecoderError::RlpInvalidIndirection)
+						}
 					} else if bytes.len() <= $size * 8 {
 						Ok($name::from_big_endian(bytes))
 					} else {
@@ -52,6 +61,10 @@ macro_rules! impl_fixed_hash_rlp {
 			fn rlp_append(&self, s: &mut $crate::rlp::RlpStream) {
 				s.encoder().encode_value(self.as_ref());
 			}
+
+			fn rlp_size_hint(&self) -> usize {
+				$crate::rlp::data_size(self.as_ref())
+			}
 		}
 
 		impl $crate::rlp::Decodable for $name {
@@ -7,6 +7,18 @@
 // except according to those terms.
 
 //! Serde serialization support for uint and fixed hash.
+//!
+//! Values are serialized as `0x`-prefixed hex strings. With the `compact-binary` feature, formats
+//! which aren't human-readable, such as bincode, get the big-endian bytes at the full width of the
+//! type as a tuple instead, which takes exactly as many bytes to encode.
+//!
+//! # Compatibility
+//!
+//! Enabling `compact-binary` changes the encoding of every uint and hash type in binary formats.
+//! Self-describing formats such as CBOR or MessagePack still read hex strings and byte arrays
+//! written without the feature. Formats which rely on the type to know what comes next, such as
+//! bincode, can't tell a hex string from raw bytes, so data written before enabling the feature
+//! has to be migrated.
 
 #![no_std]
 
@@ -33,7 +45,7 @@ macro_rules! impl_uint_serde {
 			{
 				let mut slice = [0u8; 2 + 2 * $len * 8];
 				let bytes = self.to_big_endian();
-				$crate::serialize::serialize_uint(&mut slice, &bytes, serializer)
+				$crate::serialize::serialize_fixed_uint(&mut slice, &bytes, serializer)
 			}
 		}
 
@@ -43,7 +55,7 @@ macro_rules! impl_uint_serde {
 				D: $crate::serde::Deserializer<'de>,
 			{
 				let mut bytes = [0u8; $len * 8];
-				let wrote = $crate::serialize::deserialize_check_len(
+				let wrote = $crate::serialize::deserialize_fixed(
 					deserializer,
 					$crate::serialize::ExpectedLen::Between(0, &mut bytes),
 				)?;
@@ -63,7 +75,7 @@ macro_rules! impl_fixed_hash_serde {
 				S: $crate::serde::Serializer,
 			{
 				let mut slice = [0u8; 2 + 2 * $len];
-				$crate::serialize::serialize_raw(&mut slice, &self.0, serializer)
+				$crate::serialize::serialize_fixed_raw(&mut slice, &self.0, serializer)
 			}
 		}
 
@@ -73,10 +85,7 @@ macro_rules! impl_fixed_hash_serde {
 				D: $crate::serde::Deserializer<'de>,
 			{
 				let mut bytes = [0u8; $len];
-				$crate::serialize::deserialize_check_len(
-					deserializer,
-					$crate::serialize::ExpectedLen::Exact(&mut bytes),
-				)?;
+				$crate::serialize::deserialize_fixed(deserializer, $crate::serialize::ExpectedLen::Exact(&mut bytes))?;
 				Ok($name(bytes))
 			}
 		}
@@ -184,6 +184,15 @@ pub enum ExpectedLen<'a> {
 	Between(usize, &'a mut [u8]),
 }
 
+impl<'a> ExpectedLen<'a> {
+	fn slice_len(&self) -> usize {
+		match *self {
+			ExpectedLen::Exact(ref slice) => slice.len(),
+			ExpectedLen::Between(_, ref slice) => slice.len(),
+		}
+	}
+}
+
 impl<'a> fmt::Display for ExpectedLen<'a> {
 	fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
 		match *self {
@@ -246,80 +255,189 @@ pub fn deserialize_check_len<'a, 'de, D>(deserializer: D, len: ExpectedLen<'a>)
 where
 	D: Deserializer<'de>,
 {
-	struct Visitor<'a> {
-		len: ExpectedLen<'a>,
+	deserializer.deserialize_str(CheckLenVisitor { len })
+}
+
+struct CheckLenVisitor<'a> {
+	len: ExpectedLen<'a>,
+}
+
+impl<'a, 'b> de::Visitor<'b> for CheckLenVisitor<'a> {
+	type Value = usize;
+
+	fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+		write!(formatter, "a (both 0x-prefixed or not) hex string or byte array containing {}", self.len)
 	}
 
-	impl<'a, 'b> de::Visitor<'b> for Visitor<'a> {
-		type Value = usize;
+	fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+		let (v, stripped) = v.strip_prefix("0x").map_or((v, false), |v| (v, true));
 
-		fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-			write!(formatter, "a (both 0x-prefixed or not) hex string or byte array containing {}", self.len)
+		let len = v.len();
+		let is_len_valid = match self.len {
+			ExpectedLen::Exact(ref slice) => len == 2 * slice.len(),
+			ExpectedLen::Between(min, ref slice) => len <= 2 * slice.len() && len > 2 * min,
+		};
+
+		if !is_len_valid {
+			return Err(E::invalid_length(v.len(), &self))
 		}
 
-		fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
-			let (v, stripped) = v.strip_prefix("0x").map_or((v, false), |v| (v, true));
+		let bytes = match self.len {
+			ExpectedLen::Exact(slice) => slice,
+			ExpectedLen::Between(_, slice) => slice,
+		};
 
-			let len = v.len();
-			let is_len_valid = match self.len {
-				ExpectedLen::Exact(ref slice) => len == 2 * slice.len(),
-				ExpectedLen::Between(min, ref slice) => len <= 2 * slice.len() && len > 2 * min,
-			};
+		from_hex_raw(v, bytes, stripped).map_err(E::custom)
+	}
 
-			if !is_len_valid {
-				return Err(E::invalid_length(v.len(), &self))
-			}
+	fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
+		self.visit_str(&v)
+	}
 
-			let bytes = match self.len {
-				ExpectedLen::Exact(slice) => slice,
-				ExpectedLen::Between(_, slice) => slice,
-			};
+	fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+		let len = v.len();
+		let is_len_valid = match self.len {
+			ExpectedLen::Exact(ref slice) => len == slice.len(),
+			ExpectedLen::Between(min, ref slice) => len <= slice.len() && len > min,
+		};
 
-			from_hex_raw(v, bytes, stripped).map_err(E::custom)
+		if !is_len_valid {
+			return Err(E::invalid_length(v.len(), &self))
 		}
 
-		fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
-			self.visit_str(&v)
+		let bytes = match self.len {
+			ExpectedLen::Exact(slice) => slice,
+			ExpectedLen::Between(_, slice) => slice,
+		};
+
+		bytes[..len].copy_from_slice(v);
+		Ok(len)
+	}
+
+	fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+		self.visit_bytes(&v)
+	}
+
+	fn visit_seq<A: de::SeqAccess<'b>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+		let mut v = vec![];
+		while let Some(n) = seq.next_element::<u8>()? {
+			v.push(n);
 		}
+		self.visit_byte_buf(v)
+	}
 
-		fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
-			let len = v.len();
-			let is_len_valid = match self.len {
-				ExpectedLen::Exact(ref slice) => len == slice.len(),
-				ExpectedLen::Between(min, ref slice) => len <= slice.len() && len > min,
-			};
+	fn visit_newtype_struct<D: Deserializer<'b>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+		deserializer.deserialize_bytes(self)
+	}
+}
 
-			if !is_len_valid {
-				return Err(E::invalid_length(v.len(), &self))
-			}
+// Whether to use the raw bytes instead of a hex string, see `serialize_fixed_raw`.
+fn is_compact<S: Serializer>(serializer: &S) -> bool {
+	cfg!(feature = "compact-binary") && !serializer.is_human_readable()
+}
+
+fn serialize_tuple<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+	use serde::ser::SerializeTuple;
+
+	let mut tuple = serializer.serialize_tuple(bytes.len())?;
+	for byte in bytes {
+		tuple.serialize_element(byte)?;
+	}
+	tuple.end()
+}
+
+/// Serializes a fixed-size slice of bytes, such as a hash, like `serialize_raw`.
+///
+/// With the `compact-binary` feature, formats which aren't human-readable get the bytes as a
+/// tuple instead, which binary formats encode as just the bytes, without a length.
+pub fn serialize_fixed_raw<S>(slice: &mut [u8], bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+where
+	S: Serializer,
+{
+	if is_compact(&serializer) {
+		serialize_tuple(bytes, serializer)
+	} else {
+		serialize_raw(slice, bytes, serializer)
+	}
+}
+
+/// Serializes the full-width big-endian bytes of a uint like `serialize_uint`.
+///
+/// With the `compact-binary` feature, formats which aren't human-readable get all the bytes,
+/// including leading zeros, as a tuple instead, as in `serialize_fixed_raw`.
+pub fn serialize_fixed_uint<S>(slice: &mut [u8], bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+where
+	S: Serializer,
+{
+	if is_compact(&serializer) {
+		serialize_tuple(bytes, serializer)
+	} else {
+		serialize_uint(slice, bytes, serializer)
+	}
+}
 
+/// Deserializes what `serialize_fixed_raw` or `serialize_fixed_uint` serialized, into the slice of
+/// `len`. Returns number of bytes written.
+///
+/// With the `compact-binary` feature, formats which aren't human-readable must provide a sequence of
+/// exactly as many bytes as the slice holds. Self-describing formats may also provide a byte array
+/// or a hex string, accepted as in `deserialize_check_len`, so values written without the feature
+/// can still be read.
+pub fn deserialize_fixed<'a, 'de, D>(deserializer: D, len: ExpectedLen<'a>) -> Result<usize, D::Error>
+where
+	D: Deserializer<'de>,
+{
+	if !cfg!(feature = "compact-binary") || deserializer.is_human_readable() {
+		return deserialize_check_len(deserializer, len)
+	}
+
+	struct Visitor<'a> {
+		len: ExpectedLen<'a>,
+	}
+
+	impl<'a, 'b> de::Visitor<'b> for Visitor<'a> {
+		type Value = usize;
+
+		fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+			write!(formatter, "{} bytes, or a (both 0x-prefixed or not) hex string", self.len.slice_len())
+		}
+
+		fn visit_seq<A: de::SeqAccess<'b>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+			let len = self.len.slice_len();
 			let bytes = match self.len {
 				ExpectedLen::Exact(slice) => slice,
 				ExpectedLen::Between(_, slice) => slice,
 			};
-
-			bytes[..len].copy_from_slice(v);
+			let expected = || format!("{} bytes", len);
+			for (i, byte) in bytes.iter_mut().enumerate() {
+				*byte = seq
+					.next_element()?
+					.ok_or_else(|| de::Error::invalid_length(i, &expected().as_str()))?;
+			}
+			if seq.next_element::<u8>()?.is_some() {
+				return Err(de::Error::invalid_length(len + 1, &expected().as_str()))
+			}
 			Ok(len)
 		}
 
-		fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
-			self.visit_bytes(&v)
+		fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+			CheckLenVisitor { len: self.len }.visit_str(v)
 		}
 
-		fn visit_seq<A: de::SeqAccess<'b>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
-			let mut v = vec![];
-			while let Some(n) = seq.next_element::<u8>()? {
-				v.push(n);
-			}
-			self.visit_byte_buf(v)
+		fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
+			self.visit_str(&v)
 		}
 
-		fn visit_newtype_struct<D: Deserializer<'b>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
-			deserializer.deserialize_bytes(self)
+		fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+			CheckLenVisitor { len: self.len }.visit_bytes(v)
+		}
+
+		fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+			self.visit_bytes(&v)
 		}
 	}
 
-	deserializer.deserialize_str(Visitor { len })
+	deserializer.deserialize_tuple(len.slice_len(), Visitor { len })
 }
 
 #[cfg(test)]
@@ -1,38 +1,71 @@
+// Copyright 2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! `JsonSchema` implementations describing the serde representation of the uint and hash types.
+//!
+//! Hashes are `0x` prefixed hex strings of exactly twice their byte length, as serialized. Uints
+//! are hex strings of at most that many digits, with an optional `0x` prefix, as accepted when
+//! deserializing.
+
 use super::*;
 #[cfg(not(feature = "std"))]
-use alloc::{
-	borrow::ToOwned,
-	string::{String, ToString},
-};
+use alloc::{format, string::String};
 
 use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
 
-impl JsonSchema for H160 {
-	fn schema_name() -> String {
-		"HexEncoded20Bytes".to_owned()
-	}
+macro_rules! impl_uint_json_schema {
+	($name:ident, $n_words:tt) => {
+		impl JsonSchema for $name {
+			fn schema_name() -> String {
+				format!("U{}String", $n_words * 64)
+			}
 
-	fn json_schema(gen: &mut SchemaGenerator) -> Schema {
-		let mut schema = gen.subschema_for::<String>().into_object();
-		schema.metadata().description = Some("Hex encoded 20 bytes".to_string());
-		schema.string().pattern = Some("^0(x|X)[a-fA-F0-9]{40}$".to_string());
-		schema.into()
-	}
+			fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+				let mut schema = gen.subschema_for::<String>().into_object();
+				schema.metadata().description = Some(format!("{}-bit Unsigned Integer, hex encoded", $n_words * 64));
+				schema.string().pattern = Some(format!("^(0x)?[a-fA-F0-9]{{1,{}}}$", $n_words * 16));
+				schema.into()
+			}
+		}
+	};
 }
 
-impl JsonSchema for U256 {
-	fn schema_name() -> String {
-		"U256String".to_string()
-	}
+macro_rules! impl_hash_json_schema {
+	($name:ident, $n_bytes:tt) => {
+		impl JsonSchema for $name {
+			fn schema_name() -> String {
+				format!("HexEncoded{}Bytes", $n_bytes)
+			}
 
-	fn json_schema(gen: &mut SchemaGenerator) -> Schema {
-		let mut schema = gen.subschema_for::<String>().into_object();
-		schema.metadata().description = Some("256-bit Unsigned Integer".to_string());
-		schema.string().pattern = Some("^(0|[1-9][0-9]{0,77})$".to_string());
-		schema.into()
-	}
+			fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+				let mut schema = gen.subschema_for::<String>().into_object();
+				schema.metadata().description = Some(format!("Hex encoded {} bytes", $n_bytes));
+				schema.string().pattern = Some(format!("^0x[a-fA-F0-9]{{{}}}$", $n_bytes * 2));
+				schema.into()
+			}
+		}
+	};
 }
 
+impl_uint_json_schema!(U128, 2);
+impl_uint_json_schema!(U256, 4);
+impl_uint_json_schema!(U512, 8);
+impl_uint_json_schema!(U1024, 16);
+impl_uint_json_schema!(U2048, 32);
+
+impl_hash_json_schema!(H128, 16);
+impl_hash_json_schema!(H160, 20);
+impl_hash_json_schema!(H224, 28);
+impl_hash_json_schema!(H256, 32);
+impl_hash_json_schema!(H384, 48);
+impl_hash_json_schema!(H512, 64);
+impl_hash_json_schema!(H768, 96);
+
 #[cfg(test)]
 #[cfg(any(feature = "serde", feature = "serde_no_std"))]
 mod tests {
@@ -52,9 +85,12 @@ mod tests {
 			.unwrap();
 		let value = serde_json::to_value("0x55086adeca661185c437d92b9818e6eda6d0d047").unwrap();
 		assert!(schema.validate(&value).is_ok());
-		let value = serde_json::to_value("0X0E9C8DA9FD4BDD3281879D9E328D8D74D02558CC").unwrap();
+		let value = serde_json::to_value("0x0E9C8DA9FD4BDD3281879D9E328D8D74D02558CC").unwrap();
 		assert!(schema.validate(&value).is_ok());
 
+		// serde only accepts a lowercase prefix
+		let value = serde_json::to_value("0X0E9C8DA9FD4BDD3281879D9E328D8D74D02558CC").unwrap();
+		assert!(schema.validate(&value).is_err());
 		let value = serde_json::to_value("42").unwrap();
 		assert!(schema.validate(&value).is_err());
 	}
@@ -69,7 +105,11 @@ mod tests {
 			.unwrap();
 		let addr = serde_json::to_value("42").unwrap();
 		assert!(schema.validate(&addr).is_ok());
-		let addr = serde_json::to_value(['1'; 79].into_iter().collect::<String>()).unwrap();
+		let addr = serde_json::to_value(serde_json::to_string(&U256::MAX).unwrap().trim_matches('"')).unwrap();
+		assert!(schema.validate(&addr).is_ok());
+		let addr = serde_json::to_value(['1'; 65].into_iter().collect::<String>()).unwrap();
+		assert!(schema.validate(&addr).is_err());
+		let addr = serde_json::to_value("0x").unwrap();
 		assert!(schema.validate(&addr).is_err());
 	}
 }
@@ -8,9 +8,9 @@
 
 //! Primitive types shared by Substrate and Parity Ethereum.
 //!
-//! Those are uint types `U128`, `U256` and `U512`, and fixed hash types `H160`,
-//! `H256` and `H512`, with optional serde serialization, parity-scale-codec and
-//! rlp encoding.
+//! Those are uint types `U128`, `U256`, `U512`, `U1024` and `U2048`, the signed `I256`, and fixed
+//! hash types `H160`, `H256` and `H512`, with optional serde serialization,
+//! parity-scale-codec and rlp encoding.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![feature(adt_const_params)]
@@ -19,18 +19,20 @@
 #[cfg(all(not(feature = "std"), any(feature = "serde_no_std", feature = "json-schema")))]
 extern crate alloc;
 
-#[cfg(feature = "fp-conversion")]
-mod fp_conversion;
 #[cfg(feature = "json-schema")]
 mod json_schema;
+#[cfg(feature = "impl-serde")]
+pub mod serde_decimal;
+mod signed;
 
-use core::convert::TryFrom;
-use std::marker::ConstParamTy;
-use fixed_hash::{construct_fixed_hash, impl_fixed_hash_conversions};
+use core::{convert::TryFrom, marker::ConstParamTy};
+use fixed_hash::{construct_fixed_hash, impl_fixed_hash_conversions, impl_fixed_hash_split};
 #[cfg(feature = "scale-info")]
 use scale_info::TypeInfo;
 use uint::{construct_uint, uint_full_mul_reg};
 
+pub use signed::I256;
+
 /// Error type for conversion.
 #[derive(Debug, PartialEq, Eq)]
 pub enum Error {
@@ -38,6 +40,17 @@ pub enum Error {
 	Overflow,
 }
 
+impl core::fmt::Display for Error {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		match self {
+			Error::Overflow => write!(f, "integer overflow: the value does not fit in the target type"),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
 construct_uint! {
 	/// 128-bit unsigned integer.
 	#[cfg_attr(feature = "scale-info", derive(TypeInfo))]
@@ -53,6 +66,16 @@ construct_uint! {
 	#[cfg_attr(feature = "scale-info", derive(TypeInfo))]
 	#[derive(ConstParamTy)] pub struct U512(8);
 }
+construct_uint! {
+	/// 1024-bits unsigned integer.
+	#[cfg_attr(feature = "scale-info", derive(TypeInfo))]
+	#[derive(ConstParamTy)] pub struct U1024(16);
+}
+construct_uint! {
+	/// 2048-bits unsigned integer.
+	#[cfg_attr(feature = "scale-info", derive(TypeInfo))]
+	#[derive(ConstParamTy)] pub struct U2048(32);
+}
 
 construct_fixed_hash! {
 	/// Fixed-size uninterpreted hash type with 16 bytes (128 bits) size.
@@ -65,6 +88,11 @@ construct_fixed_hash! {
 	#[cfg_attr(feature = "scale-info", derive(TypeInfo))]
 	pub struct H160(20);
 }
+construct_fixed_hash! {
+	/// Fixed-size uninterpreted hash type with 28 bytes (224 bits) size.
+	#[cfg_attr(feature = "scale-info", derive(TypeInfo))]
+	pub struct H224(28);
+}
 construct_fixed_hash! {
 	/// Fixed-size uninterpreted hash type with 32 bytes (256 bits) size.
 	#[cfg_attr(feature = "scale-info", derive(TypeInfo))]
@@ -86,14 +114,85 @@ construct_fixed_hash! {
 	pub struct H768(96);
 }
 
+#[doc(hidden)]
+pub use fixed_hash::hash_literal as __hash_literal;
+
+/// Creates an `H128` from a hex string literal at compile time, like `h256!`.
+#[macro_export]
+macro_rules! h128 {
+	($hex:expr) => {
+		$crate::__hash_literal!($crate::H128, $hex)
+	};
+}
+
+/// Creates an `H160` from a hex string literal at compile time, like `h256!`.
+#[macro_export]
+macro_rules! h160 {
+	($hex:expr) => {
+		$crate::__hash_literal!($crate::H160, $hex)
+	};
+}
+
+/// Creates an `H224` from a hex string literal at compile time, like `h256!`.
+#[macro_export]
+macro_rules! h224 {
+	($hex:expr) => {
+		$crate::__hash_literal!($crate::H224, $hex)
+	};
+}
+
+/// Creates an `H256` from a hex string literal, with an optional `0x` prefix, at compile time.
+///
+/// A literal with a character which isn't a hex digit or without exactly 64 digits fails to
+/// compile. The result is a constant, so the macro can be used to initialize a `const`.
+///
+/// ```
+/// use primitive_types::{h256, H256};
+///
+/// const ONE: H256 = h256!("0x0000000000000000000000000000000000000000000000000000000000000001");
+/// assert_eq!(ONE, H256::from_low_u64_be(1));
+/// ```
+#[macro_export]
+macro_rules! h256 {
+	($hex:expr) => {
+		$crate::__hash_literal!($crate::H256, $hex)
+	};
+}
+
+/// Creates an `H384` from a hex string literal at compile time, like `h256!`.
+#[macro_export]
+macro_rules! h384 {
+	($hex:expr) => {
+		$crate::__hash_literal!($crate::H384, $hex)
+	};
+}
+
+/// Creates an `H512` from a hex string literal at compile time, like `h256!`.
+#[macro_export]
+macro_rules! h512 {
+	($hex:expr) => {
+		$crate::__hash_literal!($crate::H512, $hex)
+	};
+}
+
+/// Creates an `H768` from a hex string literal at compile time, like `h256!`.
+#[macro_export]
+macro_rules! h768 {
+	($hex:expr) => {
+		$crate::__hash_literal!($crate::H768, $hex)
+	};
+}
+
 #[cfg(feature = "num-traits")]
 mod num_traits {
 	use super::*;
 	use impl_num_traits::impl_uint_num_traits;
 
-	impl_uint_num_traits!(U128, 2);
-	impl_uint_num_traits!(U256, 4);
-	impl_uint_num_traits!(U512, 8);
+	impl_uint_num_traits!(U128, 2, [U128, U256, U512, U1024, U2048]);
+	impl_uint_num_traits!(U256, 4, [U128, U256, U512, U1024, U2048]);
+	impl_uint_num_traits!(U512, 8, [U128, U256, U512, U1024, U2048]);
+	impl_uint_num_traits!(U1024, 16, [U128, U256, U512, U1024, U2048]);
+	impl_uint_num_traits!(U2048, 32, [U128, U256, U512, U1024, U2048]);
 }
 
 #[cfg(feature = "impl-serde")]
@@ -104,9 +203,12 @@ mod serde {
 	impl_uint_serde!(U128, 2);
 	impl_uint_serde!(U256, 4);
 	impl_uint_serde!(U512, 8);
+	impl_uint_serde!(U1024, 16);
+	impl_uint_serde!(U2048, 32);
 
 	impl_fixed_hash_serde!(H128, 16);
 	impl_fixed_hash_serde!(H160, 20);
+	impl_fixed_hash_serde!(H224, 28);
 	impl_fixed_hash_serde!(H256, 32);
 	impl_fixed_hash_serde!(H384, 48);
 	impl_fixed_hash_serde!(H512, 64);
@@ -121,9 +223,12 @@ mod codec {
 	impl_uint_codec!(U128, 2);
 	impl_uint_codec!(U256, 4);
 	impl_uint_codec!(U512, 8);
+	impl_uint_codec!(U1024, 16);
+	impl_uint_codec!(U2048, 32);
 
 	impl_fixed_hash_codec!(H128, 16);
 	impl_fixed_hash_codec!(H160, 20);
+	impl_fixed_hash_codec!(H224, 28);
 	impl_fixed_hash_codec!(H256, 32);
 	impl_fixed_hash_codec!(H384, 48);
 	impl_fixed_hash_codec!(H512, 64);
@@ -138,9 +243,12 @@ mod rlp {
 	impl_uint_rlp!(U128, 2);
 	impl_uint_rlp!(U256, 4);
 	impl_uint_rlp!(U512, 8);
+	impl_uint_rlp!(U1024, 16);
+	impl_uint_rlp!(U2048, 32);
 
 	impl_fixed_hash_rlp!(H128, 16);
 	impl_fixed_hash_rlp!(H160, 20);
+	impl_fixed_hash_rlp!(H224, 28);
 	impl_fixed_hash_rlp!(H256, 32);
 	impl_fixed_hash_rlp!(H384, 48);
 	impl_fixed_hash_rlp!(H512, 64);
@@ -148,6 +256,63 @@ mod rlp {
 }
 
 impl_fixed_hash_conversions!(H256, H160);
+impl_fixed_hash_conversions!(H256, H224);
+impl_fixed_hash_conversions!(H224, H160);
+impl_fixed_hash_split!(H256, H128);
+impl_fixed_hash_split!(H512, H256);
+impl_fixed_hash_split!(H768, H384);
+
+/// Implements conversions between a hash type and the `u64` limbs of the uint type of the same
+/// size.
+///
+/// The hash is read as a big-endian number, as by `from_big_endian` of the uint type, and the
+/// limbs are in the order of the uint type, least significant first. So `to_u64_limbs` returns the
+/// inner array of the uint with the same big-endian bytes as the hash.
+macro_rules! impl_hash_limbs {
+	($hash:ident, $uint:ident, $limbs:tt) => {
+		impl $hash {
+			#[doc = concat!("Creates a hash from the limbs of a `", stringify!($uint), "`, least significant first.")]
+			///
+			/// The most significant limb becomes the first bytes of the hash, in big-endian order.
+			#[inline]
+			pub const fn from_u64_limbs(limbs: [u64; $limbs]) -> Self {
+				let mut bytes = [0u8; $limbs * 8];
+				let mut i = 0;
+				while i < $limbs {
+					let limb = limbs[$limbs - 1 - i].to_be_bytes();
+					let mut j = 0;
+					while j < 8 {
+						bytes[i * 8 + j] = limb[j];
+						j += 1;
+					}
+					i += 1;
+				}
+				$hash(bytes)
+			}
+
+			#[doc = concat!("Returns the limbs of the `", stringify!($uint), "` with the big-endian bytes of the hash, least significant first.")]
+			#[inline]
+			pub const fn to_u64_limbs(&self) -> [u64; $limbs] {
+				let mut limbs = [0u64; $limbs];
+				let mut i = 0;
+				while i < $limbs {
+					let mut limb = [0u8; 8];
+					let mut j = 0;
+					while j < 8 {
+						limb[j] = self.0[i * 8 + j];
+						j += 1;
+					}
+					limbs[$limbs - 1 - i] = u64::from_be_bytes(limb);
+					i += 1;
+				}
+				limbs
+			}
+		}
+	};
+}
+
+impl_hash_limbs!(H256, U256, 4);
+impl_hash_limbs!(H512, U512, 8);
 
 impl U128 {
 	/// Multiplies two 128-bit integers to produce full 256-bit integer.
@@ -167,110 +332,85 @@ impl U256 {
 	}
 }
 
-impl From<U256> for U512 {
-	fn from(value: U256) -> U512 {
-		let U256(ref arr) = value;
-		let mut ret = [0; 8];
-		ret[0] = arr[0];
-		ret[1] = arr[1];
-		ret[2] = arr[2];
-		ret[3] = arr[3];
-		U512(ret)
+impl U512 {
+	/// Multiplies two 512-bit integers to produce full 1024-bit integer.
+	/// Overflow is not possible.
+	#[inline(always)]
+	pub fn full_mul(self, other: U512) -> U1024 {
+		U1024(uint_full_mul_reg!(U512, 8, self, other))
 	}
 }
 
-impl TryFrom<U256> for U128 {
-	type Error = Error;
-
-	fn try_from(value: U256) -> Result<U128, Error> {
-		let U256(ref arr) = value;
-		if arr[2] | arr[3] != 0 {
-			return Err(Error::Overflow)
-		}
-		let mut ret = [0; 2];
-		ret[0] = arr[0];
-		ret[1] = arr[1];
-		Ok(U128(ret))
+impl U1024 {
+	/// Multiplies two 1024-bit integers to produce full 2048-bit integer.
+	/// Overflow is not possible.
+	#[inline(always)]
+	pub fn full_mul(self, other: U1024) -> U2048 {
+		U2048(uint_full_mul_reg!(U1024, 16, self, other))
 	}
 }
 
-impl TryFrom<U512> for U256 {
-	type Error = Error;
-
-	fn try_from(value: U512) -> Result<U256, Error> {
-		let U512(ref arr) = value;
-		if arr[4] | arr[5] | arr[6] | arr[7] != 0 {
-			return Err(Error::Overflow)
+/// Implements the conversions between two uint types: `From` the narrower to the wider type, and
+/// `TryFrom` the wider to the narrower type, which fails if the value doesn't fit. Optionally adds
+/// a truncating accessor to the wider type, named after the ones for primitive types.
+macro_rules! impl_uint_conversions {
+	($narrow:ident, $narrow_words:tt, $wide:ident, $wide_words:tt $(, $low:ident)?) => {
+		impl From<$narrow> for $wide {
+			fn from(value: $narrow) -> $wide {
+				let mut ret = [0; $wide_words];
+				ret[..$narrow_words].copy_from_slice(&value.0);
+				$wide(ret)
+			}
 		}
-		let mut ret = [0; 4];
-		ret[0] = arr[0];
-		ret[1] = arr[1];
-		ret[2] = arr[2];
-		ret[3] = arr[3];
-		Ok(U256(ret))
-	}
-}
 
-impl TryFrom<U512> for U128 {
-	type Error = Error;
+		impl<'a> From<&'a $narrow> for $wide {
+			fn from(value: &'a $narrow) -> $wide {
+				$wide::from(*value)
+			}
+		}
 
-	fn try_from(value: U512) -> Result<U128, Error> {
-		let U512(ref arr) = value;
-		if arr[2] | arr[3] | arr[4] | arr[5] | arr[6] | arr[7] != 0 {
-			return Err(Error::Overflow)
+		impl TryFrom<$wide> for $narrow {
+			type Error = Error;
+
+			fn try_from(value: $wide) -> Result<$narrow, Error> {
+				if value.0[$narrow_words..].iter().any(|&word| word != 0) {
+					return Err(Error::Overflow)
+				}
+				let mut ret = [0; $narrow_words];
+				ret.copy_from_slice(&value.0[..$narrow_words]);
+				Ok($narrow(ret))
+			}
 		}
-		let mut ret = [0; 2];
-		ret[0] = arr[0];
-		ret[1] = arr[1];
-		Ok(U128(ret))
-	}
-}
 
-impl From<U128> for U512 {
-	fn from(value: U128) -> U512 {
-		let U128(ref arr) = value;
-		let mut ret = [0; 8];
-		ret[0] = arr[0];
-		ret[1] = arr[1];
-		U512(ret)
-	}
-}
+		impl<'a> TryFrom<&'a $wide> for $narrow {
+			type Error = Error;
 
-impl From<U128> for U256 {
-	fn from(value: U128) -> U256 {
-		let U128(ref arr) = value;
-		let mut ret = [0; 4];
-		ret[0] = arr[0];
-		ret[1] = arr[1];
-		U256(ret)
-	}
-}
+			fn try_from(value: &'a $wide) -> Result<$narrow, Error> {
+				$narrow::try_from(*value)
+			}
+		}
 
-impl<'a> From<&'a U256> for U512 {
-	fn from(value: &'a U256) -> U512 {
-		let U256(ref arr) = *value;
-		let mut ret = [0; 8];
-		ret[0] = arr[0];
-		ret[1] = arr[1];
-		ret[2] = arr[2];
-		ret[3] = arr[3];
-		U512(ret)
-	}
+		$(
+			impl $wide {
+				#[doc = concat!("Conversion to `", stringify!($narrow), "` with overflow, discarding the high bits.")]
+				pub fn $low(&self) -> $narrow {
+					let mut ret = [0; $narrow_words];
+					ret.copy_from_slice(&self.0[..$narrow_words]);
+					$narrow(ret)
+				}
+			}
+		)?
+	};
 }
 
-impl<'a> TryFrom<&'a U512> for U256 {
-	type Error = Error;
-
-	fn try_from(value: &'a U512) -> Result<U256, Error> {
-		let U512(ref arr) = *value;
-		if arr[4] | arr[5] | arr[6] | arr[7] != 0 {
-			return Err(Error::Overflow)
-		}
-		let mut ret = [0; 4];
-		ret[0] = arr[0];
-		ret[1] = arr[1];
-		ret[2] = arr[2];
-		ret[3] = arr[3];
-		Ok(U256(ret))
-	}
-}
+// The uint types already have a `low_u128` accessor, returning the primitive `u128`.
+impl_uint_conversions!(U128, 2, U256, 4);
+impl_uint_conversions!(U128, 2, U512, 8);
+impl_uint_conversions!(U256, 4, U512, 8, low_u256);
+impl_uint_conversions!(U128, 2, U1024, 16);
+impl_uint_conversions!(U256, 4, U1024, 16, low_u256);
+impl_uint_conversions!(U512, 8, U1024, 16, low_u512);
+impl_uint_conversions!(U128, 2, U2048, 32);
+impl_uint_conversions!(U256, 4, U2048, 32, low_u256);
+impl_uint_conversions!(U512, 8, U2048, 32, low_u512);
+impl_uint_conversions!(U1024, 16, U2048, 32, low_u1024);
@@ -0,0 +1,103 @@
+// Copyright 2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Serde serialization of uint types as decimal strings.
+//!
+//! Use with `#[serde(with = "primitive_types::serde_decimal")]`. Values are serialized as decimal
+//! strings such as `"1000"`, and can be deserialized from a decimal string, a `0x` prefixed hex
+//! string, or an integer, as long as it is not negative. Floats are accepted only if they hold an
+//! integer no larger than `2^53`, above which they can't represent integers exactly.
+
+use crate::{U1024, U128, U2048, U256, U512};
+use core::{fmt, marker::PhantomData};
+use impl_serde::serde::{de, Deserializer, Serializer};
+use uint::{FromDecStrErr, FromStrRadixErr};
+
+// The largest integer up to which every integer is exactly representable as `f64`.
+const MAX_EXACT_FLOAT: f64 = 9007199254740992.0;
+
+/// A uint type which can be serialized with this module.
+pub trait DecimalUint: Sized + fmt::Display + From<u64> + From<u128> {
+	#[doc(hidden)]
+	fn from_dec_str(value: &str) -> Result<Self, FromDecStrErr>;
+
+	#[doc(hidden)]
+	fn from_prefixed_str(value: &str) -> Result<Self, FromStrRadixErr>;
+}
+
+macro_rules! impl_decimal_uint {
+	($($name:ident),*) => {
+		$(
+			impl DecimalUint for $name {
+				fn from_dec_str(value: &str) -> Result<Self, FromDecStrErr> {
+					$name::from_dec_str(value)
+				}
+
+				fn from_prefixed_str(value: &str) -> Result<Self, FromStrRadixErr> {
+					$name::from_prefixed_str(value)
+				}
+			}
+		)*
+	};
+}
+
+impl_decimal_uint!(U128, U256, U512, U1024, U2048);
+
+/// Serialize `value` as a decimal string.
+pub fn serialize<T: DecimalUint, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+	serializer.collect_str(value)
+}
+
+/// Deserialize a value from a decimal or `0x` prefixed hex string, or from an integer.
+pub fn deserialize<'de, T: DecimalUint, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+	deserializer.deserialize_any(Visitor(PhantomData))
+}
+
+struct Visitor<T>(PhantomData<T>);
+
+impl<'de, T: DecimalUint> de::Visitor<'de> for Visitor<T> {
+	type Value = T;
+
+	fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+		write!(formatter, "a non-negative integer, or a decimal or 0x-prefixed hex string")
+	}
+
+	fn visit_str<E: de::Error>(self, value: &str) -> Result<T, E> {
+		if value.starts_with("0x") {
+			T::from_prefixed_str(value).map_err(|e| E::custom(format_args!("invalid hex value {:?}: {}", value, e)))
+		} else if value.is_empty() {
+			Err(E::invalid_value(de::Unexpected::Str(value), &self))
+		} else {
+			T::from_dec_str(value).map_err(|e| E::custom(format_args!("invalid decimal value {:?}: {}", value, e)))
+		}
+	}
+
+	fn visit_u64<E: de::Error>(self, value: u64) -> Result<T, E> {
+		Ok(T::from(value))
+	}
+
+	fn visit_u128<E: de::Error>(self, value: u128) -> Result<T, E> {
+		Ok(T::from(value))
+	}
+
+	fn visit_i64<E: de::Error>(self, value: i64) -> Result<T, E> {
+		if value < 0 {
+			return Err(E::invalid_value(de::Unexpected::Signed(value), &self))
+		}
+		Ok(T::from(value as u64))
+	}
+
+	fn visit_f64<E: de::Error>(self, value: f64) -> Result<T, E> {
+		// Also rejects NaN, which compares false.
+		if (0.0..=MAX_EXACT_FLOAT).contains(&value) && value as u64 as f64 == value {
+			Ok(T::from(value as u64))
+		} else {
+			Err(E::invalid_value(de::Unexpected::Float(value), &self))
+		}
+	}
+}
@@ -0,0 +1,408 @@
+// Copyright 2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Signed 256-bit integer.
+
+use crate::{Error, U256};
+use core::{cmp::Ordering, convert::TryFrom, fmt, ops, str::FromStr};
+#[cfg(feature = "scale-info")]
+use scale_info::TypeInfo;
+use uint::FromDecStrErr;
+
+const SIGN_BIT: u64 = 1 << 63;
+
+/// 256-bit signed integer, stored as a [`U256`] in two's complement.
+///
+/// Arithmetic follows Rust's primitive signed integers: division truncates towards zero and the
+/// remainder has the sign of the dividend. The `checked_*` methods return `None` on overflow or
+/// division by zero, which includes `I256::MIN / -1` and `I256::MIN % -1`, while the operators
+/// panic in those cases.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "scale-info", derive(TypeInfo))]
+pub struct I256(U256);
+
+impl I256 {
+	/// Zero.
+	pub const ZERO: I256 = I256(U256([0; 4]));
+	/// One.
+	pub const ONE: I256 = I256(U256([1, 0, 0, 0]));
+	/// Minus one.
+	pub const MINUS_ONE: I256 = I256(U256::MAX);
+	/// Largest value, `2^255 - 1`.
+	pub const MAX: I256 = I256(U256([u64::MAX, u64::MAX, u64::MAX, !SIGN_BIT]));
+	/// Smallest value, `-2^255`.
+	pub const MIN: I256 = I256(U256([0, 0, 0, SIGN_BIT]));
+
+	/// Interpret the bits of `raw` as a two's complement signed integer.
+	pub const fn from_raw(raw: U256) -> Self {
+		I256(raw)
+	}
+
+	/// The two's complement bits of `self`.
+	pub const fn into_raw(self) -> U256 {
+		self.0
+	}
+
+	/// Whether `self` is less than zero.
+	pub const fn is_negative(&self) -> bool {
+		self.0 .0[3] & SIGN_BIT != 0
+	}
+
+	/// Whether `self` is greater than zero.
+	pub const fn is_positive(&self) -> bool {
+		!self.is_negative() && !self.0.is_zero()
+	}
+
+	/// Whether `self` is zero.
+	pub const fn is_zero(&self) -> bool {
+		self.0.is_zero()
+	}
+
+	/// `-1`, `0` or `1` depending on the sign of `self`.
+	pub fn signum(self) -> Self {
+		if self.is_negative() {
+			Self::MINUS_ONE
+		} else if self.is_zero() {
+			Self::ZERO
+		} else {
+			Self::ONE
+		}
+	}
+
+	/// The absolute value of `self` as an unsigned integer. Unlike [`I256::abs`], this can't
+	/// overflow.
+	pub fn unsigned_abs(self) -> U256 {
+		if self.is_negative() {
+			negate(self.0)
+		} else {
+			self.0
+		}
+	}
+
+	/// The absolute value of `self`.
+	///
+	/// # Panics
+	///
+	/// Panics if `self` is [`I256::MIN`], whose absolute value doesn't fit.
+	pub fn abs(self) -> Self {
+		self.checked_abs().expect("arithmetic operation overflow")
+	}
+
+	/// Checked absolute value. Returns `None` if `self` is [`I256::MIN`].
+	pub fn checked_abs(self) -> Option<Self> {
+		if self.is_negative() {
+			self.checked_neg()
+		} else {
+			Some(self)
+		}
+	}
+
+	/// Negation with overflow. Only overflows for [`I256::MIN`], which is its own negation.
+	pub fn overflowing_neg(self) -> (Self, bool) {
+		(I256(negate(self.0)), self == Self::MIN)
+	}
+
+	/// Checked negation. Returns `None` if `self` is [`I256::MIN`].
+	pub fn checked_neg(self) -> Option<Self> {
+		match self.overflowing_neg() {
+			(_, true) => None,
+			(negated, false) => Some(negated),
+		}
+	}
+
+	/// Addition which wraps around on overflow and reports whether it did.
+	pub fn overflowing_add(self, other: Self) -> (Self, bool) {
+		let result = I256(self.0.overflowing_add(other.0).0);
+		// Only operands of the same sign can overflow, flipping the sign of the result.
+		let overflow = self.is_negative() == other.is_negative() && result.is_negative() != self.is_negative();
+		(result, overflow)
+	}
+
+	/// Checked addition. Returns `None` if overflow occurred.
+	pub fn checked_add(self, other: Self) -> Option<Self> {
+		match self.overflowing_add(other) {
+			(_, true) => None,
+			(result, false) => Some(result),
+		}
+	}
+
+	/// Subtraction which wraps around on overflow and reports whether it did.
+	pub fn overflowing_sub(self, other: Self) -> (Self, bool) {
+		let result = I256(self.0.overflowing_sub(other.0).0);
+		// Only operands of differing signs can overflow, flipping the sign of the result.
+		let overflow = self.is_negative() != other.is_negative() && result.is_negative() != self.is_negative();
+		(result, overflow)
+	}
+
+	/// Checked subtraction. Returns `None` if overflow occurred.
+	pub fn checked_sub(self, other: Self) -> Option<Self> {
+		match self.overflowing_sub(other) {
+			(_, true) => None,
+			(result, false) => Some(result),
+		}
+	}
+
+	/// Checked multiplication. Returns `None` if overflow occurred.
+	pub fn checked_mul(self, other: Self) -> Option<Self> {
+		let magnitude = self.unsigned_abs().checked_mul(other.unsigned_abs())?;
+		Self::from_sign_and_magnitude(self.is_negative() != other.is_negative(), magnitude)
+	}
+
+	/// Checked division, truncating towards zero. Returns `None` if `other == 0` or if the
+	/// result overflows, which only happens for `I256::MIN / -1`.
+	pub fn checked_div(self, other: Self) -> Option<Self> {
+		if other.is_zero() {
+			return None
+		}
+		let magnitude = self.unsigned_abs() / other.unsigned_abs();
+		Self::from_sign_and_magnitude(self.is_negative() != other.is_negative(), magnitude)
+	}
+
+	/// Checked remainder, with the sign of `self`. Returns `None` if `other == 0` and, like the
+	/// primitive signed integers, for `I256::MIN % -1`.
+	pub fn checked_rem(self, other: Self) -> Option<Self> {
+		if other.is_zero() || (self == Self::MIN && other == Self::MINUS_ONE) {
+			return None
+		}
+		let magnitude = self.unsigned_abs() % other.unsigned_abs();
+		Self::from_sign_and_magnitude(self.is_negative(), magnitude)
+	}
+
+	/// Convert from a decimal string with an optional leading `-` or `+`.
+	pub fn from_dec_str(value: &str) -> Result<Self, FromDecStrErr> {
+		let (negative, digits) = match value.strip_prefix('-') {
+			Some(digits) => (true, digits),
+			None => (false, value.strip_prefix('+').unwrap_or(value)),
+		};
+		if digits.is_empty() {
+			return Err(FromDecStrErr::InvalidCharacter)
+		}
+		let magnitude = U256::from_dec_str(digits)?;
+		Self::from_sign_and_magnitude(negative, magnitude).ok_or(FromDecStrErr::InvalidLength)
+	}
+
+	fn from_sign_and_magnitude(negative: bool, magnitude: U256) -> Option<Self> {
+		if negative {
+			(magnitude <= Self::MIN.0).then_some(I256(negate(magnitude)))
+		} else {
+			(magnitude <= Self::MAX.0).then_some(I256(magnitude))
+		}
+	}
+}
+
+// Two's complement negation, wrapping `-2^255` to itself.
+fn negate(value: U256) -> U256 {
+	(!value).overflowing_add(U256::one()).0
+}
+
+impl Ord for I256 {
+	fn cmp(&self, other: &Self) -> Ordering {
+		match (self.is_negative(), other.is_negative()) {
+			(true, false) => Ordering::Less,
+			(false, true) => Ordering::Greater,
+			// Values of the same sign are ordered like their two's complement bits.
+			_ => self.0.cmp(&other.0),
+		}
+	}
+}
+
+impl PartialOrd for I256 {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+macro_rules! impl_binary_op {
+	($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $checked:ident, $msg:expr) => {
+		impl ops::$trait for I256 {
+			type Output = I256;
+
+			fn $method(self, other: I256) -> I256 {
+				self.$checked(other).expect($msg)
+			}
+		}
+
+		impl ops::$assign_trait for I256 {
+			fn $assign_method(&mut self, other: I256) {
+				*self = ops::$trait::$method(*self, other);
+			}
+		}
+	};
+}
+
+impl_binary_op!(Add, add, AddAssign, add_assign, checked_add, "arithmetic operation overflow");
+impl_binary_op!(Sub, sub, SubAssign, sub_assign, checked_sub, "arithmetic operation overflow");
+impl_binary_op!(Mul, mul, MulAssign, mul_assign, checked_mul, "arithmetic operation overflow");
+impl_binary_op!(Div, div, DivAssign, div_assign, checked_div, "division by zero or overflow");
+impl_binary_op!(Rem, rem, RemAssign, rem_assign, checked_rem, "division by zero or overflow");
+
+impl ops::Neg for I256 {
+	type Output = I256;
+
+	fn neg(self) -> I256 {
+		self.checked_neg().expect("arithmetic operation overflow")
+	}
+}
+
+macro_rules! impl_from_signed {
+	($($t:ty),*) => {
+		$(
+			impl From<$t> for I256 {
+				fn from(value: $t) -> I256 {
+					I256::from(value as i128)
+				}
+			}
+		)*
+	};
+}
+
+impl_from_signed!(i8, i16, i32, i64, isize);
+
+impl From<i128> for I256 {
+	fn from(value: i128) -> I256 {
+		let magnitude = U256::from(value.unsigned_abs());
+		if value < 0 {
+			I256(negate(magnitude))
+		} else {
+			I256(magnitude)
+		}
+	}
+}
+
+macro_rules! impl_from_unsigned {
+	($($t:ty),*) => {
+		$(
+			impl From<$t> for I256 {
+				fn from(value: $t) -> I256 {
+					I256(U256::from(value))
+				}
+			}
+		)*
+	};
+}
+
+impl_from_unsigned!(u8, u16, u32, u64, u128, usize);
+
+impl TryFrom<U256> for I256 {
+	type Error = Error;
+
+	fn try_from(value: U256) -> Result<I256, Error> {
+		if value.bit(255) {
+			return Err(Error::Overflow)
+		}
+		Ok(I256(value))
+	}
+}
+
+impl TryFrom<I256> for U256 {
+	type Error = Error;
+
+	fn try_from(value: I256) -> Result<U256, Error> {
+		if value.is_negative() {
+			return Err(Error::Overflow)
+		}
+		Ok(value.0)
+	}
+}
+
+impl FromStr for I256 {
+	type Err = FromDecStrErr;
+
+	fn from_str(value: &str) -> Result<I256, FromDecStrErr> {
+		I256::from_dec_str(value)
+	}
+}
+
+impl fmt::Display for I256 {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		let mut buf = [0_u8; 80];
+		let mut i = buf.len();
+		let mut current = self.unsigned_abs();
+		let ten = U256::from(10);
+
+		loop {
+			i -= 1;
+			buf[i] = (current % ten).low_u64() as u8 + b'0';
+			current /= ten;
+			if current.is_zero() {
+				break
+			}
+		}
+
+		let digits = core::str::from_utf8(&buf[i..]).expect("only ASCII digits are written; qed");
+		f.pad_integral(!self.is_negative(), "", digits)
+	}
+}
+
+impl fmt::Debug for I256 {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		fmt::Display::fmt(self, f)
+	}
+}
+
+/// Serialized as a decimal string, e.g. `"-42"`.
+#[cfg(feature = "impl-serde")]
+mod serde {
+	use super::*;
+	use impl_serde::serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+	impl Serialize for I256 {
+		fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+			serializer.collect_str(self)
+		}
+	}
+
+	impl<'de> Deserialize<'de> for I256 {
+		fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+			deserializer.deserialize_str(Visitor)
+		}
+	}
+
+	struct Visitor;
+
+	impl<'de> de::Visitor<'de> for Visitor {
+		type Value = I256;
+
+		fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+			write!(formatter, "a decimal string of a signed 256-bit integer")
+		}
+
+		fn visit_str<E: de::Error>(self, value: &str) -> Result<I256, E> {
+			I256::from_dec_str(value).map_err(|e| E::custom(format_args!("invalid signed integer {:?}: {}", value, e)))
+		}
+	}
+}
+
+/// Encoded like the [`U256`] holding its two's complement bits.
+#[cfg(feature = "impl-codec")]
+mod codec {
+	use super::*;
+	use impl_codec::codec::{Decode, DecodeWithMemTracking, Encode, EncodeLike, Input, MaxEncodedLen};
+
+	impl Encode for I256 {
+		fn using_encoded<R, F: FnOnce(&[u8]) -> R>(&self, f: F) -> R {
+			self.0.using_encoded(f)
+		}
+	}
+
+	impl EncodeLike for I256 {}
+
+	impl Decode for I256 {
+		fn decode<I: Input>(input: &mut I) -> Result<Self, impl_codec::codec::Error> {
+			U256::decode(input).map(I256)
+		}
+	}
+
+	impl DecodeWithMemTracking for I256 {}
+
+	impl MaxEncodedLen for I256 {
+		fn max_encoded_len() -> usize {
+			U256::max_encoded_len()
+		}
+	}
+}
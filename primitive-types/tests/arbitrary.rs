@@ -0,0 +1,69 @@
+// Copyright 2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Tests for the `Arbitrary` implementations.
+
+use arbitrary::{Arbitrary, Unstructured};
+use primitive_types::{H160, H256, U128, U256, U512};
+
+// Deterministic, but not trivially patterned, input bytes.
+fn data(len: usize) -> Vec<u8> {
+	(0..len).map(|i| (i as u8).wrapping_mul(151).wrapping_add(7)).collect()
+}
+
+#[test]
+fn size_hint_is_the_byte_width() {
+	assert_eq!(U128::size_hint(0), (16, Some(16)));
+	assert_eq!(U256::size_hint(0), (32, Some(32)));
+	assert_eq!(U512::size_hint(0), (64, Some(64)));
+	assert_eq!(H160::size_hint(0), (20, Some(20)));
+	assert_eq!(H256::size_hint(0), (32, Some(32)));
+	assert_eq!(<(U256, H160)>::size_hint(0), (52, Some(52)));
+}
+
+#[test]
+fn values_come_from_the_raw_bytes() {
+	let bytes = data(52);
+	let mut u = Unstructured::new(&bytes);
+	assert_eq!(U256::arbitrary(&mut u).unwrap(), U256::from_big_endian(&bytes[..32]));
+	assert_eq!(H160::arbitrary(&mut u).unwrap(), H160::from_slice(&bytes[32..]));
+	assert!(u.is_empty());
+
+	// Missing input is filled with zeros.
+	assert_eq!(U256::arbitrary(&mut Unstructured::new(&[])).unwrap(), U256::zero());
+}
+
+#[cfg(feature = "codec")]
+#[test]
+fn scale_round_trip() {
+	use impl_codec::codec::{Decode, Encode};
+
+	let bytes = data(1024);
+	let mut u = Unstructured::new(&bytes);
+	while !u.is_empty() {
+		let (uint, hash, address) = <(U256, H256, H160)>::arbitrary(&mut u).unwrap();
+		assert_eq!(U256::decode(&mut &uint.encode()[..]).unwrap(), uint);
+		assert_eq!(H256::decode(&mut &hash.encode()[..]).unwrap(), hash);
+		assert_eq!(H160::decode(&mut &address.encode()[..]).unwrap(), address);
+	}
+}
+
+#[cfg(feature = "rlp")]
+#[test]
+fn rlp_round_trip() {
+	use impl_rlp::rlp;
+
+	let bytes = data(1024);
+	let mut u = Unstructured::new(&bytes);
+	while !u.is_empty() {
+		let (uint, hash, address) = <(U256, H256, H160)>::arbitrary(&mut u).unwrap();
+		assert_eq!(rlp::decode::<U256>(&rlp::encode(&uint)).unwrap(), uint);
+		assert_eq!(rlp::decode::<H256>(&rlp::encode(&hash)).unwrap(), hash);
+		assert_eq!(rlp::decode::<H160>(&rlp::encode(&address)).unwrap(), address);
+	}
+}
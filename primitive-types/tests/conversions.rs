@@ -0,0 +1,107 @@
+// Copyright 2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Tests for conversions between the uint types and between the hash types.
+
+use core::convert::TryFrom;
+use primitive_types::{Error, H128, H160, H224, H256, H384, H512, H768, U128, U256, U512};
+
+#[test]
+fn widening() {
+	assert_eq!(U256::from(U128::MAX), U256::from(u128::MAX));
+	assert_eq!(U512::from(U128::MAX), U512::from(u128::MAX));
+	assert_eq!(U512::from(U256::MAX), (U512::one() << 256) - 1);
+	assert_eq!(U512::from(&U256::MAX), U512::from(U256::MAX));
+	assert_eq!(U256::from(&U128::from(42)), U256::from(42));
+}
+
+#[test]
+fn narrowing_at_the_boundary() {
+	let u256_max = U512::from(U256::MAX);
+	assert_eq!(U256::try_from(u256_max), Ok(U256::MAX));
+	assert_eq!(U256::try_from(u256_max + 1), Err(Error::Overflow));
+	assert_eq!(U256::try_from(&u256_max), Ok(U256::MAX));
+	assert_eq!(U256::try_from(U512::MAX), Err(Error::Overflow));
+
+	let u128_max = U256::from(u128::MAX);
+	assert_eq!(U128::try_from(u128_max), Ok(U128::MAX));
+	assert_eq!(U128::try_from(u128_max + 1), Err(Error::Overflow));
+	assert_eq!(U128::try_from(U512::from(u128::MAX)), Ok(U128::MAX));
+	assert_eq!(U128::try_from(U512::from(u128::MAX) + 1), Err(Error::Overflow));
+	assert_eq!(U128::try_from(&(U512::one() << 511)), Err(Error::Overflow));
+	assert_eq!(U128::try_from(U512::zero()), Ok(U128::zero()));
+}
+
+#[test]
+fn truncating() {
+	assert_eq!(U512::MAX.low_u256(), U256::MAX);
+	assert_eq!(((U512::one() << 256) + 5).low_u256(), U256::from(5));
+	assert_eq!(U256::MAX.low_u128(), u128::MAX);
+	assert_eq!(U512::MAX.low_u128(), u128::MAX);
+}
+
+#[test]
+fn error_display() {
+	assert_eq!(Error::Overflow.to_string(), "integer overflow: the value does not fit in the target type");
+}
+
+#[test]
+fn hash_split_and_join() {
+	let signature = H512::from_parts(H256::repeat_byte(0x11), H256::repeat_byte(0x22));
+	let (r, s) = signature.split();
+	assert_eq!(r.as_bytes(), [0x11; 32]);
+	assert_eq!(s.as_bytes(), [0x22; 32]);
+	assert_eq!(r.concat(s), signature);
+
+	let key = H256::from_low_u64_be(0x0102);
+	assert_eq!(key.split(), (H128::zero(), H128::from_low_u64_be(0x0102)));
+	assert_eq!(H768::from_parts(H384::zero(), H384::repeat_byte(1)).split().1, H384::repeat_byte(1));
+}
+
+#[test]
+fn h224_conversions() {
+	// SHA-224 digests are the first 28 bytes of the SHA-256 computation.
+	let digest = H256::repeat_byte(0xab);
+	assert_eq!(digest.to_h224_lossy_high(), H224::repeat_byte(0xab));
+	assert_eq!(H224::repeat_byte(1).to_h256_left_padded(), H256::from(H224::repeat_byte(1)));
+	assert_eq!(H224::from(H160::repeat_byte(1)).as_bytes()[8..], [1; 20]);
+	assert_eq!(H256::from_low_u64_be(7).try_into_h224(), Some(H224::from_low_u64_be(7)));
+}
+
+#[test]
+fn hash_limbs() {
+	let hash = H256::from_low_u64_be(1);
+	assert_eq!(hash.to_u64_limbs(), [1, 0, 0, 0]);
+	assert_eq!(H256::from_u64_limbs([0, 0, 0, 0x0102_0304_0506_0708]).as_bytes()[..8], [1, 2, 3, 4, 5, 6, 7, 8]);
+
+	const LIMBS: [u64; 8] = H512::repeat_byte(0xff).to_u64_limbs();
+	assert_eq!(LIMBS, U512::MAX.0);
+}
+
+#[test]
+#[cfg(feature = "rand")]
+fn hash_limbs_match_big_endian_bytes() {
+	for seed in 0..100 {
+		let hash = H256::random_seeded(seed);
+		let uint = U256::from_big_endian(hash.as_bytes());
+		assert_eq!(hash.to_u64_limbs(), uint.0);
+		assert_eq!(H256::from_u64_limbs(uint.0), hash);
+		assert_eq!(H256::from_u64_limbs(uint.0).as_bytes(), &uint.to_big_endian()[..]);
+
+		let hash = H512::random_seeded(seed);
+		let uint = U512::from_big_endian(hash.as_bytes());
+		assert_eq!(hash.to_u64_limbs(), uint.0);
+		assert_eq!(H512::from_u64_limbs(uint.0), hash);
+	}
+
+	let mut hashes: Vec<H256> = (0..100).map(H256::random_seeded).collect();
+	let mut by_limbs = hashes.clone();
+	hashes.sort();
+	by_limbs.sort_by_key(|hash| U256(hash.to_u64_limbs()));
+	assert_eq!(hashes, by_limbs);
+}
@@ -0,0 +1,46 @@
+// Copyright 2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use primitive_types::{h128, h160, h224, h256, h384, h512, h768, H128, H160, H256, H512};
+
+const ZERO: H256 = h256!("0x0000000000000000000000000000000000000000000000000000000000000000");
+const ADDRESS: H160 = h160!("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed");
+static KEY: H128 = h128!("000102030405060708090a0b0c0d0e0f");
+
+const fn low_byte(hash: H256) -> u8 {
+	hash.to_fixed_bytes()[31]
+}
+
+const LOW_BYTE: u8 = low_byte(h256!("0x00000000000000000000000000000000000000000000000000000000000000ff"));
+
+#[test]
+fn const_contexts() {
+	assert!(ZERO.is_zero());
+	assert_eq!(ADDRESS.as_bytes()[..4], [0x5a, 0xae, 0xb6, 0x05]);
+	assert_eq!(ADDRESS.as_bytes()[16..], [0xef, 0x1b, 0xea, 0xed]);
+	assert_eq!(KEY.as_bytes(), &core::array::from_fn::<u8, 16, _>(|i| i as u8));
+	assert_eq!(LOW_BYTE, 0xff);
+}
+
+#[test]
+fn all_widths() {
+	assert_eq!(h224!("0x00000000000000000000000000000000000000000000000000000001").to_low_u64_be(), 1);
+	assert_eq!(
+		h384!("0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff")
+			.count_ones(),
+		384
+	);
+	assert_eq!(h512!("0XABABABABABABABABABABABABABABABABABABABABABABABABABABABABABABABABABABABABABABABABABABABABABABABABABABABABABABABABABABABABABABABAB"), H512::repeat_byte(0xab));
+	assert!(h768!("000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000").is_zero());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn invalid_literals_fail_to_compile() {
+	trybuild::TestCases::new().compile_fail("tests/ui/fail/*.rs");
+}
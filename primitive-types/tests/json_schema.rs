@@ -0,0 +1,80 @@
+// Copyright 2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Snapshot tests of the `JsonSchema` generated for the uint and hash types.
+
+use primitive_types::{H128, H160, H224, H256, H384, H512, H768, U1024, U128, U2048, U256, U512};
+use schemars::{gen::SchemaGenerator, schema_for, JsonSchema};
+use serde_json::json;
+
+fn schema<T: JsonSchema>() -> serde_json::Value {
+	serde_json::to_value(T::json_schema(&mut SchemaGenerator::default())).unwrap()
+}
+
+#[test]
+fn h256() {
+	assert_eq!(H256::schema_name(), "HexEncoded32Bytes");
+	assert_eq!(
+		schema::<H256>(),
+		json!({
+			"description": "Hex encoded 32 bytes",
+			"type": "string",
+			"pattern": "^0x[a-fA-F0-9]{64}$"
+		})
+	);
+}
+
+#[test]
+fn h160() {
+	assert_eq!(H160::schema_name(), "HexEncoded20Bytes");
+	assert_eq!(
+		schema::<H160>(),
+		json!({
+			"description": "Hex encoded 20 bytes",
+			"type": "string",
+			"pattern": "^0x[a-fA-F0-9]{40}$"
+		})
+	);
+}
+
+#[test]
+fn u256() {
+	assert_eq!(U256::schema_name(), "U256String");
+	assert_eq!(
+		schema::<U256>(),
+		json!({
+			"description": "256-bit Unsigned Integer, hex encoded",
+			"type": "string",
+			"pattern": "^(0x)?[a-fA-F0-9]{1,64}$"
+		})
+	);
+}
+
+#[test]
+fn names_and_patterns() {
+	fn pattern<T: JsonSchema>() -> serde_json::Value {
+		schema::<T>()["pattern"].clone()
+	}
+
+	assert_eq!((H128::schema_name(), pattern::<H128>()), ("HexEncoded16Bytes".into(), json!("^0x[a-fA-F0-9]{32}$")));
+	assert_eq!((H224::schema_name(), pattern::<H224>()), ("HexEncoded28Bytes".into(), json!("^0x[a-fA-F0-9]{56}$")));
+	assert_eq!((H384::schema_name(), pattern::<H384>()), ("HexEncoded48Bytes".into(), json!("^0x[a-fA-F0-9]{96}$")));
+	assert_eq!((H512::schema_name(), pattern::<H512>()), ("HexEncoded64Bytes".into(), json!("^0x[a-fA-F0-9]{128}$")));
+	assert_eq!((H768::schema_name(), pattern::<H768>()), ("HexEncoded96Bytes".into(), json!("^0x[a-fA-F0-9]{192}$")));
+	assert_eq!((U128::schema_name(), pattern::<U128>()), ("U128String".into(), json!("^(0x)?[a-fA-F0-9]{1,32}$")));
+	assert_eq!((U512::schema_name(), pattern::<U512>()), ("U512String".into(), json!("^(0x)?[a-fA-F0-9]{1,128}$")));
+	assert_eq!((U1024::schema_name(), pattern::<U1024>()), ("U1024String".into(), json!("^(0x)?[a-fA-F0-9]{1,256}$")));
+	assert_eq!((U2048::schema_name(), pattern::<U2048>()), ("U2048String".into(), json!("^(0x)?[a-fA-F0-9]{1,512}$")));
+}
+
+#[test]
+fn referenced_by_name() {
+	let schema = serde_json::to_value(schema_for!(Vec<H256>)).unwrap();
+	assert_eq!(schema["items"], json!({ "$ref": "#/definitions/HexEncoded32Bytes" }));
+	assert_eq!(schema["definitions"]["HexEncoded32Bytes"]["pattern"], json!("^0x[a-fA-F0-9]{64}$"));
+}
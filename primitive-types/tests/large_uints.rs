@@ -0,0 +1,90 @@
+// Copyright 2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Tests for the `U1024` and `U2048` types.
+
+use core::convert::TryFrom;
+use primitive_types::{Error, U1024, U2048, U256, U512};
+
+#[test]
+fn full_mul() {
+	assert_eq!(U512::MAX.full_mul(U512::MAX), U1024::MAX - (U1024::from(U512::MAX) << 1));
+	assert_eq!(U512::MAX.full_mul(U512::from(2)), U1024::from(U512::MAX) << 1);
+	assert_eq!(U512::zero().full_mul(U512::MAX), U1024::zero());
+	assert_eq!(U1024::MAX.full_mul(U1024::MAX), U2048::MAX - (U2048::from(U1024::MAX) << 1));
+	assert_eq!((U1024::one() << 1023).full_mul(U1024::from(2)), U2048::one() << 1024);
+}
+
+#[test]
+fn conversions() {
+	assert_eq!(U2048::from(U1024::MAX), (U2048::one() << 1024) - 1);
+	assert_eq!(U1024::from(U256::MAX), U1024::from(U512::from(U256::MAX)));
+	assert_eq!(U1024::try_from(U2048::from(U1024::MAX)), Ok(U1024::MAX));
+	assert_eq!(U1024::try_from(U2048::one() << 1024), Err(Error::Overflow));
+	assert_eq!(U512::try_from(U2048::from(U512::MAX)), Ok(U512::MAX));
+	assert_eq!(U512::try_from(U2048::from(U512::MAX) + 1), Err(Error::Overflow));
+	assert_eq!(U256::try_from(&U1024::from(U256::MAX)), Ok(U256::MAX));
+	assert_eq!(U256::try_from(U1024::from(U256::MAX) + 1), Err(Error::Overflow));
+
+	assert_eq!(U2048::MAX.low_u1024(), U1024::MAX);
+	assert_eq!(((U2048::one() << 1024) + 7).low_u1024(), U1024::from(7));
+	assert_eq!(U2048::MAX.low_u512(), U512::MAX);
+	assert_eq!(U1024::MAX.low_u256(), U256::MAX);
+}
+
+#[test]
+fn display() {
+	assert_eq!(
+		U1024::MAX.to_string(),
+		"179769313486231590772930519078902473361797697894230657273430081157732675805500963132708477322407536021120113879871393357658789768814416622492847430639474124377767893424865485276302219601246094119453082952085005768838150682342462881473913110540827237163350510684586298239947245938479716304835356329624224137215"
+	);
+	assert_eq!(U2048::from_dec_str(&U2048::MAX.to_string()), Ok(U2048::MAX));
+	assert_eq!(format!("{:x}", U2048::one() << 2047), format!("8{}", "0".repeat(511)));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde() {
+	for value in [U1024::zero(), U1024::from(0x2a), U1024::MAX, U1024::one() << 1000] {
+		let json = serde_json::to_string(&value).unwrap();
+		assert_eq!(json, format!("\"{:#x}\"", value));
+		assert_eq!(serde_json::from_str::<U1024>(&json).unwrap(), value);
+	}
+	for value in [U2048::zero(), U2048::MAX, U2048::one() << 2047] {
+		let json = serde_json::to_string(&value).unwrap();
+		assert_eq!(json, format!("\"{:#x}\"", value));
+		assert_eq!(serde_json::from_str::<U2048>(&json).unwrap(), value);
+	}
+	// One hex digit too many.
+	assert!(serde_json::from_str::<U1024>(&format!("\"0x1{}\"", "0".repeat(256))).is_err());
+}
+
+#[cfg(feature = "codec")]
+#[test]
+fn scale_codec() {
+	use impl_codec::codec::{Decode, Encode, MaxEncodedLen};
+
+	assert_eq!(U1024::max_encoded_len(), 128);
+	assert_eq!(U2048::max_encoded_len(), 256);
+	assert_eq!(U1024::MAX.encode(), vec![0xff; 128]);
+	let value = (U2048::one() << 2047) + 1;
+	let encoded = value.encode();
+	assert_eq!(encoded.len(), U2048::max_encoded_len());
+	assert_eq!(U2048::decode(&mut &encoded[..]).unwrap(), value);
+}
+
+#[cfg(feature = "rlp")]
+#[test]
+fn rlp() {
+	for value in [U1024::zero(), U1024::from(0x2a), U1024::MAX] {
+		assert_eq!(impl_rlp::rlp::decode::<U1024>(&impl_rlp::rlp::encode(&value)).unwrap(), value);
+	}
+	let value = U2048::one() << 2047;
+	assert_eq!(impl_rlp::rlp::encode(&value).len(), 3 + 256);
+	assert_eq!(impl_rlp::rlp::decode::<U2048>(&impl_rlp::rlp::encode(&value)).unwrap(), value);
+}
@@ -35,3 +35,67 @@ fn u256_checked_traits_supported() {
 	assert_eq!(<U256 as CheckedMul>::checked_mul(MAX, MAX), None);
 	assert_eq!(<U256 as CheckedMul>::checked_mul(MAX, ZERO), Some(*ZERO));
 }
+
+#[test]
+fn u256_to_primitive() {
+	use num_traits::ToPrimitive;
+
+	assert_eq!(U256::from(u64::MAX).to_u64(), Some(u64::MAX));
+	assert_eq!((U256::from(u64::MAX) + 1).to_u64(), None);
+	assert_eq!(U256::from(i64::MAX).to_i64(), Some(i64::MAX));
+	assert_eq!((U256::from(i64::MAX) + 1).to_i64(), None);
+	assert_eq!(U256::from(u128::MAX).to_u128(), Some(u128::MAX));
+	assert_eq!((U256::from(u128::MAX) + 1).to_u128(), None);
+	assert_eq!(U256::from(i128::MAX as u128).to_i128(), Some(i128::MAX));
+	assert_eq!((U256::from(i128::MAX as u128) + 1).to_i128(), None);
+	assert_eq!(U256::from(300).to_u8(), None);
+	assert_eq!(U256::from(255).to_u8(), Some(255));
+	assert_eq!(U256::MAX.to_f64(), Some(2f64.powi(256)));
+}
+
+#[test]
+fn u256_from_primitive() {
+	use num_traits::FromPrimitive;
+
+	assert_eq!(U256::from_i64(-1), None);
+	assert_eq!(U256::from_i64(7), Some(U256::from(7)));
+	// The inherent `const fn from_u128` takes precedence over the trait method.
+	assert_eq!(<U256 as FromPrimitive>::from_u128(u128::MAX), Some(U256::from(u128::MAX)));
+	assert_eq!(U256::from_i128(i128::MIN), None);
+	assert_eq!(U256::from_f64(-0.5), Some(U256::zero()));
+	assert_eq!(U256::from_f64(-1.0), None);
+	assert_eq!(U256::from_f64(1.9), Some(U256::one()));
+	assert_eq!(U256::from_f64(f64::NAN), None);
+	assert_eq!(U256::from_f64(f64::INFINITY), None);
+	assert_eq!(U256::from_f64(2f64.powi(255)), Some(U256::one() << 255));
+	assert_eq!(U256::from_f64(2f64.powi(256)), None);
+	assert_eq!(num_traits::cast(42u8), Some(U256::from(42)));
+	assert_eq!(num_traits::cast::<_, U256>(-1i32), None);
+}
+
+#[test]
+fn wide_uint_num_cast() {
+	use primitive_types::{U128, U512};
+
+	assert_eq!(num_traits::cast::<_, U512>(U256::MAX), Some((U512::one() << 256) - 1));
+	assert_eq!(num_traits::cast::<_, U256>(U256::MAX), Some(U256::MAX));
+	assert_eq!(num_traits::cast::<_, U256>(U512::from(U256::MAX)), Some(U256::MAX));
+	assert_eq!(num_traits::cast::<_, U256>(U512::from(U256::MAX) + 1), None);
+	assert_eq!(num_traits::cast::<_, U128>(U256::from(u128::MAX)), Some(U128::MAX));
+	assert_eq!(num_traits::cast::<_, U128>(U256::from(u128::MAX) + 1), None);
+}
+
+#[test]
+fn single_word_primitive_conversions() {
+	use num_traits::{FromPrimitive, ToPrimitive};
+
+	uint::construct_uint! {
+		pub struct U64(1);
+	}
+	impl_num_traits::impl_uint_num_traits!(U64, 1);
+
+	assert_eq!(<U64 as FromPrimitive>::from_u128(u64::MAX as u128), Some(U64::MAX));
+	assert_eq!(<U64 as FromPrimitive>::from_u128(u64::MAX as u128 + 1), None);
+	assert_eq!(U64::MAX.to_u128(), Some(u64::MAX as u128));
+	assert_eq!(U64::from_f64(2f64.powi(64)), None);
+}
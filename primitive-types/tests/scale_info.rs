@@ -6,9 +6,10 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-//! Tests for scale-info feature of primitive-types.
+//! Tests for scale-info feature of primitive-types, and the matching codec implementations.
 
-use primitive_types::{H256, U256};
+use impl_codec::codec::{Encode, MaxEncodedLen};
+use primitive_types::{H224, H256, H384, H768, U256};
 use scale_info::{build::Fields, Path, Type, TypeInfo};
 
 #[test]
@@ -28,3 +29,21 @@ fn h256_scale_info() {
 
 	assert_eq!(H256::type_info(), r#type.into());
 }
+
+#[test]
+fn h224_scale_info() {
+	let r#type = Type::builder()
+		.path(Path::new("H224", "primitive_types"))
+		.composite(Fields::unnamed().field(|f| f.ty::<[u8; 28]>().type_name("[u8; 28]")));
+
+	assert_eq!(H224::type_info(), r#type.into());
+}
+
+#[test]
+fn hash_max_encoded_len() {
+	assert_eq!(H224::max_encoded_len(), 28);
+	assert_eq!(H384::max_encoded_len(), 48);
+	assert_eq!(H768::max_encoded_len(), 96);
+	assert_eq!(H224::repeat_byte(0xab).encode(), [0xab; 28]);
+	assert_eq!(H768::repeat_byte(0xab).encode().len(), H768::max_encoded_len());
+}
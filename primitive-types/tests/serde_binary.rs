@@ -0,0 +1,132 @@
+// Copyright 2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Tests for serde with binary formats, which can use raw bytes instead of hex strings.
+
+use primitive_types::{H160, H256, U256};
+#[cfg(feature = "serde-compact-binary")]
+use serde_test::{assert_de_tokens, assert_de_tokens_error, assert_tokens, Configure, Token};
+
+const HASH: &str = "0x7f864e18e3dd8b58386310d2fe0919eef27c6e558564b7f67f22d99d20f587b4";
+const HASH_BYTES: [u8; 32] = [
+	0x7f, 0x86, 0x4e, 0x18, 0xe3, 0xdd, 0x8b, 0x58, 0x38, 0x63, 0x10, 0xd2, 0xfe, 0x09, 0x19, 0xee, 0xf2, 0x7c, 0x6e,
+	0x55, 0x85, 0x64, 0xb7, 0xf6, 0x7f, 0x22, 0xd9, 0x9d, 0x20, 0xf5, 0x87, 0xb4,
+];
+
+fn hash() -> H256 {
+	HASH.parse().unwrap()
+}
+
+#[test]
+fn human_readable_is_hex() {
+	assert_eq!(serde_json::to_string(&U256::from(0x2a)).unwrap(), r#""0x2a""#);
+	assert_eq!(serde_json::from_str::<U256>(r#""0x2a""#).unwrap(), U256::from(0x2a));
+	let hash = H160::repeat_byte(0xab);
+	assert_eq!(serde_json::to_string(&hash).unwrap(), format!("\"0x{}\"", "ab".repeat(20)));
+	assert_eq!(serde_json::from_str::<H160>(&serde_json::to_string(&hash).unwrap()).unwrap(), hash);
+	assert_eq!(self::hash().as_bytes(), HASH_BYTES);
+	assert_eq!(serde_json::to_string(&self::hash()).unwrap(), format!("\"{}\"", HASH));
+}
+
+#[cfg(feature = "serde-compact-binary")]
+#[test]
+fn binary_is_raw_bytes() {
+	let mut expected = [0u8; 32];
+	expected[31] = 0x2a;
+	assert_eq!(bincode::serialize(&U256::from(0x2a)).unwrap(), expected);
+	assert_eq!(bincode::serialize(&(U256::one() << 255)).unwrap()[0], 0x80);
+	assert_eq!(bincode::serialize(&U256::MAX).unwrap(), [0xff; 32]);
+	assert_eq!(bincode::serialize(&H160::repeat_byte(0xab)).unwrap(), [0xab; 20]);
+	let hash = H256::from_low_u64_be(0x0102);
+	assert_eq!(bincode::serialize(&hash).unwrap(), hash.as_bytes());
+
+	for value in [U256::zero(), U256::from(0x2a), U256::MAX, U256::one() << 200] {
+		assert_eq!(bincode::deserialize::<U256>(&bincode::serialize(&value).unwrap()).unwrap(), value);
+	}
+	assert_eq!(bincode::deserialize::<H256>(&bincode::serialize(&hash).unwrap()).unwrap(), hash);
+
+	// All the bytes are required.
+	assert!(bincode::deserialize::<U256>(&[0u8; 31]).is_err());
+	assert!(bincode::deserialize::<H160>(&[0u8; 19]).is_err());
+}
+
+#[cfg(feature = "serde-compact-binary")]
+#[test]
+fn hash_golden_bytes() {
+	assert_eq!(bincode::serialize(&hash()).unwrap(), HASH_BYTES);
+	assert_eq!(bincode::deserialize::<H256>(&HASH_BYTES).unwrap(), hash());
+
+	let address = H160::from_low_u64_be(0x0102_0304);
+	let mut expected = [0u8; 20];
+	expected[16..].copy_from_slice(&[1, 2, 3, 4]);
+	assert_eq!(bincode::serialize(&address).unwrap(), expected);
+	// Half the size of the hex string, which also has an 8 byte length prefix.
+	assert_eq!(bincode::serialize(&hash()).unwrap().len(), 32);
+}
+
+#[cfg(feature = "serde-compact-binary")]
+fn tuple_tokens(bytes: &[u8]) -> Vec<Token> {
+	let mut tokens = vec![Token::Tuple { len: bytes.len() }];
+	tokens.extend(bytes.iter().map(|&byte| Token::U8(byte)));
+	tokens.push(Token::TupleEnd);
+	tokens
+}
+
+#[cfg(feature = "serde-compact-binary")]
+#[test]
+fn compact_hash_tokens() {
+	assert_tokens(&hash().compact(), &tuple_tokens(&HASH_BYTES));
+	assert_tokens(&hash().readable(), &[Token::Str(HASH)]);
+}
+
+#[cfg(feature = "serde-compact-binary")]
+#[test]
+fn compact_hash_from_strings_and_bytes() {
+	assert_de_tokens(&hash().compact(), &[Token::Str(HASH)]);
+	assert_de_tokens(&hash().compact(), &[Token::Str(&HASH[2..])]);
+	assert_de_tokens(&hash().compact(), &[Token::Bytes(&HASH_BYTES)]);
+	assert_de_tokens(&hash().compact(), &[Token::ByteBuf(&HASH_BYTES)]);
+
+	let mut tokens = vec![Token::Seq { len: Some(32) }];
+	tokens.extend(HASH_BYTES.iter().map(|&byte| Token::U8(byte)));
+	tokens.push(Token::SeqEnd);
+	assert_de_tokens(&hash().compact(), &tokens);
+}
+
+#[cfg(feature = "serde-compact-binary")]
+#[test]
+fn compact_hash_requires_the_exact_length() {
+	assert_de_tokens_error::<serde_test::Compact<H256>>(
+		&[Token::Bytes(&[0u8; 31])],
+		"invalid length 31, expected a (both 0x-prefixed or not) hex string or byte array containing 32 bytes",
+	);
+	assert_de_tokens_error::<serde_test::Compact<H256>>(
+		&[Token::Str(&HASH[..65])],
+		"invalid length 63, expected a (both 0x-prefixed or not) hex string or byte array containing 32 bytes",
+	);
+
+	let mut short = vec![Token::Seq { len: Some(31) }];
+	short.extend([Token::U8(0); 31]);
+	short.push(Token::SeqEnd);
+	assert_de_tokens_error::<serde_test::Compact<H256>>(&short, "invalid length 31, expected 32 bytes");
+
+	let mut long = vec![Token::Seq { len: Some(33) }];
+	long.extend([Token::U8(0); 33]);
+	long.push(Token::SeqEnd);
+	assert_de_tokens_error::<serde_test::Compact<H256>>(&long, "invalid length 33, expected 32 bytes");
+}
+
+#[cfg(not(feature = "serde-compact-binary"))]
+#[test]
+fn binary_is_hex_without_the_feature() {
+	let encoded = bincode::serialize(&U256::from(0x2a)).unwrap();
+	assert_eq!(encoded, [&[4, 0, 0, 0, 0, 0, 0, 0][..], b"0x2a"].concat());
+	assert_eq!(bincode::deserialize::<U256>(&encoded).unwrap(), U256::from(0x2a));
+	let hash = H160::repeat_byte(0xab);
+	assert_eq!(bincode::deserialize::<H160>(&bincode::serialize(&hash).unwrap()).unwrap(), hash);
+}
@@ -0,0 +1,88 @@
+// Copyright 2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Tests for the decimal string serde representation of uint types.
+
+use primitive_types::{U128, U256, U512};
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(crate = "impl_serde::serde")]
+struct Amounts {
+	#[serde(with = "primitive_types::serde_decimal")]
+	small: U128,
+	#[serde(with = "primitive_types::serde_decimal")]
+	value: U256,
+	#[serde(with = "primitive_types::serde_decimal")]
+	large: U512,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(crate = "impl_serde::serde")]
+struct Value(#[serde(with = "primitive_types::serde_decimal")] U256);
+
+fn parse(json: &str) -> Result<U256, serde_json::Error> {
+	serde_json::from_str::<Value>(json).map(|v| v.0)
+}
+
+#[test]
+fn serializes_as_decimal_strings() {
+	let amounts = Amounts { small: U128::zero(), value: U256::exp10(18), large: U512::MAX };
+	let json = serde_json::to_string(&amounts).unwrap();
+	assert_eq!(
+		json,
+		concat!(
+			r#"{"small":"0","value":"1000000000000000000","large":""#,
+			"13407807929942597099574024998205846127479365820592393377723561443721764030073546976801874298166903427690031858186486050853753882811946569946433649006084095",
+			r#""}"#,
+		)
+	);
+	assert_eq!(serde_json::from_str::<Amounts>(&json).unwrap(), amounts);
+}
+
+#[test]
+fn accepted_inputs() {
+	assert_eq!(parse(r#""0""#).unwrap(), U256::zero());
+	assert_eq!(parse(r#""1000""#).unwrap(), U256::from(1000));
+	assert_eq!(parse(r#""0x3e8""#).unwrap(), U256::from(1000));
+	assert_eq!(parse(r#""0x0""#).unwrap(), U256::zero());
+	assert_eq!(parse("1000").unwrap(), U256::from(1000));
+	assert_eq!(parse("18446744073709551615").unwrap(), U256::from(u64::MAX));
+	assert_eq!(parse("1000.0").unwrap(), U256::from(1000));
+	assert_eq!(parse("1e3").unwrap(), U256::from(1000));
+	assert_eq!(parse("9007199254740992.0").unwrap(), U256::from(1u64 << 53));
+	assert_eq!(
+		parse(r#""115792089237316195423570985008687907853269984665640564039457584007913129639935""#).unwrap(),
+		U256::MAX
+	);
+}
+
+#[test]
+fn rejected_inputs() {
+	for json in [
+		r#""""#,
+		r#""-1""#,
+		r#""+1""#,
+		r#""1.5""#,
+		r#""1e3""#,
+		r#""0x""#,
+		r#""0xg""#,
+		r#""0x0x1""#,
+		r#""115792089237316195423570985008687907853269984665640564039457584007913129639936""#,
+		"-1",
+		"1.5",
+		"-1.0",
+		"9007199254740994.0",
+		"1e30",
+		"null",
+		"true",
+		"[]",
+	] {
+		assert!(parse(json).is_err(), "{} should be rejected", json);
+	}
+}
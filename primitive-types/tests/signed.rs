@@ -0,0 +1,205 @@
+// Copyright 2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Tests for the signed `I256` type.
+
+use core::convert::TryFrom;
+use primitive_types::{Error, I256, U256};
+use uint::FromDecStrErr;
+
+fn i(value: i128) -> I256 {
+	I256::from(value)
+}
+
+#[test]
+fn constants() {
+	assert_eq!(I256::ZERO, i(0));
+	assert_eq!(I256::ONE, i(1));
+	assert_eq!(I256::MINUS_ONE, i(-1));
+	assert_eq!(I256::MINUS_ONE.into_raw(), U256::MAX);
+	assert_eq!(I256::MAX.into_raw(), U256::MAX >> 1);
+	assert_eq!(I256::MIN.into_raw(), U256::one() << 255);
+	assert_eq!(I256::MAX.checked_add(I256::ONE), None);
+	assert_eq!(I256::MIN.checked_sub(I256::ONE), None);
+}
+
+#[test]
+fn sign() {
+	assert!(i(-5).is_negative());
+	assert!(!i(-5).is_positive());
+	assert!(i(5).is_positive());
+	assert!(!I256::ZERO.is_negative() && !I256::ZERO.is_positive() && I256::ZERO.is_zero());
+	assert_eq!(i(-5).signum(), I256::MINUS_ONE);
+	assert_eq!(I256::ZERO.signum(), I256::ZERO);
+	assert_eq!(i(5).signum(), I256::ONE);
+	assert_eq!(I256::MIN.signum(), I256::MINUS_ONE);
+}
+
+#[test]
+fn abs_and_neg() {
+	assert_eq!(i(-7).abs(), i(7));
+	assert_eq!(i(7).abs(), i(7));
+	assert_eq!(I256::MIN.checked_abs(), None);
+	assert_eq!(I256::MIN.unsigned_abs(), U256::one() << 255);
+	assert_eq!(-i(3), i(-3));
+	assert_eq!(I256::MAX.checked_neg(), Some(I256::MIN + I256::ONE));
+	assert_eq!(I256::MIN.checked_neg(), None);
+	assert_eq!(I256::MIN.overflowing_neg(), (I256::MIN, true));
+}
+
+#[test]
+#[should_panic(expected = "arithmetic operation overflow")]
+fn abs_of_min_panics() {
+	let _ = I256::MIN.abs();
+}
+
+#[test]
+fn add_and_sub() {
+	assert_eq!(i(-3) + i(5), i(2));
+	assert_eq!(i(-3) - i(5), i(-8));
+	assert_eq!(i(3) - i(-5), i(8));
+	assert_eq!(I256::MAX.overflowing_add(I256::ONE), (I256::MIN, true));
+	assert_eq!(I256::MIN.overflowing_add(I256::MINUS_ONE), (I256::MAX, true));
+	assert_eq!(I256::MIN.overflowing_sub(I256::ONE), (I256::MAX, true));
+	assert_eq!(I256::MAX.overflowing_sub(I256::MINUS_ONE), (I256::MIN, true));
+	assert_eq!(I256::MAX.checked_add(I256::MIN), Some(I256::MINUS_ONE));
+	assert_eq!(I256::MIN.checked_sub(I256::MIN), Some(I256::ZERO));
+	assert_eq!(I256::ZERO.checked_sub(I256::MIN), None);
+}
+
+#[test]
+fn mul() {
+	assert_eq!(i(-3) * i(5), i(-15));
+	assert_eq!(i(-3) * i(-5), i(15));
+	assert_eq!(i(3) * I256::ZERO, I256::ZERO);
+	let half_min = I256::from_raw(U256::one() << 254);
+	assert_eq!(i(-2).checked_mul(half_min), Some(I256::MIN));
+	assert_eq!(i(2).checked_mul(half_min), None);
+	assert_eq!(I256::MIN.checked_mul(I256::ONE), Some(I256::MIN));
+	assert_eq!(I256::MIN.checked_mul(I256::MINUS_ONE), None);
+	assert_eq!(I256::MAX.checked_mul(I256::MINUS_ONE), Some(I256::MIN + I256::ONE));
+}
+
+#[test]
+fn div_and_rem_truncate() {
+	for (a, b) in [(7, 2), (-7, 2), (7, -2), (-7, -2), (6, 3), (-1, 5)] {
+		assert_eq!(i(a) / i(b), i(a / b), "{} / {}", a, b);
+		assert_eq!(i(a) % i(b), i(a % b), "{} % {}", a, b);
+	}
+	assert_eq!(I256::MIN / I256::ONE, I256::MIN);
+	assert_eq!(I256::MIN / i(2), I256::from_raw(U256::MAX << 254));
+	assert_eq!(I256::MIN % i(2), I256::ZERO);
+	assert_eq!(I256::MIN % I256::MAX, I256::MINUS_ONE);
+}
+
+#[test]
+fn min_divided_by_minus_one() {
+	assert_eq!(I256::MIN.checked_div(I256::MINUS_ONE), None);
+	assert_eq!(I256::MIN.checked_rem(I256::MINUS_ONE), None);
+	assert_eq!((I256::MIN + I256::ONE).checked_div(I256::MINUS_ONE), Some(I256::MAX));
+	assert_eq!((I256::MIN + I256::ONE).checked_rem(I256::MINUS_ONE), Some(I256::ZERO));
+}
+
+#[test]
+fn division_by_zero() {
+	assert_eq!(i(1).checked_div(I256::ZERO), None);
+	assert_eq!(i(1).checked_rem(I256::ZERO), None);
+}
+
+#[test]
+#[should_panic(expected = "division by zero or overflow")]
+fn min_divided_by_minus_one_panics() {
+	let _ = I256::MIN / I256::MINUS_ONE;
+}
+
+#[test]
+fn signed_ordering() {
+	let mut values = vec![I256::MAX, i(1), I256::MIN, i(-1), I256::ZERO, i(-2), i(2)];
+	values.sort();
+	assert_eq!(values, vec![I256::MIN, i(-2), i(-1), I256::ZERO, i(1), i(2), I256::MAX]);
+	assert!(I256::MINUS_ONE < I256::ZERO);
+	assert!(I256::MINUS_ONE.into_raw() > I256::ZERO.into_raw());
+}
+
+#[test]
+fn from_primitives() {
+	assert_eq!(I256::from(-1i8), I256::MINUS_ONE);
+	assert_eq!(I256::from(i64::MIN), i(i64::MIN as i128));
+	assert_eq!(I256::from(i128::MIN).into_raw(), !(U256::one() << 127) + 1);
+	assert_eq!(I256::from(u128::MAX).into_raw(), U256::from(u128::MAX));
+	assert_eq!(I256::from(255u8), i(255));
+}
+
+#[test]
+fn conversions_with_u256() {
+	assert_eq!(I256::try_from(U256::from(5)), Ok(i(5)));
+	assert_eq!(I256::try_from(U256::MAX >> 1), Ok(I256::MAX));
+	assert_eq!(I256::try_from(U256::one() << 255), Err(Error::Overflow));
+	assert_eq!(U256::try_from(i(5)), Ok(U256::from(5)));
+	assert_eq!(U256::try_from(I256::MINUS_ONE), Err(Error::Overflow));
+
+	assert_eq!(I256::from_raw(U256::MAX), I256::MINUS_ONE);
+	assert_eq!(I256::from_raw(U256::one() << 255), I256::MIN);
+	assert_eq!(i(-42).into_raw(), U256::MAX - 41);
+}
+
+#[test]
+fn display() {
+	assert_eq!(I256::ZERO.to_string(), "0");
+	assert_eq!(i(-42).to_string(), "-42");
+	assert_eq!(format!("{:?}", i(42)), "42");
+	assert_eq!(format!("{:+}", i(42)), "+42");
+	assert_eq!(format!("{:>5}", i(-7)), "   -7");
+	assert_eq!(format!("{:05}", i(-7)), "-0007");
+	assert_eq!(I256::MIN.to_string(), "-57896044618658097711785492504343953926634992332820282019728792003956564819968");
+	assert_eq!(I256::MAX.to_string(), "57896044618658097711785492504343953926634992332820282019728792003956564819967");
+}
+
+#[test]
+fn from_str() {
+	assert_eq!("-42".parse::<I256>(), Ok(i(-42)));
+	assert_eq!("+42".parse::<I256>(), Ok(i(42)));
+	assert_eq!("0".parse::<I256>(), Ok(I256::ZERO));
+	assert_eq!("-0".parse::<I256>(), Ok(I256::ZERO));
+	assert_eq!(I256::MIN.to_string().parse::<I256>(), Ok(I256::MIN));
+	assert_eq!(I256::MAX.to_string().parse::<I256>(), Ok(I256::MAX));
+
+	assert_eq!("".parse::<I256>(), Err(FromDecStrErr::InvalidCharacter));
+	assert_eq!("-".parse::<I256>(), Err(FromDecStrErr::InvalidCharacter));
+	assert_eq!("--1".parse::<I256>(), Err(FromDecStrErr::InvalidCharacter));
+	assert_eq!("1a".parse::<I256>(), Err(FromDecStrErr::InvalidCharacter));
+	assert_eq!(
+		"57896044618658097711785492504343953926634992332820282019728792003956564819968".parse::<I256>(),
+		Err(FromDecStrErr::InvalidLength)
+	);
+	assert_eq!(
+		"-57896044618658097711785492504343953926634992332820282019728792003956564819969".parse::<I256>(),
+		Err(FromDecStrErr::InvalidLength)
+	);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde() {
+	assert_eq!(serde_json::to_string(&i(-42)).unwrap(), r#""-42""#);
+	assert_eq!(serde_json::from_str::<I256>(r#""-42""#).unwrap(), i(-42));
+	assert_eq!(serde_json::from_str::<I256>(&serde_json::to_string(&I256::MIN).unwrap()).unwrap(), I256::MIN);
+	assert!(serde_json::from_str::<I256>(r#""0x2a""#).is_err());
+	assert!(serde_json::from_str::<I256>("42").is_err());
+}
+
+#[cfg(feature = "codec")]
+#[test]
+fn scale_codec() {
+	use impl_codec::codec::{Decode, Encode, MaxEncodedLen};
+
+	assert_eq!(I256::MINUS_ONE.encode(), vec![0xff; 32]);
+	assert_eq!(i(-42).encode(), (U256::MAX - 41).encode());
+	assert_eq!(I256::decode(&mut &I256::MIN.encode()[..]).unwrap(), I256::MIN);
+	assert_eq!(I256::max_encoded_len(), 32);
+}
@@ -0,0 +1,5 @@
+use primitive_types::h256;
+
+fn main() {
+	let _ = h256!("0xdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeeg");
+}
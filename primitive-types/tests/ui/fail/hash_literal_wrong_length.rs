@@ -0,0 +1,7 @@
+use primitive_types::{h160, H160};
+
+const ADDRESS: H160 = h160!("0x5aaeb6053f3e94c9b9a09f33669435e7ef1bea");
+
+fn main() {
+	let _ = ADDRESS;
+}
@@ -0,0 +1,75 @@
+// Copyright 2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Bounds of the generated impls for generic types.
+//!
+//! Like serde's derives, the bounds are put on the types of the fields rather than on the type
+//! parameters, so `PhantomData<T>` or `T::Hash` fields don't require anything of `T` itself.
+
+use std::collections::HashSet;
+
+use proc_macro2::{TokenStream, TokenTree};
+use quote::ToTokens;
+
+/// Returns the generics of `ast` with a `ty: bound` predicate for each of the `bounds` whose type
+/// uses a type parameter, or with the predicates of an `#[rlp(bound = "...")]` override instead.
+pub fn with_bounds(
+	ast: &syn::DeriveInput,
+	bound_override: Option<&[syn::WherePredicate]>,
+	bounds: &[(&syn::Type, TokenStream)],
+) -> syn::Generics {
+	let mut generics = ast.generics.clone();
+	let params: HashSet<_> = generics.type_params().map(|param| param.ident.to_string()).collect();
+	let predicates = &mut generics.make_where_clause().predicates;
+	if let Some(bound_override) = bound_override {
+		predicates.extend(bound_override.iter().cloned());
+		return generics
+	}
+
+	let mut seen = HashSet::new();
+	for (ty, bound) in bounds {
+		if !uses_params(ty.to_token_stream(), &params) {
+			continue
+		}
+		let predicate: syn::WherePredicate = syn::parse_quote! { #ty: #bound };
+		if seen.insert(predicate.to_token_stream().to_string()) {
+			predicates.push(predicate);
+		}
+	}
+	generics
+}
+
+/// Returns the type bounded to encode or decode a field of type `ty`, the type of the items for a
+/// `Vec`, which is encoded as a list of them.
+pub fn item_type(ty: &syn::Type) -> &syn::Type {
+	vec_item_type(ty).unwrap_or(ty)
+}
+
+/// Returns `T` if `ty` is `Vec<T>`.
+pub fn vec_item_type(ty: &syn::Type) -> Option<&syn::Type> {
+	let path = if let syn::Type::Path(path) = ty { path } else { return None };
+	let segment = path.path.segments.first()?;
+	if segment.ident != "Vec" {
+		return None
+	}
+	match &segment.arguments {
+		syn::PathArguments::AngleBracketed(angle) => match angle.args.first()? {
+			syn::GenericArgument::Type(ty) => Some(ty),
+			_ => None,
+		},
+		_ => None,
+	}
+}
+
+fn uses_params(tokens: TokenStream, params: &HashSet<String>) -> bool {
+	tokens.into_iter().any(|token| match token {
+		TokenTree::Ident(ident) => params.contains(&ident.to_string()),
+		TokenTree::Group(group) => uses_params(group.stream(), params),
+		_ => false,
+	})
+}
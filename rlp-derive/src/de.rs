@@ -9,6 +9,11 @@
 use proc_macro2::TokenStream;
 use quote::quote;
 
+use crate::{
+	bound::{item_type, vec_item_type, with_bounds},
+	parse::{is_phantom_data, parse_container, parse_enum, struct_field_kinds, FieldKind},
+};
+
 struct ParseQuotes {
 	single: TokenStream,
 	list: TokenStream,
@@ -24,23 +29,45 @@ fn decodable_wrapper_parse_quotes() -> ParseQuotes {
 }
 
 pub fn impl_decodable(ast: &syn::DeriveInput) -> TokenStream {
-	let body = if let syn::Data::Struct(s) = &ast.data {
-		s
-	} else {
-		panic!("#[derive(RlpDecodable)] is only defined for structs.");
+	let body = match &ast.data {
+		syn::Data::Struct(s) => s,
+		syn::Data::Enum(e) => return impl_decodable_enum(ast, e),
+		syn::Data::Union(_) => panic!("#[derive(RlpDecodable)] is only defined for structs and enums."),
 	};
 
-	let mut default_attribute_encountered = false;
+	let container = parse_container(ast);
+	assert!(!container.untagged, "#[rlp(untagged)] is only supported on enums");
+	let kinds = struct_field_kinds(&body.fields);
+	let mut bounds = Vec::new();
+	for (field, kind) in body.fields.iter().zip(&kinds) {
+		if *kind != FieldKind::Skip {
+			bounds.push((item_type(&field.ty), quote! { rlp::Decodable }));
+		}
+		if *kind != FieldKind::Required && !is_phantom_data(&field.ty) {
+			bounds.push((&field.ty, quote! { ::core::default::Default }));
+		}
+	}
+	let generics = with_bounds(ast, container.decode_bound.as_deref(), &bounds);
+	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+	let mut index = 0;
 	let stmts: Vec<_> = body
 		.fields
 		.iter()
+		.zip(kinds)
 		.enumerate()
-		.map(|(i, field)| decodable_field(i, field, decodable_parse_quotes(), &mut default_attribute_encountered))
+		.map(|(i, (field, kind))| {
+			let stmt = decodable_field(i, index, field, &decodable_parse_quotes(), kind);
+			if kind != FieldKind::Skip {
+				index += 1;
+			}
+			stmt
+		})
 		.collect();
 	let name = &ast.ident;
 
 	let impl_block = quote! {
-		impl rlp::Decodable for #name {
+		impl #impl_generics rlp::Decodable for #name #ty_generics #where_clause {
 			fn decode(rlp: &rlp::Rlp) -> Result<Self, rlp::DecoderError> {
 				let result = #name {
 					#(#stmts)*
@@ -66,21 +93,22 @@ pub fn impl_decodable_wrapper(ast: &syn::DeriveInput) -> TokenStream {
 		panic!("#[derive(RlpDecodableWrapper)] is only defined for structs.");
 	};
 
-	let stmt = {
-		let fields: Vec<_> = body.fields.iter().collect();
-		if fields.len() == 1 {
-			let field = fields.first().expect("fields.len() == 1; qed");
-			let mut default_attribute_encountered = false;
-			decodable_field(0, field, decodable_wrapper_parse_quotes(), &mut default_attribute_encountered)
-		} else {
-			panic!("#[derive(RlpEncodableWrapper)] is only defined for structs with one field.")
-		}
+	let fields: Vec<_> = body.fields.iter().collect();
+	let field = if fields.len() == 1 {
+		fields.first().expect("fields.len() == 1; qed")
+	} else {
+		panic!("#[derive(RlpEncodableWrapper)] is only defined for structs with one field.")
 	};
+	let stmt = decodable_field(0, 0, field, &decodable_wrapper_parse_quotes(), FieldKind::Required);
 
+	let container = parse_container(ast);
+	let bounds = [(item_type(&field.ty), quote! { rlp::Decodable })];
+	let generics = with_bounds(ast, container.decode_bound.as_deref(), &bounds);
+	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 	let name = &ast.ident;
 
 	let impl_block = quote! {
-		impl rlp::Decodable for #name {
+		impl #impl_generics rlp::Decodable for #name #ty_generics #where_clause {
 			fn decode(rlp: &rlp::Rlp) -> Result<Self, rlp::DecoderError> {
 				let result = #name {
 					#stmt
@@ -99,65 +127,112 @@ pub fn impl_decodable_wrapper(ast: &syn::DeriveInput) -> TokenStream {
 	}
 }
 
+fn impl_decodable_enum(ast: &syn::DeriveInput, data: &syn::DataEnum) -> TokenStream {
+	let parsed = parse_enum(ast, data, "RlpDecodable");
+	let bounds: Vec<_> = parsed
+		.variants
+		.iter()
+		.flat_map(|variant| &variant.fields)
+		.map(|field| (item_type(&field.ty), quote! { rlp::Decodable }))
+		.collect();
+	let generics = with_bounds(ast, parse_container(ast).decode_bound.as_deref(), &bounds);
+	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+	let name = &ast.ident;
+
+	let arms = parsed.variants.iter().map(|variant| {
+		let ident = variant.ident;
+		let first = usize::from(!parsed.untagged);
+		let values: Vec<_> = variant
+			.fields
+			.iter()
+			.enumerate()
+			.map(|(i, field)| {
+				let index = i + first;
+				decodable_value(&quote! { #index }, &field.ty, &decodable_parse_quotes())
+			})
+			.collect();
+		let value = if values.is_empty() {
+			quote! { #name::#ident }
+		} else {
+			quote! { #name::#ident(#(#values),*) }
+		};
+		let pattern = if parsed.untagged {
+			let len = variant.fields.len();
+			quote! { #len }
+		} else {
+			let discriminant = variant.discriminant;
+			quote! { #discriminant }
+		};
+		quote! { #pattern => Ok(#value), }
+	});
+
+	let (selector, error) = if parsed.untagged {
+		(quote! { rlp.item_count()? }, format!("unexpected number of items for untagged enum {}", name))
+	} else {
+		(quote! { rlp.val_at::<u8>(0)? }, format!("unknown discriminant for enum {}", name))
+	};
+
+	let impl_block = quote! {
+		impl #impl_generics rlp::Decodable for #name #ty_generics #where_clause {
+			fn decode(rlp: &rlp::Rlp) -> Result<Self, rlp::DecoderError> {
+				match #selector {
+					#(#arms)*
+					_ => Err(rlp::DecoderError::Custom(#error)),
+				}
+			}
+		}
+	};
+
+	quote! {
+		const _: () = {
+			extern crate rlp;
+			#impl_block
+		};
+	}
+}
+
+/// Decodes the field at `position` in the struct from the item at `index`.
 fn decodable_field(
-	mut index: usize,
+	position: usize,
+	index: usize,
 	field: &syn::Field,
-	quotes: ParseQuotes,
-	default_attribute_encountered: &mut bool,
+	quotes: &ParseQuotes,
+	kind: FieldKind,
 ) -> TokenStream {
 	let id = if let Some(ident) = &field.ident {
 		quote! { #ident }
 	} else {
-		let index = syn::Index::from(index);
-		quote! { #index }
+		let position = syn::Index::from(position);
+		quote! { #position }
 	};
 
-	if *default_attribute_encountered {
-		index -= 1;
-	}
-	let index = quote! { #index };
-
-	let single = quotes.single;
-	let list = quotes.list;
-
-	let attributes = &field.attrs;
-	let default = if let Some(attr) = attributes.iter().find(|attr| attr.path().is_ident("rlp")) {
-		if *default_attribute_encountered {
-			panic!("only 1 #[rlp(default)] attribute is allowed in a struct")
-		}
-		match attr.parse_args() {
-			Ok(proc_macro2::TokenTree::Ident(ident)) if ident == "default" => {},
-			_ => panic!("only #[rlp(default)] attribute is supported"),
-		}
-		*default_attribute_encountered = true;
-		true
-	} else {
-		false
+	let value = match kind {
+		FieldKind::Required => decodable_value(&quote! { #index }, &field.ty, quotes),
+		FieldKind::Default => {
+			let value = decodable_value(&quote! { #index }, &field.ty, quotes);
+			quote! {
+				if rlp.item_count()? > #index { #value } else { Default::default() }
+			}
+		},
+		FieldKind::Skip => quote! { Default::default() },
 	};
+	quote! { #id: #value, }
+}
 
-	if let syn::Type::Path(path) = &field.ty {
-		let ident = &path.path.segments.first().expect("there must be at least 1 segment").ident;
-		let ident_type = ident.to_string();
-		if ident_type == "Vec" {
-			if quotes.takes_index {
-				if default {
-					quote! { #id: #list(#index).unwrap_or_default(), }
-				} else {
-					quote! { #id: #list(#index)?, }
-				}
-			} else {
-				quote! { #id: #list()?, }
-			}
-		} else if quotes.takes_index {
-			if default {
-				quote! { #id: #single(#index).unwrap_or_default(), }
-			} else {
-				quote! { #id: #single(#index)?, }
-			}
+/// Decodes a value of type `ty` from the item at `index`, or from the whole rlp for wrappers.
+fn decodable_value(index: &TokenStream, ty: &syn::Type, quotes: &ParseQuotes) -> TokenStream {
+	let single = &quotes.single;
+	let list = &quotes.list;
+
+	if vec_item_type(ty).is_some() {
+		if quotes.takes_index {
+			quote! { #list(#index)? }
 		} else {
-			quote! { #id: #single()?, }
+			quote! { #list()? }
 		}
+	} else if quotes.takes_index {
+		quote! { #single(#index)? }
 	} else {
-		panic!("rlp_derive not supported");
+		quote! { #single()? }
 	}
 }
@@ -7,30 +7,96 @@
 // except according to those terms.
 
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote};
+
+use crate::{
+	bound::{item_type, vec_item_type, with_bounds},
+	parse::{parse_container, parse_enum, struct_field_kinds, FieldKind},
+};
 
 pub fn impl_encodable(ast: &syn::DeriveInput) -> TokenStream {
-	let body = if let syn::Data::Struct(s) = &ast.data {
-		s
-	} else {
-		panic!("#[derive(RlpEncodable)] is only defined for structs.");
+	let body = match &ast.data {
+		syn::Data::Struct(s) => s,
+		syn::Data::Enum(e) => return impl_encodable_enum(ast, e),
+		syn::Data::Union(_) => panic!("#[derive(RlpEncodable)] is only defined for structs and enums."),
 	};
 
-	let stmts: Vec<_> = body
-		.fields
-		.iter()
-		.enumerate()
+	let container = parse_container(ast);
+	assert!(!container.untagged, "#[rlp(untagged)] is only supported on enums");
+	let kinds = struct_field_kinds(&body.fields);
+	let fields_of_kind = |kind| {
+		body.fields
+			.iter()
+			.enumerate()
+			.zip(&kinds)
+			.filter(move |(_, field_kind)| **field_kind == kind)
+			.map(|(f, _)| f)
+	};
+	let stmts: Vec<_> = fields_of_kind(FieldKind::Required)
 		.map(|(i, field)| encodable_field(i, field))
 		.collect();
+	let defaults: Vec<_> = fields_of_kind(FieldKind::Default).collect();
+	let mut bounds: Vec<_> = fields_of_kind(FieldKind::Required)
+		.chain(defaults.iter().copied())
+		.map(|(_, field)| (item_type(&field.ty), quote! { rlp::Encodable }))
+		.collect();
+	bounds.extend(
+		defaults
+			.iter()
+			.map(|(_, field)| (&field.ty, quote! { ::core::default::Default + ::core::cmp::PartialEq })),
+	);
+	let generics = with_bounds(ast, container.encode_bound.as_deref(), &bounds);
+	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 	let name = &ast.ident;
 
 	let stmts_len = stmts.len();
-	let stmts_len = quote! { #stmts_len };
+	let hints: Vec<_> = fields_of_kind(FieldKind::Required)
+		.map(|(i, field)| size_hint_field(i, field))
+		.collect();
+	let (append, size_hint) = if defaults.is_empty() {
+		let append = quote! {
+			stream.begin_list(#stmts_len);
+			#(#stmts)*
+		};
+		let size_hint = quote! { rlp::list_size::<[usize; #stmts_len]>([#(#hints),*]) };
+		(append, size_hint)
+	} else {
+		// The default fields up to the last one with a value other than its default are encoded.
+		let checks = defaults.iter().enumerate().rev().map(|(n, (i, field))| {
+			let ident = field_ident(*i, field);
+			let ty = &field.ty;
+			let count = n + 1;
+			quote! { if self.#ident != <#ty as Default>::default() { #count } else }
+		});
+		let trailing = quote! { let trailing: usize = #(#checks)* { 0 }; };
+		let default_stmts = defaults.iter().enumerate().map(|(n, (i, field))| {
+			let stmt = encodable_field(*i, field);
+			quote! { if trailing > #n { #stmt } }
+		});
+		let default_hints = defaults.iter().map(|(i, field)| size_hint_field(*i, field));
+		let append = quote! {
+			#trailing
+			stream.begin_list(#stmts_len + trailing);
+			#(#stmts)*
+			#(#default_stmts)*
+		};
+		let defaults_len = defaults.len();
+		let size_hint = quote! {
+			#trailing
+			let hints = <[usize; #stmts_len] as ::core::iter::IntoIterator>::into_iter([#(#hints),*]);
+			let default_hints = <[usize; #defaults_len] as ::core::iter::IntoIterator>::into_iter([#(#default_hints),*]);
+			rlp::list_size(::core::iter::Iterator::chain(hints, ::core::iter::Iterator::take(default_hints, trailing)))
+		};
+		(append, size_hint)
+	};
 	let impl_block = quote! {
-		impl rlp::Encodable for #name {
+		impl #impl_generics rlp::Encodable for #name #ty_generics #where_clause {
 			fn rlp_append(&self, stream: &mut rlp::RlpStream) {
-				stream.begin_list(#stmts_len);
-				#(#stmts)*
+				#append
+			}
+
+			fn rlp_size_hint(&self) -> usize {
+				#size_hint
 			}
 		}
 	};
@@ -50,23 +116,118 @@ pub fn impl_encodable_wrapper(ast: &syn::DeriveInput) -> TokenStream {
 		panic!("#[derive(RlpEncodableWrapper)] is only defined for structs.");
 	};
 
-	let stmt = {
-		let fields: Vec<_> = body.fields.iter().collect();
-		if fields.len() == 1 {
-			let field = fields.first().expect("fields.len() == 1; qed");
-			encodable_field(0, field)
-		} else {
-			panic!("#[derive(RlpEncodableWrapper)] is only defined for structs with one field.")
-		}
+	let fields: Vec<_> = body.fields.iter().collect();
+	let field = if fields.len() == 1 {
+		fields.first().expect("fields.len() == 1; qed")
+	} else {
+		panic!("#[derive(RlpEncodableWrapper)] is only defined for structs with one field.")
 	};
+	let stmt = encodable_field(0, field);
+	let size_hint = size_hint_field(0, field);
 
+	let container = parse_container(ast);
+	let bounds = [(item_type(&field.ty), quote! { rlp::Encodable })];
+	let generics = with_bounds(ast, container.encode_bound.as_deref(), &bounds);
+	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 	let name = &ast.ident;
 
 	let impl_block = quote! {
-		impl rlp::Encodable for #name {
+		impl #impl_generics rlp::Encodable for #name #ty_generics #where_clause {
 			fn rlp_append(&self, stream: &mut rlp::RlpStream) {
 				#stmt
 			}
+
+			fn rlp_size_hint(&self) -> usize {
+				#size_hint
+			}
+		}
+	};
+
+	quote! {
+		const _: () = {
+			extern crate rlp;
+			#impl_block
+		};
+	}
+}
+
+fn impl_encodable_enum(ast: &syn::DeriveInput, data: &syn::DataEnum) -> TokenStream {
+	let parsed = parse_enum(ast, data, "RlpEncodable");
+	let bounds: Vec<_> = parsed
+		.variants
+		.iter()
+		.flat_map(|variant| &variant.fields)
+		.map(|field| (item_type(&field.ty), quote! { rlp::Encodable }))
+		.collect();
+	let generics = with_bounds(ast, parse_container(ast).encode_bound.as_deref(), &bounds);
+	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+	let name = &ast.ident;
+
+	let patterns: Vec<_> = parsed
+		.variants
+		.iter()
+		.map(|variant| {
+			let ident = variant.ident;
+			let bindings: Vec<_> = (0..variant.fields.len()).map(|i| format_ident!("field_{}", i)).collect();
+			let pattern = if bindings.is_empty() {
+				quote! { #name::#ident }
+			} else {
+				quote! { #name::#ident(#(#bindings),*) }
+			};
+			(pattern, bindings)
+		})
+		.collect();
+	let arms = parsed.variants.iter().zip(&patterns).map(|(variant, (pattern, bindings))| {
+		let stmts = variant
+			.fields
+			.iter()
+			.zip(bindings)
+			.map(|(field, binding)| encodable_value(&quote! { #binding }, &field.ty));
+		let (len, tag) = if parsed.untagged {
+			(variant.fields.len(), quote! {})
+		} else {
+			let discriminant = variant.discriminant;
+			(variant.fields.len() + 1, quote! { stream.append(&#discriminant); })
+		};
+		quote! {
+			#pattern => {
+				stream.begin_list(#len);
+				#tag
+				#(#stmts)*
+			}
+		}
+	});
+
+	let hint_arms = parsed.variants.iter().zip(&patterns).map(|(variant, (pattern, bindings))| {
+		let mut hints: Vec<_> = variant
+			.fields
+			.iter()
+			.zip(bindings)
+			.map(|(field, binding)| size_hint_value(&quote! { #binding }, &field.ty))
+			.collect();
+		if !parsed.untagged {
+			let discriminant_size: usize = if variant.discriminant < 0x80 { 1 } else { 2 };
+			hints.insert(0, quote! { #discriminant_size });
+		}
+		let len = hints.len();
+		quote! {
+			#pattern => rlp::list_size::<[usize; #len]>([#(#hints),*]),
+		}
+	});
+
+	let impl_block = quote! {
+		impl #impl_generics rlp::Encodable for #name #ty_generics #where_clause {
+			fn rlp_append(&self, stream: &mut rlp::RlpStream) {
+				match self {
+					#(#arms)*
+				}
+			}
+
+			fn rlp_size_hint(&self) -> usize {
+				match self {
+					#(#hint_arms)*
+				}
+			}
 		}
 	};
 
@@ -79,37 +240,35 @@ pub fn impl_encodable_wrapper(ast: &syn::DeriveInput) -> TokenStream {
 }
 
 fn encodable_field(index: usize, field: &syn::Field) -> TokenStream {
-	let ident = if let Some(ident) = &field.ident {
+	let ident = field_ident(index, field);
+	encodable_value(&quote! { &self.#ident }, &field.ty)
+}
+
+fn field_ident(index: usize, field: &syn::Field) -> TokenStream {
+	if let Some(ident) = &field.ident {
 		quote! { #ident }
 	} else {
 		let index = syn::Index::from(index);
 		quote! { #index }
-	};
+	}
+}
 
-	let id = quote! { self.#ident };
-
-	if let syn::Type::Path(path) = &field.ty {
-		let top_segment = path.path.segments.first().expect("there must be at least 1 segment");
-		let ident = &top_segment.ident;
-		if ident == "Vec" {
-			let inner_ident = {
-				if let syn::PathArguments::AngleBracketed(angle) = &top_segment.arguments {
-					if let syn::GenericArgument::Type(syn::Type::Path(path)) =
-						angle.args.first().expect("Vec has only one angle bracketed type; qed")
-					{
-						&path.path.segments.first().expect("there must be at least 1 segment").ident
-					} else {
-						panic!("rlp_derive not supported");
-					}
-				} else {
-					unreachable!("Vec has only one angle bracketed type; qed")
-				}
-			};
-			quote! { stream.append_list::<#inner_ident, _>(&#id); }
-		} else {
-			quote! { stream.append(&#id); }
-		}
+/// Appends `value`, a reference to a value of type `ty`.
+fn encodable_value(value: &TokenStream, ty: &syn::Type) -> TokenStream {
+	vec_item_type(ty)
+		.map_or_else(|| quote! { stream.append(#value); }, |item| quote! { stream.append_list::<#item, _>(#value); })
+}
+
+fn size_hint_field(index: usize, field: &syn::Field) -> TokenStream {
+	let ident = field_ident(index, field);
+	size_hint_value(&quote! { &self.#ident }, &field.ty)
+}
+
+/// Returns the size hint of `value`, a reference to a value of type `ty`.
+fn size_hint_value(value: &TokenStream, ty: &syn::Type) -> TokenStream {
+	if vec_item_type(ty).is_some() {
+		quote! { rlp::list_size(::core::iter::Iterator::map((#value).iter(), rlp::Encodable::rlp_size_hint)) }
 	} else {
-		panic!("rlp_derive not supported");
+		quote! { rlp::Encodable::rlp_size_hint(#value) }
 	}
 }
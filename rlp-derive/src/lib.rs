@@ -10,18 +10,42 @@
 //!
 //! For example of usage see `./tests/rlp.rs`.
 //!
-//! This library also supports up to 1 `#[rlp(default)]` in a struct,
-//! which is similar to [`#[serde(default)]`](https://serde.rs/field-attrs.html#default)
-//! with the caveat that we use the `Default` value if
-//! the field deserialization fails, as we don't serialize field
-//! names and there is no way to tell if it is present or not.
+//! Struct fields support two attributes:
+//!
+//! - `#[rlp(skip)]`: the field is not encoded, and is set to its `Default` value when decoding.
+//! - `#[rlp(default)]`: the field may be missing from the end of the list, for formats which add
+//!   optional fields over time, and is then set to its `Default` value, similar to
+//!   [`#[serde(default)]`](https://serde.rs/field-attrs.html#default). These fields must come after
+//!   all the other encoded fields, and need `PartialEq` and `Default` implementations. When encoding,
+//!   the trailing default fields which equal their `Default` value are left out, so decoding a short
+//!   list and encoding the result gives back the same list. A default field which is present but
+//!   fails to decode is an error.
+//!
+//! Enums are encoded as a list of a `u8` discriminant followed by the fields of the variant. The
+//! discriminants count up from `0` like those of Rust enums, and can be set with
+//! `#[rlp(discriminant = N)]` on a variant, which the following variants count up from. An enum
+//! with `#[rlp(untagged)]` is encoded as just the list of the fields instead, and its variants are
+//! told apart by their number of fields, which must be distinct. Only unit and tuple variants are
+//! supported.
+//!
+//! The impls for generic types are bounded like those of serde: each field type which uses a type
+//! parameter must implement `Encodable` or `Decodable`, the type of the items for `Vec` fields, as
+//! well as the traits needed by `#[rlp(skip)]` and `#[rlp(default)]`. `PhantomData` fields are
+//! skipped without adding any bound. Where the bounds can't be inferred, as for recursive types,
+//! they can be replaced with `#[rlp(bound = "T: Trait, ...")]` on the type, or separately for each
+//! derive with `#[rlp(bound(encode = "...", decode = "..."))]`.
+//!
+//! The derived `Encodable::rlp_size_hint` is computed from the hints of the encoded fields, so it
+//! is unknown (0) if that of any field is.
 
 #![warn(clippy::all, clippy::pedantic, clippy::nursery)]
 
 extern crate proc_macro;
 
+mod bound;
 mod de;
 mod en;
+mod parse;
 
 use de::{impl_decodable, impl_decodable_wrapper};
 use en::{impl_encodable, impl_encodable_wrapper};
@@ -34,7 +58,7 @@ pub fn encodable(input: TokenStream) -> TokenStream {
 	gen.into()
 }
 
-#[proc_macro_derive(RlpEncodableWrapper)]
+#[proc_macro_derive(RlpEncodableWrapper, attributes(rlp))]
 pub fn encodable_wrapper(input: TokenStream) -> TokenStream {
 	let ast = syn::parse(input).unwrap();
 	let gen = impl_encodable_wrapper(&ast);
@@ -48,7 +72,7 @@ pub fn decodable(input: TokenStream) -> TokenStream {
 	gen.into()
 }
 
-#[proc_macro_derive(RlpDecodableWrapper)]
+#[proc_macro_derive(RlpDecodableWrapper, attributes(rlp))]
 pub fn decodable_wrapper(input: TokenStream) -> TokenStream {
 	let ast = syn::parse(input).unwrap();
 	let gen = impl_decodable_wrapper(&ast);
@@ -0,0 +1,203 @@
+// Copyright 2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Parsing of enums and of the `#[rlp(...)]` attributes, shared by both derives.
+
+use std::collections::HashSet;
+
+/// How a struct field is encoded.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum FieldKind {
+	/// The field is always encoded, and decoding fails without it.
+	Required,
+	/// `#[rlp(default)]`: the field may be missing from the end of the list, and then decodes to its
+	/// default value. It is only encoded if it or one of the following default fields has a value
+	/// other than its default.
+	Default,
+	/// `#[rlp(skip)]`: the field is never encoded and decodes to its default value.
+	Skip,
+}
+
+/// Returns the kinds of the fields of a struct, checking that the default fields come after all the
+/// required ones.
+pub fn struct_field_kinds(fields: &syn::Fields) -> Vec<FieldKind> {
+	let kinds: Vec<_> = fields.iter().map(field_kind).collect();
+	let mut default_encountered = false;
+	for (field, kind) in fields.iter().zip(&kinds) {
+		match kind {
+			FieldKind::Default => default_encountered = true,
+			FieldKind::Required if default_encountered => {
+				let name = field
+					.ident
+					.as_ref()
+					.map_or_else(|| "a tuple field".to_owned(), |ident| format!("`{}`", ident));
+				panic!("#[rlp(default)] fields must come after all the other encoded fields, but {} follows one", name);
+			},
+			_ => {},
+		}
+	}
+	kinds
+}
+
+/// Returns the kind of a field, `Skip` for `PhantomData` fields without attributes.
+fn field_kind(field: &syn::Field) -> FieldKind {
+	let mut attrs = field.attrs.iter().filter(|attr| attr.path().is_ident("rlp"));
+	let unmarked = if is_phantom_data(&field.ty) { FieldKind::Skip } else { FieldKind::Required };
+	let kind = attrs.next().map_or(unmarked, |attr| match attr.parse_args() {
+		Ok(proc_macro2::TokenTree::Ident(ident)) if ident == "default" => FieldKind::Default,
+		Ok(proc_macro2::TokenTree::Ident(ident)) if ident == "skip" => FieldKind::Skip,
+		_ => panic!("only #[rlp(default)] and #[rlp(skip)] attributes are supported on fields"),
+	});
+	assert!(attrs.next().is_none(), "only one #[rlp(...)] attribute is allowed on a field");
+	kind
+}
+
+pub fn is_phantom_data(ty: &syn::Type) -> bool {
+	if let syn::Type::Path(path) = ty {
+		path.path
+			.segments
+			.last()
+			.map_or(false, |segment| segment.ident == "PhantomData")
+	} else {
+		false
+	}
+}
+
+/// The `#[rlp(...)]` attributes of a struct or an enum.
+#[derive(Default)]
+pub struct Container {
+	/// `#[rlp(untagged)]`, only supported on enums.
+	pub untagged: bool,
+	/// The where predicates of `#[rlp(bound = "...")]` or `#[rlp(bound(encode = "..."))]`, replacing
+	/// the bounds inferred from the fields for `Encodable`.
+	pub encode_bound: Option<Vec<syn::WherePredicate>>,
+	/// The same for `Decodable`.
+	pub decode_bound: Option<Vec<syn::WherePredicate>>,
+}
+
+pub fn parse_container(ast: &syn::DeriveInput) -> Container {
+	let mut container = Container::default();
+	for attr in ast.attrs.iter().filter(|attr| attr.path().is_ident("rlp")) {
+		attr.parse_nested_meta(|meta| {
+			if meta.path.is_ident("untagged") {
+				container.untagged = true;
+			} else if meta.path.is_ident("bound") {
+				if meta.input.peek(syn::token::Paren) {
+					meta.parse_nested_meta(|meta| {
+						let bound = Some(parse_predicates(&meta.value()?.parse()?)?);
+						if meta.path.is_ident("encode") {
+							container.encode_bound = bound;
+						} else if meta.path.is_ident("decode") {
+							container.decode_bound = bound;
+						} else {
+							return Err(meta.error("expected #[rlp(bound(encode = \"...\", decode = \"...\"))]"))
+						}
+						Ok(())
+					})?;
+				} else {
+					let bound = parse_predicates(&meta.value()?.parse()?)?;
+					container.encode_bound = Some(bound.clone());
+					container.decode_bound = Some(bound);
+				}
+			} else {
+				return Err(meta.error("only #[rlp(untagged)] and #[rlp(bound = \"...\")] attributes are supported"))
+			}
+			Ok(())
+		})
+		.unwrap_or_else(|err| panic!("{}", err));
+	}
+	container
+}
+
+/// Parses the comma separated where predicates of `#[rlp(bound = "...")]`.
+fn parse_predicates(lit: &syn::LitStr) -> syn::Result<Vec<syn::WherePredicate>> {
+	let predicates =
+		lit.parse_with(syn::punctuated::Punctuated::<syn::WherePredicate, syn::Token![,]>::parse_terminated)?;
+	Ok(predicates.into_iter().collect())
+}
+
+/// An enum to derive an implementation for.
+pub struct Enum<'a> {
+	/// Whether the variants are told apart by their number of fields instead of a discriminant.
+	pub untagged: bool,
+	pub variants: Vec<Variant<'a>>,
+}
+
+pub struct Variant<'a> {
+	pub ident: &'a syn::Ident,
+	/// The discriminant encoded as the first item, ignored for untagged enums.
+	pub discriminant: u8,
+	pub fields: Vec<&'a syn::Field>,
+}
+
+pub fn parse_enum<'a>(ast: &'a syn::DeriveInput, data: &'a syn::DataEnum, derive: &str) -> Enum<'a> {
+	let name = &ast.ident;
+	let untagged = parse_container(ast).untagged;
+
+	let mut next_discriminant = 0u16;
+	let mut discriminants = HashSet::new();
+	let mut field_counts = HashSet::new();
+	let variants: Vec<_> = data
+		.variants
+		.iter()
+		.map(|variant| {
+			if let syn::Fields::Named(_) = variant.fields {
+				panic!(
+					"#[derive({})] doesn't support struct variants such as `{}::{}`, use a tuple variant instead",
+					derive, name, variant.ident
+				);
+			}
+			let fields: Vec<_> = variant.fields.iter().collect();
+			if fields
+				.iter()
+				.any(|field| field.attrs.iter().any(|attr| attr.path().is_ident("rlp")))
+			{
+				panic!("#[rlp(...)] attributes are not supported on the fields of enum variants");
+			}
+
+			let discriminant = match variant_discriminant(variant) {
+				Some(_) if untagged => panic!("#[rlp(discriminant)] is not supported in untagged enums"),
+				Some(discriminant) => discriminant,
+				None => u8::try_from(next_discriminant)
+					.unwrap_or_else(|_| panic!("the discriminant of `{}::{}` doesn't fit in u8", name, variant.ident)),
+			};
+			next_discriminant = u16::from(discriminant) + 1;
+
+			if untagged {
+				assert!(
+					field_counts.insert(fields.len()),
+					"the variants of the untagged enum `{}` must have distinct numbers of fields",
+					name
+				);
+			} else if !discriminants.insert(discriminant) {
+				panic!("the discriminant {} of `{}::{}` is used by another variant", discriminant, name, variant.ident);
+			}
+
+			Variant { ident: &variant.ident, discriminant, fields }
+		})
+		.collect();
+
+	Enum { untagged, variants }
+}
+
+/// Parses the `#[rlp(discriminant = N)]` attribute of a variant.
+fn variant_discriminant(variant: &syn::Variant) -> Option<u8> {
+	let attr = variant.attrs.iter().find(|attr| attr.path().is_ident("rlp"))?;
+	let mut discriminant = None;
+	attr.parse_nested_meta(|meta| {
+		if meta.path.is_ident("discriminant") {
+			let value: syn::LitInt = meta.value()?.parse()?;
+			discriminant = Some(value.base10_parse::<u8>()?);
+			Ok(())
+		} else {
+			Err(meta.error("only #[rlp(discriminant = N)] attribute is supported on variants"))
+		}
+	})
+	.unwrap_or_else(|err| panic!("{}", err));
+	discriminant
+}
@@ -6,7 +6,7 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use rlp::{decode, encode};
+use rlp::{decode, encode, DecoderError, Encodable};
 use rlp_derive::{RlpDecodable, RlpDecodableWrapper, RlpEncodable, RlpEncodableWrapper};
 
 #[derive(Debug, PartialEq, RlpEncodable, RlpDecodable)]
@@ -69,3 +69,159 @@ fn test_encode_item_default() {
 	let out = encode(&item_some);
 	assert_eq!(decode(&out), Ok(item_some));
 }
+
+#[derive(Debug, PartialEq, RlpEncodable, RlpDecodable)]
+struct Header {
+	number: u64,
+	#[rlp(skip)]
+	cached_size: usize,
+	#[rlp(default)]
+	base_fee: Option<u64>,
+	#[rlp(default)]
+	withdrawals_root: Vec<u8>,
+}
+
+#[test]
+fn test_trailing_defaults() {
+	// A list without the optional fields.
+	let short = vec![0xc1, 0x07];
+	let header: Header = decode(&short).expect("short list");
+	assert_eq!(header, Header { number: 7, cached_size: 0, base_fee: None, withdrawals_root: vec![] });
+	assert_eq!(encode(&header), short);
+
+	// Only the first optional field.
+	let header = Header { number: 7, cached_size: 0, base_fee: Some(16), withdrawals_root: vec![] };
+	let encoded = encode(&header);
+	assert_eq!(encoded, vec![0xc3, 0x07, 0xc1, 0x10]);
+	assert_eq!(decode(&encoded), Ok(header));
+
+	// A later optional field is set, so the earlier ones are encoded too.
+	let header = Header { number: 7, cached_size: 0, base_fee: None, withdrawals_root: vec![1] };
+	let encoded = encode(&header);
+	assert_eq!(encoded, vec![0xc4, 0x07, 0xc0, 0xc1, 0x01]);
+	assert_eq!(decode(&encoded), Ok(header));
+
+	// Skipped fields are never encoded.
+	let header = Header { number: 7, cached_size: 100, base_fee: Some(16), withdrawals_root: vec![1] };
+	let encoded = encode(&header);
+	assert_eq!(encoded, vec![0xc5, 0x07, 0xc1, 0x10, 0xc1, 0x01]);
+	assert_eq!(decode(&encoded), Ok(Header { cached_size: 0, ..header }));
+}
+
+#[test]
+fn test_trailing_defaults_errors() {
+	// The required field is missing.
+	assert_eq!(decode::<Header>(&[0xc0]), Err(DecoderError::RlpIsTooShort));
+	// An optional field which is present must decode.
+	assert_eq!(decode::<Header>(&[0xc4, 0x07, 0xc2, 0x01, 0x02]), Err(DecoderError::RlpIncorrectListLen));
+}
+
+#[test]
+fn test_skip_in_tuple_struct() {
+	#[derive(Debug, PartialEq, RlpEncodable, RlpDecodable)]
+	struct Pair(#[rlp(skip)] bool, u8, #[rlp(default)] u8);
+
+	assert_eq!(encode(&Pair(true, 1, 0)), vec![0xc1, 0x01]);
+	assert_eq!(encode(&Pair(true, 1, 2)), vec![0xc2, 0x01, 0x02]);
+	assert_eq!(decode(&[0xc1, 0x01]), Ok(Pair(false, 1, 0)));
+	assert_eq!(decode(&[0xc2, 0x01, 0x02]), Ok(Pair(false, 1, 2)));
+}
+
+#[derive(Debug, PartialEq, RlpEncodable, RlpDecodable)]
+enum Message {
+	Ping,
+	Status(u64, String),
+	#[rlp(discriminant = 5)]
+	Hashes(Vec<u64>),
+	Item(Item),
+}
+
+#[test]
+fn test_encode_enum() {
+	let ping = Message::Ping;
+	let expected = vec![0xc1, 0x80];
+	assert_eq!(encode(&ping), expected);
+	assert_eq!(decode(&expected), Ok(ping));
+
+	let status = Message::Status(3, "cat".into());
+	let expected = vec![0xc6, 0x01, 0x03, 0x83, b'c', b'a', b't'];
+	assert_eq!(encode(&status), expected);
+	assert_eq!(decode(&expected), Ok(status));
+
+	let hashes = Message::Hashes(vec![1, 2]);
+	let expected = vec![0xc4, 0x05, 0xc2, 0x01, 0x02];
+	assert_eq!(encode(&hashes), expected);
+	assert_eq!(decode(&expected), Ok(hashes));
+
+	// the discriminants after an explicit one count up from it
+	let item = Message::Item(Item { a: "cat".into() });
+	let expected = vec![0xc6, 0x06, 0xc4, 0x83, b'c', b'a', b't'];
+	assert_eq!(encode(&item), expected);
+	assert_eq!(decode(&expected), Ok(item));
+}
+
+#[test]
+fn test_decode_enum_errors() {
+	assert_eq!(decode::<Message>(&[0xc1, 0x02]), Err(DecoderError::Custom("unknown discriminant for enum Message")));
+	assert_eq!(decode::<Message>(&[0xc1, 0x01]), Err(DecoderError::RlpIsTooShort));
+	assert_eq!(decode::<Message>(&[0x80]), Err(DecoderError::RlpExpectedToBeList));
+}
+
+#[derive(Debug, PartialEq, RlpEncodable, RlpDecodable)]
+#[rlp(untagged)]
+enum Legacy {
+	Empty,
+	Transfer(Vec<u8>, u64),
+	Call(Vec<u8>, u64, Vec<u8>),
+}
+
+#[test]
+fn test_encode_untagged_enum() {
+	let empty = Legacy::Empty;
+	assert_eq!(encode(&empty), vec![0xc0]);
+	assert_eq!(decode(&[0xc0]), Ok(empty));
+
+	let transfer = Legacy::Transfer(vec![0xaa; 2], 7);
+	// `Vec` fields are encoded as lists, as in structs
+	let expected = vec![0xc6, 0xc4, 0x81, 0xaa, 0x81, 0xaa, 0x07];
+	assert_eq!(encode(&transfer), expected);
+	assert_eq!(decode(&expected), Ok(transfer));
+
+	let call = Legacy::Call(vec![0xaa; 2], 7, vec![]);
+	let out = encode(&call);
+	assert_eq!(decode(&out), Ok(call));
+
+	assert_eq!(
+		decode::<Legacy>(&[0xc1, 0x80]),
+		Err(DecoderError::Custom("unexpected number of items for untagged enum Legacy"))
+	);
+}
+
+#[test]
+fn test_unsupported_derives_fail_to_compile() {
+	trybuild::TestCases::new().compile_fail("tests/ui/*.rs");
+}
+
+#[test]
+fn test_generic_derives_compile() {
+	trybuild::TestCases::new().pass("tests/ui/pass/*.rs");
+}
+
+#[test]
+fn test_size_hints() {
+	fn assert_exact_size_hint<E: Encodable>(value: &E) {
+		assert_eq!(value.rlp_size_hint(), encode(value).len());
+	}
+
+	assert_exact_size_hint(&Item { a: "cat".into() });
+	assert_exact_size_hint(&ItemWrapper { a: "x".repeat(100) });
+	assert_exact_size_hint(&Header { number: 7, cached_size: 100, base_fee: None, withdrawals_root: vec![] });
+	assert_exact_size_hint(&Header { number: 7, cached_size: 0, base_fee: Some(16), withdrawals_root: vec![] });
+	assert_exact_size_hint(&Header { number: 7, cached_size: 0, base_fee: None, withdrawals_root: vec![0xff; 60] });
+	assert_exact_size_hint(&Message::Ping);
+	assert_exact_size_hint(&Message::Status(3, "cat".into()));
+	assert_exact_size_hint(&Message::Hashes((0..100).collect()));
+	assert_exact_size_hint(&Message::Item(Item { a: "dog".into() }));
+	assert_exact_size_hint(&Legacy::Empty);
+	assert_exact_size_hint(&Legacy::Call(vec![0xaa; 2], 7, vec![]));
+}
@@ -0,0 +1,11 @@
+use rlp_derive::RlpDecodable;
+
+#[derive(RlpDecodable)]
+struct Header {
+	number: u64,
+	#[rlp(default)]
+	base_fee: Option<u64>,
+	gas_limit: u64,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use rlp_derive::RlpDecodable;
+
+#[derive(RlpDecodable)]
+enum Message {
+	#[rlp(discriminant = 1)]
+	Ping,
+	Pong,
+	#[rlp(discriminant = 2)]
+	Status(u64),
+}
+
+fn main() {}
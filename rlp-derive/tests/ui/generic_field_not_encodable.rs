@@ -0,0 +1,13 @@
+use rlp_derive::RlpEncodable;
+
+#[derive(RlpEncodable)]
+struct Sealed<T> {
+	payload: T,
+	sig: Vec<u8>,
+}
+
+struct NotRlp;
+
+fn main() {
+	rlp::encode(&Sealed { payload: NotRlp, sig: vec![] });
+}
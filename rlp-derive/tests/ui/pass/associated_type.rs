@@ -0,0 +1,38 @@
+use rlp_derive::{RlpDecodable, RlpEncodable};
+
+trait Config {
+	type Hash;
+	type Number;
+}
+
+/// A config without rlp impls, whose associated types have them.
+struct Mainnet;
+
+impl Config for Mainnet {
+	type Hash = Vec<u8>;
+	type Number = u64;
+}
+
+#[derive(RlpEncodable, RlpDecodable)]
+struct Header<C: Config> {
+	parent: C::Hash,
+	number: <C as Config>::Number,
+	uncles: Vec<C::Hash>,
+}
+
+/// Bounds given in full, for a field whose type hides the associated type.
+#[derive(RlpEncodable, RlpDecodable)]
+#[rlp(bound(encode = "C::Number: rlp::Encodable", decode = "C::Number: rlp::Decodable"))]
+struct Numbers<C: Config> {
+	numbers: Box<[C::Number; 2]>,
+}
+
+fn main() {
+	let header = Header::<Mainnet> { parent: vec![0xab; 32], number: 5, uncles: vec![vec![1], vec![2]] };
+	let decoded: Header<Mainnet> = rlp::decode(&rlp::encode(&header)).unwrap();
+	assert_eq!((decoded.parent, decoded.number, decoded.uncles), (header.parent, header.number, header.uncles));
+
+	let numbers = Numbers::<Mainnet> { numbers: Box::new([1, 2]) };
+	let decoded: Numbers<Mainnet> = rlp::decode(&rlp::encode(&numbers)).unwrap();
+	assert_eq!(decoded.numbers, numbers.numbers);
+}
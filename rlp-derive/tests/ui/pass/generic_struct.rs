@@ -0,0 +1,55 @@
+use rlp_derive::{RlpDecodable, RlpDecodableWrapper, RlpEncodable, RlpEncodableWrapper};
+
+#[derive(Debug, PartialEq, RlpEncodable, RlpDecodable)]
+struct Sealed<T> {
+	payload: T,
+	sig: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, RlpEncodable, RlpDecodable)]
+struct Batch<'a, T, const N: usize>
+where
+	T: Clone,
+{
+	label: std::borrow::Cow<'a, [u8]>,
+	items: Vec<T>,
+	fixed: [T; N],
+	#[rlp(default)]
+	extra: Option<T>,
+}
+
+#[derive(Debug, PartialEq, RlpEncodableWrapper, RlpDecodableWrapper)]
+struct Wrapper<T>(Vec<T>);
+
+#[derive(Debug, PartialEq, RlpEncodable, RlpDecodable)]
+enum Either<L, R> {
+	Left(L),
+	Right(R),
+}
+
+// Inferring `Tree<T>: Encodable` for the children of a tree would make its impl depend on itself.
+#[derive(Debug, PartialEq, RlpEncodable, RlpDecodable)]
+#[rlp(bound = "T: rlp::Encodable + rlp::Decodable")]
+struct Tree<T> {
+	value: T,
+	children: Vec<Tree<T>>,
+}
+
+fn main() {
+	let sealed = Sealed { payload: 7u64, sig: vec![1, 2] };
+	assert_eq!(rlp::decode::<Sealed<u64>>(&rlp::encode(&sealed)), Ok(sealed));
+	let sealed = Sealed { payload: String::from("cat"), sig: vec![] };
+	assert_eq!(rlp::decode::<Sealed<String>>(&rlp::encode(&sealed)), Ok(sealed));
+
+	let batch = Batch { label: b"label"[..].into(), items: vec![1u16, 2], fixed: [3, 4, 5], extra: None };
+	assert_eq!(rlp::decode::<Batch<u16, 3>>(&rlp::encode(&batch)), Ok(batch));
+
+	let wrapper = Wrapper(vec![1u8, 2]);
+	assert_eq!(rlp::decode::<Wrapper<u8>>(&rlp::encode(&wrapper)), Ok(wrapper));
+
+	let either: Either<u8, String> = Either::Right(String::from("dog"));
+	assert_eq!(rlp::decode::<Either<u8, String>>(&rlp::encode(&either)), Ok(either));
+
+	let tree = Tree { value: 1u8, children: vec![Tree { value: 2, children: vec![] }] };
+	assert_eq!(rlp::decode::<Tree<u8>>(&rlp::encode(&tree)), Ok(tree));
+}
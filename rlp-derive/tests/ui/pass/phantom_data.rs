@@ -0,0 +1,20 @@
+use std::marker::PhantomData;
+
+use rlp_derive::{RlpDecodable, RlpEncodable};
+
+/// A marker type without rlp impls.
+#[derive(Debug, PartialEq)]
+struct Mainnet;
+
+#[derive(Debug, PartialEq, RlpEncodable, RlpDecodable)]
+struct Tagged<N> {
+	value: u64,
+	network: PhantomData<N>,
+}
+
+fn main() {
+	let tagged = Tagged::<Mainnet> { value: 7, network: PhantomData };
+	let encoded = rlp::encode(&tagged);
+	assert_eq!(encoded, rlp::encode_list::<u64, u64>(&[7]));
+	assert_eq!(rlp::decode::<Tagged<Mainnet>>(&encoded), Ok(tagged));
+}
@@ -0,0 +1,9 @@
+use rlp_derive::RlpEncodable;
+
+#[derive(RlpEncodable)]
+enum Message {
+	Ping,
+	Status { version: u64 },
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use rlp_derive::RlpDecodable;
+
+#[derive(RlpDecodable)]
+#[rlp(untagged)]
+enum Legacy {
+	Transfer(Vec<u8>, u64),
+	Create(Vec<u8>, Vec<u8>),
+}
+
+fn main() {}
@@ -8,7 +8,36 @@
 
 //! benchmarking for rlp
 
+use std::{
+	alloc::{GlobalAlloc, Layout, System},
+	sync::atomic::{AtomicUsize, Ordering},
+};
+
 use criterion::{criterion_group, criterion_main, Criterion};
+use primitive_types::{H160, H256, U256};
+
+/// The system allocator, counting reallocations.
+struct CountingAllocator;
+
+static REALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+	unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+		System.alloc(layout)
+	}
+
+	unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+		System.dealloc(ptr, layout)
+	}
+
+	unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+		REALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+		System.realloc(ptr, layout, new_size)
+	}
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
 
 fn bench_encode(c: &mut Criterion) {
 	c.bench_function("encode_u64", |b| {
@@ -87,6 +116,32 @@ fn bench_decode(c: &mut Criterion) {
 			}
 		});
 	});
+	c.bench_function("decode_200_u64_backwards", |b| {
+		let mut stream = rlp::RlpStream::new_list(200);
+		for i in 0..200u64 {
+			stream.append(&i);
+		}
+		let data = stream.out();
+		b.iter(|| {
+			let rlp = rlp::Rlp::new(&data);
+			for i in (0..200).rev() {
+				let _: u64 = rlp.val_at(i).unwrap();
+			}
+		});
+	});
+	c.bench_function("decode_200_u64_backwards_indexed", |b| {
+		let mut stream = rlp::RlpStream::new_list(200);
+		for i in 0..200u64 {
+			stream.append(&i);
+		}
+		let data = stream.out();
+		b.iter(|| {
+			let rlp = rlp::Rlp::new(&data).indexed().unwrap();
+			for i in (0..200).rev() {
+				let _: u64 = rlp.val_at(i).unwrap();
+			}
+		});
+	});
 	c.bench_function("decode_nested_empty_lists", |b| {
 		b.iter(|| {
 			// [ [], [[]], [ [], [[]] ] ]
@@ -114,5 +169,172 @@ fn bench_decode(c: &mut Criterion) {
 	});
 }
 
-criterion_group!(benches, bench_encode, bench_decode);
+struct Transfer {
+	nonce: u64,
+	to: [u8; 20],
+	value: u64,
+}
+
+impl rlp::Encodable for Transfer {
+	fn rlp_append(&self, s: &mut rlp::RlpStream) {
+		s.begin_list(3)
+			.append(&self.nonce)
+			.append(&self.to.as_slice())
+			.append(&self.value);
+	}
+}
+
+fn bench_stream_reuse(c: &mut Criterion) {
+	let transfers: Vec<_> = (0..1_000_000u64)
+		.map(|i| Transfer { nonce: i, to: [i as u8; 20], value: i * 3 })
+		.collect();
+	let mut group = c.benchmark_group("encode_1m_transfers");
+	group.sample_size(10);
+	group.bench_function("new_stream", |b| {
+		b.iter(|| {
+			let mut total = 0;
+			for transfer in &transfers {
+				total += rlp::encode(transfer).len();
+			}
+			total
+		})
+	});
+	group.bench_function("reused_stream", |b| {
+		let mut stream = rlp::RlpStream::new();
+		b.iter(|| {
+			let mut total = 0;
+			for transfer in &transfers {
+				stream.clear();
+				stream.append(transfer);
+				total += stream.as_raw().len();
+			}
+			total
+		})
+	});
+	group.finish();
+}
+
+struct BlockHeader {
+	parent_hash: H256,
+	beneficiary: H160,
+	number: u64,
+	gas_limit: U256,
+	extra_data: Vec<u8>,
+}
+
+struct Transaction {
+	nonce: u64,
+	gas_price: U256,
+	to: H160,
+	value: U256,
+	data: Vec<u8>,
+}
+
+struct Block {
+	header: BlockHeader,
+	transactions: Vec<Transaction>,
+}
+
+impl rlp::Encodable for BlockHeader {
+	fn rlp_append(&self, s: &mut rlp::RlpStream) {
+		s.begin_list(5)
+			.append(&self.parent_hash)
+			.append(&self.beneficiary)
+			.append(&self.number)
+			.append(&self.gas_limit)
+			.append(&self.extra_data);
+	}
+
+	fn rlp_size_hint(&self) -> usize {
+		rlp::list_size([
+			self.parent_hash.rlp_size_hint(),
+			self.beneficiary.rlp_size_hint(),
+			self.number.rlp_size_hint(),
+			self.gas_limit.rlp_size_hint(),
+			self.extra_data.rlp_size_hint(),
+		])
+	}
+}
+
+impl rlp::Encodable for Transaction {
+	fn rlp_append(&self, s: &mut rlp::RlpStream) {
+		s.begin_list(5)
+			.append(&self.nonce)
+			.append(&self.gas_price)
+			.append(&self.to)
+			.append(&self.value)
+			.append(&self.data);
+	}
+
+	fn rlp_size_hint(&self) -> usize {
+		rlp::list_size([
+			self.nonce.rlp_size_hint(),
+			self.gas_price.rlp_size_hint(),
+			self.to.rlp_size_hint(),
+			self.value.rlp_size_hint(),
+			self.data.rlp_size_hint(),
+		])
+	}
+}
+
+impl rlp::Encodable for Block {
+	fn rlp_append(&self, s: &mut rlp::RlpStream) {
+		s.begin_list(2).append(&self.header).append_list(&self.transactions);
+	}
+
+	fn rlp_size_hint(&self) -> usize {
+		let transactions = rlp::list_size(self.transactions.iter().map(rlp::Encodable::rlp_size_hint));
+		rlp::list_size([self.header.rlp_size_hint(), transactions])
+	}
+}
+
+/// A `Block` encoded without size hints.
+struct Unhinted<'a>(&'a Block);
+
+impl rlp::Encodable for Unhinted<'_> {
+	fn rlp_append(&self, s: &mut rlp::RlpStream) {
+		self.0.rlp_append(s);
+	}
+}
+
+fn bench_size_hint(c: &mut Criterion) {
+	// About 1 MiB of transactions.
+	let block = Block {
+		header: BlockHeader {
+			parent_hash: H256::repeat_byte(0x11),
+			beneficiary: H160::repeat_byte(0x22),
+			number: 19_000_000,
+			gas_limit: U256::from(30_000_000),
+			extra_data: b"rlp".to_vec(),
+		},
+		transactions: (0..4000u64)
+			.map(|i| Transaction {
+				nonce: i,
+				gas_price: U256::from(i) * 1_000_000_000u64,
+				to: H160::repeat_byte(i as u8),
+				value: U256::from(i) << 64,
+				data: vec![i as u8; 200],
+			})
+			.collect(),
+	};
+
+	let reallocations = |encode: &dyn Fn() -> usize| {
+		let before = REALLOCATIONS.load(Ordering::Relaxed);
+		let len = encode();
+		(REALLOCATIONS.load(Ordering::Relaxed) - before, len)
+	};
+	let (with_hint, len) = reallocations(&|| rlp::encode(&block).len());
+	let (without_hint, _) = reallocations(&|| rlp::encode(&Unhinted(&block)).len());
+	println!(
+		"encoding a block of {} bytes: {} reallocations without size hints, {} with",
+		len, without_hint, with_hint
+	);
+
+	let mut group = c.benchmark_group("encode_block");
+	group.bench_function("without_size_hint", |b| b.iter(|| rlp::encode(&Unhinted(&block))));
+	group.bench_function("with_size_hint", |b| b.iter(|| rlp::encode(&block)));
+	group.finish();
+}
+
+criterion_group!(benches, bench_encode, bench_decode, bench_stream_reuse, bench_size_hint);
 criterion_main!(benches);
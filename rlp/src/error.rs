@@ -6,6 +6,8 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
 use core::fmt;
 #[cfg(feature = "std")]
 use std::error::Error as StdError;
@@ -33,6 +35,18 @@ pub enum DecoderError {
 	RlpInconsistentLengthAndData,
 	/// Declared length is invalid and results in overflow
 	RlpInvalidLength,
+	/// Lists are nested deeper than the decoder's limit.
+	DepthLimitExceeded,
+	/// An item is longer than the decoder's limit.
+	LengthLimitExceeded,
+	/// A list has more items than the type decoded from it can hold.
+	RlpListTooLong,
+	/// A length is encoded with more bytes than needed, rejected by `Rlp::new_strict`.
+	RlpNonCanonicalLength,
+	/// A single byte below 0x80 has a length prefix, rejected by `Rlp::new_strict`.
+	RlpNonCanonicalSingleByte,
+	/// An integer has leading zero bytes, rejected by the views created with `Rlp::new_strict`.
+	RlpNonCanonicalInteger,
 	/// Custom rlp decoding error.
 	Custom(&'static str),
 }
@@ -49,3 +63,52 @@ impl fmt::Display for DecoderError {
 		fmt::Debug::fmt(&self, f)
 	}
 }
+
+/// Error decoding RLP with the path of the item which failed, see `decode_with_path`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct DecodeError {
+	/// What went wrong.
+	pub kind: DecoderError,
+	/// The index of the item in each list from the outermost one down to the item being decoded
+	/// when the error happened, empty for the outermost item.
+	pub path: Vec<usize>,
+}
+
+impl fmt::Display for DecodeError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self.kind {
+			DecoderError::RlpIsTooBig => write!(f, "unexpected bytes after the end of the item")?,
+			DecoderError::RlpIsTooShort => write!(f, "unexpected end of input")?,
+			DecoderError::RlpExpectedToBeList => write!(f, "expected list")?,
+			DecoderError::RlpExpectedToBeData => write!(f, "expected data")?,
+			DecoderError::RlpIncorrectListLen => write!(f, "unexpected number of list items")?,
+			DecoderError::RlpDataLenWithZeroPrefix => write!(f, "leading zero byte in data length")?,
+			DecoderError::RlpListLenWithZeroPrefix => write!(f, "leading zero byte in list length")?,
+			DecoderError::RlpInvalidIndirection => write!(f, "non-canonical encoding")?,
+			DecoderError::RlpInconsistentLengthAndData => write!(f, "length inconsistent with data")?,
+			DecoderError::RlpInvalidLength => write!(f, "invalid length")?,
+			DecoderError::DepthLimitExceeded => write!(f, "lists nested too deeply")?,
+			DecoderError::LengthLimitExceeded => write!(f, "item too long")?,
+			DecoderError::RlpListTooLong => write!(f, "too many list items")?,
+			DecoderError::RlpNonCanonicalLength => write!(f, "non-minimal length")?,
+			DecoderError::RlpNonCanonicalSingleByte => write!(f, "single byte with a length prefix")?,
+			DecoderError::RlpNonCanonicalInteger => write!(f, "integer with leading zero bytes")?,
+			DecoderError::Custom(msg) => write!(f, "{}", msg)?,
+		}
+		if self.path.is_empty() {
+			return write!(f, " at the root")
+		}
+		write!(f, " at path ")?;
+		for index in &self.path {
+			write!(f, "[{}]", index)?;
+		}
+		Ok(())
+	}
+}
+
+#[cfg(feature = "std")]
+impl StdError for DecodeError {
+	fn source(&self) -> Option<&(dyn StdError + 'static)> {
+		Some(&self.kind)
+	}
+}
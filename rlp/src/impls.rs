@@ -7,18 +7,26 @@
 // except according to those terms.
 
 #[cfg(not(feature = "std"))]
-use alloc::{borrow::ToOwned, boxed::Box, string::String, vec::Vec};
+use alloc::{
+	borrow::{Cow, ToOwned},
+	boxed::Box,
+	string::String,
+	sync::Arc,
+	vec::Vec,
+};
 use bytes::{Bytes, BytesMut};
 use core::{
 	iter::{empty, once},
 	mem, str,
 };
+#[cfg(feature = "std")]
+use std::{borrow::Cow, sync::Arc};
 
 use crate::{
 	error::DecoderError,
 	rlpin::Rlp,
-	stream::RlpStream,
-	traits::{Decodable, Encodable},
+	stream::{data_size, list_size, RlpStream},
+	traits::{Decodable, DecodableBorrowed, Encodable},
 };
 
 pub fn decode_usize(bytes: &[u8]) -> Result<usize, DecoderError> {
@@ -42,6 +50,10 @@ impl<T: Encodable + ?Sized> Encodable for Box<T> {
 	fn rlp_append(&self, s: &mut RlpStream) {
 		Encodable::rlp_append(&**self, s)
 	}
+
+	fn rlp_size_hint(&self) -> usize {
+		(**self).rlp_size_hint()
+	}
 }
 
 impl<T: Decodable> Decodable for Box<T> {
@@ -50,11 +62,31 @@ impl<T: Decodable> Decodable for Box<T> {
 	}
 }
 
+impl<T: Encodable + ?Sized> Encodable for Arc<T> {
+	fn rlp_append(&self, s: &mut RlpStream) {
+		Encodable::rlp_append(&**self, s)
+	}
+
+	fn rlp_size_hint(&self) -> usize {
+		(**self).rlp_size_hint()
+	}
+}
+
+impl<T: Decodable> Decodable for Arc<T> {
+	fn decode(rlp: &Rlp) -> Result<Self, DecoderError> {
+		T::decode(rlp).map(Arc::new)
+	}
+}
+
 impl Encodable for bool {
 	fn rlp_append(&self, s: &mut RlpStream) {
 		let as_uint = u8::from(*self);
 		Encodable::rlp_append(&as_uint, s);
 	}
+
+	fn rlp_size_hint(&self) -> usize {
+		1
+	}
 }
 
 impl Decodable for bool {
@@ -72,12 +104,20 @@ impl<'a> Encodable for &'a [u8] {
 	fn rlp_append(&self, s: &mut RlpStream) {
 		s.encoder().encode_value(self);
 	}
+
+	fn rlp_size_hint(&self) -> usize {
+		data_size(self)
+	}
 }
 
 impl Encodable for Vec<u8> {
 	fn rlp_append(&self, s: &mut RlpStream) {
 		s.encoder().encode_value(self);
 	}
+
+	fn rlp_size_hint(&self) -> usize {
+		data_size(self)
+	}
 }
 
 impl Decodable for Vec<u8> {
@@ -86,10 +126,37 @@ impl Decodable for Vec<u8> {
 	}
 }
 
+impl<'a> DecodableBorrowed<'a> for &'a [u8] {
+	fn decode_borrowed(rlp: &Rlp<'a>) -> Result<Self, DecoderError> {
+		rlp.decoder().value()
+	}
+}
+
+impl<'a> Encodable for Cow<'a, [u8]> {
+	fn rlp_append(&self, s: &mut RlpStream) {
+		s.encoder().encode_value(self);
+	}
+
+	fn rlp_size_hint(&self) -> usize {
+		data_size(self)
+	}
+}
+
+/// Decodes into `Cow::Owned`, use `&[u8]` to borrow the payload.
+impl<'a> Decodable for Cow<'a, [u8]> {
+	fn decode(rlp: &Rlp) -> Result<Self, DecoderError> {
+		rlp.decoder().decode_value(|bytes| Ok(Cow::Owned(bytes.to_vec())))
+	}
+}
+
 impl Encodable for Bytes {
 	fn rlp_append(&self, s: &mut RlpStream) {
 		s.encoder().encode_value(self);
 	}
+
+	fn rlp_size_hint(&self) -> usize {
+		data_size(self)
+	}
 }
 
 impl Decodable for Bytes {
@@ -102,6 +169,10 @@ impl Encodable for BytesMut {
 	fn rlp_append(&self, s: &mut RlpStream) {
 		s.encoder().encode_value(self);
 	}
+
+	fn rlp_size_hint(&self) -> usize {
+		data_size(self)
+	}
 }
 
 impl Decodable for BytesMut {
@@ -110,6 +181,8 @@ impl Decodable for BytesMut {
 	}
 }
 
+/// `None` is encoded as the empty list and `Some(value)` as the list of the single item `value`,
+/// unlike the empty data item used for missing values in some Ethereum structures.
 impl<T> Encodable for Option<T>
 where
 	T: Encodable,
@@ -125,8 +198,16 @@ where
 			},
 		}
 	}
+
+	fn rlp_size_hint(&self) -> usize {
+		match *self {
+			None => 1,
+			Some(ref value) => list_size(once(value.rlp_size_hint())),
+		}
+	}
 }
 
+/// Decodes the empty list as `None` and a list of one item as `Some`.
 impl<T> Decodable for Option<T>
 where
 	T: Decodable,
@@ -149,6 +230,23 @@ impl Encodable for u8 {
 			s.encoder().encode_iter(empty());
 		}
 	}
+
+	fn rlp_size_hint(&self) -> usize {
+		if *self < 0x80 {
+			1
+		} else {
+			2
+		}
+	}
+}
+
+/// The error for an integer with leading zero bytes.
+fn leading_zeros_error(rlp: &Rlp) -> DecoderError {
+	if rlp.is_strict() {
+		DecoderError::RlpNonCanonicalInteger
+	} else {
+		DecoderError::RlpInvalidIndirection
+	}
 }
 
 impl Decodable for u8 {
@@ -156,7 +254,7 @@ impl Decodable for u8 {
 		rlp.decoder().decode_value(|bytes| match bytes.len() {
 			1 if bytes[0] != 0 => Ok(bytes[0]),
 			0 => Ok(0),
-			1 => Err(DecoderError::RlpInvalidIndirection),
+			1 => Err(leading_zeros_error(rlp)),
 			_ => Err(DecoderError::RlpIsTooBig),
 		})
 	}
@@ -170,6 +268,11 @@ macro_rules! impl_encodable_for_u {
 				let buffer = self.to_be_bytes();
 				s.encoder().encode_value(&buffer[leading_empty_bytes..]);
 			}
+
+			fn rlp_size_hint(&self) -> usize {
+				let leading_empty_bytes = self.leading_zeros() as usize / 8;
+				data_size(&self.to_be_bytes()[leading_empty_bytes..])
+			}
 		}
 	};
 }
@@ -182,7 +285,7 @@ macro_rules! impl_decodable_for_u {
 					0 | 1 => u8::decode(rlp).map(|v| v as $name),
 					l if l <= mem::size_of::<$name>() => {
 						if bytes[0] == 0 {
-							return Err(DecoderError::RlpInvalidIndirection)
+							return Err(leading_zeros_error(rlp))
 						}
 						let mut res = 0 as $name;
 						for (i, byte) in bytes.iter().enumerate().take(l) {
@@ -212,6 +315,10 @@ impl Encodable for usize {
 	fn rlp_append(&self, s: &mut RlpStream) {
 		(*self as u64).rlp_append(s);
 	}
+
+	fn rlp_size_hint(&self) -> usize {
+		(*self as u64).rlp_size_hint()
+	}
 }
 
 impl Decodable for usize {
@@ -224,12 +331,20 @@ impl<'a> Encodable for &'a str {
 	fn rlp_append(&self, s: &mut RlpStream) {
 		s.encoder().encode_value(self.as_bytes());
 	}
+
+	fn rlp_size_hint(&self) -> usize {
+		data_size(self.as_bytes())
+	}
 }
 
 impl Encodable for String {
 	fn rlp_append(&self, s: &mut RlpStream) {
 		s.encoder().encode_value(self.as_bytes());
 	}
+
+	fn rlp_size_hint(&self) -> usize {
+		data_size(self.as_bytes())
+	}
 }
 
 impl Decodable for String {
@@ -243,3 +358,79 @@ impl Decodable for String {
 		})
 	}
 }
+
+impl<'a> DecodableBorrowed<'a> for &'a str {
+	fn decode_borrowed(rlp: &Rlp<'a>) -> Result<Self, DecoderError> {
+		// the same error as for `String`
+		str::from_utf8(rlp.decoder().value()?).map_err(|_| DecoderError::RlpExpectedToBeData)
+	}
+}
+
+/// Encoded as a list of exactly `N` items. Unlike `Vec<u8>`, `[u8; N]` is a list of integers rather
+/// than a data item.
+impl<T: Encodable, const N: usize> Encodable for [T; N] {
+	fn rlp_append(&self, s: &mut RlpStream) {
+		s.begin_list(N);
+		for item in self {
+			s.append(item);
+		}
+	}
+
+	fn rlp_size_hint(&self) -> usize {
+		list_size(self.iter().map(Encodable::rlp_size_hint))
+	}
+}
+
+/// Decodes a list of exactly `N` items, failing with `DecoderError::RlpIncorrectListLen` for any
+/// other number of items.
+impl<T: Decodable, const N: usize> Decodable for [T; N] {
+	fn decode(rlp: &Rlp) -> Result<Self, DecoderError> {
+		let items = rlp.indexed()?;
+		if items.item_count() != N {
+			return Err(DecoderError::RlpIncorrectListLen)
+		}
+		let items = items.iter().map(|item| item.as_val()).collect::<Result<Vec<T>, _>>()?;
+		items.try_into().map_err(|_| DecoderError::RlpIncorrectListLen)
+	}
+}
+
+macro_rules! impl_rlp_for_tuple {
+	($len: expr, $($name: ident $index: tt),+) => {
+		/// Encoded as a list of the items of the tuple.
+		impl<$($name: Encodable),+> Encodable for ($($name,)+) {
+			fn rlp_append(&self, s: &mut RlpStream) {
+				s.begin_list($len);
+				$(s.append(&self.$index);)+
+			}
+
+			fn rlp_size_hint(&self) -> usize {
+				list_size([$(self.$index.rlp_size_hint()),+])
+			}
+		}
+
+		/// Decodes a list with exactly as many items as the tuple, failing with
+		/// `DecoderError::RlpIncorrectListLen` for any other number of items.
+		impl<$($name: Decodable),+> Decodable for ($($name,)+) {
+			fn decode(rlp: &Rlp) -> Result<Self, DecoderError> {
+				let items = rlp.indexed()?;
+				if items.item_count() != $len {
+					return Err(DecoderError::RlpIncorrectListLen)
+				}
+				Ok(($(items.val_at($index)?,)+))
+			}
+		}
+	};
+}
+
+impl_rlp_for_tuple!(1, A 0);
+impl_rlp_for_tuple!(2, A 0, B 1);
+impl_rlp_for_tuple!(3, A 0, B 1, C 2);
+impl_rlp_for_tuple!(4, A 0, B 1, C 2, D 3);
+impl_rlp_for_tuple!(5, A 0, B 1, C 2, D 3, E 4);
+impl_rlp_for_tuple!(6, A 0, B 1, C 2, D 3, E 4, F 5);
+impl_rlp_for_tuple!(7, A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_rlp_for_tuple!(8, A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+impl_rlp_for_tuple!(9, A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8);
+impl_rlp_for_tuple!(10, A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9);
+impl_rlp_for_tuple!(11, A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10);
+impl_rlp_for_tuple!(12, A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11);
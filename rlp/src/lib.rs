@@ -31,6 +31,9 @@
 //! * You are working on input data.
 //! * You want to get view onto rlp-slice.
 //! * You don't want to decode whole rlp at once.
+//!
+//! ### Use `RlpStreamReader` when:
+//! * Your input is too large to read into memory at once.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -39,6 +42,8 @@ extern crate alloc;
 
 mod error;
 mod impls;
+#[cfg(feature = "std")]
+mod reader;
 mod rlpin;
 mod stream;
 mod traits;
@@ -51,11 +56,15 @@ use core::borrow::Borrow;
 #[cfg(feature = "derive")]
 pub use rlp_derive::{RlpDecodable, RlpDecodableWrapper, RlpEncodable, RlpEncodableWrapper};
 
+#[cfg(feature = "std")]
+pub use self::reader::{ReaderError, RlpEvent, RlpStreamReader, DEFAULT_MAX_ITEM_SIZE};
 pub use self::{
-	error::DecoderError,
-	rlpin::{PayloadInfo, Prototype, Rlp, RlpIterator},
-	stream::RlpStream,
-	traits::{Decodable, Encodable},
+	error::{DecodeError, DecoderError},
+	rlpin::{
+		IndexedRlp, IndexedRlpIterator, MaxDepth, MaxLen, PayloadInfo, Prototype, Rlp, RlpIterator, DEFAULT_MAX_DEPTH,
+	},
+	stream::{data_size, list_size, RlpStream, UnboundedListGuard},
+	traits::{Decodable, DecodableBorrowed, Encodable},
 };
 
 /// The RLP encoded empty data (used to mean "null value").
@@ -78,6 +87,38 @@ where
 	rlp.as_val()
 }
 
+/// Decodes like `decode`, failing unless `bytes` are the canonical encoding of the value, see
+/// `Rlp::new_strict`.
+///
+/// ```
+/// assert_eq!(rlp::decode::<u64>(&[0x05, 0x06]), Ok(5));
+/// assert_eq!(rlp::decode_strict::<u64>(&[0x05, 0x06]), Err(rlp::DecoderError::RlpIsTooBig));
+/// assert_eq!(rlp::decode_strict::<u64>(&[0x82, 0x00, 0x05]), Err(rlp::DecoderError::RlpNonCanonicalInteger));
+/// ```
+pub fn decode_strict<T>(bytes: &[u8]) -> Result<T, DecoderError>
+where
+	T: Decodable,
+{
+	let rlp = Rlp::new_strict(bytes)?;
+	rlp.as_val()
+}
+
+/// Decodes like `decode`, returning the path to the item which failed with any error.
+///
+/// ```
+/// let data = vec![0xc1, 0xc0];
+/// let err = rlp::decode_with_path::<Option<u64>>(&data).unwrap_err();
+/// assert_eq!(err.path, vec![0]);
+/// assert_eq!(err.to_string(), "expected data at path [0]");
+/// ```
+pub fn decode_with_path<T>(bytes: &[u8]) -> Result<T, DecodeError>
+where
+	T: Decodable,
+{
+	let rlp = Rlp::new(bytes);
+	rlp.as_val_with_path()
+}
+
 pub fn decode_list<T>(bytes: &[u8]) -> Vec<T>
 where
 	T: Decodable,
@@ -97,7 +138,7 @@ pub fn encode<E>(object: &E) -> BytesMut
 where
 	E: Encodable,
 {
-	let mut stream = RlpStream::new();
+	let mut stream = RlpStream::with_size_hint(object.rlp_size_hint());
 	stream.append(object);
 	stream.out()
 }
@@ -107,7 +148,8 @@ where
 	E: Encodable,
 	K: Borrow<E>,
 {
-	let mut stream = RlpStream::new();
+	let size_hint = list_size(object.iter().map(|value| value.borrow().rlp_size_hint()));
+	let mut stream = RlpStream::with_size_hint(size_hint);
 	stream.append_list(object);
 	stream.out()
 }
@@ -0,0 +1,254 @@
+// Copyright 2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Streaming decoding of RLP read from an `io::Read`.
+
+use std::{error::Error as StdError, fmt, io};
+
+use crate::{error::DecoderError, rlpin::PayloadInfo, DEFAULT_MAX_DEPTH};
+
+/// The default limit on the size of the data read at once by `RlpStreamReader`.
+pub const DEFAULT_MAX_ITEM_SIZE: usize = 16 * 1024 * 1024;
+
+/// A part of the RLP read by `RlpStreamReader::next_event`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RlpEvent {
+	/// The start of a list whose items take `payload_len` bytes.
+	ListStart {
+		/// Length of the encoded items of the list in bytes.
+		payload_len: usize,
+	},
+	/// A data item.
+	Data(Vec<u8>),
+	/// The end of the innermost list.
+	ListEnd,
+}
+
+/// Error reading RLP from an `io::Read`.
+#[derive(Debug)]
+pub enum ReaderError {
+	/// Reading failed.
+	Io(io::Error),
+	/// The input isn't valid RLP.
+	Decoder(DecoderError),
+	/// An item is larger than the limit of the reader.
+	ItemTooBig {
+		/// Size of the item in bytes.
+		size: usize,
+		/// The limit of the reader.
+		max: usize,
+	},
+}
+
+impl fmt::Display for ReaderError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			ReaderError::Io(err) => write!(f, "failed to read rlp: {}", err),
+			ReaderError::Decoder(err) => write!(f, "invalid rlp: {}", err),
+			ReaderError::ItemTooBig { size, max } => {
+				write!(f, "rlp item of {} bytes is larger than the limit of {} bytes", size, max)
+			},
+		}
+	}
+}
+
+impl StdError for ReaderError {
+	fn source(&self) -> Option<&(dyn StdError + 'static)> {
+		match self {
+			ReaderError::Io(err) => Some(err),
+			ReaderError::Decoder(err) => Some(err),
+			ReaderError::ItemTooBig { .. } => None,
+		}
+	}
+}
+
+impl From<io::Error> for ReaderError {
+	fn from(err: io::Error) -> Self {
+		// Running out of input in the middle of an item is invalid RLP.
+		if err.kind() == io::ErrorKind::UnexpectedEof {
+			ReaderError::Decoder(DecoderError::RlpIsTooShort)
+		} else {
+			ReaderError::Io(err)
+		}
+	}
+}
+
+impl From<DecoderError> for ReaderError {
+	fn from(err: DecoderError) -> Self {
+		ReaderError::Decoder(err)
+	}
+}
+
+/// The header of an item read from the input.
+struct Header {
+	bytes: [u8; 9],
+	info: PayloadInfo,
+	/// Size of the whole item in bytes.
+	total: usize,
+	is_list: bool,
+}
+
+impl Header {
+	fn bytes(&self) -> &[u8] {
+		&self.bytes[..self.info.header_len]
+	}
+}
+
+/// Pull-based decoder of RLP read from an `io::Read`, for inputs too large to be read into memory.
+///
+/// The input may hold any number of items one after the other. Headers are checked as they are read
+/// with the same rules as `Rlp`, and lists may be nested up to `DEFAULT_MAX_DEPTH` deep. No more
+/// than the maximum item size is allocated for any item, whatever its header claims.
+///
+/// ```
+/// use rlp::{RlpEvent, RlpStreamReader};
+/// let data = [0xc5, 0x83, b'c', b'a', b't', 0x01];
+/// let mut reader = RlpStreamReader::new(&data[..]);
+/// assert_eq!(reader.next_event().unwrap(), Some(RlpEvent::ListStart { payload_len: 5 }));
+/// assert_eq!(reader.next_event().unwrap(), Some(RlpEvent::Data(b"cat".to_vec())));
+/// assert_eq!(reader.next_event().unwrap(), Some(RlpEvent::Data(vec![1])));
+/// assert_eq!(reader.next_event().unwrap(), Some(RlpEvent::ListEnd));
+/// assert_eq!(reader.next_event().unwrap(), None);
+/// ```
+pub struct RlpStreamReader<R> {
+	reader: R,
+	max_item_size: usize,
+	/// The number of bytes left to read of each open list, the innermost last.
+	open_lists: Vec<usize>,
+}
+
+impl<R: io::Read> RlpStreamReader<R> {
+	/// Creates a reader which reads items of up to `DEFAULT_MAX_ITEM_SIZE` bytes.
+	pub fn new(reader: R) -> Self {
+		Self::new_with_max_item_size(reader, DEFAULT_MAX_ITEM_SIZE)
+	}
+
+	/// Creates a reader which reads items of up to `max_item_size` bytes.
+	///
+	/// The limit applies to the payloads of data items returned by `next_event` and to the whole
+	/// items returned by `next_item`, but not to lists read with `next_event`, which aren't held in
+	/// memory.
+	pub fn new_with_max_item_size(reader: R, max_item_size: usize) -> Self {
+		RlpStreamReader { reader, max_item_size, open_lists: Vec::new() }
+	}
+
+	/// Returns the next part of the input, or `None` at its end.
+	pub fn next_event(&mut self) -> Result<Option<RlpEvent>, ReaderError> {
+		if self.open_lists.last() == Some(&0) {
+			self.open_lists.pop();
+			return Ok(Some(RlpEvent::ListEnd))
+		}
+		let header = match self.read_header()? {
+			Some(header) => header,
+			None => return Ok(None),
+		};
+
+		if header.is_list {
+			if self.open_lists.len() >= DEFAULT_MAX_DEPTH {
+				return Err(DecoderError::DepthLimitExceeded.into())
+			}
+			self.open_lists.push(header.info.value_len);
+			return Ok(Some(RlpEvent::ListStart { payload_len: header.info.value_len }))
+		}
+
+		if header.info.header_len == 0 {
+			// A single byte below 0x80 is its own encoding.
+			return Ok(Some(RlpEvent::Data(header.bytes[..1].to_vec())))
+		}
+		let value = self.read_value(header.info.value_len, Vec::new())?;
+		if header.bytes[0] == 0x81 && value[0] < 0x80 {
+			return Err(DecoderError::RlpInvalidIndirection.into())
+		}
+		Ok(Some(RlpEvent::Data(value)))
+	}
+
+	/// Returns the encoding of the next item, including its header, to be decoded with `Rlp`, or
+	/// `None` at the end of the input or of the innermost list opened with `next_event`.
+	///
+	/// ```
+	/// use rlp::{Rlp, RlpStreamReader};
+	/// let data = [0xc5, 0x83, b'c', b'a', b't', 0x01, 0x83, b'd', b'o', b'g'];
+	/// let mut reader = RlpStreamReader::new(&data[..]);
+	/// let item = reader.next_item().unwrap().unwrap();
+	/// assert_eq!(Rlp::new(&item).val_at::<String>(0), Ok("cat".to_owned()));
+	/// assert_eq!(reader.next_item().unwrap().unwrap(), b"\x83dog");
+	/// assert_eq!(reader.next_item().unwrap(), None);
+	/// ```
+	pub fn next_item(&mut self) -> Result<Option<Vec<u8>>, ReaderError> {
+		if self.open_lists.last() == Some(&0) {
+			return Ok(None)
+		}
+		let header = match self.read_header()? {
+			Some(header) => header,
+			None => return Ok(None),
+		};
+
+		if header.info.header_len == 0 {
+			return Ok(Some(header.bytes[..1].to_vec()))
+		}
+		let item = self.read_value(header.total, header.bytes().to_vec())?;
+		Ok(Some(item))
+	}
+
+	/// Returns the underlying reader.
+	pub fn into_inner(self) -> R {
+		self.reader
+	}
+
+	/// Reads and checks the header of the next item, and counts the item as read from the
+	/// innermost list.
+	fn read_header(&mut self) -> Result<Option<Header>, ReaderError> {
+		let mut bytes = [0u8; 9];
+		match self.read_byte()? {
+			Some(byte) => bytes[0] = byte,
+			// The input may only end between items at the top level.
+			None if self.open_lists.is_empty() => return Ok(None),
+			None => return Err(DecoderError::RlpIsTooShort.into()),
+		}
+
+		let len_of_len = match bytes[0] {
+			0xb8..=0xbf => bytes[0] as usize - 0xb7,
+			0xf8..=0xff => bytes[0] as usize - 0xf7,
+			_ => 0,
+		};
+		self.reader.read_exact(&mut bytes[1..1 + len_of_len])?;
+		let info = PayloadInfo::from(&bytes[..1 + len_of_len])?;
+
+		let total = info
+			.header_len
+			.checked_add(info.value_len)
+			.ok_or(DecoderError::RlpInvalidLength)?;
+		if let Some(left) = self.open_lists.last_mut() {
+			*left = left.checked_sub(total).ok_or(DecoderError::RlpIsTooShort)?;
+		}
+		Ok(Some(Header { bytes, info, total, is_list: bytes[0] >= 0xc0 }))
+	}
+
+	/// Reads the rest of an item of `size` bytes, after the bytes already in `buffer`.
+	fn read_value(&mut self, size: usize, mut buffer: Vec<u8>) -> Result<Vec<u8>, ReaderError> {
+		if size > self.max_item_size {
+			return Err(ReaderError::ItemTooBig { size, max: self.max_item_size })
+		}
+		let start = buffer.len();
+		buffer.resize(size, 0);
+		self.reader.read_exact(&mut buffer[start..])?;
+		Ok(buffer)
+	}
+
+	fn read_byte(&mut self) -> io::Result<Option<u8>> {
+		let mut byte = [0u8];
+		loop {
+			match self.reader.read(&mut byte) {
+				Ok(0) => return Ok(None),
+				Ok(_) => return Ok(Some(byte[0])),
+				Err(err) if err.kind() == io::ErrorKind::Interrupted => {},
+				Err(err) => return Err(err),
+			}
+		}
+	}
+}
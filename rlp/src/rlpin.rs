@@ -8,11 +8,18 @@
 
 #[cfg(not(feature = "std"))]
 use alloc::{string::String, vec::Vec};
-use core::{cell::Cell, fmt};
+use core::{
+	cell::{Cell, RefCell},
+	fmt, iter, slice,
+};
 
 use rustc_hex::ToHex;
 
-use crate::{error::DecoderError, impls::decode_usize, traits::Decodable};
+use crate::{
+	error::{DecodeError, DecoderError},
+	impls::decode_usize,
+	traits::{Decodable, DecodableBorrowed},
+};
 
 /// rlp offset
 #[derive(Copy, Clone, Debug)]
@@ -64,6 +71,53 @@ fn calculate_payload_info(header_bytes: &[u8], len_of_len: usize) -> Result<Payl
 	Ok(PayloadInfo::new(header_len, value_len))
 }
 
+/// Checks that `bytes` is a single item with canonical headers and lists nested up to `max_depth`
+/// deep.
+fn check_canonical(bytes: &[u8], max_depth: usize) -> Result<(), DecoderError> {
+	// The end of each open list, the innermost last.
+	let mut list_ends = Vec::new();
+	let mut offset = 0;
+	loop {
+		let end = list_ends.last().copied().unwrap_or(bytes.len());
+		let item = &bytes[offset..end];
+		let info = match PayloadInfo::from(item) {
+			Err(DecoderError::RlpDataLenWithZeroPrefix) | Err(DecoderError::RlpInvalidIndirection) =>
+				return Err(DecoderError::RlpNonCanonicalLength),
+			info => info?,
+		};
+		let total = info
+			.header_len
+			.checked_add(info.value_len)
+			.ok_or(DecoderError::RlpInvalidLength)?;
+		if total > item.len() {
+			return Err(DecoderError::RlpIsTooShort)
+		}
+		if item[0] == 0x81 && item[1] < 0x80 {
+			return Err(DecoderError::RlpNonCanonicalSingleByte)
+		}
+
+		if item[0] >= 0xc0 {
+			if info.value_len > 0 && list_ends.len() >= max_depth {
+				return Err(DecoderError::DepthLimitExceeded)
+			}
+			list_ends.push(offset + total);
+			offset += info.header_len;
+		} else {
+			offset += total;
+		}
+		while list_ends.last() == Some(&offset) {
+			list_ends.pop();
+		}
+		if list_ends.is_empty() {
+			break
+		}
+	}
+	if offset < bytes.len() {
+		return Err(DecoderError::RlpIsTooBig)
+	}
+	Ok(())
+}
+
 impl PayloadInfo {
 	const fn new(header_len: usize, value_len: usize) -> PayloadInfo {
 		PayloadInfo { header_len, value_len }
@@ -93,6 +147,20 @@ impl PayloadInfo {
 	}
 }
 
+/// The default limit on how deeply lists may be nested in decoded RLP, see `Rlp::new_with_limits`.
+///
+/// Far deeper than any structure in practical use, while bounding the recursion of `Decodable`
+/// implementations for nested types to a few hundred levels.
+pub const DEFAULT_MAX_DEPTH: usize = 256;
+
+/// The limit on how deeply lists may be nested, see `Rlp::new_with_limits`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MaxDepth(pub usize);
+
+/// The limit on the length in bytes of the payload of any item, see `Rlp::new_with_limits`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MaxLen(pub usize);
+
 /// Data-oriented view onto rlp-slice.
 ///
 /// This is an immutable structure. No operations change it.
@@ -104,8 +172,18 @@ pub struct Rlp<'a> {
 	bytes: &'a [u8],
 	offset_cache: Cell<Option<OffsetCache>>,
 	count_cache: Cell<Option<usize>>,
+	depth: usize,
+	max_depth: usize,
+	max_len: usize,
+	path: Option<&'a PathTracker>,
+	strict: bool,
 }
 
+/// The indexes of the items being decoded, shared by the views created from the same root by
+/// `decode_with_path`.
+#[derive(Debug, Default)]
+struct PathTracker(RefCell<Vec<usize>>);
+
 impl<'a> fmt::Display for Rlp<'a> {
 	fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
 		match self.prototype() {
@@ -125,8 +203,70 @@ impl<'a> fmt::Display for Rlp<'a> {
 }
 
 impl<'a> Rlp<'a> {
+	/// Creates a view onto `bytes` which can open lists nested up to `DEFAULT_MAX_DEPTH` deep, with
+	/// items of any length.
 	pub const fn new(bytes: &'a [u8]) -> Rlp<'a> {
-		Rlp { bytes, offset_cache: Cell::new(None), count_cache: Cell::new(None) }
+		Self::new_with_limits(bytes, MaxDepth(DEFAULT_MAX_DEPTH), MaxLen(usize::MAX))
+	}
+
+	/// Creates a view onto `bytes` which can open lists nested up to `max_depth` deep and read
+	/// items whose payload is up to `max_len` bytes long.
+	///
+	/// The items of a list are one level deeper than the list, the outermost item being at depth 0.
+	/// Getting the items of a list at depth `max_depth`, with `item_count`, `at`, `try_iter` or
+	/// `as_list`, fails with `DecoderError::DepthLimitExceeded`, which bounds the recursion of
+	/// `Decodable` implementations for nested types on untrusted input. Getting the items of a
+	/// longer list or the payload of a longer data item fails with
+	/// `DecoderError::LengthLimitExceeded`. `iter` yields no items from a list it can't read.
+	///
+	/// ```
+	/// use rlp::{DecoderError, MaxDepth, MaxLen, Rlp};
+	/// let data = [0xc2, 0xc1, 0xc0];
+	/// let rlp = Rlp::new_with_limits(&data, MaxDepth(2), MaxLen(2));
+	/// assert_eq!(rlp.at(0).unwrap().item_count(), Ok(1));
+	/// let rlp = Rlp::new_with_limits(&data, MaxDepth(1), MaxLen(2));
+	/// assert_eq!(rlp.at(0).unwrap().item_count(), Err(DecoderError::DepthLimitExceeded));
+	/// let rlp = Rlp::new_with_limits(&data, MaxDepth(2), MaxLen(1));
+	/// assert_eq!(rlp.item_count(), Err(DecoderError::LengthLimitExceeded));
+	/// ```
+	pub const fn new_with_limits(bytes: &'a [u8], max_depth: MaxDepth, max_len: MaxLen) -> Rlp<'a> {
+		Rlp {
+			bytes,
+			offset_cache: Cell::new(None),
+			count_cache: Cell::new(None),
+			depth: 0,
+			max_depth: max_depth.0,
+			max_len: max_len.0,
+			path: None,
+			strict: false,
+		}
+	}
+
+	/// Creates a view onto `bytes` after checking that they are a single item in canonical form.
+	///
+	/// Fails with `DecoderError::RlpNonCanonicalLength` for a length encoded with more bytes than
+	/// needed, `DecoderError::RlpNonCanonicalSingleByte` for a byte below 0x80 with a length prefix
+	/// and `DecoderError::RlpIsTooBig` for bytes after the item. `new` accepts non-minimal lengths of
+	/// data items and bytes after the item, and only checks the items which are read. The integer `Decodable` implementations of this crate
+	/// fail with `DecoderError::RlpNonCanonicalInteger` for leading zero bytes in items of the
+	/// returned view, so no two encodings of the same value decode successfully.
+	///
+	/// ```
+	/// use rlp::{DecoderError, Rlp};
+	/// let data = [0xb8, 0x01, 0xff];
+	/// assert_eq!(Rlp::new(&data).as_val::<Vec<u8>>(), Ok(vec![0xff]));
+	/// assert_eq!(Rlp::new_strict(&data).unwrap_err(), DecoderError::RlpNonCanonicalLength);
+	/// assert_eq!(Rlp::new_strict(&[0x81, 0xff]).unwrap().data(), Ok(&[0xff][..]));
+	/// ```
+	pub fn new_strict(bytes: &'a [u8]) -> Result<Rlp<'a>, DecoderError> {
+		check_canonical(bytes, DEFAULT_MAX_DEPTH)?;
+		Ok(Rlp { strict: true, ..Rlp::new(bytes) })
+	}
+
+	/// Returns whether this view was created with `new_strict`, in which case `Decodable`
+	/// implementations should reject the encodings of values which aren't canonical.
+	pub fn is_strict(&self) -> bool {
+		self.strict
 	}
 
 	pub fn as_raw<'view>(&'view self) -> &'a [u8]
@@ -156,11 +296,13 @@ impl<'a> Rlp<'a> {
 		'a: 'view,
 	{
 		let pi = BasicDecoder::payload_info(self.bytes)?;
+		self.check_len(pi.value_len)?;
 		Ok(&self.bytes[pi.header_len..(pi.header_len + pi.value_len)])
 	}
 
 	pub fn item_count(&self) -> Result<usize, DecoderError> {
 		if self.is_list() {
+			self.check_limits()?;
 			match self.count_cache.get() {
 				Some(c) => Ok(c),
 				None => {
@@ -205,6 +347,7 @@ impl<'a> Rlp<'a> {
 		if !self.is_list() {
 			return Err(DecoderError::RlpExpectedToBeList)
 		}
+		self.check_limits()?;
 
 		// move to cached position if its index is less or equal to
 		// current search index, otherwise move to beginning of list
@@ -227,7 +370,38 @@ impl<'a> Rlp<'a> {
 
 		// construct new rlp
 		let found = BasicDecoder::payload_info(bytes)?;
-		Ok((Rlp::new(&bytes[0..found.header_len + found.value_len]), offset))
+		Ok((self.item(index, &bytes[0..found.header_len + found.value_len]), offset))
+	}
+
+	/// Returns an index of the items of this list, for constant time access to any of them.
+	///
+	/// `at` is fast when the items are read in increasing order, but walks the list from its
+	/// beginning whenever an earlier item is read. Building the index walks the list once, and
+	/// fails if any of its items is malformed.
+	///
+	/// ```
+	/// use rlp::Rlp;
+	/// let data = [0xc3, 0x01, 0x02, 0x03];
+	/// let list = Rlp::new(&data).indexed().unwrap();
+	/// assert_eq!(list.item_count(), 3);
+	/// assert_eq!(list.val_at::<u8>(2), Ok(3));
+	/// assert_eq!(list.val_at::<u8>(0), Ok(1));
+	/// ```
+	pub fn indexed(&self) -> Result<IndexedRlp<'a>, DecoderError> {
+		if !self.is_list() {
+			return Err(DecoderError::RlpExpectedToBeList)
+		}
+		self.check_limits()?;
+
+		let (mut bytes, mut offset) = self.consume_list_payload()?;
+		let mut items = Vec::new();
+		while !bytes.is_empty() {
+			let len = BasicDecoder::payload_info(bytes)?.total();
+			bytes = Rlp::consume(bytes, len)?;
+			items.push((offset, len));
+			offset += len;
+		}
+		Ok(IndexedRlp { rlp: self.clone(), items })
 	}
 
 	pub fn is_null(&self) -> bool {
@@ -262,6 +436,8 @@ impl<'a> Rlp<'a> {
 		}
 	}
 
+	/// Returns an iterator over the items of this list, which ends at the first item which can't be
+	/// read, see `try_iter`.
 	pub fn iter<'view>(&'view self) -> RlpIterator<'a, 'view>
 	where
 		'a: 'view,
@@ -269,36 +445,194 @@ impl<'a> Rlp<'a> {
 		self.into_iter()
 	}
 
+	/// Returns an iterator over the items of this list like `iter`, after checking that the list can
+	/// be read.
+	///
+	/// `iter` yields no items from a list which exceeds the limits of the view, while this fails
+	/// with `DecoderError::DepthLimitExceeded` or `DecoderError::LengthLimitExceeded`.
+	///
+	/// ```
+	/// use rlp::{DecoderError, MaxDepth, MaxLen, Rlp};
+	/// let data = [0xc2, 0xc1, 0xc0];
+	/// let inner = Rlp::new_with_limits(&data, MaxDepth(1), MaxLen(usize::MAX)).at(0).unwrap();
+	/// assert_eq!(inner.iter().count(), 0);
+	/// assert_eq!(inner.try_iter().err(), Some(DecoderError::DepthLimitExceeded));
+	/// ```
+	pub fn try_iter<'view>(&'view self) -> Result<RlpIterator<'a, 'view>, DecoderError>
+	where
+		'a: 'view,
+	{
+		if !self.is_list() {
+			return Err(DecoderError::RlpExpectedToBeList)
+		}
+		self.check_limits()?;
+		Ok(self.iter())
+	}
+
+	/// Returns an iterator decoding the items of this list.
+	///
+	/// Unlike `iter`, which stops at the first malformed item, a malformed item or list is returned
+	/// as an error, after which the iterator ends. Items which are well-formed but fail to decode as
+	/// `T` are returned as errors without ending the iteration.
+	///
+	/// ```
+	/// use rlp::{DecoderError, Rlp};
+	/// let data = [0xc6, 0x01, 0x02, 0x83, b'c', b'a', b't'];
+	/// let items: Vec<Result<u8, DecoderError>> = Rlp::new(&data).iter_decoded().collect();
+	/// assert_eq!(items, vec![Ok(1), Ok(2), Err(DecoderError::RlpIsTooBig)]);
+	/// ```
+	pub fn iter_decoded<T>(&self) -> impl Iterator<Item = Result<T, DecoderError>> + 'a
+	where
+		T: DecodableBorrowed<'a>,
+	{
+		let list = self.clone();
+		let mut index = 0;
+		let mut rest = if self.is_list() {
+			self.check_limits()
+				.and_then(|()| self.consume_list_payload())
+				.map(|(payload, _)| payload)
+		} else {
+			Err(DecoderError::RlpExpectedToBeList)
+		};
+		iter::from_fn(move || {
+			let bytes = match &rest {
+				Ok([]) => return None,
+				Ok(bytes) => *bytes,
+				Err(err) => {
+					let err = err.clone();
+					rest = Ok(&[]);
+					return Some(Err(err))
+				},
+			};
+			match BasicDecoder::payload_info(bytes) {
+				Ok(info) => {
+					let (item, tail) = bytes.split_at(info.total());
+					rest = Ok(tail);
+					index += 1;
+					Some(list.item(index - 1, item).as_val())
+				},
+				Err(err) => {
+					rest = Ok(&[]);
+					Some(Err(err))
+				},
+			}
+		})
+	}
+
+	/// Decodes the item, which may be a `Decodable` type or `&'a [u8]` or `&'a str` borrowed from
+	/// the decoded bytes.
 	pub fn as_val<T>(&self) -> Result<T, DecoderError>
+	where
+		T: DecodableBorrowed<'a>,
+	{
+		let value = T::decode_borrowed(self)?;
+		self.leave();
+		Ok(value)
+	}
+
+	/// Decodes the item like `as_val`, returning the path from this item to the item which failed
+	/// with any error.
+	///
+	/// ```
+	/// use rlp::{DecoderError, Rlp};
+	/// let data = [0xc2, 0xc1, 0x05];
+	/// let err = Rlp::new(&data).at(0).unwrap().as_val_with_path::<Option<Option<u8>>>().unwrap_err();
+	/// assert_eq!(err.kind, DecoderError::RlpExpectedToBeList);
+	/// assert_eq!(err.path, vec![0]);
+	/// assert_eq!(err.to_string(), "expected list at path [0]");
+	/// ```
+	pub fn as_val_with_path<T>(&self) -> Result<T, DecodeError>
 	where
 		T: Decodable,
 	{
-		T::decode(self)
+		let tracker = PathTracker::default();
+		let rlp = Rlp {
+			bytes: self.bytes,
+			offset_cache: Cell::new(None),
+			count_cache: Cell::new(None),
+			depth: 0,
+			max_depth: self.max_depth.saturating_sub(self.depth),
+			max_len: self.max_len,
+			path: Some(&tracker),
+			strict: self.strict,
+		};
+		rlp.as_val().map_err(|kind| DecodeError { kind, path: tracker.0.take() })
 	}
 
 	pub fn as_list<T>(&self) -> Result<Vec<T>, DecoderError>
 	where
-		T: Decodable,
+		T: DecodableBorrowed<'a>,
 	{
+		if self.is_list() {
+			self.check_limits()?;
+		}
 		self.iter().map(|rlp| rlp.as_val()).collect()
 	}
 
 	pub fn val_at<T>(&self, index: usize) -> Result<T, DecoderError>
 	where
-		T: Decodable,
+		T: DecodableBorrowed<'a>,
 	{
 		self.at(index)?.as_val()
 	}
 
 	pub fn list_at<T>(&self, index: usize) -> Result<Vec<T>, DecoderError>
 	where
-		T: Decodable,
+		T: DecodableBorrowed<'a>,
 	{
 		self.at(index)?.as_list()
 	}
 
-	pub fn decoder(&self) -> BasicDecoder {
-		BasicDecoder::new(self.bytes)
+	pub fn decoder(&self) -> BasicDecoder<'a> {
+		BasicDecoder { rlp: self.bytes, max_len: self.max_len }
+	}
+
+	/// Returns the view onto the item of this list at `index`.
+	fn item(&self, index: usize, bytes: &'a [u8]) -> Rlp<'a> {
+		if let Some(tracker) = self.path {
+			let mut path = tracker.0.borrow_mut();
+			path.truncate(self.depth);
+			path.push(index);
+		}
+		Rlp {
+			bytes,
+			offset_cache: Cell::new(None),
+			count_cache: Cell::new(None),
+			depth: self.depth + 1,
+			max_depth: self.max_depth,
+			max_len: self.max_len,
+			path: self.path,
+			strict: self.strict,
+		}
+	}
+
+	/// Removes this item from the tracked path once it is decoded, so errors of its list which
+	/// follow are reported at the path of the list.
+	fn leave(&self) {
+		if let Some(tracker) = self.path {
+			tracker.0.borrow_mut().truncate(self.depth.saturating_sub(1));
+		}
+	}
+
+	/// Checks that the items of this list may be read without exceeding the depth and length limits.
+	fn check_limits(&self) -> Result<(), DecoderError> {
+		if self.depth >= self.max_depth {
+			return Err(DecoderError::DepthLimitExceeded)
+		}
+		match PayloadInfo::from(self.bytes) {
+			Ok(info) => self.check_len(info.value_len),
+			// Malformed headers are reported when the items are read.
+			Err(_) => Ok(()),
+		}
+	}
+
+	/// Checks that a payload of `len` bytes may be read without exceeding the length limit.
+	fn check_len(&self, len: usize) -> Result<(), DecoderError> {
+		if len <= self.max_len {
+			Ok(())
+		} else {
+			Err(DecoderError::LengthLimitExceeded)
+		}
 	}
 
 	/// consumes first found prefix
@@ -333,6 +667,62 @@ impl<'a> Rlp<'a> {
 	}
 }
 
+/// A list with the offsets of its items, for constant time access to any of them.
+///
+/// Created with `Rlp::indexed`.
+#[derive(Debug, Clone)]
+pub struct IndexedRlp<'a> {
+	rlp: Rlp<'a>,
+	/// The offset into the raw list and the length of each item.
+	items: Vec<(usize, usize)>,
+}
+
+impl<'a> IndexedRlp<'a> {
+	/// Returns the indexed list.
+	pub fn as_rlp(&self) -> &Rlp<'a> {
+		&self.rlp
+	}
+
+	pub fn item_count(&self) -> usize {
+		self.items.len()
+	}
+
+	/// Returns an Rlp item in the list at the given index.
+	///
+	/// Returns an error if the index is out of range.
+	pub fn at(&self, index: usize) -> Result<Rlp<'a>, DecoderError> {
+		let (rlp, _offset) = self.at_with_offset(index)?;
+		Ok(rlp)
+	}
+
+	/// Returns an Rlp item in the list at the given index along with the byte offset into the
+	/// raw data slice.
+	///
+	/// Returns an error if the index is out of range.
+	pub fn at_with_offset(&self, index: usize) -> Result<(Rlp<'a>, usize), DecoderError> {
+		let (offset, len) = *self.items.get(index).ok_or(DecoderError::RlpIsTooShort)?;
+		Ok((self.rlp.item(index, &self.rlp.bytes[offset..offset + len]), offset))
+	}
+
+	pub fn val_at<T>(&self, index: usize) -> Result<T, DecoderError>
+	where
+		T: DecodableBorrowed<'a>,
+	{
+		self.at(index)?.as_val()
+	}
+
+	pub fn list_at<T>(&self, index: usize) -> Result<Vec<T>, DecoderError>
+	where
+		T: DecodableBorrowed<'a>,
+	{
+		self.at(index)?.as_list()
+	}
+
+	pub fn iter<'view>(&'view self) -> IndexedRlpIterator<'a, 'view> {
+		self.into_iter()
+	}
+}
+
 /// Iterator over rlp-slice list elements.
 pub struct RlpIterator<'a, 'view>
 where
@@ -363,21 +753,71 @@ impl<'a, 'view> Iterator for RlpIterator<'a, 'view> {
 		self.index += 1;
 		result
 	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.len();
+		(len, Some(len))
+	}
 }
 
 impl<'a, 'view> ExactSizeIterator for RlpIterator<'a, 'view> {
+	/// Returns the number of items left, counting the items of the list once and caching the count.
 	fn len(&self) -> usize {
 		self.rlp.item_count().unwrap_or(0).saturating_sub(self.index)
 	}
 }
 
+/// Iterator over the items of an `IndexedRlp`, from either end.
+pub struct IndexedRlpIterator<'a, 'view> {
+	rlp: &'view Rlp<'a>,
+	items: iter::Enumerate<slice::Iter<'view, (usize, usize)>>,
+}
+
+impl<'a, 'view> IntoIterator for &'view IndexedRlp<'a> {
+	type Item = Rlp<'a>;
+	type IntoIter = IndexedRlpIterator<'a, 'view>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		IndexedRlpIterator { rlp: &self.rlp, items: self.items.iter().enumerate() }
+	}
+}
+
+impl<'a, 'view> IndexedRlpIterator<'a, 'view> {
+	fn item(&self, (index, &(offset, len)): (usize, &(usize, usize))) -> Rlp<'a> {
+		self.rlp.item(index, &self.rlp.bytes[offset..offset + len])
+	}
+}
+
+impl<'a, 'view> Iterator for IndexedRlpIterator<'a, 'view> {
+	type Item = Rlp<'a>;
+
+	fn next(&mut self) -> Option<Rlp<'a>> {
+		let item = self.items.next()?;
+		Some(self.item(item))
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.items.size_hint()
+	}
+}
+
+impl<'a, 'view> DoubleEndedIterator for IndexedRlpIterator<'a, 'view> {
+	fn next_back(&mut self) -> Option<Rlp<'a>> {
+		let item = self.items.next_back()?;
+		Some(self.item(item))
+	}
+}
+
+impl<'a, 'view> ExactSizeIterator for IndexedRlpIterator<'a, 'view> {}
+
 pub struct BasicDecoder<'a> {
 	rlp: &'a [u8],
+	max_len: usize,
 }
 
 impl<'a> BasicDecoder<'a> {
 	pub const fn new(rlp: &'a [u8]) -> BasicDecoder<'a> {
-		BasicDecoder { rlp }
+		BasicDecoder { rlp, max_len: usize::MAX }
 	}
 
 	/// Return first item info.
@@ -393,12 +833,25 @@ impl<'a> BasicDecoder<'a> {
 	where
 		F: Fn(&[u8]) -> Result<T, DecoderError>,
 	{
+		f(self.value()?)
+	}
+
+	/// Returns the payload of the data item, borrowed from the decoded bytes.
+	pub(crate) fn value(&self) -> Result<&'a [u8], DecoderError> {
+		let value = self.unlimited_value()?;
+		if value.len() > self.max_len {
+			return Err(DecoderError::LengthLimitExceeded)
+		}
+		Ok(value)
+	}
+
+	fn unlimited_value(&self) -> Result<&'a [u8], DecoderError> {
 		let bytes = self.rlp;
 
 		let l = *bytes.first().ok_or_else(|| DecoderError::RlpIsTooShort)?;
 
 		if l <= 0x7f {
-			Ok(f(&[l])?)
+			Ok(&bytes[..1])
 		} else if l <= 0xb7 {
 			let last_index_of = 1 + l as usize - 0x80;
 			if bytes.len() < last_index_of {
@@ -408,7 +861,7 @@ impl<'a> BasicDecoder<'a> {
 			if l == 0x81 && d[0] < 0x80 {
 				return Err(DecoderError::RlpInvalidIndirection)
 			}
-			Ok(f(d)?)
+			Ok(d)
 		} else if l <= 0xbf {
 			let len_of_len = l as usize - 0xb7;
 			let begin_of_value = 1 as usize + len_of_len;
@@ -421,7 +874,7 @@ impl<'a> BasicDecoder<'a> {
 			if bytes.len() < last_index_of_value {
 				return Err(DecoderError::RlpInconsistentLengthAndData)
 			}
-			Ok(f(&bytes[begin_of_value..last_index_of_value])?)
+			Ok(&bytes[begin_of_value..last_index_of_value])
 		} else {
 			Err(DecoderError::RlpExpectedToBeData)
 		}
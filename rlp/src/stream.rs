@@ -9,7 +9,11 @@
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
 use bytes::{BufMut, BytesMut};
-use core::borrow::Borrow;
+use core::{
+	borrow::Borrow,
+	mem,
+	ops::{Deref, DerefMut},
+};
 
 use crate::traits::Encodable;
 
@@ -51,6 +55,36 @@ impl RlpStream {
 		Self::new_list_with_buffer(BytesMut::with_capacity(1024), len)
 	}
 
+	/// Initializes instance of empty `Stream` with room for `bytes` bytes of output and `lists`
+	/// nested lists, so that encoding up to that much allocates nothing more.
+	///
+	/// The capacity is kept when lists are finished and their headers inserted, and by `clear`.
+	///
+	/// ```
+	/// use rlp::RlpStream;
+	/// let mut stream = RlpStream::with_capacity(2, 64);
+	/// let buffer = stream.as_raw().as_ptr();
+	/// stream.begin_list(2).append(&"cat").begin_list(1).append(&[0xab_u8; 50].as_slice());
+	/// assert_eq!(stream.as_raw().as_ptr(), buffer);
+	/// assert_eq!(stream.out().len(), 58);
+	/// ```
+	pub fn with_capacity(lists: usize, bytes: usize) -> Self {
+		RlpStream {
+			unfinished_lists: Vec::with_capacity(lists),
+			start_pos: 0,
+			buffer: BytesMut::with_capacity(bytes),
+			finished_list: false,
+		}
+	}
+
+	/// Initializes instance of empty `Stream` for a value with the given `Encodable::rlp_size_hint`.
+	pub(crate) fn with_size_hint(size_hint: usize) -> Self {
+		match size_hint {
+			0 => Self::new(),
+			_ => Self::with_capacity(16, size_hint),
+		}
+	}
+
 	/// Initializes instance of empty `Stream`.
 	pub fn new_with_buffer(buffer: BytesMut) -> Self {
 		RlpStream { unfinished_lists: Vec::with_capacity(16), start_pos: buffer.len(), buffer, finished_list: false }
@@ -101,6 +135,8 @@ impl RlpStream {
 
 	/// Appends value to the end of stream, chainable.
 	///
+	/// Space for the value is reserved up front from its `Encodable::rlp_size_hint`.
+	///
 	/// ```
 	/// use rlp::RlpStream;
 	/// let mut stream = RlpStream::new_list(2);
@@ -113,6 +149,7 @@ impl RlpStream {
 		E: Encodable,
 	{
 		self.finished_list = false;
+		self.buffer.reserve(value.rlp_size_hint());
 		value.rlp_append(self);
 		if !self.finished_list {
 			self.note_appended(1);
@@ -154,6 +191,33 @@ impl RlpStream {
 		self
 	}
 
+	/// Appends a list of values of unknown length to the end of stream, chainable.
+	///
+	/// The values are encoded as they are produced and the list header is written once the
+	/// iterator is exhausted, so the output is the same as with `begin_list` and the number of
+	/// values.
+	///
+	/// ```
+	/// use rlp::RlpStream;
+	/// let mut stream = RlpStream::new();
+	/// stream.append_list_iter::<u8, _, _>((1..=3).filter(|n| n % 2 == 1));
+	/// let out = stream.out();
+	/// assert_eq!(out, vec![0xc2, 0x01, 0x03]);
+	/// ```
+	pub fn append_list_iter<E, K, I>(&mut self, values: I) -> &mut Self
+	where
+		E: Encodable,
+		K: Borrow<E>,
+		I: IntoIterator<Item = K>,
+	{
+		self.begin_unbounded_list();
+		for value in values {
+			self.append(value.borrow());
+		}
+		self.finalize_unbounded_list();
+		self
+	}
+
 	/// Appends value to the end of stream, but do not count it as an appended item.
 	/// It's useful for wrapper types
 	pub fn append_internal<E>(&mut self, value: &E) -> &mut Self
@@ -212,6 +276,31 @@ impl RlpStream {
 		self
 	}
 
+	/// Declare appending the list of unknown size, returning a guard which finalizes the list when
+	/// dropped.
+	///
+	/// The guard dereferences to the stream, so items are appended through it. If another list
+	/// opened through the guard is still unfinished when it is dropped, the list is left open and
+	/// the stream is not finished.
+	///
+	/// ```
+	/// use rlp::RlpStream;
+	/// let mut stream = RlpStream::new();
+	/// {
+	///     let mut list = stream.begin_unbounded_list_guarded();
+	///     for animal in ["cat", "dog"] {
+	///         list.append(&animal);
+	///     }
+	/// }
+	/// let out = stream.out();
+	/// assert_eq!(out, vec![0xc8, 0x83, b'c', b'a', b't', 0x83, b'd', b'o', b'g']);
+	/// ```
+	pub fn begin_unbounded_list_guarded(&mut self) -> UnboundedListGuard<'_> {
+		self.begin_unbounded_list();
+		let depth = self.unfinished_lists.len();
+		UnboundedListGuard { stream: self, depth }
+	}
+
 	/// Appends raw (pre-serialised) RLP data. Checks for size overflow.
 	pub fn append_raw_checked(&mut self, bytes: &[u8], item_count: usize, max_size: usize) -> bool {
 		if self.estimate_size(bytes.len()) > max_size {
@@ -247,6 +336,8 @@ impl RlpStream {
 
 	/// Clear the output stream so far.
 	///
+	/// The allocations of the stream are kept, so one stream can encode many items in turn.
+	///
 	/// ```
 	/// use rlp::RlpStream;
 	/// let mut stream = RlpStream::new_list(3);
@@ -256,12 +347,25 @@ impl RlpStream {
 	/// let out = stream.out();
 	/// assert_eq!(out, vec![0x83, b'd', b'o', b'g']);
 	/// ```
+	///
+	/// ```
+	/// use rlp::RlpStream;
+	/// let mut stream = RlpStream::new();
+	/// let mut encoded = Vec::new();
+	/// for animal in ["cat", "dog"] {
+	///     stream.clear();
+	///     stream.begin_list(1).append(&animal);
+	///     encoded.push(stream.as_raw().to_vec());
+	/// }
+	/// assert_eq!(encoded, [[0xc4, 0x83, b'c', b'a', b't'], [0xc4, 0x83, b'd', b'o', b'g']]);
+	/// ```
 	pub fn clear(&mut self) {
 		// clear bytes
 		self.buffer.truncate(self.start_pos);
 
 		// clear lists
 		self.unfinished_lists.clear();
+		self.finished_list = false;
 	}
 
 	/// Returns true if stream doesnt expect any more items.
@@ -297,6 +401,22 @@ impl RlpStream {
 		}
 	}
 
+	/// Returns the buffer, including any bytes it had when passed to `new_with_buffer`, even if the
+	/// stream is not finished.
+	///
+	/// The headers of unfinished lists are not written, so the encoding of an unfinished stream is
+	/// not valid RLP.
+	///
+	/// ```
+	/// use rlp::RlpStream;
+	/// let mut stream = RlpStream::new_list(2);
+	/// stream.append(&"cat");
+	/// assert_eq!(stream.into_buffer(), vec![0x00, 0x83, b'c', b'a', b't']);
+	/// ```
+	pub fn into_buffer(self) -> BytesMut {
+		self.buffer
+	}
+
 	/// Try to finish lists
 	fn note_appended(&mut self, inserted_items: usize) {
 		if self.unfinished_lists.is_empty() {
@@ -341,6 +461,88 @@ impl RlpStream {
 	}
 }
 
+/// An unbounded list in an `RlpStream`, finalized when the guard is dropped.
+///
+/// Returned by `RlpStream::begin_unbounded_list_guarded`.
+pub struct UnboundedListGuard<'a> {
+	stream: &'a mut RlpStream,
+	depth: usize,
+}
+
+impl UnboundedListGuard<'_> {
+	/// Finalizes the list, the same as dropping the guard.
+	pub fn finish(self) {}
+}
+
+impl Deref for UnboundedListGuard<'_> {
+	type Target = RlpStream;
+
+	fn deref(&self) -> &RlpStream {
+		self.stream
+	}
+}
+
+impl DerefMut for UnboundedListGuard<'_> {
+	fn deref_mut(&mut self) -> &mut RlpStream {
+		self.stream
+	}
+}
+
+impl Drop for UnboundedListGuard<'_> {
+	fn drop(&mut self) {
+		// Only finalize our own list, so that dropping the guard never panics.
+		if self.stream.unfinished_lists.len() == self.depth {
+			self.stream.finalize_unbounded_list();
+		}
+	}
+}
+
+/// Returns the size in bytes of the header of an item with a payload of `len` bytes.
+fn header_size(len: usize) -> usize {
+	match len {
+		0..=55 => 1,
+		_ => 1 + mem::size_of::<usize>() - len.leading_zeros() as usize / 8,
+	}
+}
+
+/// Returns the size in bytes of the encoding of `data` as a data item, for `Encodable::rlp_size_hint`.
+///
+/// ```
+/// assert_eq!(rlp::data_size(&[0x7f]), 1);
+/// assert_eq!(rlp::data_size(b"cat"), rlp::encode(&"cat").len());
+/// assert_eq!(rlp::data_size(&[0; 56]), 58);
+/// ```
+pub fn data_size(data: &[u8]) -> usize {
+	match data {
+		[byte] if *byte < 0x80 => 1,
+		_ => header_size(data.len()) + data.len(),
+	}
+}
+
+/// Returns the size in bytes of the encoding of a list of items of the given sizes, for
+/// `Encodable::rlp_size_hint`, or 0 if any of the sizes is 0, as the hint of an item whose size is
+/// unknown.
+///
+/// ```
+/// assert_eq!(rlp::list_size([]), 1);
+/// assert_eq!(rlp::list_size([1, 4]), rlp::encode(&(1u8, "cat")).len());
+/// assert_eq!(rlp::list_size([100, 100]), 202);
+/// assert_eq!(rlp::list_size([1, 0]), 0);
+/// ```
+pub fn list_size<I>(item_sizes: I) -> usize
+where
+	I: IntoIterator<Item = usize>,
+{
+	let mut payload_len = 0;
+	for size in item_sizes {
+		if size == 0 {
+			return 0
+		}
+		payload_len += size;
+	}
+	header_size(payload_len) + payload_len
+}
+
 pub struct BasicEncoder<'a> {
 	buffer: &'a mut BytesMut,
 	start_pos: usize,
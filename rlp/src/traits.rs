@@ -17,14 +17,38 @@ pub trait Decodable: Sized {
 	fn decode(rlp: &Rlp) -> Result<Self, DecoderError>;
 }
 
+/// RLP decodable trait for values which may borrow from the decoded bytes
+///
+/// Implemented for `&'a [u8]` and `&'a str`, which borrow the payload of a data item without
+/// copying it, and for every `Decodable` type.
+pub trait DecodableBorrowed<'a>: Sized {
+	/// Decode a value from RLP bytes, which it may borrow
+	fn decode_borrowed(rlp: &Rlp<'a>) -> Result<Self, DecoderError>;
+}
+
+impl<'a, T: Decodable> DecodableBorrowed<'a> for T {
+	fn decode_borrowed(rlp: &Rlp<'a>) -> Result<Self, DecoderError> {
+		T::decode(rlp)
+	}
+}
+
 /// Structure encodable to RLP
 pub trait Encodable {
 	/// Append a value to the stream
 	fn rlp_append(&self, s: &mut RlpStream);
 
+	/// Size of the encoding of this instance in bytes, reserved by `RlpStream::append` before
+	/// encoding it
+	///
+	/// An estimate is fine, as it only affects allocations. The default of 0 means the size is
+	/// unknown and reserves nothing. `data_size` and `list_size` help to compute it.
+	fn rlp_size_hint(&self) -> usize {
+		0
+	}
+
 	/// Get rlp-encoded bytes for this instance
 	fn rlp_bytes(&self) -> BytesMut {
-		let mut s = RlpStream::new();
+		let mut s = RlpStream::with_size_hint(self.rlp_size_hint());
 		self.rlp_append(&mut s);
 		s.out()
 	}
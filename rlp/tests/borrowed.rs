@@ -0,0 +1,136 @@
+// Copyright 2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Tests for decoding byte strings and strings borrowed from the decoded bytes.
+
+use std::{
+	alloc::{GlobalAlloc, Layout, System},
+	cell::Cell,
+};
+
+use hex_literal::hex;
+use primitive_types::U256;
+use rlp::{DecoderError, Rlp, RlpStream};
+
+// Counts the allocations of each thread, so that tests running in parallel don't disturb each other.
+struct CountingAllocator;
+
+thread_local! {
+	static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+	unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+		let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+		System.alloc(layout)
+	}
+
+	unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+		System.dealloc(ptr, layout)
+	}
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn allocations<T>(f: impl FnOnce() -> T) -> (T, usize) {
+	let before = ALLOCATIONS.with(Cell::get);
+	let result = f();
+	(result, ALLOCATIONS.with(Cell::get) - before)
+}
+
+const TO: [u8; 20] = hex!("3535353535353535353535353535353535353535");
+const STORAGE_KEY: [u8; 32] = hex!("0000000000000000000000000000000000000000000000000000000000000007");
+
+// An access list transaction payload: `[chain_id, nonce, gas_price, gas, to, value, data, access_list]`,
+// where the access list is `[[address, [storage_key]]]`, followed by a memo string.
+fn transaction() -> Vec<u8> {
+	let mut s = RlpStream::new_list(9);
+	s.append(&1u64);
+	s.append(&9u64);
+	s.append(&U256::from(20_000_000_000u64));
+	s.append(&21_000u64);
+	s.append(&TO.as_ref());
+	s.append(&U256::from(10).pow(18.into()));
+	s.append(&vec![0xa9u8; 68]);
+	s.begin_list(1);
+	s.begin_list(2);
+	s.append(&TO.as_ref());
+	s.begin_list(1);
+	s.append(&STORAGE_KEY.as_ref());
+	s.append(&"transfer 1 ether");
+	s.out().to_vec()
+}
+
+#[test]
+fn decodes_nested_list_without_allocating() {
+	let bytes = transaction();
+	let ((to, data, address, key, memo, nonce), count) = allocations(|| {
+		let rlp = Rlp::new(&bytes);
+		let to: &[u8] = rlp.val_at(4).unwrap();
+		let data: &[u8] = rlp.val_at(6).unwrap();
+		let entry = rlp.at(7).unwrap().at(0).unwrap();
+		let address: &[u8] = entry.val_at(0).unwrap();
+		let key: &[u8] = entry.at(1).unwrap().val_at(0).unwrap();
+		let memo: &str = rlp.val_at(8).unwrap();
+		let nonce: u64 = rlp.val_at(1).unwrap();
+		(to, data, address, key, memo, nonce)
+	});
+	assert_eq!(count, 0);
+
+	assert_eq!(to, TO);
+	assert_eq!(data, [0xa9; 68]);
+	assert_eq!(address, TO);
+	assert_eq!(key, STORAGE_KEY);
+	assert_eq!(memo, "transfer 1 ether");
+	assert_eq!(nonce, 9);
+
+	// the borrowed values point into the input
+	let range = bytes.as_ptr_range();
+	for value in [to, data, address, key, memo.as_bytes()] {
+		assert!(range.contains(&value.as_ptr()));
+	}
+}
+
+#[test]
+fn owned_decoding_allocates() {
+	let bytes = transaction();
+	let (data, count) = allocations(|| Rlp::new(&bytes).val_at::<Vec<u8>>(6).unwrap());
+	assert_eq!(data, vec![0xa9; 68]);
+	assert!(count > 0);
+}
+
+// The result outlives the `Rlp`, which only lives in this function.
+fn memo(bytes: &[u8]) -> Result<&str, DecoderError> {
+	Rlp::new(bytes).val_at(8)
+}
+
+#[test]
+fn borrows_from_the_data_not_the_rlp() {
+	let bytes = transaction();
+	assert_eq!(memo(&bytes), Ok("transfer 1 ether"));
+
+	let rlp = Rlp::new(&bytes);
+	let keys: Vec<&[u8]> = rlp.at(7).unwrap().at(0).unwrap().list_at(1).unwrap();
+	drop(rlp);
+	assert_eq!(keys, [STORAGE_KEY.as_ref()]);
+}
+
+#[test]
+fn borrowed_values_are_validated_like_owned_ones() {
+	// a single byte below 0x80 is its own encoding
+	assert_eq!(Rlp::new(&[0x05]).as_val::<&[u8]>(), Ok(&[0x05][..]));
+	assert_eq!(Rlp::new(&[0x80]).as_val::<&[u8]>(), Ok(&[][..]));
+	assert_eq!(Rlp::new(&[0x81, 0x05]).as_val::<&[u8]>(), Err(DecoderError::RlpInvalidIndirection));
+	assert_eq!(Rlp::new(&[0x83, 0x61, 0x62]).as_val::<&[u8]>(), Err(DecoderError::RlpInconsistentLengthAndData));
+	assert_eq!(Rlp::new(&[0xc1, 0x05]).as_val::<&[u8]>(), Err(DecoderError::RlpExpectedToBeData));
+
+	assert_eq!(Rlp::new(&[0x82, 0x68, 0x69]).as_val::<&str>(), Ok("hi"));
+	assert_eq!(Rlp::new(&[0x82, 0xc3, 0x28]).as_val::<&str>(), Err(DecoderError::RlpExpectedToBeData));
+	assert_eq!(Rlp::new(&[0x82, 0xc3, 0x28]).as_val::<String>(), Err(DecoderError::RlpExpectedToBeData));
+}
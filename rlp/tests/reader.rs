@@ -0,0 +1,217 @@
+// Copyright 2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![cfg(feature = "std")]
+
+use std::io;
+
+use rlp::{DecoderError, ReaderError, Rlp, RlpEvent, RlpStream, RlpStreamReader};
+
+/// Reads at most `chunk` bytes at a time.
+struct ChunkedReader<'a> {
+	data: &'a [u8],
+	chunk: usize,
+}
+
+impl io::Read for ChunkedReader<'_> {
+	fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+		let len = buf.len().min(self.chunk).min(self.data.len());
+		buf[..len].copy_from_slice(&self.data[..len]);
+		self.data = &self.data[len..];
+		Ok(len)
+	}
+}
+
+fn reader(data: &[u8], chunk: usize) -> RlpStreamReader<ChunkedReader<'_>> {
+	RlpStreamReader::new(ChunkedReader { data, chunk })
+}
+
+#[derive(Debug, PartialEq)]
+enum Tree {
+	Data(Vec<u8>),
+	List(Vec<Tree>),
+}
+
+impl Tree {
+	fn from_rlp(rlp: &Rlp) -> Tree {
+		if rlp.is_list() {
+			Tree::List(rlp.iter().map(|item| Tree::from_rlp(&item)).collect())
+		} else {
+			Tree::Data(rlp.data().unwrap().to_vec())
+		}
+	}
+
+	fn append(&self, stream: &mut RlpStream) {
+		match self {
+			Tree::Data(data) => {
+				stream.append(&data.as_slice());
+			},
+			Tree::List(items) => {
+				stream.begin_list(items.len());
+				for item in items {
+					item.append(stream);
+				}
+			},
+		}
+	}
+}
+
+/// Builds the trees of the items of the input from the events of the reader.
+fn read_trees<R: io::Read>(reader: &mut RlpStreamReader<R>) -> Result<Vec<Tree>, ReaderError> {
+	let mut trees = Vec::new();
+	let mut open_lists: Vec<Vec<Tree>> = Vec::new();
+	while let Some(event) = reader.next_event()? {
+		let tree = match event {
+			RlpEvent::ListStart { .. } => {
+				open_lists.push(Vec::new());
+				continue
+			},
+			RlpEvent::Data(data) => Tree::Data(data),
+			RlpEvent::ListEnd => Tree::List(open_lists.pop().unwrap()),
+		};
+		match open_lists.last_mut() {
+			Some(list) => list.push(tree),
+			None => trees.push(tree),
+		}
+	}
+	assert!(open_lists.is_empty());
+	Ok(trees)
+}
+
+fn sample() -> Vec<u8> {
+	let mut stream = RlpStream::new_list(7);
+	stream.append(&0u8).append(&0x7fu8).append(&0x80u8).append(&"cat");
+	stream.append(&vec![0xab; 1000]);
+	stream
+		.begin_list(3)
+		.begin_list(0)
+		.append_empty_data()
+		.begin_list(1)
+		.append(&"dog");
+	stream.begin_list(100);
+	for i in 0..100u64 {
+		stream.append(&(i << 40));
+	}
+	stream.out().to_vec()
+}
+
+#[test]
+fn test_reader_events_match_rlp() {
+	let data = sample();
+	let expected = Tree::from_rlp(&Rlp::new(&data));
+	for chunk in [1, 2, 7, 4096] {
+		let trees = read_trees(&mut reader(&data, chunk)).unwrap();
+		assert_eq!(trees.len(), 1);
+		assert_eq!(trees[0], expected, "chunks of {} bytes", chunk);
+
+		let mut stream = RlpStream::new();
+		trees[0].append(&mut stream);
+		assert_eq!(stream.out().to_vec(), data);
+	}
+
+	let mut reader = reader(&data, 1);
+	assert_eq!(reader.next_event().unwrap(), Some(RlpEvent::ListStart { payload_len: data.len() - 3 }));
+	assert_eq!(reader.next_event().unwrap(), Some(RlpEvent::Data(vec![])));
+	assert_eq!(reader.next_event().unwrap(), Some(RlpEvent::Data(vec![0x7f])));
+	assert_eq!(reader.next_event().unwrap(), Some(RlpEvent::Data(vec![0x80])));
+	assert_eq!(reader.next_event().unwrap(), Some(RlpEvent::Data(b"cat".to_vec())));
+}
+
+#[test]
+fn test_reader_items() {
+	let first = sample();
+	let mut data = first.clone();
+	data.extend_from_slice(&[0x05, 0x83, b'd', b'o', b'g', 0xc0]);
+
+	let mut reader = reader(&data, 1);
+	assert_eq!(reader.next_item().unwrap(), Some(first));
+	assert_eq!(reader.next_item().unwrap(), Some(vec![0x05]));
+	assert_eq!(reader.next_item().unwrap(), Some(b"\x83dog".to_vec()));
+	assert_eq!(reader.next_item().unwrap(), Some(vec![0xc0]));
+	assert_eq!(reader.next_item().unwrap(), None);
+
+	// Whole items can be read from inside a list.
+	let data = sample();
+	let mut reader = self::reader(&data, 1);
+	assert!(matches!(reader.next_event().unwrap(), Some(RlpEvent::ListStart { .. })));
+	let rlp = Rlp::new(&data);
+	for index in 0..7 {
+		assert_eq!(reader.next_item().unwrap().unwrap(), rlp.at(index).unwrap().as_raw());
+	}
+	assert_eq!(reader.next_item().unwrap(), None);
+	assert_eq!(reader.next_event().unwrap(), Some(RlpEvent::ListEnd));
+	assert_eq!(reader.next_event().unwrap(), None);
+}
+
+fn read_error(data: &[u8]) -> ReaderError {
+	read_trees(&mut reader(data, 1)).unwrap_err()
+}
+
+fn decoder_error(data: &[u8]) -> DecoderError {
+	match read_error(data) {
+		ReaderError::Decoder(err) => err,
+		err => panic!("unexpected error: {}", err),
+	}
+}
+
+#[test]
+fn test_reader_errors() {
+	// Truncated data and lists.
+	assert_eq!(decoder_error(&[0x83, b'c', b'a']), DecoderError::RlpIsTooShort);
+	assert_eq!(decoder_error(&[0xc2, 0x01]), DecoderError::RlpIsTooShort);
+	assert_eq!(decoder_error(&[0xb9, 0x01]), DecoderError::RlpIsTooShort);
+	// An item longer than the rest of its list.
+	assert_eq!(decoder_error(&[0xc2, 0x83, b'c', b'a', b't']), DecoderError::RlpIsTooShort);
+	// Non-canonical headers.
+	assert_eq!(decoder_error(&[0x81, 0x05]), DecoderError::RlpInvalidIndirection);
+	assert_eq!(decoder_error(&[0xb8, 0x05, 1, 2, 3, 4, 5]), DecoderError::RlpInvalidIndirection);
+	assert_eq!(decoder_error(&[0xb9, 0x00, 0x40]), DecoderError::RlpDataLenWithZeroPrefix);
+	assert_eq!(decoder_error(&[0xf8, 0x02, 0x01, 0x02]), DecoderError::RlpInvalidIndirection);
+
+	let mut deep = RlpStream::new();
+	for _ in 0..1000 {
+		deep.begin_list(1);
+	}
+	deep.begin_list(0);
+	assert_eq!(decoder_error(&deep.out()), DecoderError::DepthLimitExceeded);
+}
+
+#[test]
+fn test_reader_item_size_limit() {
+	// A corrupt header claiming an item of 4 GiB fails before allocating.
+	let data = [0xbb, 0xff, 0xff, 0xff, 0xff, 0x00];
+	assert!(matches!(read_error(&data), ReaderError::ItemTooBig { size: 0xffff_ffff, .. }));
+	assert!(matches!(reader(&data, 1).next_item(), Err(ReaderError::ItemTooBig { size: 0x1_0000_0004, .. })));
+	// A length which overflows with its header.
+	let data = [0xbf, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x00];
+	assert_eq!(decoder_error(&data), DecoderError::RlpInvalidLength);
+
+	let data = sample();
+	let mut reader = RlpStreamReader::new_with_max_item_size(&data[..], 999);
+	assert!(matches!(reader.next_item(), Err(ReaderError::ItemTooBig { size, max: 999 }) if size == data.len()));
+	assert!(matches!(
+		read_trees(&mut RlpStreamReader::new_with_max_item_size(&data[..], 999)),
+		Err(ReaderError::ItemTooBig { size: 1000, max: 999 })
+	));
+	assert!(read_trees(&mut RlpStreamReader::new_with_max_item_size(&data[..], 1000)).is_ok());
+}
+
+#[test]
+fn test_reader_io_errors() {
+	struct Failing;
+
+	impl io::Read for Failing {
+		fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+			Err(io::Error::new(io::ErrorKind::Other, "disk on fire"))
+		}
+	}
+
+	let err = RlpStreamReader::new(Failing).next_event().unwrap_err();
+	assert!(matches!(&err, ReaderError::Io(err) if err.to_string() == "disk on fire"));
+	assert_eq!(err.to_string(), "failed to read rlp: disk on fire");
+}
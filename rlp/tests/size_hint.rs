@@ -0,0 +1,146 @@
+// Copyright 2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Tests that encoding values with size hints doesn't reallocate the output.
+
+use std::{
+	alloc::{GlobalAlloc, Layout, System},
+	cell::Cell,
+};
+
+use primitive_types::{H160, H256, U256};
+use rlp::{Encodable, RlpStream};
+
+// Counts the reallocations of each thread, so that tests running in parallel don't disturb each other.
+struct CountingAllocator;
+
+thread_local! {
+	static REALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+	unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+		System.alloc(layout)
+	}
+
+	unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+		System.dealloc(ptr, layout)
+	}
+
+	unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+		let _ = REALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+		System.realloc(ptr, layout, new_size)
+	}
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn reallocations<T>(f: impl FnOnce() -> T) -> (T, usize) {
+	let before = REALLOCATIONS.with(Cell::get);
+	let result = f();
+	(result, REALLOCATIONS.with(Cell::get) - before)
+}
+
+struct Transaction {
+	nonce: u64,
+	gas_price: U256,
+	to: H160,
+	value: U256,
+	data: Vec<u8>,
+}
+
+struct Block {
+	parent_hash: H256,
+	number: u64,
+	transactions: Vec<Transaction>,
+}
+
+impl Encodable for Transaction {
+	fn rlp_append(&self, s: &mut RlpStream) {
+		s.begin_list(5)
+			.append(&self.nonce)
+			.append(&self.gas_price)
+			.append(&self.to)
+			.append(&self.value)
+			.append(&self.data);
+	}
+
+	fn rlp_size_hint(&self) -> usize {
+		rlp::list_size([
+			self.nonce.rlp_size_hint(),
+			self.gas_price.rlp_size_hint(),
+			self.to.rlp_size_hint(),
+			self.value.rlp_size_hint(),
+			self.data.rlp_size_hint(),
+		])
+	}
+}
+
+impl Encodable for Block {
+	fn rlp_append(&self, s: &mut RlpStream) {
+		s.begin_list(3)
+			.append(&self.parent_hash)
+			.append(&self.number)
+			.append_list(&self.transactions);
+	}
+
+	fn rlp_size_hint(&self) -> usize {
+		let transactions = rlp::list_size(self.transactions.iter().map(Encodable::rlp_size_hint));
+		rlp::list_size([self.parent_hash.rlp_size_hint(), self.number.rlp_size_hint(), transactions])
+	}
+}
+
+/// A `Block` encoded without size hints.
+struct Unhinted<'a>(&'a Block);
+
+impl Encodable for Unhinted<'_> {
+	fn rlp_append(&self, s: &mut RlpStream) {
+		self.0.rlp_append(s);
+	}
+}
+
+fn block() -> Block {
+	Block {
+		parent_hash: H256::repeat_byte(0x11),
+		number: 19_000_000,
+		transactions: (0..1000u64)
+			.map(|i| Transaction {
+				nonce: i,
+				gas_price: U256::from(i) * 1_000_000_000u64,
+				to: H160::repeat_byte(i as u8),
+				value: U256::from(i) << 64,
+				data: vec![i as u8; 200],
+			})
+			.collect(),
+	}
+}
+
+#[test]
+fn encoding_with_size_hints_does_not_reallocate() {
+	let block = block();
+	assert_eq!(block.rlp_size_hint(), rlp::encode(&block).len());
+
+	let (hinted, count) = reallocations(|| rlp::encode(&block));
+	assert_eq!(count, 0);
+	let (unhinted, count) = reallocations(|| rlp::encode(&Unhinted(&block)));
+	assert!(count > 0);
+	assert_eq!(hinted, unhinted);
+
+	let (encoded, count) = reallocations(|| {
+		let mut s = RlpStream::with_capacity(4, block.rlp_size_hint());
+		s.append(&block);
+		s.out()
+	});
+	assert_eq!(count, 0);
+	assert_eq!(encoded, hinted);
+
+	let (list, count) = reallocations(|| rlp::encode_list(&block.transactions));
+	assert_eq!(count, 0);
+	assert_eq!(list.len(), rlp::list_size(block.transactions.iter().map(Encodable::rlp_size_hint)));
+}
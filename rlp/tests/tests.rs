@@ -11,7 +11,7 @@ use core::{cmp, fmt};
 use bytes::{Bytes, BytesMut};
 use hex_literal::hex;
 use primitive_types::{H160, U256};
-use rlp::{Decodable, DecoderError, Encodable, Rlp, RlpStream};
+use rlp::{Decodable, DecoderError, Encodable, MaxDepth, MaxLen, Rlp, RlpStream};
 
 #[test]
 fn test_rlp_display() {
@@ -626,6 +626,308 @@ fn test_rlp_stream_unbounded_list() {
 	assert!(stream.is_finished());
 }
 
+fn counted_list(items: &[String]) -> Vec<u8> {
+	let mut stream = RlpStream::new_list(2);
+	stream.begin_list(items.len());
+	for item in items {
+		stream.append(item);
+	}
+	stream.append(&"end");
+	stream.out().to_vec()
+}
+
+#[test]
+fn test_rlp_stream_append_list_iter() {
+	for count in [0, 1, 5, 20, 300] {
+		let items: Vec<String> = (0..count).map(|i| format!("item {}", i)).collect();
+		let expected = counted_list(&items);
+
+		let mut stream = RlpStream::new_list(2);
+		stream
+			.append_list_iter::<String, _, _>(items.iter().filter(|_| true))
+			.append(&"end");
+		assert!(stream.is_finished());
+		assert_eq!(stream.out().to_vec(), expected, "{} items", count);
+	}
+}
+
+#[test]
+fn test_rlp_stream_unbounded_list_guard() {
+	for count in [0, 1, 5, 20, 300] {
+		let items: Vec<String> = (0..count).map(|i| format!("item {}", i)).collect();
+		let expected = counted_list(&items);
+
+		let mut stream = RlpStream::new_list(2);
+		{
+			let mut list = stream.begin_unbounded_list_guarded();
+			for item in &items {
+				list.append(item);
+			}
+			assert!(!list.is_finished());
+		}
+		stream.append(&"end");
+		assert!(stream.is_finished());
+		assert_eq!(stream.out().to_vec(), expected, "{} items", count);
+
+		let mut stream = RlpStream::new_list(2);
+		let mut list = stream.begin_unbounded_list_guarded();
+		for item in &items {
+			list.append(item);
+		}
+		list.finish();
+		stream.append(&"end");
+		assert_eq!(stream.out().to_vec(), expected, "{} items", count);
+	}
+}
+
+#[test]
+fn test_rlp_stream_unbounded_list_guard_nested() {
+	struct Numbers(u64);
+
+	impl Encodable for Numbers {
+		fn rlp_append(&self, s: &mut RlpStream) {
+			let mut list = s.begin_unbounded_list_guarded();
+			for n in 0..self.0 {
+				list.append(&n);
+			}
+		}
+	}
+
+	let mut stream = RlpStream::new_list(2);
+	stream.append(&Numbers(3)).append(&Numbers(0));
+	assert_eq!(stream.out().to_vec(), vec![0xc5, 0xc3, 0x80, 0x01, 0x02, 0xc0]);
+
+	// A list left unfinished inside the guard keeps the stream unfinished.
+	let mut stream = RlpStream::new();
+	{
+		let mut list = stream.begin_unbounded_list_guarded();
+		list.begin_list(2).append(&1u8);
+	}
+	assert!(!stream.is_finished());
+}
+
+/// A type which decodes recursively, one level per nested list.
+#[derive(Debug)]
+struct Nested(Vec<Nested>);
+
+impl Decodable for Nested {
+	fn decode(rlp: &Rlp<'_>) -> Result<Self, DecoderError> {
+		rlp.as_list().map(Nested)
+	}
+}
+
+fn nested_lists(depth: usize) -> Vec<u8> {
+	let mut stream = RlpStream::new();
+	for _ in 0..depth {
+		stream.begin_list(1);
+	}
+	stream.begin_list(0);
+	stream.out().to_vec()
+}
+
+#[test]
+fn test_depth_limit() {
+	let data = nested_lists(3);
+	assert!(Rlp::new_with_limits(&data, MaxDepth(4), MaxLen(usize::MAX))
+		.as_val::<Nested>()
+		.is_ok());
+	assert_eq!(
+		Rlp::new_with_limits(&data, MaxDepth(3), MaxLen(usize::MAX))
+			.as_val::<Nested>()
+			.unwrap_err(),
+		DecoderError::DepthLimitExceeded
+	);
+
+	let rlp = Rlp::new_with_limits(&data, MaxDepth(1), MaxLen(usize::MAX));
+	assert_eq!(rlp.item_count(), Ok(1));
+	let inner = rlp.at(0).unwrap();
+	assert_eq!(inner.item_count(), Err(DecoderError::DepthLimitExceeded));
+	assert_eq!(inner.at(0).unwrap_err(), DecoderError::DepthLimitExceeded);
+	assert_eq!(inner.iter().count(), 0);
+	assert_eq!(inner.try_iter().err(), Some(DecoderError::DepthLimitExceeded));
+	assert_eq!(rlp.try_iter().unwrap().count(), 1);
+	// Data doesn't count towards the depth.
+	let data = hex!("c2c180");
+	assert_eq!(
+		Rlp::new_with_limits(&data, MaxDepth(2), MaxLen(usize::MAX))
+			.at(0)
+			.unwrap()
+			.val_at::<u8>(0),
+		Ok(0)
+	);
+
+	let data = nested_lists(rlp::DEFAULT_MAX_DEPTH - 1);
+	assert!(rlp::decode::<Nested>(&data).is_ok());
+}
+
+#[test]
+fn test_length_limit() {
+	let data = rlp::encode_list::<&str, &str>(&["cat", "horse"]);
+	let rlp = Rlp::new_with_limits(&data, MaxDepth(rlp::DEFAULT_MAX_DEPTH), MaxLen(10));
+	assert_eq!(rlp.as_list::<String>(), Ok(vec!["cat".to_owned(), "horse".to_owned()]));
+
+	// The list takes 10 bytes, the second item 5.
+	let rlp = Rlp::new_with_limits(&data, MaxDepth(rlp::DEFAULT_MAX_DEPTH), MaxLen(9));
+	assert_eq!(rlp.item_count(), Err(DecoderError::LengthLimitExceeded));
+	assert_eq!(rlp.at(0).unwrap_err(), DecoderError::LengthLimitExceeded);
+	assert_eq!(rlp.as_list::<String>(), Err(DecoderError::LengthLimitExceeded));
+	assert_eq!(rlp.try_iter().err(), Some(DecoderError::LengthLimitExceeded));
+	assert_eq!(rlp.iter().count(), 0);
+
+	let horse = rlp::encode(&"horse");
+	let rlp = Rlp::new_with_limits(&horse, MaxDepth(rlp::DEFAULT_MAX_DEPTH), MaxLen(4));
+	assert_eq!(rlp.data(), Err(DecoderError::LengthLimitExceeded));
+	assert_eq!(rlp.as_val::<Vec<u8>>(), Err(DecoderError::LengthLimitExceeded));
+	assert_eq!(rlp.as_val::<&str>(), Err(DecoderError::LengthLimitExceeded));
+	assert_eq!(Rlp::new_with_limits(&horse, MaxDepth(0), MaxLen(5)).as_val::<&str>(), Ok("horse"));
+}
+
+#[test]
+fn test_depth_limit_stops_deep_recursion() {
+	// Decoding this without a limit needs much more stack than the thread has.
+	let data = nested_lists(10_000);
+	let result = std::thread::Builder::new()
+		.stack_size(1024 * 1024)
+		.spawn(move || rlp::decode::<Nested>(&data).map(|_| ()))
+		.unwrap()
+		.join()
+		.unwrap();
+	assert_eq!(result, Err(DecoderError::DepthLimitExceeded));
+}
+
+#[test]
+fn test_indexed_rlp_matches_at() {
+	let long = vec![0xab; 100];
+	let mut stream = RlpStream::new_list(6);
+	stream
+		.append(&0u8)
+		.append(&"cat")
+		.append(&long)
+		.append_list(&[1u64, 2, 3])
+		.begin_list(0)
+		.append(&1u64);
+	let mixed = stream.out();
+	let mut stream = RlpStream::new_list(200);
+	for i in 0..200u64 {
+		stream.append(&(i * 1_000_003));
+	}
+	let many = stream.out();
+	let lists: [&[u8]; 4] = [&[0xc0], &hex!("c3010203"), &mixed, &many];
+
+	for data in lists {
+		let rlp = Rlp::new(data);
+		let indexed = rlp.indexed().unwrap();
+		assert_eq!(indexed.item_count(), rlp.item_count().unwrap());
+		// Backwards, and past the end.
+		for index in (0..indexed.item_count() + 2).rev() {
+			let expected = Rlp::new(data)
+				.at_with_offset(index)
+				.map(|(item, offset)| (item.as_raw(), offset));
+			let found = indexed.at_with_offset(index).map(|(item, offset)| (item.as_raw(), offset));
+			assert_eq!(found, expected, "item {}", index);
+		}
+	}
+
+	let indexed = Rlp::new(&many).indexed().unwrap();
+	for i in (0..200).rev() {
+		assert_eq!(indexed.val_at::<u64>(i), Ok(i as u64 * 1_000_003));
+	}
+	let indexed = Rlp::new(&mixed).indexed().unwrap();
+	assert_eq!(indexed.val_at::<&str>(1), Ok("cat"));
+	assert_eq!(indexed.list_at::<u64>(3), Ok(vec![1, 2, 3]));
+	assert_eq!(indexed.at(5).unwrap().as_raw(), Rlp::new(&mixed).at(5).unwrap().as_raw());
+}
+
+#[test]
+fn test_indexed_rlp_errors() {
+	assert_eq!(Rlp::new(&[0x83, b'c', b'a', b't']).indexed().unwrap_err(), DecoderError::RlpExpectedToBeList);
+	// The last item claims more bytes than the list has.
+	assert_eq!(Rlp::new(&hex!("c30183ab")).indexed().unwrap_err(), DecoderError::RlpIsTooShort);
+	let data = nested_lists(2);
+	let inner = Rlp::new_with_limits(&data, MaxDepth(1), MaxLen(usize::MAX)).at(0).unwrap();
+	assert_eq!(inner.indexed().unwrap_err(), DecoderError::DepthLimitExceeded);
+	// Items keep the depth limit.
+	let indexed = Rlp::new_with_limits(&data, MaxDepth(2), MaxLen(usize::MAX)).indexed().unwrap();
+	assert_eq!(indexed.at(0).unwrap().at(0).unwrap().item_count(), Err(DecoderError::DepthLimitExceeded));
+}
+
+#[test]
+fn test_rlp_stream_reuse() {
+	let mut stream = RlpStream::new_with_buffer(BytesMut::from(&b"prefix"[..]));
+	for count in [3, 0, 60, 1] {
+		let items: Vec<u64> = (0..count).collect();
+		stream.clear();
+		stream.append_list(&items);
+		assert!(stream.is_finished());
+		assert_eq!(&stream.as_raw()[..6], b"prefix");
+		assert_eq!(&stream.as_raw()[6..], &rlp::encode_list::<u64, _>(&items)[..]);
+	}
+
+	// An unfinished stream can be cleared too.
+	stream.clear();
+	stream.begin_list(2).append(&1u8);
+	stream.clear();
+	stream.append(&"cat");
+	assert!(stream.is_finished());
+	assert_eq!(stream.into_buffer(), b"prefix\x83cat"[..]);
+}
+
+#[test]
+fn test_rlp_iterator_len() {
+	for count in [0, 1, 100] {
+		let items: Vec<u64> = (0..count).collect();
+		let data = rlp::encode_list::<u64, u64>(&items);
+		let rlp = Rlp::new(&data);
+		let mut iter = rlp.iter();
+		assert_eq!(iter.len(), count as usize);
+		assert_eq!(iter.size_hint(), (count as usize, Some(count as usize)));
+		if count > 0 {
+			iter.next();
+			assert_eq!(iter.len(), count as usize - 1);
+		}
+
+		let indexed = rlp.indexed().unwrap();
+		assert_eq!(indexed.iter().len(), count as usize);
+		let backwards: Vec<u64> = indexed.iter().rev().map(|item| item.as_val().unwrap()).collect();
+		assert_eq!(backwards, items.iter().rev().cloned().collect::<Vec<_>>());
+	}
+
+	let mut stream = RlpStream::new_list(3);
+	stream.append(&1u8).append(&2u8).append(&3u8);
+	let data = stream.out();
+	let indexed = Rlp::new(&data).indexed().unwrap();
+	let mut iter = indexed.iter();
+	assert_eq!(iter.next().unwrap().as_val::<u8>(), Ok(1));
+	assert_eq!(iter.next_back().unwrap().as_val::<u8>(), Ok(3));
+	assert_eq!(iter.len(), 1);
+	assert_eq!(iter.next().unwrap().as_val::<u8>(), Ok(2));
+	assert!(iter.next_back().is_none());
+}
+
+#[test]
+fn test_rlp_iter_decoded() {
+	let decoded = |data: &[u8]| Rlp::new(data).iter_decoded::<u16>().collect::<Vec<_>>();
+
+	assert_eq!(decoded(&[0xc0]), vec![]);
+	assert_eq!(decoded(&[0xc1, 0x05]), vec![Ok(5)]);
+	// The list is cut short.
+	assert_eq!(decoded(&hex!("c60182010083abcdef")[..5]), vec![Err(DecoderError::RlpIsTooShort)]);
+	// The list is complete but its last item claims more bytes than are left.
+	assert_eq!(decoded(&hex!("c5018201ff83")), vec![Ok(1), Ok(0x1ff), Err(DecoderError::RlpIsTooShort)]);
+	// An item which doesn't decode doesn't end the iteration.
+	assert_eq!(decoded(&hex!("c501c0820102")), vec![Ok(1), Err(DecoderError::RlpExpectedToBeData), Ok(0x102)]);
+	assert_eq!(decoded(&[0x05]), vec![Err(DecoderError::RlpExpectedToBeList)]);
+
+	let data = nested_lists(2);
+	let inner = Rlp::new_with_limits(&data, MaxDepth(1), MaxLen(usize::MAX)).at(0).unwrap();
+	let items: Vec<Result<Vec<u8>, _>> = inner.iter_decoded().collect();
+	assert_eq!(items, vec![Err(DecoderError::DepthLimitExceeded)]);
+
+	let data = rlp::encode_list::<&str, &str>(&["cat", "dog"]);
+	let items: Result<Vec<&str>, _> = Rlp::new(&data).iter_decoded().collect();
+	assert_eq!(items, Ok(vec!["cat", "dog"]));
+}
+
 #[test]
 fn test_rlp_is_int() {
 	for b in 0xb8..0xc0 {
@@ -754,3 +1056,386 @@ fn test_list_at() {
 	let rlp2 = rlp.at(2).unwrap();
 	assert_eq!(rlp2.val_at::<u16>(2).unwrap(), 33338);
 }
+
+/// A list of any `Decodable` type.
+#[derive(Debug, PartialEq)]
+struct List<T>(Vec<T>);
+
+impl<T: Decodable> Decodable for List<T> {
+	fn decode(rlp: &Rlp) -> Result<Self, DecoderError> {
+		rlp.as_list().map(List)
+	}
+}
+
+/// Appends `[[[[0], [1], ..], ..], ..]`, the encoding of `Some(u64)`s in three levels of lists, with
+/// `odd` in place of the item at `[2][0][7]`.
+fn append_tree(stream: &mut RlpStream, odd: impl Fn(&mut RlpStream)) {
+	stream.begin_list(3);
+	for i in 0..3 {
+		stream.begin_list(2);
+		for j in 0..2 {
+			stream.begin_list(10);
+			for k in 0..10u64 {
+				if (i, j, k) == (2, 0, 7) {
+					odd(stream);
+				} else {
+					stream.begin_list(1).append(&k);
+				}
+			}
+		}
+	}
+}
+
+#[test]
+fn test_decode_with_path() {
+	let mut stream = RlpStream::new();
+	append_tree(&mut stream, |stream| {
+		stream.append(&7u64);
+	});
+	let err = rlp::decode_with_path::<List<List<List<Option<u64>>>>>(&stream.out()).unwrap_err();
+	assert_eq!(err.kind, DecoderError::RlpExpectedToBeList);
+	assert_eq!(err.path, vec![2, 0, 7]);
+	assert_eq!(err.to_string(), "expected list at path [2][0][7]");
+
+	let mut stream = RlpStream::new();
+	append_tree(&mut stream, |stream| {
+		stream.begin_list(1).begin_list(0);
+	});
+	let data = stream.out();
+	let err = rlp::decode_with_path::<List<List<List<Option<u64>>>>>(&data).unwrap_err();
+	assert_eq!(err.to_string(), "expected data at path [2][0][7][0]");
+
+	// The same errors as `decode`, which is unchanged.
+	assert_eq!(rlp::decode::<List<List<List<Option<u64>>>>>(&data), Err(DecoderError::RlpExpectedToBeData));
+	let err = rlp::decode_with_path::<u64>(&[0xc0]).unwrap_err();
+	assert_eq!(err.to_string(), "expected data at the root");
+	assert_eq!(rlp::decode_with_path::<List<u64>>(&[0xc2, 0x01, 0x02]), Ok(List(vec![1, 2])));
+
+	// Malformed items.
+	let err = rlp::decode_with_path::<List<Range>>(&[0xc5, 0xc2, 0x01, 0x02, 0xc1, 0x82]).unwrap_err();
+	assert_eq!(err.to_string(), "unexpected end of input at path [1]");
+	let err = rlp::decode_with_path::<List<Option<u64>>>(&[0xc5, 0xc0, 0xc3, 0x82, 0x00, 0x01]).unwrap_err();
+	assert_eq!(err.to_string(), "non-canonical encoding at path [1][0]");
+}
+
+#[derive(Debug, PartialEq)]
+struct Range(u64, u64);
+
+impl Decodable for Range {
+	fn decode(rlp: &Rlp) -> Result<Self, DecoderError> {
+		let items = rlp.indexed()?;
+		let (start, end) = (items.val_at(0)?, items.val_at(1)?);
+		if start > end {
+			return Err(DecoderError::Custom("range ends before its start"))
+		}
+		Ok(Range(start, end))
+	}
+}
+
+#[test]
+fn test_decode_with_path_errors_of_lists() {
+	// Errors found by a list after decoding its items are reported at the path of the list.
+	let mut stream = RlpStream::new_list(3);
+	stream.begin_list(2).append(&1u64).append(&2u64);
+	stream.begin_list(2).append(&3u64).append(&4u64);
+	stream.begin_list(2).append(&6u64).append(&5u64);
+	let err = rlp::decode_with_path::<List<Range>>(&stream.out()).unwrap_err();
+	assert_eq!(err.kind, DecoderError::Custom("range ends before its start"));
+	assert_eq!(err.to_string(), "range ends before its start at path [2]");
+
+	let data = [0xc4, 0xc2, 0x01, 0x02, 0xc0];
+	let err = rlp::decode_with_path::<List<Range>>(&data).unwrap_err();
+	assert_eq!(err.to_string(), "unexpected end of input at path [1]");
+	let data = [0xc4, 0xc2, 0x01, 0x02, 0x03];
+	let err = rlp::decode_with_path::<List<Range>>(&data).unwrap_err();
+	assert_eq!(err.to_string(), "expected list at path [1]");
+
+	// The path of `as_val_with_path` starts from the item it's called on.
+	let mut stream = RlpStream::new_list(2);
+	stream.append(&0u64);
+	stream.begin_list(2).append(&1u64).append(&vec![0u8; 9]);
+	let data = stream.out();
+	let err = Rlp::new(&data).at(1).unwrap().as_val_with_path::<List<u64>>().unwrap_err();
+	assert_eq!(err.kind, DecoderError::RlpIsTooBig);
+	assert_eq!(err.path, vec![1]);
+}
+
+/// Cases of the Ethereum RLP tests, `rlptest.json` and `invalidRLPTest.json`, with the result of
+/// `Rlp::new_strict`.
+#[test]
+fn test_strict_canonical_form() {
+	let long = (0..64u8).collect::<Vec<_>>();
+	let cases: Vec<(&str, Vec<u8>, Result<(), DecoderError>)> = vec![
+		("emptystring", hex!("80").to_vec(), Ok(())),
+		("bytestring00", hex!("00").to_vec(), Ok(())),
+		("bytestring7F", hex!("7f").to_vec(), Ok(())),
+		("shortstring", hex!("83646f67").to_vec(), Ok(())),
+		("shortstring2", [&[0xb7][..], &[b'a'; 55]].concat(), Ok(())),
+		("longstring", [&[0xb8, 0x38][..], &[b'a'; 56]].concat(), Ok(())),
+		("emptylist", hex!("c0").to_vec(), Ok(())),
+		("stringlist", hex!("cc83646f6783676f6483636174").to_vec(), Ok(())),
+		("multilist", hex!("c6827a77c10401").to_vec(), Ok(())),
+		("listsoflists", hex!("c4c2c0c0c0").to_vec(), Ok(())),
+		("listsoflists2", hex!("c7c0c1c0c3c0c1c0").to_vec(), Ok(())),
+		("bytesShouldBeSingleByte00", hex!("8100").to_vec(), Err(DecoderError::RlpNonCanonicalSingleByte)),
+		("bytesShouldBeSingleByte01", hex!("8101").to_vec(), Err(DecoderError::RlpNonCanonicalSingleByte)),
+		("bytesShouldBeSingleByte7F", hex!("817f").to_vec(), Err(DecoderError::RlpNonCanonicalSingleByte)),
+		(
+			"nonOptimalLongLengthArray1",
+			hex!("b81000112233445566778899aabbccddeeff").to_vec(),
+			Err(DecoderError::RlpNonCanonicalLength),
+		),
+		("nonOptimalLongLengthArray2", hex!("b801ff").to_vec(), Err(DecoderError::RlpNonCanonicalLength)),
+		(
+			"nonOptimalLongLengthList1",
+			hex!("f810000102030405060708090a0b0c0d0e0f").to_vec(),
+			Err(DecoderError::RlpNonCanonicalLength),
+		),
+		("nonOptimalLongLengthList2", hex!("f803112233").to_vec(), Err(DecoderError::RlpNonCanonicalLength)),
+		(
+			"leadingZerosInLongLengthArray1",
+			[&hex!("b90040")[..], &long].concat(),
+			Err(DecoderError::RlpNonCanonicalLength),
+		),
+		("leadingZerosInLongLengthArray2", hex!("b800").to_vec(), Err(DecoderError::RlpNonCanonicalLength)),
+		(
+			"leadingZerosInLongLengthList1",
+			[&hex!("fb00000040")[..], &long].concat(),
+			Err(DecoderError::RlpNonCanonicalLength),
+		),
+		("leadingZerosInLongLengthList2", hex!("f800").to_vec(), Err(DecoderError::RlpNonCanonicalLength)),
+		("lessThanShortLengthArray1", hex!("81").to_vec(), Err(DecoderError::RlpIsTooShort)),
+		("lessThanShortLengthList1", hex!("c5010203").to_vec(), Err(DecoderError::RlpIsTooShort)),
+		("lessThanLongLengthArray1", [&hex!("ba010000")[..], &long].concat(), Err(DecoderError::RlpIsTooShort)),
+		("emptyEncoding", vec![], Err(DecoderError::RlpIsTooShort)),
+		// Not canonical in nested items either.
+		("nestedSingleByte", hex!("c3c28105").to_vec(), Err(DecoderError::RlpNonCanonicalSingleByte)),
+		("nestedLongLength", hex!("c4c3b80105").to_vec(), Err(DecoderError::RlpNonCanonicalLength)),
+		("trailingBytes", hex!("c00102").to_vec(), Err(DecoderError::RlpIsTooBig)),
+		("itemOverrunsList", hex!("c3c1820102").to_vec(), Err(DecoderError::RlpIsTooShort)),
+	];
+	for (name, data, expected) in cases {
+		assert_eq!(Rlp::new_strict(&data).map(|_| ()), expected, "{}", name);
+	}
+
+	// The default view accepts some of them.
+	assert_eq!(rlp::decode::<Vec<u8>>(&hex!("b801ff")), Ok(vec![0xff]));
+	assert_eq!(rlp::decode_strict::<Vec<u8>>(&hex!("b801ff")), Err(DecoderError::RlpNonCanonicalLength));
+	assert_eq!(rlp::decode::<u8>(&hex!("0505")), Ok(5));
+	assert_eq!(rlp::decode_strict::<u8>(&hex!("0505")), Err(DecoderError::RlpIsTooBig));
+	assert_eq!(rlp::decode::<Vec<u8>>(&hex!("8105")), Err(DecoderError::RlpInvalidIndirection));
+	assert_eq!(Rlp::new(&hex!("c3c28105")).item_count(), Ok(1));
+}
+
+#[test]
+fn test_strict_integers() {
+	let cases: Vec<(Vec<u8>, Result<u64, DecoderError>, Result<u64, DecoderError>)> = vec![
+		(hex!("80").to_vec(), Ok(0), Ok(0)),
+		(hex!("01").to_vec(), Ok(1), Ok(1)),
+		(hex!("8203e8").to_vec(), Ok(1000), Ok(1000)),
+		(hex!("00").to_vec(), Err(DecoderError::RlpInvalidIndirection), Err(DecoderError::RlpNonCanonicalInteger)),
+		(hex!("820004").to_vec(), Err(DecoderError::RlpInvalidIndirection), Err(DecoderError::RlpNonCanonicalInteger)),
+		(
+			hex!("8400000001").to_vec(),
+			Err(DecoderError::RlpInvalidIndirection),
+			Err(DecoderError::RlpNonCanonicalInteger),
+		),
+	];
+	for (data, default, strict) in cases {
+		assert_eq!(rlp::decode::<u64>(&data), default, "{:?}", data);
+		assert_eq!(rlp::decode_strict::<u64>(&data), strict, "{:?}", data);
+		assert_eq!(rlp::decode_strict::<U256>(&data), strict.map(U256::from), "{:?}", data);
+	}
+	assert_eq!(rlp::decode_strict::<u8>(&hex!("00")), Err(DecoderError::RlpNonCanonicalInteger));
+	assert_eq!(rlp::decode_strict::<bool>(&hex!("00")), Err(DecoderError::RlpNonCanonicalInteger));
+
+	// Items of a strict view are strict too.
+	let data = hex!("c3820004");
+	let rlp = Rlp::new_strict(&data).unwrap();
+	assert!(rlp.is_strict() && rlp.at(0).unwrap().is_strict() && !Rlp::new(&data).is_strict());
+	assert_eq!(rlp.val_at::<u32>(0), Err(DecoderError::RlpNonCanonicalInteger));
+	assert_eq!(
+		rlp.as_val_with_path::<Option<u32>>().unwrap_err().to_string(),
+		"integer with leading zero bytes at path [0]"
+	);
+}
+
+#[test]
+fn test_array_round_trip() {
+	let array = [1u64, 0x400, 0];
+	let encoded = rlp::encode(&array);
+	assert_eq!(encoded, hex!("c50182040080").to_vec());
+	assert_eq!(rlp::decode::<[u64; 3]>(&encoded), Ok(array));
+
+	// A list of integers, unlike `Vec<u8>`.
+	assert_eq!(rlp::encode(&[1u8, 2]), hex!("c20102").to_vec());
+	assert_eq!(rlp::decode::<[u8; 2]>(&hex!("c20102")), Ok([1, 2]));
+	assert_eq!(rlp::encode(&[0u8; 0]), hex!("c0").to_vec());
+	assert_eq!(rlp::decode::<[u8; 0]>(&hex!("c0")), Ok([]));
+
+	let nested = [[String::from("cat"), String::from("dog")], [String::new(), String::from("a")]];
+	assert_eq!(rlp::decode::<[[String; 2]; 2]>(&rlp::encode(&nested)), Ok(nested));
+}
+
+#[test]
+fn test_array_wrong_item_count() {
+	assert_eq!(rlp::decode::<[u64; 3]>(&hex!("c20102")), Err(DecoderError::RlpIncorrectListLen));
+	assert_eq!(rlp::decode::<[u64; 3]>(&hex!("c401020304")), Err(DecoderError::RlpIncorrectListLen));
+	assert_eq!(rlp::decode::<[u64; 1]>(&hex!("c0")), Err(DecoderError::RlpIncorrectListLen));
+	assert_eq!(rlp::decode::<[u64; 2]>(&hex!("820102")), Err(DecoderError::RlpExpectedToBeList));
+	// A malformed item isn't skipped, which would leave the right number of items.
+	assert_eq!(rlp::decode::<[u64; 2]>(&hex!("c4010282ff")), Err(DecoderError::RlpIsTooShort));
+	assert_eq!(rlp::decode::<[u64; 2]>(&hex!("c30102c0")), Err(DecoderError::RlpIncorrectListLen));
+	assert_eq!(rlp::decode::<[u64; 2]>(&hex!("c201c0")), Err(DecoderError::RlpExpectedToBeData));
+}
+
+#[test]
+fn test_tuple_round_trip() {
+	let pair = (7u8, String::from("cat"));
+	let encoded = rlp::encode(&pair);
+	assert_eq!(encoded, hex!("c50783636174").to_vec());
+	assert_eq!(rlp::decode::<(u8, String)>(&encoded), Ok(pair));
+
+	let single = (U256::from(1000),);
+	assert_eq!(rlp::encode(&single), hex!("c38203e8").to_vec());
+	assert_eq!(rlp::decode::<(U256,)>(&hex!("c38203e8")), Ok(single));
+
+	let nested = (Some(1u64), [2u16, 3], (true, vec![4u8, 5]));
+	assert_eq!(rlp::decode::<(Option<u64>, [u16; 2], (bool, Vec<u8>))>(&rlp::encode(&nested)), Ok(nested));
+
+	let twelve = (0u8, 1u16, 2u32, 3u64, 4u128, 5usize, true, String::from("7"), vec![8u8], Some(9u8), [10u8], (11u8,));
+	let encoded = rlp::encode(&twelve);
+	assert_eq!(Rlp::new(&encoded).item_count(), Ok(12));
+	assert_eq!(rlp::decode(&encoded), Ok(twelve));
+}
+
+#[test]
+fn test_tuple_wrong_item_count() {
+	assert_eq!(rlp::decode::<(u8, u8)>(&hex!("c101")), Err(DecoderError::RlpIncorrectListLen));
+	assert_eq!(rlp::decode::<(u8, u8)>(&hex!("c3010203")), Err(DecoderError::RlpIncorrectListLen));
+	assert_eq!(rlp::decode::<(u8,)>(&hex!("c0")), Err(DecoderError::RlpIncorrectListLen));
+	assert_eq!(rlp::decode::<(u8, u8)>(&hex!("0102")), Err(DecoderError::RlpExpectedToBeList));
+	assert_eq!(rlp::decode::<(u8, u8)>(&hex!("c4010282ff")), Err(DecoderError::RlpIsTooShort));
+	assert_eq!(rlp::decode::<(u8, String)>(&hex!("c2c001")), Err(DecoderError::RlpExpectedToBeData));
+	assert_eq!(rlp::decode::<((u8, u8), u8)>(&hex!("c4c301020301")), Err(DecoderError::RlpIncorrectListLen));
+}
+
+#[test]
+fn test_smart_pointers_and_cow() {
+	use std::{borrow::Cow, sync::Arc};
+
+	let arc = Arc::new(String::from("cat"));
+	assert_eq!(rlp::encode(&arc), rlp::encode(&"cat"));
+	assert_eq!(rlp::decode::<Arc<String>>(&rlp::encode(&arc)), Ok(arc));
+
+	let boxed = Box::new([1u8, 2]);
+	assert_eq!(rlp::decode::<Box<[u8; 2]>>(&rlp::encode(&boxed)), Ok(boxed));
+
+	let data = vec![0xab; 60];
+	let borrowed: Cow<[u8]> = Cow::Borrowed(&data);
+	let encoded = rlp::encode(&borrowed);
+	assert_eq!(encoded, rlp::encode(&data));
+	assert_eq!(rlp::encode(&Cow::<[u8]>::Owned(data.clone())), encoded);
+	assert_eq!(rlp::decode::<Cow<[u8]>>(&encoded), Ok(Cow::Owned(data)));
+	assert_eq!(rlp::decode::<Cow<[u8]>>(&hex!("c0")), Err(DecoderError::RlpExpectedToBeData));
+}
+
+/// Asserts that the size hint of `value` is the size of its encoding.
+fn assert_exact_size_hint<E: Encodable>(value: &E) {
+	assert_eq!(value.rlp_size_hint(), rlp::encode(value).len());
+}
+
+#[test]
+fn test_size_hints() {
+	for n in [0u64, 1, 0x7f, 0x80, 0xff, 0x100, u64::MAX] {
+		assert_exact_size_hint(&n);
+		assert_exact_size_hint(&(n as u8));
+		assert_exact_size_hint(&(n as u16));
+		assert_exact_size_hint(&(n as usize));
+		assert_exact_size_hint(&U256::from(n));
+		assert_exact_size_hint(&(U256::from(n) << 200));
+	}
+	assert_exact_size_hint(&u128::MAX);
+	assert_exact_size_hint(&true);
+	assert_exact_size_hint(&false);
+	for len in [0, 1, 55, 56, 255, 256, 0x10000] {
+		let data = vec![0xab; len];
+		assert_exact_size_hint(&data);
+		assert_exact_size_hint(&data.as_slice());
+		assert_exact_size_hint(&Bytes::from(data.clone()));
+		assert_exact_size_hint(&String::from_utf8(vec![b'a'; len]).unwrap());
+		assert_exact_size_hint(&Some(data));
+	}
+	assert_exact_size_hint(&vec![0x05u8]);
+	assert_exact_size_hint(&H160::repeat_byte(0x11));
+	assert_exact_size_hint(&None::<u64>);
+	assert_exact_size_hint(&Box::new(1000u64));
+	assert_exact_size_hint(&[[0xffu64; 8]; 8]);
+	assert_exact_size_hint(&(1u8, "cat", [H160::zero(); 3], (Some(0u64), vec![0u8; 60])));
+}
+
+/// A `Transfer` without a size hint.
+struct Unhinted<'a>(&'a Transfer);
+
+struct Transfer {
+	nonce: u64,
+	to: H160,
+	value: U256,
+	data: Vec<u8>,
+}
+
+impl Encodable for Transfer {
+	fn rlp_append(&self, s: &mut RlpStream) {
+		s.begin_list(4)
+			.append(&self.nonce)
+			.append(&self.to)
+			.append(&self.value)
+			.append(&self.data);
+	}
+
+	fn rlp_size_hint(&self) -> usize {
+		rlp::list_size([
+			self.nonce.rlp_size_hint(),
+			self.to.rlp_size_hint(),
+			self.value.rlp_size_hint(),
+			self.data.rlp_size_hint(),
+		])
+	}
+}
+
+impl Encodable for Unhinted<'_> {
+	fn rlp_append(&self, s: &mut RlpStream) {
+		self.0.rlp_append(s);
+	}
+}
+
+#[test]
+fn test_size_hint_keeps_output() {
+	let transfers: Vec<_> = (0..200u64)
+		.map(|i| Transfer {
+			nonce: i,
+			to: H160::repeat_byte(i as u8),
+			value: U256::from(i) << 100,
+			data: vec![0; i as usize],
+		})
+		.collect();
+	let unhinted: Vec<_> = transfers.iter().map(Unhinted).collect();
+	let encoded = rlp::encode_list::<Transfer, _>(&transfers);
+	assert_eq!(encoded, rlp::encode_list::<Unhinted, _>(&unhinted));
+	for (transfer, unhinted) in transfers.iter().zip(&unhinted) {
+		assert_eq!(rlp::encode(transfer), rlp::encode(unhinted));
+		assert_eq!(transfer.rlp_bytes(), unhinted.rlp_bytes());
+	}
+
+	// The capacity of the stream is enough for the nested lists and their headers.
+	let size = rlp::list_size(transfers.iter().map(Encodable::rlp_size_hint));
+	assert_eq!(size, encoded.len());
+	let mut stream = RlpStream::with_capacity(2, size);
+	let buffer = stream.as_raw().as_ptr();
+	stream.append_list::<Transfer, _>(&transfers);
+	assert_eq!(stream.as_raw().as_ptr(), buffer);
+	assert_eq!(stream.out(), encoded);
+
+	// The hint of a list is unknown if the hint of any of its items is.
+	assert_eq!(rlp::list_size(unhinted.iter().map(Encodable::rlp_size_hint)), 0);
+}
@@ -43,6 +43,7 @@ criterion_group!(
 	u256_mul_full,
 	u256_div,
 	u512_div_mod,
+	u512_div_mod_by_size,
 	u256_rem,
 	u256_integer_sqrt,
 	u256_bit_and,
@@ -193,6 +194,23 @@ fn u512_div_mod(c: &mut Criterion) {
 	group.finish();
 }
 
+// Divides values of the given sizes in words, to compare the cost of the quotient digit
+// estimation across the number of digits and divisor words.
+fn u512_div_mod_by_size(c: &mut Criterion) {
+	let mut group = c.benchmark_group("u512_div_mod_by_size");
+	for (x_words, y_words) in [(8, 1), (8, 2), (8, 3), (8, 4), (8, 6), (8, 7), (6, 4), (4, 2), (4, 3)] {
+		// arbitrary words, with the most significant one nonzero so the sizes are exact
+		let x = (U512::MAX / 7) >> (64 * (8 - x_words));
+		let y = (U512::MAX / 3) >> (64 * (8 - y_words));
+		group.bench_with_input(
+			BenchmarkId::from_parameter(format!("{}/{}", x_words, y_words)),
+			&(x, y),
+			|b, (x, y)| b.iter(|| black_box(black_box(*x).div_mod(*y))),
+		);
+	}
+	group.finish();
+}
+
 fn u256_mul_full(c: &mut Criterion) {
 	let mut group = c.benchmark_group("hex_to_bytes");
 	for input in [(U256::from(42), 1u64), (U256::from(3), u64::max_value())] {
@@ -0,0 +1,110 @@
+// Copyright 2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Iteration over the indices of set or unset bits of a uint.
+
+use core::iter::FusedIterator;
+
+/// An iterator over the indices of the set (or unset) bits of a uint, from the least significant.
+///
+/// Returned by `iter_ones` and `iter_zeros`. Each step skips straight to the next matching bit, so
+/// iterating costs time proportional to the number of indices yielded rather than the bit width.
+#[derive(Debug, Clone)]
+pub struct BitIndices<'a> {
+	limbs: &'a [u64],
+	// Flips every limb, so the unset bits are iterated.
+	mask: u64,
+	// Range of limbs which haven't been loaded into `front` or `back` yet.
+	next: usize,
+	end: usize,
+	// Partially consumed limbs, as the limb index and its remaining bits.
+	front: (usize, u64),
+	back: (usize, u64),
+}
+
+impl<'a> BitIndices<'a> {
+	#[doc(hidden)]
+	pub fn new(limbs: &'a [u64], zeros: bool) -> Self {
+		BitIndices {
+			limbs,
+			mask: if zeros { u64::MAX } else { 0 },
+			next: 0,
+			end: limbs.len(),
+			front: (0, 0),
+			back: (0, 0),
+		}
+	}
+}
+
+impl<'a> Iterator for BitIndices<'a> {
+	type Item = usize;
+
+	fn next(&mut self) -> Option<usize> {
+		loop {
+			let (index, word) = self.front;
+			if word != 0 {
+				self.front.1 = word & (word - 1);
+				return Some(index * 64 + word.trailing_zeros() as usize)
+			}
+			if self.next == self.end {
+				break
+			}
+			self.front = (self.next, self.limbs[self.next] ^ self.mask);
+			self.next += 1;
+		}
+		// All limbs are loaded, so finish off the one partially consumed from the back.
+		let (index, word) = self.back;
+		if word != 0 {
+			self.back.1 = word & (word - 1);
+			return Some(index * 64 + word.trailing_zeros() as usize)
+		}
+		None
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.len();
+		(len, Some(len))
+	}
+}
+
+impl<'a> DoubleEndedIterator for BitIndices<'a> {
+	fn next_back(&mut self) -> Option<usize> {
+		loop {
+			let (index, word) = self.back;
+			if word != 0 {
+				let bit = 63 - word.leading_zeros() as usize;
+				self.back.1 = word & !(1 << bit);
+				return Some(index * 64 + bit)
+			}
+			if self.next == self.end {
+				break
+			}
+			self.end -= 1;
+			self.back = (self.end, self.limbs[self.end] ^ self.mask);
+		}
+		let (index, word) = self.front;
+		if word != 0 {
+			let bit = 63 - word.leading_zeros() as usize;
+			self.front.1 = word & !(1 << bit);
+			return Some(index * 64 + bit)
+		}
+		None
+	}
+}
+
+impl<'a> ExactSizeIterator for BitIndices<'a> {
+	fn len(&self) -> usize {
+		let pending: u32 = self.limbs[self.next..self.end]
+			.iter()
+			.map(|limb| (limb ^ self.mask).count_ones())
+			.sum();
+		(pending + self.front.1.count_ones() + self.back.1.count_ones()) as usize
+	}
+}
+
+impl<'a> FusedIterator for BitIndices<'a> {}
@@ -18,6 +18,10 @@ pub use byteorder;
 #[doc(hidden)]
 pub use core as core_;
 
+#[cfg(feature = "std")]
+#[doc(hidden)]
+pub use std as std_;
+
 #[doc(hidden)]
 pub use hex;
 
@@ -29,6 +33,10 @@ pub use quickcheck;
 #[doc(hidden)]
 pub use arbitrary;
 
+#[cfg(feature = "rand")]
+#[doc(hidden)]
+pub use rand;
+
 #[doc(hidden)]
 pub use static_assertions;
 
@@ -38,3 +46,11 @@ pub use crunchy::unroll;
 #[rustfmt::skip]
 mod uint;
 pub use crate::uint::*;
+
+mod bit_indices;
+pub use crate::bit_indices::BitIndices;
+
+#[cfg(feature = "rand")]
+mod uniform;
+#[cfg(feature = "rand")]
+pub use crate::uniform::{UintSample, UniformUint};
@@ -43,6 +43,9 @@ pub enum FromStrRadixErrKind {
 
 	/// The given radix is not supported.
 	UnsupportedRadix,
+
+	/// The number is too large for the type.
+	Overflow,
 }
 
 #[derive(Debug)]
@@ -65,18 +68,39 @@ impl fmt::Display for FromStrRadixErrSrc {
 pub struct FromStrRadixErr {
 	kind: FromStrRadixErrKind,
 	source: Option<FromStrRadixErrSrc>,
+	position: Option<usize>,
 }
 
 impl FromStrRadixErr {
 	#[doc(hidden)]
 	pub fn unsupported() -> Self {
-		Self { kind: FromStrRadixErrKind::UnsupportedRadix, source: None }
+		Self { kind: FromStrRadixErrKind::UnsupportedRadix, source: None, position: None }
+	}
+
+	#[doc(hidden)]
+	pub fn invalid_character(position: usize) -> Self {
+		Self { kind: FromStrRadixErrKind::InvalidCharacter, source: None, position: Some(position) }
+	}
+
+	#[doc(hidden)]
+	pub fn empty() -> Self {
+		Self { kind: FromStrRadixErrKind::InvalidLength, source: None, position: None }
+	}
+
+	#[doc(hidden)]
+	pub fn overflow() -> Self {
+		Self { kind: FromStrRadixErrKind::Overflow, source: None, position: None }
 	}
 
 	/// Returns the corresponding `FromStrRadixErrKind` for this error.
 	pub fn kind(&self) -> FromStrRadixErrKind {
 		self.kind
 	}
+
+	/// Returns the byte offset of the invalid character in the input, if known.
+	pub fn position(&self) -> Option<usize> {
+		self.position
+	}
 }
 
 impl fmt::Display for FromStrRadixErr {
@@ -85,10 +109,13 @@ impl fmt::Display for FromStrRadixErr {
 			return write!(f, "{}", src);
 		}
 
-		match self.kind {
-			FromStrRadixErrKind::UnsupportedRadix => write!(f, "the given radix is not supported"),
-			FromStrRadixErrKind::InvalidCharacter => write!(f, "input contains an invalid character"),
-			FromStrRadixErrKind::InvalidLength => write!(f, "length not supported for radix or type"),
+		match (self.kind, self.position) {
+			(FromStrRadixErrKind::UnsupportedRadix, _) => write!(f, "the given radix is not supported"),
+			(FromStrRadixErrKind::InvalidCharacter, Some(position)) =>
+				write!(f, "input contains an invalid character at position {}", position),
+			(FromStrRadixErrKind::InvalidCharacter, None) => write!(f, "input contains an invalid character"),
+			(FromStrRadixErrKind::InvalidLength, _) => write!(f, "length not supported for radix or type"),
+			(FromStrRadixErrKind::Overflow, _) => write!(f, "the number is too large for the type"),
 		}
 	}
 }
@@ -108,10 +135,10 @@ impl From<FromDecStrErr> for FromStrRadixErr {
 	fn from(e: FromDecStrErr) -> Self {
 		let kind = match e {
 			FromDecStrErr::InvalidCharacter => FromStrRadixErrKind::InvalidCharacter,
-			FromDecStrErr::InvalidLength => FromStrRadixErrKind::InvalidLength,
+			FromDecStrErr::InvalidLength => FromStrRadixErrKind::Overflow,
 		};
 
-		Self { kind, source: Some(FromStrRadixErrSrc::Dec(e)) }
+		Self { kind, source: Some(FromStrRadixErrSrc::Dec(e)), position: None }
 	}
 }
 
@@ -123,7 +150,7 @@ impl From<FromHexError> for FromStrRadixErr {
 			hex::FromHexError::OddLength => FromStrRadixErrKind::InvalidLength,
 		};
 
-		Self { kind, source: Some(FromStrRadixErrSrc::Hex(e)) }
+		Self { kind, source: Some(FromStrRadixErrSrc::Hex(e)), position: None }
 	}
 }
 
@@ -152,6 +179,28 @@ impl fmt::Display for FromDecStrErr {
 #[cfg(feature = "std")]
 impl std::error::Error for FromDecStrErr {}
 
+/// Conversion error, returned when an integer is out of the range of the type it is converted to.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct TryFromIntErr {
+	message: &'static str,
+}
+
+impl TryFromIntErr {
+	#[doc(hidden)]
+	pub const fn new(message: &'static str) -> Self {
+		Self { message }
+	}
+}
+
+impl fmt::Display for TryFromIntErr {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(self.message)
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TryFromIntErr {}
+
 #[derive(Debug)]
 pub struct FromHexError {
 	inner: hex::FromHexError,
@@ -194,13 +243,13 @@ macro_rules! impl_map_from {
 macro_rules! impl_try_from_for_primitive {
 	($from:ident, $to:ty) => {
 		impl $crate::core_::convert::TryFrom<$from> for $to {
-			type Error = &'static str;
+			type Error = $crate::TryFromIntErr;
 
 			#[inline]
-			fn try_from(u: $from) -> $crate::core_::result::Result<$to, &'static str> {
+			fn try_from(u: $from) -> $crate::core_::result::Result<$to, $crate::TryFromIntErr> {
 				let $from(arr) = u;
 				if !u.fits_word() || arr[0] > <$to>::max_value() as u64 {
-					Err(concat!("integer overflow when casting to ", stringify!($to)))
+					Err($crate::TryFromIntErr::new(concat!("integer overflow when casting to ", stringify!($to))))
 				} else {
 					Ok(arr[0] as $to)
 				}
@@ -484,11 +533,14 @@ macro_rules! construct_uint {
 				}
 			}
 
-			impl $crate::core_::convert::From<i128> for $name {
-				fn from(value: i128) -> $name {
-					match value >= 0 {
-						true => From::from(value as u128),
-						false => { panic!("Unsigned integer can't be created from negative value"); }
+			impl $crate::core_::convert::TryFrom<i128> for $name {
+				type Error = $crate::TryFromIntErr;
+
+				fn try_from(value: i128) -> $crate::core_::result::Result<$name, $crate::TryFromIntErr> {
+					if value < 0 {
+						Err($crate::TryFromIntErr::new("unsigned integer can't be created from negative value"))
+					} else {
+						Ok(From::from(value as u128))
 					}
 				}
 			}
@@ -501,6 +553,15 @@ macro_rules! construct_uint {
 					((arr[1] as u128) << 64) + arr[0] as u128
 				}
 
+				/// Create an integer from a `u128`. Unlike `From<u128>`, this can be used in constants.
+				#[inline]
+				pub const fn from_u128(value: u128) -> Self {
+					let mut words = [0; $n_words];
+					words[0] = value as u64;
+					words[1] = (value >> 64) as u64;
+					$name(words)
+				}
+
 				/// Conversion to u128 with overflow checking
 				///
 				/// # Panics
@@ -520,14 +581,14 @@ macro_rules! construct_uint {
 			}
 
 			impl $crate::core_::convert::TryFrom<$name> for u128 {
-				type Error = &'static str;
+				type Error = $crate::TryFromIntErr;
 
 				#[inline]
-				fn try_from(u: $name) -> $crate::core_::result::Result<u128, &'static str> {
+				fn try_from(u: $name) -> $crate::core_::result::Result<u128, $crate::TryFromIntErr> {
 					let $name(arr) = u;
 					for i in 2..$n_words {
 						if arr[i] != 0 {
-							return Err("integer overflow when casting to u128");
+							return Err($crate::TryFromIntErr::new("integer overflow when casting to u128"));
 						}
 					}
 					Ok(((arr[1] as u128) << 64) + arr[0] as u128)
@@ -535,14 +596,14 @@ macro_rules! construct_uint {
 			}
 
 			impl $crate::core_::convert::TryFrom<$name> for i128 {
-				type Error = &'static str;
+				type Error = $crate::TryFromIntErr;
 
 				#[inline]
-				fn try_from(u: $name) -> $crate::core_::result::Result<i128, &'static str> {
-					let err_str = "integer overflow when casting to i128";
-					let i = u128::try_from(u).map_err(|_| err_str)?;
+				fn try_from(u: $name) -> $crate::core_::result::Result<i128, $crate::TryFromIntErr> {
+					let err = $crate::TryFromIntErr::new("integer overflow when casting to i128");
+					let i = u128::try_from(u).map_err(|_| err)?;
 					if i > i128::max_value() as u128 {
-						Err(err_str)
+						Err(err)
 					} else {
 						Ok(i as i128)
 					}
@@ -575,16 +636,63 @@ macro_rules! construct_uint {
 			/// Maximum value.
 			pub const MAX: $name = $name([u64::max_value(); $n_words]);
 
-			/// Converts a string slice in a given base to an integer. Only supports radixes of 10
-			/// and 16.
+			/// Converts a string slice in a given base to an integer.
+			///
+			/// Radixes from 2 to 36 are supported, with digits above 9 written as letters of either
+			/// case. Underscores may separate the digits, but not come first. For compatibility with
+			/// `FromStr`, a `0x` prefix is accepted in radix 16.
 			pub fn from_str_radix(txt: &str, radix: u32) -> Result<Self, $crate::FromStrRadixErr> {
-				let parsed = match radix {
-					10 => Self::from_dec_str(txt)?,
-					16 => core::str::FromStr::from_str(txt)?,
-					_ => return Err($crate::FromStrRadixErr::unsupported()),
+				if !(2..=36).contains(&radix) {
+					return Err($crate::FromStrRadixErr::unsupported());
+				}
+				let digits = match txt.strip_prefix("0x") {
+					Some(digits) if radix == 16 => digits,
+					_ => txt,
 				};
+				Self::from_digits(digits, radix, txt.len() - digits.len())
+			}
+
+			/// Converts a string slice to an integer, with the radix given by a `0x`, `0o` or `0b`
+			/// prefix, or decimal without one. Underscores may separate the digits, as in
+			/// [`Self::from_str_radix`].
+			pub fn from_prefixed_str(txt: &str) -> Result<Self, $crate::FromStrRadixErr> {
+				let (radix, digits) = match txt.get(..2) {
+					Some("0x") | Some("0X") => (16, &txt[2..]),
+					Some("0o") | Some("0O") => (8, &txt[2..]),
+					Some("0b") | Some("0B") => (2, &txt[2..]),
+					_ => (10, txt),
+				};
+				Self::from_digits(digits, radix, txt.len() - digits.len())
+			}
 
-				Ok(parsed)
+			// Parse `digits` in `radix`, which must be between 2 and 36. Reported positions are offset
+			// by the length of the prefix stripped from the input.
+			fn from_digits(digits: &str, radix: u32, offset: usize) -> Result<Self, $crate::FromStrRadixErr> {
+				let mut res = Self::zero();
+				let mut empty = true;
+				for (i, c) in digits.char_indices() {
+					if c == '_' && !empty {
+						continue;
+					}
+					let digit = match c.to_digit(radix) {
+						Some(digit) => digit,
+						None => return Err($crate::FromStrRadixErr::invalid_character(offset + i)),
+					};
+					let (r, overflow) = res.overflowing_mul_u64(radix as u64);
+					if overflow > 0 {
+						return Err($crate::FromStrRadixErr::overflow());
+					}
+					let (r, overflow) = r.overflowing_add(Self::from(digit));
+					if overflow {
+						return Err($crate::FromStrRadixErr::overflow());
+					}
+					res = r;
+					empty = false;
+				}
+				if empty {
+					return Err($crate::FromStrRadixErr::empty());
+				}
+				Ok(res)
 			}
 
 			/// Convert from a decimal string.
@@ -663,6 +771,59 @@ macro_rules! construct_uint {
 				arr[0] as usize
 			}
 
+			/// Lossy saturating conversion from a `f64`. Like for floating point to primitive integer
+			/// type conversions, this truncates fractional parts.
+			///
+			/// The conversion of `value: f64` behaves as follows:
+			/// - `NaN` => `0`
+			/// - `(-∞, 0]` => `0`
+			/// - `(0, Self::MAX]` => `value as Self`
+			/// - `(Self::MAX, +∞)` => `Self::MAX`
+			pub fn from_f64_lossy(value: f64) -> Self {
+				if value >= 1.0 {
+					let bits = value.to_bits();
+					// NOTE: Don't consider the sign or check that the subtraction will
+					//   underflow since we already checked that the value is greater
+					//   than 1.0.
+					let exponent = (((bits >> 52) & 0x7ff) - 1023) as usize;
+					let mantissa = (bits & 0x0f_ffff_ffff_ffff) | 0x10_0000_0000_0000;
+					if exponent <= 52 {
+						Self::from(mantissa >> (52 - exponent))
+					} else if exponent >= Self::WORD_BITS * $n_words {
+						Self::MAX
+					} else {
+						Self::from(mantissa) << (exponent - 52)
+					}
+				} else {
+					Self::zero()
+				}
+			}
+
+			/// Lossy conversion to `f64`, rounding to the nearest representable value (ties to even).
+			///
+			/// Values too large for a `f64` convert to infinity.
+			pub fn to_f64_lossy(self) -> f64 {
+				let bits = self.bits();
+				if bits <= 64 {
+					return self.low_u64() as f64;
+				}
+				if bits > 1024 {
+					return f64::INFINITY;
+				}
+
+				// Keep the 64 most significant bits, and set the lowest of them if any of the dropped
+				// bits are set. A `f64` only has 53 significant bits, so that lowest bit only breaks
+				// ties when converting to `f64`, which then rounds correctly.
+				let shift = bits - 64;
+				let mut top = (self >> shift).low_u64();
+				if (self.trailing_zeros() as usize) < shift {
+					top |= 1;
+				}
+				// `2^shift` is exactly representable, as `shift` is at most `1024 - 64`.
+				let pow2 = f64::from_bits((shift as u64 + 1023) << 52);
+				top as f64 * pow2
+			}
+
 			/// Whether this is zero.
 			#[inline]
 			pub const fn is_zero(&self) -> bool {
@@ -732,6 +893,106 @@ macro_rules! construct_uint {
 				r
 			}
 
+			/// Returns the number of leading ones in the binary representation of self.
+			pub fn leading_ones(&self) -> u32 {
+				let mut r = 0;
+				for i in 0..$n_words {
+					let w = self.0[$n_words - i - 1];
+					if w == u64::MAX {
+						r += 64;
+					} else {
+						r += w.leading_ones();
+						break;
+					}
+				}
+				r
+			}
+
+			/// Returns the number of trailing ones in the binary representation of self.
+			pub fn trailing_ones(&self) -> u32 {
+				let mut r = 0;
+				for i in 0..$n_words {
+					let w = self.0[i];
+					if w == u64::MAX {
+						r += 64;
+					} else {
+						r += w.trailing_ones();
+						break;
+					}
+				}
+				r
+			}
+
+			/// Returns the bits in `range`, shifted down so the start of the range is bit 0.
+			///
+			/// # Panics
+			///
+			/// Panics if the range is decreasing or its end exceeds the bit width of the number.
+			#[inline]
+			#[track_caller]
+			pub fn bit_range(&self, range: $crate::core_::ops::Range<usize>) -> Self {
+				match self.checked_bit_range(range) {
+					Some(bits) => bits,
+					None => panic!("bit range out of bounds"),
+				}
+			}
+
+			/// Returns the bits in `range`, shifted down so the start of the range is bit 0.
+			///
+			/// Returns `None` if the range is decreasing or its end exceeds the bit width of the number.
+			#[inline]
+			pub fn checked_bit_range(&self, range: $crate::core_::ops::Range<usize>) -> Option<Self> {
+				if range.start > range.end || range.end > Self::WORD_BITS * $n_words {
+					return None;
+				}
+				Some((*self >> range.start).low_bits(range.end - range.start))
+			}
+
+			/// Replaces the bits in `range` with the least significant bits of `value`.
+			///
+			/// Bits of `value` beyond the length of the range are ignored.
+			///
+			/// # Panics
+			///
+			/// Panics if the range is decreasing or its end exceeds the bit width of the number.
+			#[inline]
+			#[track_caller]
+			pub fn set_bit_range(&mut self, range: $crate::core_::ops::Range<usize>, value: Self) {
+				if range.start > range.end || range.end > Self::WORD_BITS * $n_words {
+					panic!("bit range out of bounds");
+				}
+				let mask = Self::MAX.low_bits(range.end - range.start);
+				*self = (*self & !(mask << range.start)) | ((value & mask) << range.start);
+			}
+
+			/// Returns the number of ones in the binary representation of self.
+			#[inline]
+			pub fn count_ones(&self) -> u32 {
+				self.0.iter().map(|w| w.count_ones()).sum()
+			}
+
+			/// Returns the number of zeros in the binary representation of self.
+			#[inline]
+			pub fn count_zeros(&self) -> u32 {
+				self.0.iter().map(|w| w.count_zeros()).sum()
+			}
+
+			/// Returns an iterator over the indices of the set bits, from the least significant.
+			///
+			/// Reverse the iterator to scan from the most significant bit.
+			#[inline]
+			pub fn iter_ones(&self) -> $crate::BitIndices<'_> {
+				$crate::BitIndices::new(&self.0, false)
+			}
+
+			/// Returns an iterator over the indices of the unset bits, from the least significant.
+			///
+			/// Reverse the iterator to scan from the most significant bit.
+			#[inline]
+			pub fn iter_zeros(&self) -> $crate::BitIndices<'_> {
+				$crate::BitIndices::new(&self.0, true)
+			}
+
 			/// Return specific byte. Byte 0 is the least significant value (ie~ little endian).
 			///
 			/// # Panics
@@ -779,6 +1040,133 @@ macro_rules! construct_uint {
 			}
 
 
+			/// Return the memory representation of this integer as a byte array in big-endian byte
+			/// order.
+			#[inline]
+			pub const fn to_be_bytes(&self) -> [u8; $n_words * 8] {
+				let mut bytes = [0u8; $n_words * 8];
+				let mut i = 0;
+				while i < $n_words {
+					let word = self.0[$n_words - i - 1].to_be_bytes();
+					let mut j = 0;
+					while j < 8 {
+						bytes[8 * i + j] = word[j];
+						j += 1;
+					}
+					i += 1;
+				}
+				bytes
+			}
+
+			/// Return the memory representation of this integer as a byte array in little-endian byte
+			/// order.
+			#[inline]
+			pub const fn to_le_bytes(&self) -> [u8; $n_words * 8] {
+				let mut bytes = [0u8; $n_words * 8];
+				let mut i = 0;
+				while i < $n_words {
+					let word = self.0[i].to_le_bytes();
+					let mut j = 0;
+					while j < 8 {
+						bytes[8 * i + j] = word[j];
+						j += 1;
+					}
+					i += 1;
+				}
+				bytes
+			}
+
+			/// Create an integer from its representation as a byte array in big-endian byte order.
+			#[inline]
+			pub const fn from_be_bytes(bytes: [u8; $n_words * 8]) -> Self {
+				let mut ret = [0u64; $n_words];
+				let mut i = 0;
+				while i < $n_words {
+					let mut word = [0u8; 8];
+					let mut j = 0;
+					while j < 8 {
+						word[j] = bytes[8 * i + j];
+						j += 1;
+					}
+					ret[$n_words - i - 1] = u64::from_be_bytes(word);
+					i += 1;
+				}
+				$name(ret)
+			}
+
+			/// Create an integer from its representation as a byte array in little-endian byte order.
+			#[inline]
+			pub const fn from_le_bytes(bytes: [u8; $n_words * 8]) -> Self {
+				let mut ret = [0u64; $n_words];
+				let mut i = 0;
+				while i < $n_words {
+					let mut word = [0u8; 8];
+					let mut j = 0;
+					while j < 8 {
+						word[j] = bytes[8 * i + j];
+						j += 1;
+					}
+					ret[i] = u64::from_le_bytes(word);
+					i += 1;
+				}
+				$name(ret)
+			}
+
+			/// Reads an integer in big-endian byte order from the start of `input`, advancing it past
+			/// the bytes read. Returns `None`, leaving `input` unchanged, if it is too short.
+			#[inline]
+			pub fn take_be(input: &mut &[u8]) -> Option<Self> {
+				Self::take_bytes(input).map(Self::from_be_bytes)
+			}
+
+			/// Reads an integer in little-endian byte order from the start of `input`, advancing it
+			/// past the bytes read. Returns `None`, leaving `input` unchanged, if it is too short.
+			#[inline]
+			pub fn take_le(input: &mut &[u8]) -> Option<Self> {
+				Self::take_bytes(input).map(Self::from_le_bytes)
+			}
+
+			/// Writes the integer in big-endian byte order to the start of `output`, advancing it
+			/// past the bytes written.
+			///
+			/// # Panics
+			///
+			/// Panics if `output` is shorter than the byte width of the type.
+			#[inline]
+			pub fn put_be(&self, output: &mut &mut [u8]) {
+				Self::put_bytes(output, &self.to_be_bytes());
+			}
+
+			/// Writes the integer in little-endian byte order to the start of `output`, advancing it
+			/// past the bytes written.
+			///
+			/// # Panics
+			///
+			/// Panics if `output` is shorter than the byte width of the type.
+			#[inline]
+			pub fn put_le(&self, output: &mut &mut [u8]) {
+				Self::put_bytes(output, &self.to_le_bytes());
+			}
+
+			fn take_bytes(input: &mut &[u8]) -> Option<[u8; $n_words * 8]> {
+				if input.len() < $n_words * 8 {
+					return None;
+				}
+				let (head, rest) = input.split_at($n_words * 8);
+				let mut bytes = [0u8; $n_words * 8];
+				bytes.copy_from_slice(head);
+				*input = rest;
+				Some(bytes)
+			}
+
+			#[track_caller]
+			fn put_bytes(output: &mut &mut [u8], bytes: &[u8; $n_words * 8]) {
+				assert!(output.len() >= bytes.len(), "output is too short to write the integer to");
+				let (head, rest) = $crate::core_::mem::take(output).split_at_mut(bytes.len());
+				head.copy_from_slice(bytes);
+				*output = rest;
+			}
+
 			/// Create `10**n` as this type.
 			///
 			/// # Panics
@@ -812,6 +1200,96 @@ macro_rules! construct_uint {
 				Self::MAX
 			}
 
+			/// Create an integer from its little-endian words.
+			#[inline]
+			pub const fn from_limbs(limbs: [u64; $n_words]) -> Self {
+				$name(limbs)
+			}
+
+			/// Get a reference to the underlying little-endian words.
+			#[inline]
+			pub const fn as_limbs(&self) -> &[u64; $n_words] {
+				&self.0
+			}
+
+			/// Create an integer from a `u64`. Unlike `From<u64>`, this can be used in constants.
+			#[inline]
+			pub const fn from_u64(value: u64) -> Self {
+				let mut words = [0; $n_words];
+				words[0] = value;
+				$name(words)
+			}
+
+			/// Addition for use in constants, where the operators are unavailable.
+			///
+			/// # Panics
+			///
+			/// Panics if the result overflows the type, which fails compilation in a constant.
+			pub const fn const_add(self, other: Self) -> Self {
+				let mut ret = [0u64; $n_words];
+				let mut carry = false;
+				let mut i = 0;
+				while i < $n_words {
+					let (sum, c1) = self.0[i].overflowing_add(other.0[i]);
+					let (sum, c2) = sum.overflowing_add(carry as u64);
+					ret[i] = sum;
+					carry = c1 || c2;
+					i += 1;
+				}
+				$crate::panic_on_overflow!(carry);
+				$name(ret)
+			}
+
+			/// Subtraction for use in constants, where the operators are unavailable.
+			///
+			/// # Panics
+			///
+			/// Panics if `other` is greater than `self`, which fails compilation in a constant.
+			pub const fn const_sub(self, other: Self) -> Self {
+				let mut ret = [0u64; $n_words];
+				let mut borrow = false;
+				let mut i = 0;
+				while i < $n_words {
+					let (diff, b1) = self.0[i].overflowing_sub(other.0[i]);
+					let (diff, b2) = diff.overflowing_sub(borrow as u64);
+					ret[i] = diff;
+					borrow = b1 || b2;
+					i += 1;
+				}
+				$crate::panic_on_overflow!(borrow);
+				$name(ret)
+			}
+
+			/// Multiplication for use in constants, where the operators are unavailable.
+			///
+			/// # Panics
+			///
+			/// Panics if the result overflows the type, which fails compilation in a constant.
+			pub const fn const_mul(self, other: Self) -> Self {
+				let mut ret = [0u64; $n_words];
+				let mut i = 0;
+				while i < $n_words {
+					let mut carry = 0u64;
+					let mut j = 0;
+					while i + j < $n_words {
+						// Can't overflow: (2^64 - 1)^2 + 2 * (2^64 - 1) = 2^128 - 1.
+						let t = self.0[i] as u128 * other.0[j] as u128 + ret[i + j] as u128 + carry as u128;
+						ret[i + j] = t as u64;
+						carry = (t >> 64) as u64;
+						j += 1;
+					}
+					// Whatever is left belongs above the top word.
+					let mut overflow = carry != 0;
+					while j < $n_words {
+						overflow |= self.0[i] != 0 && other.0[j] != 0;
+						j += 1;
+					}
+					$crate::panic_on_overflow!(overflow);
+					i += 1;
+				}
+				$name(ret)
+			}
+
 			fn full_shl(self, shift: u32) -> [u64; $n_words + 1] {
 				debug_assert!(shift < Self::WORD_BITS as u32);
 				let mut u = [0u64; $n_words + 1];
@@ -870,72 +1348,98 @@ macro_rules! construct_uint {
 
 				// quotient
 				let mut q = Self::zero();
-				let v_n_1 = v.0[n - 1];
-				let v_n_2 = v.0[n - 2];
+
+				let reciprocal = Self::reciprocal_3by2(v.0[n - 1], v.0[n - 2]);
 
 				// D2. D7.
 				// iterate from m downto 0
 				for j in (0..=m).rev() {
-					let u_jn = u[j + n];
-
-					// D3.
-					// q_hat is our guess for the j-th quotient digit
-					// q_hat = min(b - 1, (u_{j+n} * b + u_{j+n-1}) / v_{n-1})
-					// b = 1 << WORD_BITS
-					// Theorem B: q_hat >= q_j >= q_hat - 2
-					let mut q_hat = if u_jn < v_n_1 {
-						let (mut q_hat, mut r_hat) = Self::div_mod_word(u_jn, u[j + n - 1], v_n_1);
-						// this loop takes at most 2 iterations
-						loop {
-							// check if q_hat * v_{n-2} > b * r_hat + u_{j+n-2}
-							let (hi, lo) = Self::split_u128(u128::from(q_hat) * u128::from(v_n_2));
-							if (hi, lo) <= (r_hat, u[j + n - 2]) {
-								break;
-							}
-							// then iterate till it doesn't hold
-							q_hat -= 1;
-							let (new_r_hat, overflow) = r_hat.overflowing_add(v_n_1);
-							r_hat = new_r_hat;
-							// if r_hat overflowed, we're done
-							if overflow {
-								break;
-							}
-						}
-						q_hat
-					} else {
-						// here q_hat >= q_j >= q_hat - 1
-						u64::max_value()
-					};
+					q.0[j] = Self::div_mod_knuth_step(&mut u, &v, n, j, reciprocal);
+				}
+
+				// D8.
+				let remainder = Self::full_shr(u, shift);
 
-					// ex. 20:
-					// since q_hat * v_{n-2} <= b * r_hat + u_{j+n-2},
-					// either q_hat == q_j, or q_hat == q_j + 1
+				(q, remainder)
+			}
 
+			// Steps D3 to D6 of `div_mod_knuth`: divides `u[j..=j + n]` by the normalized `v` with `n`
+			// words, leaving the remainder in `u[j..j + n]` and returning the quotient digit.
+			//
+			// The quotient digit is estimated from the top three words of `u` and the top two words of
+			// `v` with `div_mod_3by2`, as in GMP. That estimate is too large by at most one, and only
+			// with a probability of about `2 / b`.
+			fn div_mod_knuth_step(u: &mut [u64], v: &Self, n: usize, j: usize, reciprocal: u64) -> u64 {
+				let (v_n_1, v_n_2) = (v.0[n - 1], v.0[n - 2]);
+				let (u_jn, u_jn_1, u_jn_2) = (u[j + n], u[j + n - 1], u[j + n - 2]);
+
+				// D3.
+				// `u[j..=j + n] < v * b`, so the top two words of `u` are at most those of `v`. If they are
+				// equal, the quotient digit is `b - 1`, which `div_mod_3by2` can't return.
+				if (u_jn, u_jn_1) == (v_n_1, v_n_2) {
 					// D4.
-					// let's assume optimistically q_hat == q_j
-					// subtract (q_hat * v) from u[j..]
-					let q_hat_v = v.full_mul_u64(q_hat);
-					// u[j..] -= q_hat_v;
+					// `v` is normalized, so `v > b^(n - 1)` and `u[j..] - (b - 1) * v` can't overflow.
+					let q_hat_v = v.full_mul_u64(u64::MAX);
 					let c = Self::sub_slice(&mut u[j..], &q_hat_v[..n + 1]);
+					debug_assert!(!c);
+					return u64::MAX;
+				}
 
-					// D6.
-					// actually, q_hat == q_j + 1 and u[j..] has overflowed
-					// highly unlikely ~ (1 / 2^63)
-					if c {
-						q_hat -= 1;
-						// add v to u[j..]
-						let c = Self::add_slice(&mut u[j..], &v.0[..n]);
-						u[j + n] = u[j + n].wrapping_add(u64::from(c));
-					}
+				// The remainder of the top three words, `(r_1, r_0)`, is exact for the top two words of `v`.
+				let (mut q_hat, r_1, r_0) = Self::div_mod_3by2(u_jn, u_jn_1, u_jn_2, v_n_1, v_n_2, reciprocal);
+
+				// D4.
+				// subtract `q_hat` times the remaining words of `v`, borrowing from `(r_1, r_0)`
+				let borrow = Self::mul_sub_slice(&mut u[j..j + n - 2], &v.0[..n - 2], q_hat);
+				let (r_0, c_0) = r_0.overflowing_sub(borrow);
+				let (r_1, c_1) = r_1.overflowing_sub(u64::from(c_0));
+				u[j + n - 2] = r_0;
+				u[j + n - 1] = r_1;
+				u[j + n] = 0;
+
+				// D6.
+				// `q_hat` was one too large, and `u[j..]` has overflowed
+				if c_1 {
+					q_hat -= 1;
+					Self::add_slice(&mut u[j..j + n], &v.0[..n]);
+				}
+
+				q_hat
+			}
+
+			// Computes `(self * other) % modulus` from the full double-width product, so that the
+			// multiplication can't overflow. `modulus` must not be zero.
+			fn full_mul_mod(self, other: Self, mut modulus: Self) -> Self {
+				debug_assert!(!modulus.is_zero());
+				let prod: [u64; $n_words * 2] = $crate::uint_full_mul_reg!($name, $n_words, self, other);
 
-					// D5.
-					q.0[j] = q_hat;
+				if modulus.fits_word() {
+					let modulus = modulus.low_u64();
+					let rem = prod.iter().rev().fold(0, |rem, d| Self::div_mod_word(rem, *d, modulus).1);
+					return rem.into();
 				}
 
-				// D8.
-				let remainder = Self::full_shr(u, shift);
+				// Same as `div_mod_knuth`, but with a dividend of twice the width, and only keeping the
+				// remainder.
+				let n = Self::words(modulus.bits());
+				let shift = modulus.0[n - 1].leading_zeros();
+				modulus <<= shift;
+				let mut u = [0u64; $n_words * 2 + 1];
+				for i in 0..$n_words * 2 {
+					u[i] |= prod[i] << shift;
+					if shift > 0 {
+						u[i + 1] = prod[i] >> (Self::WORD_BITS as u32 - shift);
+					}
+				}
 
-				(q, remainder)
+				let reciprocal = Self::reciprocal_3by2(modulus.0[n - 1], modulus.0[n - 2]);
+				for j in (0..=$n_words * 2 - n).rev() {
+					Self::div_mod_knuth_step(&mut u, &modulus, n, j, reciprocal);
+				}
+
+				let mut rem = [0u64; $n_words + 1];
+				rem.copy_from_slice(&u[..$n_words + 1]);
+				Self::full_shr(rem, shift)
 			}
 
 			// Returns the least number of words needed to represent the nonzero number
@@ -997,6 +1501,96 @@ macro_rules! construct_uint {
 				}
 			}
 
+			/// Returns the base 2 logarithm of `self`, rounded down, or `None` if `self` is zero.
+			#[inline]
+			pub fn checked_ilog2(&self) -> Option<u32> {
+				if self.is_zero() {
+					None
+				} else {
+					Some(self.bits() as u32 - 1)
+				}
+			}
+
+			/// Returns the base 10 logarithm of `self`, rounded down, or `None` if `self` is zero.
+			#[inline]
+			pub fn checked_ilog10(&self) -> Option<u32> {
+				self.checked_ilog(Self::from(10u64))
+			}
+
+			/// Returns the logarithm of `self` with respect to `base`, rounded down, or `None` if
+			/// `self` is zero or `base` is less than 2.
+			pub fn checked_ilog(&self, base: Self) -> Option<u32> {
+				if self.is_zero() || base < Self::from(2u64) {
+					return None;
+				}
+				// Multiply up rather than dividing down, stopping before the power overflows.
+				let mut log = 0;
+				let mut power = base;
+				while power <= *self {
+					log += 1;
+					power = match power.checked_mul(base) {
+						Some(power) => power,
+						None => break,
+					};
+				}
+				Some(log)
+			}
+
+			/// Whether `self` is a power of two. Zero is not.
+			#[inline]
+			pub fn is_power_of_two(&self) -> bool {
+				!self.is_zero() && (*self & (*self - Self::one())).is_zero()
+			}
+
+			/// Returns the smallest power of two greater than or equal to `self`, with 0 mapping to 1.
+			///
+			/// # Panics
+			///
+			/// Panics if the result overflows the type, i.e. if `self` is greater than the largest
+			/// power of two.
+			#[inline]
+			pub fn next_power_of_two(self) -> Self {
+				self.checked_next_power_of_two().expect("arithmetic operation overflow")
+			}
+
+			/// Returns the smallest power of two greater than or equal to `self`, with 0 mapping to 1.
+			/// Returns `None` if the result overflows the type.
+			pub fn checked_next_power_of_two(self) -> Option<Self> {
+				if self <= Self::one() {
+					return Some(Self::one());
+				}
+				// `self - 1` needs `bits` bits, so `1 << bits` is the first power of two from `self` on.
+				let bits = (self - Self::one()).bits();
+				if bits == Self::WORD_BITS * $n_words {
+					None
+				} else {
+					Some(Self::one() << bits)
+				}
+			}
+
+			/// Returns the smallest multiple of `rhs` greater than or equal to `self`, saturating at
+			/// the numeric bounds instead of overflowing.
+			///
+			/// # Panics
+			///
+			/// Panics if `rhs` is zero.
+			#[track_caller]
+			pub fn next_multiple_of(self, rhs: Self) -> Self {
+				match self % rhs {
+					r if r.is_zero() => self,
+					r => self.saturating_add(rhs - r),
+				}
+			}
+
+			/// Returns the smallest multiple of `rhs` greater than or equal to `self`, or `None` if
+			/// `rhs` is zero or the result overflows.
+			pub fn checked_next_multiple_of(self, rhs: Self) -> Option<Self> {
+				match self.checked_rem(rhs)? {
+					r if r.is_zero() => Some(self),
+					r => self.checked_add(rhs - r),
+				}
+			}
+
 			/// Fast exponentiation by squaring
 			/// https://en.wikipedia.org/wiki/Exponentiation_by_squaring
 			///
@@ -1062,6 +1656,172 @@ macro_rules! construct_uint {
 				}
 			}
 
+			/// Exponentiation which saturates at the maximum value (Self::MAX).
+			pub fn saturating_pow(self, expon: u32) -> $name {
+				match self.overflowing_pow($name::from(expon)) {
+					(_, true) => $name::MAX,
+					(val, false) => val,
+				}
+			}
+
+			/// Computes `(self + other) % modulus` without the intermediate sum overflowing.
+			///
+			/// Following the EVM `ADDMOD` convention, returns zero if `modulus` is zero.
+			pub fn add_mod(self, other: Self, modulus: Self) -> Self {
+				if modulus.is_zero() {
+					return Self::zero();
+				}
+
+				// both are less than `modulus`, so their sum is less than `2 * modulus`.
+				let (sum, overflow) = (self % modulus).overflowing_add(other % modulus);
+				if overflow || sum >= modulus {
+					sum.overflowing_sub(modulus).0
+				} else {
+					sum
+				}
+			}
+
+			/// Computes `(self * other) % modulus` without the intermediate product overflowing.
+			///
+			/// Following the EVM `MULMOD` convention, returns zero if `modulus` is zero.
+			pub fn mul_mod(self, other: Self, modulus: Self) -> Self {
+				if modulus.is_zero() {
+					return Self::zero();
+				}
+
+				self.full_mul_mod(other, modulus)
+			}
+
+			/// Modular exponentiation by squaring, computing `self.pow(exp) % modulus` without
+			/// overflowing.
+			///
+			/// # Panics
+			///
+			/// Panics if `modulus` is zero.
+			pub fn pow_mod(self, exp: Self, modulus: Self) -> Self {
+				self.checked_pow_mod(exp, modulus).expect("modulus is zero")
+			}
+
+			/// Checked modular exponentiation. Returns `None` if `modulus` is zero.
+			pub fn checked_pow_mod(self, exp: Self, modulus: Self) -> Option<Self> {
+				if modulus.is_zero() {
+					return None;
+				}
+
+				let mut base = self % modulus;
+				let mut res = Self::one() % modulus;
+				let bits = exp.bits();
+				for i in 0..bits {
+					if exp.bit(i) {
+						res = res.full_mul_mod(base, modulus);
+					}
+					if i + 1 < bits {
+						base = base.full_mul_mod(base, modulus);
+					}
+				}
+				Some(res)
+			}
+
+			/// Computes the modular multiplicative inverse of `self`, the `x < modulus` for which
+			/// `(self * x) % modulus == 1`.
+			///
+			/// Returns `None` if `self` and `modulus` aren't coprime, or if `modulus` is zero or one.
+			pub fn mod_inv(self, modulus: Self) -> Option<Self> {
+				if modulus <= Self::one() {
+					return None;
+				}
+				let a = self % modulus;
+
+				// Split `modulus` into an odd part and a power of two, and combine the inverses
+				// modulo both with the Chinese remainder theorem.
+				let twos = modulus.trailing_zeros() as usize;
+				let odd = modulus >> twos;
+				if twos == 0 {
+					return a.mod_inv_odd(odd);
+				}
+				if a.is_even() {
+					return None;
+				}
+				let inv_pow2 = a.inv_mod_pow2(twos);
+				if odd == Self::one() {
+					return Some(inv_pow2);
+				}
+				let inv_odd = a.mod_inv_odd(odd)?;
+				// `inv_odd + odd * t` is the inverse modulo `2^twos` for this `t < 2^twos`, and it is
+				// less than `odd * 2^twos`.
+				let t = inv_pow2
+					.overflowing_sub(inv_odd).0
+					.overflowing_mul(odd.inv_mod_pow2(twos)).0
+					.low_bits(twos);
+				Some(inv_odd + odd * t)
+			}
+
+			/// The modular inverse for an odd `modulus` greater than one, and `self < modulus`, by
+			/// the binary extended Euclidean algorithm.
+			fn mod_inv_odd(self, modulus: Self) -> Option<Self> {
+				// Halves `x` modulo the odd `modulus`, for `x < modulus`, without overflowing.
+				let halve = |x: Self| {
+					if x.is_even() {
+						x >> 1
+					} else {
+						(x >> 1) + (modulus >> 1) + Self::one()
+					}
+				};
+
+				// Invariants: `u == x1 * self` and `v == x2 * self`, modulo `modulus`.
+				let (mut u, mut v) = (self, modulus);
+				let (mut x1, mut x2) = (Self::one(), Self::zero());
+				while u != Self::one() && v != Self::one() {
+					// `u` only reaches zero by subtracting an equal `v`, their gcd, which isn't one.
+					if u.is_zero() {
+						return None;
+					}
+					while u.is_even() {
+						u >>= 1;
+						x1 = halve(x1);
+					}
+					while v.is_even() {
+						v >>= 1;
+						x2 = halve(x2);
+					}
+					if u >= v {
+						u -= v;
+						x1 = if x1 >= x2 { x1 - x2 } else { modulus - (x2 - x1) };
+					} else {
+						v -= u;
+						x2 = if x2 >= x1 { x2 - x1 } else { modulus - (x1 - x2) };
+					}
+				}
+				Some(if u == Self::one() { x1 } else { x2 })
+			}
+
+			/// The inverse of odd `self` modulo `2^bits`, by Newton's iteration, which doubles the
+			/// number of correct low bits each step.
+			fn inv_mod_pow2(self, bits: usize) -> Self {
+				// Any odd number is its own inverse modulo 8.
+				let mut inv = self;
+				let mut correct = 3;
+				while correct < bits {
+					let two = Self::from(2u64);
+					inv = inv.overflowing_mul(two.overflowing_sub(self.overflowing_mul(inv).0).0).0;
+					correct *= 2;
+				}
+				inv.low_bits(bits)
+			}
+
+			/// Returns the `bits` least significant bits.
+			fn low_bits(self, bits: usize) -> Self {
+				if bits >= Self::WORD_BITS * $n_words {
+					self
+				} else {
+					self & ((Self::one() << bits) - Self::one())
+				}
+			}
+
+			fn is_even(&self) -> bool {
+				self.0[0] & 1 == 0
+			}
+
 			/// Addition which overflows and returns a flag if it does.
 			#[inline(always)]
 			pub fn overflowing_add(self, other: $name) -> ($name, bool) {
@@ -1127,12 +1887,97 @@ macro_rules! construct_uint {
 				}
 			}
 
+			/// Add a signed integer with overflow, returning a flag if it does, which for a negative
+			/// `other` means the result would be below zero.
+			pub fn overflowing_add_signed(self, other: i64) -> ($name, bool) {
+				if other >= 0 {
+					self.overflowing_add($name::from(other as u64))
+				} else {
+					self.overflowing_sub($name::from(other.unsigned_abs()))
+				}
+			}
+
+			/// Checked addition of a signed integer. Returns `None` if the result would overflow or
+			/// be below zero.
+			pub fn checked_add_signed(self, other: i64) -> Option<$name> {
+				match self.overflowing_add_signed(other) {
+					(_, true) => None,
+					(val, _) => Some(val),
+				}
+			}
+
 			/// Multiply with overflow, returning a flag if it does.
 			#[inline(always)]
 			pub fn overflowing_mul(self, other: $name) -> ($name, bool) {
 				$crate::uint_overflowing_mul!($name, $n_words, self, other)
 			}
 
+			/// Multiplies two integers, returning the low and high halves of the full product, which
+			/// is twice the width of the type. Overflow is not possible.
+			///
+			/// Computed by schoolbook multiplication of the 64-bit limbs: every pair of limbs is
+			/// multiplied as a `u128` and added to the limbs of the double-width result, carrying the
+			/// upper 64 bits into the next limb.
+			#[inline(always)]
+			pub fn widening_mul(self, other: $name) -> ($name, $name) {
+				let ret: [u64; $n_words * 2] = $crate::uint_full_mul_reg!($name, $n_words, self, other);
+				let mut lo = Self::zero();
+				let mut hi = Self::zero();
+				lo.0.copy_from_slice(&ret[..$n_words]);
+				hi.0.copy_from_slice(&ret[$n_words..]);
+				(lo, hi)
+			}
+
+			/// Calculates `self + other + carry`, returning the sum and the carry out of the most
+			/// significant limb.
+			///
+			/// Chaining the carry between calls adds numbers wider than the type, which makes this a
+			/// building block for bignum arithmetic on top of it.
+			#[inline]
+			pub fn carrying_add(self, other: $name, carry: bool) -> ($name, bool) {
+				let mut ret = [0u64; $n_words];
+				let mut carry = carry;
+				for i in 0..$n_words {
+					let (sum, overflow1) = self.0[i].overflowing_add(other.0[i]);
+					let (sum, overflow2) = sum.overflowing_add(carry as u64);
+					ret[i] = sum;
+					carry = overflow1 | overflow2;
+				}
+				($name(ret), carry)
+			}
+
+			/// Calculates `self - other - borrow`, returning the difference and whether a borrow was
+			/// needed from beyond the most significant limb.
+			///
+			/// The counterpart of [`carrying_add`](Self::carrying_add) for subtracting numbers wider
+			/// than the type.
+			#[inline]
+			pub fn borrowing_sub(self, other: $name, borrow: bool) -> ($name, bool) {
+				let mut ret = [0u64; $n_words];
+				let mut borrow = borrow;
+				for i in 0..$n_words {
+					let (diff, overflow1) = self.0[i].overflowing_sub(other.0[i]);
+					let (diff, overflow2) = diff.overflowing_sub(borrow as u64);
+					ret[i] = diff;
+					borrow = overflow1 | overflow2;
+				}
+				($name(ret), borrow)
+			}
+
+			/// Calculates `self * other + carry`, returning the low and high halves of the result,
+			/// which is twice the width of the type. Overflow is not possible.
+			///
+			/// Passing the high half as the carry of the next call multiplies a number wider than the
+			/// type by `other`, limb by limb. Use [`widening_mul`](Self::widening_mul) if there is no
+			/// carry.
+			#[inline]
+			pub fn carrying_mul(self, other: $name, carry: $name) -> ($name, $name) {
+				let (lo, hi) = self.widening_mul(other);
+				let (lo, overflow) = lo.overflowing_add(carry);
+				// The result is at most `MAX * MAX + MAX`, which fits in double width.
+				(lo, hi.carrying_add($name::zero(), overflow).0)
+			}
+
 			/// Multiplication which saturates at the maximum value..
 			pub fn saturating_mul(self, other: $name) -> $name {
 				match self.overflowing_mul(other) {
@@ -1149,6 +1994,19 @@ macro_rules! construct_uint {
 				}
 			}
 
+			/// Checked sum of all items. Returns `None` if overflow occurred.
+			pub fn checked_sum<I: IntoIterator<Item = $name>>(iter: I) -> Option<$name> {
+				iter.into_iter().try_fold($name::zero(), $name::checked_add)
+			}
+
+			/// Checked product of all items. Returns `None` if overflow occurred.
+			///
+			/// Stops at the first overflow, so an overflowing product containing a later zero is
+			/// still `None`.
+			pub fn checked_product<I: IntoIterator<Item = $name>>(iter: I) -> Option<$name> {
+				iter.into_iter().try_fold($name::one(), $name::checked_mul)
+			}
+
 			/// Checked division. Returns `None` if `other == 0`.
 			pub fn checked_div(self, other: $name) -> Option<$name> {
 				if other.is_zero() {
@@ -1184,6 +2042,57 @@ macro_rules! construct_uint {
 				}
 			}
 
+			/// Strict addition, panicking on overflow in every build profile, like the `+`
+			/// operator of this type.
+			#[track_caller]
+			pub fn strict_add(self, other: $name) -> $name {
+				self.checked_add(other).expect("arithmetic operation overflow")
+			}
+
+			/// Strict subtraction, panicking on overflow in every build profile, like the `-`
+			/// operator of this type.
+			#[track_caller]
+			pub fn strict_sub(self, other: $name) -> $name {
+				self.checked_sub(other).expect("arithmetic operation overflow")
+			}
+
+			/// Strict multiplication, panicking on overflow in every build profile, like the `*`
+			/// operator of this type.
+			#[track_caller]
+			pub fn strict_mul(self, other: $name) -> $name {
+				self.checked_mul(other).expect("arithmetic operation overflow")
+			}
+
+			/// Strict division, panicking if `other == 0`, like the `/` operator.
+			#[track_caller]
+			pub fn strict_div(self, other: $name) -> $name {
+				self.checked_div(other).expect("division by zero")
+			}
+
+			/// Strict remainder, panicking if `other == 0`, like the `%` operator.
+			#[track_caller]
+			pub fn strict_rem(self, other: $name) -> $name {
+				self.checked_rem(other).expect("division by zero")
+			}
+
+			/// Strict shift left, panicking if `shift` is at least the bit width of the type.
+			///
+			/// Unlike the `<<` operator, which shifts everything out and returns zero in that case.
+			#[track_caller]
+			pub fn strict_shl(self, shift: u32) -> $name {
+				assert!((shift as usize) < Self::WORD_BITS * $n_words, "shift left with overflow");
+				self << shift
+			}
+
+			/// Strict shift right, panicking if `shift` is at least the bit width of the type.
+			///
+			/// Unlike the `>>` operator, which shifts everything out and returns zero in that case.
+			#[track_caller]
+			pub fn strict_shr(self, shift: u32) -> $name {
+				assert!((shift as usize) < Self::WORD_BITS * $n_words, "shift right with overflow");
+				self >> shift
+			}
+
 			#[inline(always)]
 			fn div_mod_word(hi: u64, lo: u64, y: u64) -> (u64, u64) {
 				debug_assert!(hi < y);
@@ -1192,6 +2101,73 @@ macro_rules! construct_uint {
 				((x / y) as u64, (x % y) as u64)
 			}
 
+			// Returns `floor((b^3 - 1) / (d_1 * b + d_0)) - b` for a normalized divisor, where `b = 2^64`.
+			//
+			// See Möller and Granlund, "Improved division by invariant integers", algorithm 6.
+			fn reciprocal_3by2(d_1: u64, d_0: u64) -> u64 {
+				debug_assert!(d_1 >> 63 == 1);
+				// `floor((b^2 - 1) / d_1) - b`, which fits a word as `d_1` is normalized
+				let mut v = (u128::MAX / u128::from(d_1)) as u64;
+				let mut p = d_1.wrapping_mul(v).wrapping_add(d_0);
+				if p < d_0 {
+					v -= 1;
+					if p >= d_1 {
+						v -= 1;
+						p -= d_1;
+					}
+					p = p.wrapping_sub(d_1);
+				}
+				let (t_1, t_0) = Self::split_u128(u128::from(v) * u128::from(d_0));
+				p = p.wrapping_add(t_1);
+				if p < t_1 {
+					v -= 1;
+					if (p, t_0) >= (d_1, d_0) {
+						v -= 1;
+					}
+				}
+				v
+			}
+
+			// Divides `(u_2, u_1, u_0)` by the normalized `(d_1, d_0)`, returning the quotient word and
+			// the remainder. `(u_2, u_1)` must be less than `(d_1, d_0)`, and `reciprocal` must be
+			// `reciprocal_3by2(d_1, d_0)`.
+			//
+			// See Möller and Granlund, "Improved division by invariant integers", algorithm 5.
+			#[inline(always)]
+			fn div_mod_3by2(u_2: u64, u_1: u64, u_0: u64, d_1: u64, d_0: u64, reciprocal: u64) -> (u64, u64, u64) {
+				debug_assert!((u_2, u_1) < (d_1, d_0));
+				let d = (u128::from(d_1) << 64) | u128::from(d_0);
+				let q = (u128::from(reciprocal) * u128::from(u_2)).wrapping_add((u128::from(u_2) << 64) | u128::from(u_1));
+				let (mut q_1, q_0) = Self::split_u128(q);
+				let r_1 = u_1.wrapping_sub(q_1.wrapping_mul(d_1));
+				let t = u128::from(d_0) * u128::from(q_1);
+				let mut r = ((u128::from(r_1) << 64) | u128::from(u_0)).wrapping_sub(t).wrapping_sub(d);
+				q_1 = q_1.wrapping_add(1);
+				if (r >> 64) as u64 >= q_0 {
+					q_1 = q_1.wrapping_sub(1);
+					r = r.wrapping_add(d);
+				}
+				if r >= d {
+					q_1 += 1;
+					r -= d;
+				}
+				let (r_1, r_0) = Self::split_u128(r);
+				(q_1, r_1, r_0)
+			}
+
+			// Subtracts `b * q` from `a`, returning the word borrowed from above `a`.
+			#[inline(always)]
+			fn mul_sub_slice(a: &mut [u64], b: &[u64], q: u64) -> u64 {
+				let mut borrow = 0u64;
+				a.iter_mut().zip(b.iter()).for_each(|(x, y)| {
+					let (hi, lo) = Self::split_u128(u128::from(*y) * u128::from(q) + u128::from(borrow));
+					let (res, c) = x.overflowing_sub(lo);
+					*x = res;
+					borrow = hi + u64::from(c);
+				});
+				borrow
+			}
+
 			#[inline(always)]
 			fn add_slice(a: &mut [u64], b: &[u64]) -> bool {
 				Self::binop_slice(a, b, u64::overflowing_add)
@@ -1320,6 +2296,32 @@ macro_rules! construct_uint {
 				};
 				f.pad_integral(true, "0x", s)
 			}
+
+			// Formats in a radix of `2^digit_bits`, for binary and octal. Digits are read straight from
+			// the words, taking the bits from the next word when a digit straddles two.
+			fn fmt_pow2_radix(&self, f: &mut $crate::core_::fmt::Formatter, digit_bits: usize, prefix: &str) -> $crate::core_::fmt::Result {
+				let &$name(ref data) = self;
+				let mask = (1u64 << digit_bits) - 1;
+				let digits = $crate::core_::cmp::max(1, (self.bits() + digit_bits - 1) / digit_bits);
+
+				let mut buf = [0_u8; $n_words * 64];
+				for (i, digit) in (0..digits).rev().enumerate() {
+					let bit = digit * digit_bits;
+					let (word, offset) = (bit / 64, bit % 64);
+					let mut value = data[word] >> offset;
+					if offset + digit_bits > 64 && word + 1 < $n_words {
+						value |= data[word + 1] << (64 - offset);
+					}
+					// digit < 8, so it is `'0'..'7'`
+					buf[i] = (value & mask) as u8 + b'0';
+				}
+
+				// sequence of `'0'..'7'` chars is guaranteed to be a valid UTF8 string
+				let s = unsafe {
+					$crate::core_::str::from_utf8_unchecked(&buf[..digits])
+				};
+				f.pad_integral(true, prefix, s)
+			}
 		}
 
 		impl $crate::core_::default::Default for $name {
@@ -1432,6 +2434,34 @@ macro_rules! construct_uint {
 		$crate::impl_mul_for_primitive!($name, i64);
 		$crate::impl_mul_for_primitive!($name, isize);
 
+		/// Panics on overflow, like `+`. Use `checked_sum` to handle it instead.
+		impl $crate::core_::iter::Sum for $name {
+			fn sum<I: Iterator<Item = $name>>(iter: I) -> $name {
+				iter.fold($name::zero(), |acc, x| acc + x)
+			}
+		}
+
+		/// Panics on overflow, like `+`. Use `checked_sum` to handle it instead.
+		impl<'a> $crate::core_::iter::Sum<&'a $name> for $name {
+			fn sum<I: Iterator<Item = &'a $name>>(iter: I) -> $name {
+				iter.fold($name::zero(), |acc, x| acc + *x)
+			}
+		}
+
+		/// Panics on overflow, like `*`. Use `checked_product` to handle it instead.
+		impl $crate::core_::iter::Product for $name {
+			fn product<I: Iterator<Item = $name>>(iter: I) -> $name {
+				iter.fold($name::one(), |acc, x| acc * x)
+			}
+		}
+
+		/// Panics on overflow, like `*`. Use `checked_product` to handle it instead.
+		impl<'a> $crate::core_::iter::Product<&'a $name> for $name {
+			fn product<I: Iterator<Item = &'a $name>>(iter: I) -> $name {
+				iter.fold($name::one(), |acc, x| acc * *x)
+			}
+		}
+
 		impl<T> $crate::core_::ops::Div<T> for $name where T: Into<$name> {
 			type Output = $name;
 
@@ -1693,6 +2723,18 @@ macro_rules! construct_uint {
 			}
 		}
 
+		impl $crate::core_::fmt::Binary for $name {
+			fn fmt(&self, f: &mut $crate::core_::fmt::Formatter) -> $crate::core_::fmt::Result {
+				self.fmt_pow2_radix(f, 1, "0b")
+			}
+		}
+
+		impl $crate::core_::fmt::Octal for $name {
+			fn fmt(&self, f: &mut $crate::core_::fmt::Formatter) -> $crate::core_::fmt::Result {
+				self.fmt_pow2_radix(f, 3, "0o")
+			}
+		}
+
 		impl $crate::core_::str::FromStr for $name {
 			type Err = $crate::FromHexError;
 
@@ -1738,6 +2780,8 @@ macro_rules! construct_uint {
 		// uints use 64 bit (8 byte) words
 		$crate::impl_quickcheck_arbitrary_for_uint!($name, ($n_words * 8));
 		$crate::impl_arbitrary_for_uint!($name, ($n_words * 8));
+		$crate::impl_rand_for_uint!($name, $n_words);
+		$crate::impl_io_for_uint!($name, $n_words);
 	}
 }
 
@@ -1749,16 +2793,31 @@ macro_rules! impl_quickcheck_arbitrary_for_uint {
 		impl $crate::quickcheck::Arbitrary for $uint {
 			fn arbitrary(g: &mut $crate::quickcheck::Gen) -> Self {
 				let p = usize::arbitrary(g) % 100;
+				// 20% chance to generate an edge case, which random bytes are unlikely to hit
+				if p < 20 {
+					let bits = $n_bytes * 8;
+					return match u8::arbitrary(g) % 6 {
+						0 => Self::zero(),
+						1 => Self::one(),
+						2 => Self::MAX,
+						3 => Self::MAX - Self::from(u8::arbitrary(g)),
+						// a single bit set
+						4 => Self::one() << (usize::arbitrary(g) % bits),
+						// only the most significant word set
+						_ => Self::from(u64::arbitrary(g)) << (bits - 64),
+					};
+				}
+
 				// make it more likely to generate smaller numbers that
 				// don't use up the full $n_bytes
 				let range =
 					// 10% chance to generate number that uses up to $n_bytes
-					if p < 10 {
+					if p < 30 {
 						$n_bytes
 					// 10% chance to generate number that uses up to $n_bytes / 2
-					} else if p < 20 {
+					} else if p < 40 {
 						$n_bytes / 2
-					// 80% chance to generate number that uses up to $n_bytes / 5
+					// 60% chance to generate number that uses up to $n_bytes / 5
 					} else {
 						$n_bytes / 5
 					};
@@ -1766,8 +2825,9 @@ macro_rules! impl_quickcheck_arbitrary_for_uint {
 				let range = $crate::core_::cmp::max(range, 1);
 				let size: usize = usize::arbitrary(g) % range;
 
+				// the bytes are big-endian, so the least significant ones are at the end
 				let res: [u8; $n_bytes] = $crate::core_::array::from_fn(|i| {
-					if i > size {
+					if i + size + 1 < $n_bytes {
 						0
 					} else {
 						u8::arbitrary(g)
@@ -1798,6 +2858,10 @@ macro_rules! impl_arbitrary_for_uint {
 				u.fill_buffer(&mut res)?;
 				Ok(Self::from_big_endian(&res))
 			}
+
+			fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+				($n_bytes, Some($n_bytes))
+			}
 		}
 	};
 }
@@ -1808,3 +2872,121 @@ macro_rules! impl_arbitrary_for_uint {
 macro_rules! impl_arbitrary_for_uint {
 	($uint: ty, $n_bytes: tt) => {};
 }
+
+// Implementation for enabled rand crate support.
+//
+// # Note
+//
+// Feature guarded macro definitions instead of feature guarded impl blocks
+// to work around the problems of introducing `rand` crate feature in
+// a user crate.
+#[cfg(feature = "rand")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_rand_for_uint {
+	($name: ident, $n_words: tt) => {
+		impl $crate::rand::distributions::Distribution<$name> for $crate::rand::distributions::Standard {
+			fn sample<R: $crate::rand::Rng + ?Sized>(&self, rng: &mut R) -> $name {
+				let mut ret = [0u64; $n_words];
+				for word in ret.iter_mut() {
+					*word = rng.next_u64();
+				}
+				$name(ret)
+			}
+		}
+
+		impl $crate::rand::distributions::uniform::SampleUniform for $name {
+			type Sampler = $crate::UniformUint<$name>;
+		}
+
+		impl $crate::UintSample for $name {
+			fn one() -> Self {
+				$name::one()
+			}
+
+			fn is_zero(&self) -> bool {
+				$name::is_zero(self)
+			}
+
+			fn bits(&self) -> usize {
+				$name::bits(self)
+			}
+
+			fn overflowing_add(self, other: Self) -> (Self, bool) {
+				$name::overflowing_add(self, other)
+			}
+
+			fn overflowing_sub(self, other: Self) -> (Self, bool) {
+				$name::overflowing_sub(self, other)
+			}
+
+			fn sample_bits<R: $crate::rand::Rng + ?Sized>(rng: &mut R, bits: usize) -> Self {
+				let mut ret = [0u64; $n_words];
+				for (i, word) in ret.iter_mut().enumerate() {
+					if bits <= i * 64 {
+						break;
+					}
+					*word = rng.next_u64();
+					if bits - i * 64 < 64 {
+						*word &= (1u64 << (bits - i * 64)) - 1;
+					}
+				}
+				$name(ret)
+			}
+		}
+	};
+}
+
+// Implementation for disabled rand crate support.
+#[cfg(not(feature = "rand"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_rand_for_uint {
+	($name: ident, $n_words: tt) => {};
+}
+
+// Implementation for enabled std support, with `std::io` reading and writing.
+#[cfg(feature = "std")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_io_for_uint {
+	($name: ident, $n_words: tt) => {
+		impl $name {
+			/// Reads an integer in big-endian byte order from `reader`.
+			#[inline]
+			pub fn read_be<R: $crate::std_::io::Read>(reader: &mut R) -> $crate::std_::io::Result<Self> {
+				let mut bytes = [0u8; $n_words * 8];
+				reader.read_exact(&mut bytes)?;
+				Ok(Self::from_be_bytes(bytes))
+			}
+
+			/// Reads an integer in little-endian byte order from `reader`.
+			#[inline]
+			pub fn read_le<R: $crate::std_::io::Read>(reader: &mut R) -> $crate::std_::io::Result<Self> {
+				let mut bytes = [0u8; $n_words * 8];
+				reader.read_exact(&mut bytes)?;
+				Ok(Self::from_le_bytes(bytes))
+			}
+
+			/// Writes the integer in big-endian byte order to `writer`.
+			#[inline]
+			pub fn write_be<W: $crate::std_::io::Write>(&self, writer: &mut W) -> $crate::std_::io::Result<()> {
+				writer.write_all(&self.to_be_bytes())
+			}
+
+			/// Writes the integer in little-endian byte order to `writer`.
+			#[inline]
+			pub fn write_le<W: $crate::std_::io::Write>(&self, writer: &mut W) -> $crate::std_::io::Result<()> {
+				writer.write_all(&self.to_le_bytes())
+			}
+		}
+	};
+}
+
+// Implementation for disabled std support.
+#[cfg(not(feature = "std"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_io_for_uint {
+	($name: ident, $n_words: tt) => {};
+}
@@ -0,0 +1,75 @@
+// Copyright 2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Uniform sampling of uint types in a range.
+
+use rand::{
+	distributions::uniform::{SampleBorrow, SampleUniform, UniformSampler},
+	Rng,
+};
+
+/// The operations on a uint type needed to sample it uniformly, implemented by `construct_uint!`.
+#[doc(hidden)]
+pub trait UintSample: Copy + Ord {
+	fn one() -> Self;
+	fn is_zero(&self) -> bool;
+	fn bits(&self) -> usize;
+	fn overflowing_add(self, other: Self) -> (Self, bool);
+	fn overflowing_sub(self, other: Self) -> (Self, bool);
+	/// Sample a value below `2^bits` with uniformly random bits.
+	fn sample_bits<R: Rng + ?Sized>(rng: &mut R, bits: usize) -> Self;
+}
+
+/// The backend of `rng.gen_range` for types created by `construct_uint!`.
+///
+/// Samples uniformly by rejection: random values with as many bits as the size of the range are
+/// drawn until one falls in the range, which on average takes fewer than two attempts.
+#[derive(Clone, Copy, Debug)]
+pub struct UniformUint<T> {
+	low: T,
+	// The number of values in the range, which is zero if it covers the whole type.
+	range: T,
+}
+
+impl<T: UintSample + SampleUniform> UniformSampler for UniformUint<T> {
+	type X = T;
+
+	fn new<B1, B2>(low: B1, high: B2) -> Self
+	where
+		B1: SampleBorrow<T> + Sized,
+		B2: SampleBorrow<T> + Sized,
+	{
+		let (low, high) = (*low.borrow(), *high.borrow());
+		assert!(low < high, "Uniform::new called with `low >= high`");
+		Self::new_inclusive(low, high.overflowing_sub(T::one()).0)
+	}
+
+	fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+	where
+		B1: SampleBorrow<T> + Sized,
+		B2: SampleBorrow<T> + Sized,
+	{
+		let (low, high) = (*low.borrow(), *high.borrow());
+		assert!(low <= high, "Uniform::new_inclusive called with `low > high`");
+		let range = high.overflowing_sub(low).0.overflowing_add(T::one()).0;
+		UniformUint { low, range }
+	}
+
+	fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> T {
+		if self.range.is_zero() {
+			return T::sample_bits(rng, usize::MAX)
+		}
+		let bits = self.range.overflowing_sub(T::one()).0.bits();
+		loop {
+			let value = T::sample_bits(rng, bits);
+			if value < self.range {
+				return self.low.overflowing_add(value).0
+			}
+		}
+	}
+}
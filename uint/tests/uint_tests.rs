@@ -6,9 +6,13 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use core::{convert::TryInto, str::FromStr, u64::MAX};
+use core::{
+	convert::{TryFrom, TryInto},
+	str::FromStr,
+	u64::MAX,
+};
 use crunchy::unroll;
-use uint::{construct_uint, overflowing, FromDecStrErr};
+use uint::{construct_uint, overflowing, FromDecStrErr, FromStrRadixErrKind, TryFromIntErr};
 
 construct_uint! {
 	pub struct U256(4);
@@ -158,6 +162,29 @@ fn uint256_abs_diff() {
 	assert_eq!(max.abs_diff(max), zero);
 	assert_eq!(zero.abs_diff(max), max);
 	assert_eq!(max.abs_diff(zero), max);
+
+	let one = U256::one();
+	assert_eq!(one.abs_diff(zero), one);
+	assert_eq!(zero.abs_diff(one), one);
+	assert_eq!(max.abs_diff(max - 1), one);
+	assert_eq!((max - 1).abs_diff(max), one);
+	let x = U256([0, 1, 0, 0]);
+	assert_eq!(x.abs_diff(x - 1), one);
+	assert_eq!(U256::from(3).abs_diff(U256::from(10)), U256::from(7));
+	assert_eq!(U256::from(10).abs_diff(U256::from(3)), U256::from(7));
+}
+
+#[test]
+fn uint256_checked_add_signed() {
+	let one = U256::one();
+	assert_eq!(one.checked_add_signed(1), Some(U256::from(2)));
+	assert_eq!(one.checked_add_signed(-1), Some(U256::zero()));
+	assert_eq!(one.checked_add_signed(-2), None);
+	assert_eq!(U256::zero().checked_add_signed(i64::MIN), None);
+	assert_eq!(U256::MAX.checked_add_signed(1), None);
+	assert_eq!(U256::MAX.checked_add_signed(i64::MIN), Some(U256::MAX - (1u64 << 63)));
+	assert_eq!(U256::zero().overflowing_add_signed(-1), (U256::MAX, true));
+	assert_eq!(U256::MAX.overflowing_add_signed(1), (U256::zero(), true));
 }
 
 #[test]
@@ -249,8 +276,9 @@ fn uint256_try_into_primitives() {
 	macro_rules! try_into_primitive_err {
 		($small: ty, $big: ty) => {
 			assert_eq!(
-				U256::from(<$small>::max_value() as $big + 1).try_into() as Result<$small, _>,
-				Err(concat!("integer overflow when casting to ", stringify!($small)))
+				(U256::from(<$small>::max_value() as $big + 1).try_into() as Result<$small, TryFromIntErr>)
+					.map_err(|e| e.to_string()),
+				Err(concat!("integer overflow when casting to ", stringify!($small)).to_string())
 			);
 		};
 	}
@@ -259,14 +287,45 @@ fn uint256_try_into_primitives() {
 	try_into_primitive_err!(u32, u64);
 	try_into_primitive_err!(usize, u128);
 	try_into_primitive_err!(u64, u128);
-	assert_eq!(U256([0, 0, 1, 0]).try_into() as Result<u128, _>, Err("integer overflow when casting to u128"));
+	assert_eq!(
+		(U256([0, 0, 1, 0]).try_into() as Result<u128, TryFromIntErr>).map_err(|e| e.to_string()),
+		Err("integer overflow when casting to u128".to_string())
+	);
 	try_into_primitive_err!(i8, i16);
 	try_into_primitive_err!(i16, i32);
 	try_into_primitive_err!(i32, i64);
-	try_into_primitive_err!(isize, i128);
-	try_into_primitive_err!(i64, i128);
+	try_into_primitive_err!(isize, u128);
+	try_into_primitive_err!(i64, u128);
 	try_into_primitive_err!(i128, u128);
-	assert_eq!(U256([0, 0, 1, 0]).try_into() as Result<i128, _>, Err("integer overflow when casting to i128"));
+	assert_eq!(
+		(U256([0, 0, 1, 0]).try_into() as Result<i128, TryFromIntErr>).map_err(|e| e.to_string()),
+		Err("integer overflow when casting to i128".to_string())
+	);
+}
+
+#[test]
+fn uint_128_bit_conversions() {
+	assert_eq!(U256::from(u128::MAX).try_into(), Ok(u128::MAX));
+	assert!(u128::try_from(U256::from(u128::MAX) + 1).is_err());
+	assert_eq!(U512::from(u128::MAX).try_into(), Ok(u128::MAX));
+	assert!(u128::try_from(U512::from(u128::MAX) + 1).is_err());
+	assert_eq!(U256::from(u128::MAX).low_u128(), u128::MAX);
+	assert_eq!((U256::from(u128::MAX) + 1).low_u128(), 0);
+
+	assert_eq!(U256::from(i128::MAX as u128).try_into(), Ok(i128::MAX));
+	assert!(i128::try_from(U256::from(i128::MAX as u128) + 1).is_err());
+	assert_eq!(U256::try_from(i128::MAX), Ok(U256::from(i128::MAX as u128)));
+	assert_eq!(U512::try_from(0i128), Ok(U512::zero()));
+	assert_eq!(
+		U256::try_from(-1i128).map_err(|e| e.to_string()),
+		Err("unsigned integer can't be created from negative value".to_string())
+	);
+	assert!(U512::try_from(i128::MIN).is_err());
+
+	assert_eq!(U256::from(u64::MAX).try_into(), Ok(u64::MAX));
+	assert!(u64::try_from(U256::from(u64::MAX) + 1).is_err());
+	assert_eq!(U256::from(usize::MAX).try_into(), Ok(usize::MAX));
+	assert!(usize::try_from(U256::from(usize::MAX) + 1).is_err());
 }
 
 #[test]
@@ -476,6 +535,1072 @@ fn uint256_overflowing_pow() {
 	assert_eq!(U256::from(2).overflowing_pow(U256::from(0x100)), (U256::zero(), true));
 }
 
+#[test]
+fn uint256_integer_sqrt() {
+	for (x, sqrt) in [(0u64, 0u64), (1, 1), (2, 1), (3, 1), (4, 2), (8, 2), (9, 3), (u64::MAX, u32::MAX as u64)] {
+		assert_eq!(U256::from(x).integer_sqrt(), U256::from(sqrt));
+	}
+
+	// perfect squares and their neighbours near the top of the range.
+	let r = U256::MAX >> 128;
+	assert_eq!(U256::MAX.integer_sqrt(), r);
+	assert_eq!((r * r).integer_sqrt(), r);
+	assert_eq!((r * r - 1).integer_sqrt(), r - 1);
+	let r = r - 12345;
+	assert_eq!((r * r).integer_sqrt(), r);
+	assert_eq!((r * r - 1).integer_sqrt(), r - 1);
+	assert_eq!((r * r + r + r).integer_sqrt(), r);
+}
+
+#[test]
+fn uint512_integer_sqrt() {
+	let r = U512::MAX >> 256;
+	assert_eq!(U512::MAX.integer_sqrt(), r);
+	assert_eq!((r * r).integer_sqrt(), r);
+	assert_eq!((r * r - 1).integer_sqrt(), r - 1);
+}
+
+#[test]
+fn uint256_pow_mod() {
+	assert_eq!(U256::from(3).pow_mod(U256::from(200), U256::from(1000)), U256::from(1));
+	assert_eq!(U256::from(4).pow_mod(U256::from(13), U256::from(497)), U256::from(445));
+	assert_eq!(U256::from(5).pow_mod(U256::zero(), U256::from(7)), U256::one());
+	assert_eq!(U256::from(5).pow_mod(U256::zero(), U256::one()), U256::zero());
+	assert_eq!(U256::zero().pow_mod(U256::from(5), U256::from(7)), U256::zero());
+	assert_eq!(U256::MAX.pow_mod(U256::MAX, U256::MAX), U256::zero());
+	assert_eq!(U256::MAX.pow_mod(U256::from(2), U256::MAX - 1), U256::one());
+
+	// Fermat's little theorem: `a^(p-1) = 1 (mod p)` for a prime `p`.
+	let secp256k1_p = U256::from_str("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f").unwrap();
+	let example_p =
+		U256::from_dec_str("38873241744847760218045702002058062581688990428170398542849190507947196700873").unwrap();
+	let mersenne_p = U256::from(u64::MAX >> 3);
+	for p in [secp256k1_p, example_p, mersenne_p] {
+		for a in [U256::from(2), U256::from(3), U256::MAX >> 3, p - 1] {
+			assert_eq!(a.pow_mod(p - 1, p), U256::one());
+			assert_eq!(a.pow_mod(p, p), a % p);
+		}
+	}
+	// but not for a composite.
+	assert_ne!(U256::from(2).pow_mod(mersenne_p * 3 - 1, mersenne_p * 3), U256::one());
+}
+
+#[test]
+fn uint256_add_mod() {
+	let m = U256::from(7);
+	assert_eq!(U256::from(3).add_mod(U256::from(5), m), U256::one());
+	assert_eq!(m.add_mod(m, m), U256::zero());
+	assert_eq!(U256::from(3).add_mod(U256::from(5), U256::one()), U256::zero());
+	assert_eq!(U256::from(3).add_mod(U256::from(5), U256::zero()), U256::zero());
+
+	// the sum overflows.
+	assert_eq!(U256::MAX.add_mod(U256::MAX, U256::MAX), U256::zero());
+	assert_eq!(U256::MAX.add_mod(U256::from(2), U256::MAX - 1), U256::from(3));
+	assert_eq!((U256::MAX - 1).add_mod(U256::MAX - 1, U256::MAX), U256::MAX - 2);
+}
+
+#[test]
+fn uint256_mul_mod() {
+	let m = U256::from(7);
+	assert_eq!(U256::from(3).mul_mod(U256::from(5), m), U256::one());
+	assert_eq!(m.mul_mod(U256::from(5), m), U256::zero());
+	assert_eq!(U256::from(3).mul_mod(U256::from(5), U256::one()), U256::zero());
+	assert_eq!(U256::from(3).mul_mod(U256::from(5), U256::zero()), U256::zero());
+
+	// the product overflows.
+	assert_eq!(U256::MAX.mul_mod(U256::MAX, U256::MAX), U256::zero());
+	assert_eq!(U256::MAX.mul_mod(U256::MAX, U256::MAX - 1), U256::one());
+	assert_eq!(U256::MAX.mul_mod(U256::MAX, U256::from(12)), U256::from(9));
+	let m = U256::from_str("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f").unwrap();
+	assert_eq!((m - 1).mul_mod(m - 1, m), U256::one());
+}
+
+#[test]
+fn uint256_checked_pow_mod() {
+	assert_eq!(U256::from(2).checked_pow_mod(U256::from(10), U256::zero()), None);
+	assert_eq!(U256::from(2).checked_pow_mod(U256::from(10), U256::from(1000)), Some(U256::from(24)));
+}
+
+#[test]
+#[should_panic(expected = "modulus is zero")]
+fn uint256_pow_mod_zero_modulus_panics() {
+	U256::from(2).pow_mod(U256::from(10), U256::zero());
+}
+
+#[test]
+fn uint256_widening_mul() {
+	assert_eq!(U256::MAX.widening_mul(U256::MAX), (U256::one(), U256::MAX - 1));
+	assert_eq!(U256::MAX.widening_mul(U256::zero()), (U256::zero(), U256::zero()));
+	assert_eq!(U256::MAX.widening_mul(U256::from(2)), (U256::MAX - 1, U256::one()));
+	assert_eq!((U256::one() << 255).widening_mul(U256::from(4)), (U256::zero(), U256::from(2)));
+
+	// cross-check against multiplying in `U512`.
+	let widen = |x: U256| {
+		let mut words = [0u64; 8];
+		words[..4].copy_from_slice(&x.0);
+		U512(words)
+	};
+	let x = U256::from_str("d3a4c7f1e2b5968f0a1b2c3d4e5f60718293a4b5c6d7e8f9a0b1c2d3e4f50617").unwrap();
+	let y = U256::from_str("8f0a1b2c3d4e5f60718293a4b5c6d7e8f9a0b1c2d3e4f50617d3a4c7f1e2b596").unwrap();
+	let (lo, hi) = x.widening_mul(y);
+	let full = widen(x) * widen(y);
+	assert_eq!(widen(lo), full & widen(U256::MAX));
+	assert_eq!(widen(hi), full >> 256);
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn uint256_to_f64_lossy() {
+	assert_eq!(U256::zero().to_f64_lossy(), 0.0);
+	assert_eq!(U256::from(42).to_f64_lossy(), 42.0);
+
+	// just above 2^53, rounding to the nearest even.
+	let two_53 = U256::one() << 53;
+	assert_eq!((two_53 + 1).to_f64_lossy(), 9007199254740992.0);
+	assert_eq!((two_53 + 3).to_f64_lossy(), 9007199254740996.0);
+	let two_100 = U256::one() << 100;
+	assert_eq!((two_100 + (U256::one() << 47)).to_f64_lossy(), 2f64.powi(100));
+	assert_eq!((two_100 + (U256::one() << 47) + 1).to_f64_lossy(), 2f64.powi(100) + 2f64.powi(48));
+	assert_eq!((two_100 + (U256::one() << 47) * 3).to_f64_lossy(), 2f64.powi(100) + 2f64.powi(49));
+
+	assert_eq!(U256::MAX.to_f64_lossy(), 2f64.powi(256));
+	assert_eq!(U512::MAX.to_f64_lossy(), 2f64.powi(512));
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn uint256_from_f64_lossy() {
+	assert_eq!(U256::from_f64_lossy(0.0), U256::zero());
+	assert_eq!(U256::from_f64_lossy(-0.0), U256::zero());
+	assert_eq!(U256::from_f64_lossy(-1.5), U256::zero());
+	assert_eq!(U256::from_f64_lossy(f64::NEG_INFINITY), U256::zero());
+	assert_eq!(U256::from_f64_lossy(f64::NAN), U256::zero());
+	assert_eq!(U256::from_f64_lossy(0.999), U256::zero());
+	assert_eq!(U256::from_f64_lossy(999.999), U256::from(999));
+	assert_eq!(U256::from_f64_lossy(9007199254740993.0), U256::one() << 53);
+
+	assert_eq!(U256::from_f64_lossy(f64::MAX), U256::MAX);
+	assert_eq!(U256::from_f64_lossy(f64::INFINITY), U256::MAX);
+	assert_eq!(U256::from_f64_lossy(2f64.powi(256)), U256::MAX);
+	assert_eq!(U512::from_f64_lossy(f64::MAX), U512::MAX);
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn uint_f64_lossy_roundtrips_powers_of_two() {
+	for i in 0..256 {
+		let x = U256::one() << i;
+		assert_eq!(x.to_f64_lossy(), 2f64.powi(i));
+		assert_eq!(U256::from_f64_lossy(x.to_f64_lossy()), x);
+	}
+	for i in 0..512 {
+		let x = U512::one() << i;
+		assert_eq!(U512::from_f64_lossy(x.to_f64_lossy()), x);
+	}
+}
+
+#[test]
+fn uint256_next_power_of_two() {
+	for (x, p) in [(0u64, 1u64), (1, 1), (2, 2), (3, 4), (4, 4), (5, 8), (1 << 40, 1 << 40), ((1 << 40) + 1, 1 << 41)] {
+		assert_eq!(U256::from(x).next_power_of_two(), U256::from(p));
+		assert_eq!(U256::from(x).checked_next_power_of_two(), Some(U256::from(p)));
+	}
+	assert_eq!(U256::from(u64::MAX).next_power_of_two(), U256::one() << 64);
+
+	let top = U256::one() << 255;
+	assert_eq!(top.checked_next_power_of_two(), Some(top));
+	assert_eq!((top - 1).checked_next_power_of_two(), Some(top));
+	assert_eq!((top + 1).checked_next_power_of_two(), None);
+	assert_eq!(U256::MAX.checked_next_power_of_two(), None);
+	assert_eq!((U512::one() << 511).checked_next_power_of_two(), Some(U512::one() << 511));
+	assert_eq!((U512::one() << 511 | U512::one()).checked_next_power_of_two(), None);
+
+	for i in 0..256 {
+		let x = U256::one() << i;
+		assert!(x.is_power_of_two());
+		assert_eq!(x.next_power_of_two(), x);
+		if i > 0 {
+			assert!(!(x + 1).is_power_of_two());
+		}
+	}
+	assert!(!U256::zero().is_power_of_two());
+	assert!(!U256::MAX.is_power_of_two());
+}
+
+#[test]
+#[should_panic(expected = "arithmetic operation overflow")]
+fn uint256_next_power_of_two_overflow() {
+	let _ = ((U256::one() << 255) + 1).next_power_of_two();
+}
+
+#[test]
+fn uint256_checked_ilog2() {
+	assert_eq!(U256::zero().checked_ilog2(), None);
+	assert_eq!(U256::one().checked_ilog2(), Some(0));
+	assert_eq!(U256::from(2).checked_ilog2(), Some(1));
+	assert_eq!(U256::from(3).checked_ilog2(), Some(1));
+	assert_eq!(U256::from(u64::MAX).checked_ilog2(), Some(63));
+	assert_eq!((U256::one() << 64).checked_ilog2(), Some(64));
+	assert_eq!(U256::MAX.checked_ilog2(), Some(255));
+	assert_eq!(U512::MAX.checked_ilog2(), Some(511));
+}
+
+#[test]
+fn uint256_checked_ilog10() {
+	assert_eq!(U256::zero().checked_ilog10(), None);
+	assert_eq!(U256::one().checked_ilog10(), Some(0));
+	assert_eq!(U256::from(9).checked_ilog10(), Some(0));
+	let ten = U256::from(10);
+	for k in 1..=77 {
+		let power = ten.pow(U256::from(k));
+		assert_eq!((power - 1).checked_ilog10(), Some(k - 1), "10^{} - 1", k);
+		assert_eq!(power.checked_ilog10(), Some(k), "10^{}", k);
+		assert_eq!((power + 1).checked_ilog10(), Some(k), "10^{} + 1", k);
+	}
+	assert_eq!(U256::MAX.checked_ilog10(), Some(77));
+	assert_eq!(U512::MAX.checked_ilog10(), Some(154));
+}
+
+#[test]
+fn uint256_checked_ilog() {
+	let x = U256::from(80);
+	assert_eq!(x.checked_ilog(U256::zero()), None);
+	assert_eq!(x.checked_ilog(U256::one()), None);
+	assert_eq!(U256::zero().checked_ilog(U256::from(3)), None);
+	assert_eq!(x.checked_ilog(U256::from(3)), Some(3));
+	assert_eq!(U256::from(81).checked_ilog(U256::from(3)), Some(4));
+	assert_eq!(x.checked_ilog(U256::from(81)), Some(0));
+	assert_eq!(x.checked_ilog(U256::from(2)), x.checked_ilog2());
+	assert_eq!(U256::MAX.checked_ilog(U256::MAX), Some(1));
+	assert_eq!(U256::MAX.checked_ilog(U256::one() << 128), Some(1));
+	assert_eq!(U256::MAX.checked_ilog(U256::from(u64::MAX)), Some(4));
+}
+
+#[test]
+fn uint256_be_le_bytes() {
+	const X: U256 = U256::from_be_bytes([
+		0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f, 0x10, 0x11, 0x12,
+		0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e, 0x1f, 0x20,
+	]);
+	const BE: [u8; 32] = X.to_be_bytes();
+	const LE: [u8; 32] = X.to_le_bytes();
+
+	assert_eq!(X, U256([0x191a1b1c1d1e1f20, 0x1112131415161718, 0x090a0b0c0d0e0f10, 0x0102030405060708]));
+	assert_eq!(BE, X.to_big_endian());
+	assert_eq!(LE, X.to_little_endian());
+	let mut reversed = BE;
+	reversed.reverse();
+	assert_eq!(LE, reversed);
+	assert_eq!(U256::from_le_bytes(LE), X);
+	assert_eq!(U256::from_big_endian(&BE), X);
+
+	assert_eq!(U256::from_be_bytes([0; 32]), U256::zero());
+	assert_eq!(U256::one().to_be_bytes()[31], 1);
+	assert_eq!(U256::one().to_le_bytes()[0], 1);
+	assert_eq!(U512::MAX.to_be_bytes(), [0xff; 64]);
+	assert_eq!(U512::from_le_bytes([0xff; 64]), U512::MAX);
+}
+
+#[test]
+fn uint256_const_fns() {
+	// Evaluated at compile time, so these also guard the functions staying `const`.
+	const WEI_PER_ETH: U256 = U256::from_u64(1_000_000_000_000_000_000);
+	const LIMBS: U256 = U256::from_limbs([1, 2, 3, 4]);
+	const FIRST_LIMB: u64 = LIMBS.as_limbs()[0];
+	const WIDE: U256 = U256::from_u128(u128::MAX);
+	const SUM: U256 = WIDE.const_add(U256::one());
+	const DIFF: U256 = SUM.const_sub(U256::one());
+	const SUPPLY: U256 = WEI_PER_ETH.const_mul(U256::from_u64(120_000_000));
+	const SQUARE: U256 = WIDE.const_mul(WIDE);
+	const IS_ZERO: bool = U256::zero().is_zero();
+	const LOW_U64: u64 = SUM.low_u64();
+	const LOW_U128: u128 = DIFF.low_u128();
+	const BIT: bool = SUM.bit(128);
+	const MAX: U512 = U512::MAX.const_sub(U512::one()).const_add(U512::one());
+
+	assert_eq!(WEI_PER_ETH, U256::exp10(18));
+	assert_eq!(LIMBS, U256([1, 2, 3, 4]));
+	assert_eq!(FIRST_LIMB, 1);
+	assert_eq!(WIDE, U256::from(u128::MAX));
+	assert_eq!(SUM, U256::one() << 128);
+	assert_eq!(DIFF, WIDE);
+	assert_eq!(SUPPLY, U256::exp10(18) * 120_000_000u64);
+	assert_eq!(SQUARE, WIDE * WIDE);
+	assert_eq!((IS_ZERO, BIT), (true, true));
+	assert_eq!(LOW_U64, 0);
+	assert_eq!(LOW_U128, u128::MAX);
+	assert_eq!(MAX, U512::MAX);
+}
+
+#[test]
+#[should_panic(expected = "arithmetic operation overflow")]
+fn uint256_const_add_overflow() {
+	let _ = U256::MAX.const_add(U256::one());
+}
+
+#[test]
+#[should_panic(expected = "arithmetic operation overflow")]
+fn uint256_const_sub_overflow() {
+	let _ = U256::zero().const_sub(U256::one());
+}
+
+#[test]
+#[should_panic(expected = "arithmetic operation overflow")]
+fn uint256_const_mul_overflow() {
+	let _ = (U256::one() << 128).const_mul(U256::one() << 128);
+}
+
+#[test]
+fn uint256_from_str_radix() {
+	assert_eq!(U256::from_str_radix("1010", 2).unwrap(), U256::from(10));
+	assert_eq!(U256::from_str_radix("777", 8).unwrap(), U256::from(511));
+	assert_eq!(U256::from_str_radix("1_000_000", 10).unwrap(), U256::from(1_000_000));
+	assert_eq!(U256::from_str_radix("dead_BEEF", 16).unwrap(), U256::from(0xdead_beefu64));
+	assert_eq!(U256::from_str_radix("0xff", 16).unwrap(), U256::from(255));
+	assert_eq!(U256::from_str_radix("zz", 36).unwrap(), U256::from(36 * 36 - 1));
+	assert_eq!(U256::from_str_radix(&"f".repeat(64), 16).unwrap(), U256::MAX);
+	assert_eq!(U256::from_str_radix(&"0".repeat(100), 10).unwrap(), U256::zero());
+	assert_eq!(U256::from_str_radix(&"1".repeat(256), 2).unwrap(), U256::MAX);
+
+	for radix in [0, 1, 37] {
+		assert_eq!(U256::from_str_radix("1", radix).unwrap_err().kind(), FromStrRadixErrKind::UnsupportedRadix);
+	}
+	for (txt, radix, position) in
+		[("12a", 10, 2), ("102", 2, 2), ("_1", 10, 0), ("0x1g", 16, 3), ("0x1", 10, 1), ("-1", 10, 0)]
+	{
+		let err = U256::from_str_radix(txt, radix).unwrap_err();
+		assert_eq!(err.kind(), FromStrRadixErrKind::InvalidCharacter, "{}", txt);
+		assert_eq!(err.position(), Some(position), "{}", txt);
+	}
+	assert_eq!(
+		U256::from_str_radix("12a", 10).unwrap_err().to_string(),
+		"input contains an invalid character at position 2"
+	);
+	for txt in ["", "0x"] {
+		assert_eq!(U256::from_str_radix(txt, 16).unwrap_err().kind(), FromStrRadixErrKind::InvalidLength);
+	}
+	let too_large = [
+		("1".repeat(257), 2),
+		("f".repeat(65), 16),
+		("115792089237316195423570985008687907853269984665640564039457584007913129639936".to_string(), 10),
+	];
+	for (txt, radix) in too_large {
+		assert_eq!(U256::from_str_radix(&txt, radix).unwrap_err().kind(), FromStrRadixErrKind::Overflow);
+	}
+}
+
+#[test]
+fn uint256_from_prefixed_str() {
+	assert_eq!(U256::from_prefixed_str("0x1F").unwrap(), U256::from(31));
+	assert_eq!(U256::from_prefixed_str("0X1f").unwrap(), U256::from(31));
+	assert_eq!(U256::from_prefixed_str("0o17").unwrap(), U256::from(15));
+	assert_eq!(U256::from_prefixed_str("0b1_0000").unwrap(), U256::from(16));
+	assert_eq!(U256::from_prefixed_str("42").unwrap(), U256::from(42));
+	assert_eq!(U256::from_prefixed_str("0").unwrap(), U256::zero());
+	assert_eq!(U256::from_prefixed_str("042").unwrap(), U256::from(42));
+
+	let err = U256::from_prefixed_str("0b102").unwrap_err();
+	assert_eq!((err.kind(), err.position()), (FromStrRadixErrKind::InvalidCharacter, Some(4)));
+	let err = U256::from_prefixed_str("0o8").unwrap_err();
+	assert_eq!((err.kind(), err.position()), (FromStrRadixErrKind::InvalidCharacter, Some(2)));
+	assert_eq!(U256::from_prefixed_str("0x").unwrap_err().kind(), FromStrRadixErrKind::InvalidLength);
+	assert_eq!(U256::from_prefixed_str("").unwrap_err().kind(), FromStrRadixErrKind::InvalidLength);
+	assert_eq!(
+		U256::from_prefixed_str(&format!("0x1{}", "0".repeat(64))).unwrap_err().kind(),
+		FromStrRadixErrKind::Overflow
+	);
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn uint256_gen_range_is_uniform() {
+	use rand::{rngs::StdRng, Rng, SeedableRng};
+
+	let mut rng = StdRng::seed_from_u64(0x5eed);
+	// Ranges within the low limb and straddling a limb boundary.
+	for low in [U256::zero(), U256::from(u64::MAX - 4), U256::MAX - 9] {
+		let mut counts = [0u32; 10];
+		for _ in 0..10_000 {
+			let x = rng.gen_range(low..=low + 9);
+			counts[(x - low).as_usize()] += 1;
+		}
+		// 1000 expected per bucket, with a standard deviation of 30.
+		for count in counts {
+			assert!((850..=1150).contains(&count), "{:?} from {}", counts, low);
+		}
+	}
+
+	let half_open = rng.gen_range(U256::from(5)..U256::from(6));
+	assert_eq!(half_open, U256::from(5));
+	let full = rng.gen_range(U256::zero()..=U256::MAX);
+	assert!(full.bits() > 0);
+	let high = U256::one() << 200;
+	for _ in 0..1000 {
+		assert!(rng.gen_range(high..high * 2) >= high);
+		assert!(rng.gen_range(U256::zero()..high) < high);
+	}
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn uint256_standard_distribution_uses_all_limbs() {
+	use rand::{rngs::StdRng, Rng, SeedableRng};
+
+	let mut rng = StdRng::seed_from_u64(0x5eed);
+	let mut ones = [0u32; 256];
+	for _ in 0..1000 {
+		let x: U256 = rng.gen();
+		for (i, count) in ones.iter_mut().enumerate() {
+			*count += x.bit(i) as u32;
+		}
+	}
+	// Every bit is set about half of the time, with a standard deviation of about 16.
+	for count in ones {
+		assert!((400..=600).contains(&count), "{:?}", ones);
+	}
+	let _: U512 = rng.gen();
+}
+
+#[cfg(feature = "rand")]
+#[test]
+#[should_panic(expected = "low >= high")]
+fn uint256_uniform_empty_range() {
+	let _ = rand::distributions::Uniform::new(U256::one(), U256::one());
+}
+
+#[test]
+fn uint256_strict_ops() {
+	let x = U256::from(12);
+	let y = U256::from(5);
+	assert_eq!(x.strict_add(y), U256::from(17));
+	assert_eq!(x.strict_sub(y), U256::from(7));
+	assert_eq!(x.strict_mul(y), U256::from(60));
+	assert_eq!(x.strict_div(y), U256::from(2));
+	assert_eq!(x.strict_rem(y), U256::from(2));
+	assert_eq!(U256::one().strict_shl(255), U256::one() << 255);
+	assert_eq!(U256::MAX.strict_shr(255), U256::one());
+	assert_eq!(U256::MAX.strict_sub(U256::MAX), U256::zero());
+}
+
+// Like the operators, the strict methods panic whether or not debug assertions are enabled, which
+// is checked by also running these tests with `--release`.
+#[test]
+#[should_panic(expected = "arithmetic operation overflow")]
+fn uint256_strict_add_overflow() {
+	let _ = U256::MAX.strict_add(U256::one());
+}
+
+#[test]
+#[should_panic(expected = "arithmetic operation overflow")]
+fn uint256_strict_sub_overflow() {
+	let _ = U256::zero().strict_sub(U256::one());
+}
+
+#[test]
+#[should_panic(expected = "arithmetic operation overflow")]
+fn uint256_strict_mul_overflow() {
+	let _ = (U256::one() << 128).strict_mul(U256::one() << 128);
+}
+
+#[test]
+#[should_panic(expected = "division by zero")]
+fn uint256_strict_div_by_zero() {
+	let _ = U256::one().strict_div(U256::zero());
+}
+
+#[test]
+#[should_panic(expected = "division by zero")]
+fn uint256_strict_rem_by_zero() {
+	let _ = U256::one().strict_rem(U256::zero());
+}
+
+#[test]
+#[should_panic(expected = "shift left with overflow")]
+fn uint256_strict_shl_overflow() {
+	let _ = U256::one().strict_shl(256);
+}
+
+#[test]
+#[should_panic(expected = "shift right with overflow")]
+fn uint256_strict_shr_overflow() {
+	let _ = U256::one().strict_shr(256);
+}
+
+#[test]
+#[should_panic(expected = "arithmetic operation overflow")]
+fn uint256_add_operator_overflow() {
+	let _ = U256::MAX + 1;
+}
+
+#[test]
+fn uint256_iter_bits() {
+	assert_eq!(U256::zero().iter_ones().next(), None);
+	assert_eq!(U256::zero().iter_zeros().collect::<Vec<_>>(), (0..256).collect::<Vec<_>>());
+	assert_eq!(U256::MAX.iter_ones().rev().collect::<Vec<_>>(), (0..256).rev().collect::<Vec<_>>());
+	assert_eq!(U256::MAX.iter_zeros().next_back(), None);
+	assert_eq!((U256::zero().count_ones(), U256::zero().count_zeros()), (0, 256));
+	assert_eq!((U256::MAX.count_ones(), U256::MAX.count_zeros()), (256, 0));
+
+	let alternating = U256([0x5555_5555_5555_5555; 4]);
+	assert_eq!(alternating.iter_ones().collect::<Vec<_>>(), (0..256).step_by(2).collect::<Vec<_>>());
+	assert_eq!(alternating.iter_zeros().collect::<Vec<_>>(), (1..256).step_by(2).collect::<Vec<_>>());
+	assert_eq!(alternating.count_ones(), 128);
+
+	let sparse = U256::one() | U256::from(1u64 << 63) | (U256::one() << 64) | (U256::one() << 255);
+	let mut iter = sparse.iter_ones();
+	assert_eq!(iter.len(), 4);
+	assert_eq!(iter.next_back(), Some(255));
+	assert_eq!(iter.next(), Some(0));
+	assert_eq!(iter.next_back(), Some(64));
+	assert_eq!(iter.len(), 1);
+	assert_eq!(iter.next_back(), Some(63));
+	assert_eq!(iter.next(), None);
+	assert_eq!(iter.next_back(), None);
+}
+
+fn u512_from_halves(lo: U256, hi: U256) -> U512 {
+	let mut ret = U512::zero();
+	ret.0[..4].copy_from_slice(&lo.0);
+	ret.0[4..].copy_from_slice(&hi.0);
+	ret
+}
+
+#[test]
+fn uint256_carrying_add_and_borrowing_sub() {
+	assert_eq!(U256::from(2).carrying_add(U256::from(3), false), (U256::from(5), false));
+	assert_eq!(U256::from(2).carrying_add(U256::from(3), true), (U256::from(6), false));
+	assert_eq!(U256::MAX.carrying_add(U256::zero(), true), (U256::zero(), true));
+	assert_eq!(U256::MAX.carrying_add(U256::MAX, true), (U256::MAX, true));
+	assert_eq!(U256::from(5).borrowing_sub(U256::from(3), true), (U256::from(1), false));
+	assert_eq!(U256::zero().borrowing_sub(U256::zero(), true), (U256::MAX, true));
+	assert_eq!(U256::zero().borrowing_sub(U256::MAX, true), (U256::zero(), true));
+
+	// Chaining the carry across two halves agrees with the double-width type.
+	let a = U512::MAX / 3;
+	let b = U512::MAX / 5;
+	let halves = |x: U512| (U256(x.0[..4].try_into().unwrap()), U256(x.0[4..].try_into().unwrap()));
+	let ((a_lo, a_hi), (b_lo, b_hi)) = (halves(a), halves(b));
+	let (lo, carry) = a_lo.carrying_add(b_lo, false);
+	let (hi, carry) = a_hi.carrying_add(b_hi, carry);
+	assert_eq!((u512_from_halves(lo, hi), carry), a.overflowing_add(b));
+	let (lo, borrow) = b_lo.borrowing_sub(a_lo, false);
+	let (hi, borrow) = b_hi.borrowing_sub(a_hi, borrow);
+	assert_eq!((u512_from_halves(lo, hi), borrow), b.overflowing_sub(a));
+}
+
+#[test]
+fn uint256_carrying_mul() {
+	assert_eq!(U256::from(6).carrying_mul(U256::from(7), U256::from(3)), (U256::from(45), U256::zero()));
+	assert_eq!(U256::MAX.carrying_mul(U256::MAX, U256::zero()), (U256::one(), U256::MAX - 1));
+	assert_eq!(U256::MAX.carrying_mul(U256::MAX, U256::MAX), (U256::zero(), U256::MAX));
+	assert_eq!(U256::MAX.carrying_mul(U256::zero(), U256::MAX), (U256::MAX, U256::zero()));
+
+	let (a, b, c) = (U256::MAX / 7, U256::MAX - 12345, U256::MAX / 3);
+	let (lo, hi) = a.carrying_mul(b, c);
+	let widen = |x: U256| u512_from_halves(x, U256::zero());
+	let expected = widen(a) * widen(b) + widen(c);
+	assert_eq!(u512_from_halves(lo, hi), expected);
+}
+
+#[test]
+fn uint256_saturating_pow() {
+	assert_eq!(U256::from(7).saturating_pow(0), U256::one());
+	assert_eq!(U256::zero().saturating_pow(0), U256::one());
+	assert_eq!(U256::zero().saturating_pow(1000), U256::zero());
+	assert_eq!(U256::one().saturating_pow(u32::MAX), U256::one());
+	assert_eq!(U256::from(10).saturating_pow(3), U256::from(1000));
+	assert_eq!(U256::from(2).saturating_pow(255), U256::one() << 255);
+	assert_eq!(U256::from(2).saturating_pow(256), U256::MAX);
+	assert_eq!(U256::from(3).saturating_pow(161), U256::from(3).pow(U256::from(161)));
+	assert_eq!(U256::from(3).saturating_pow(162), U256::MAX);
+	assert_eq!(U256::MAX.saturating_pow(1), U256::MAX);
+	assert_eq!(U256::MAX.saturating_pow(2), U256::MAX);
+}
+
+#[test]
+fn uint256_next_multiple_of() {
+	assert_eq!(U256::from(16).next_multiple_of(U256::from(8)), U256::from(16));
+	assert_eq!(U256::from(23).next_multiple_of(U256::from(8)), U256::from(24));
+	assert_eq!(U256::zero().next_multiple_of(U256::from(8)), U256::zero());
+	assert_eq!(U256::from(23).checked_next_multiple_of(U256::from(8)), Some(U256::from(24)));
+	assert_eq!(U256::from(23).checked_next_multiple_of(U256::zero()), None);
+	assert_eq!(U256::MAX.checked_next_multiple_of(U256::MAX), Some(U256::MAX));
+	assert_eq!(U256::MAX.checked_next_multiple_of(U256::from(2)), None);
+	assert_eq!((U256::MAX - 1).checked_next_multiple_of(U256::from(2)), Some(U256::MAX - 1));
+}
+
+#[test]
+#[should_panic(expected = "division by zero")]
+fn uint256_next_multiple_of_zero() {
+	let _ = U256::one().next_multiple_of(U256::zero());
+}
+
+#[test]
+fn uint256_next_multiple_of_saturates() {
+	assert_eq!((U256::MAX - 1).next_multiple_of(U256::from(2)), U256::MAX - 1);
+	assert_eq!(U256::MAX.next_multiple_of(U256::from(2)), U256::MAX);
+	assert_eq!(U256::MAX.next_multiple_of(U256::MAX), U256::MAX);
+	assert_eq!((U256::one() << 255).next_multiple_of(U256::one() << 255), U256::one() << 255);
+	assert_eq!(((U256::one() << 255) + 1).next_multiple_of(U256::one() << 255), U256::MAX);
+}
+
+#[test]
+fn uint256_mod_inv() {
+	let inv = |a: u64, m: u64| U256::from(a).mod_inv(U256::from(m));
+	assert_eq!(inv(3, 7), Some(U256::from(5)));
+	assert_eq!(inv(10, 17), Some(U256::from(12)));
+	assert_eq!(inv(1, 2), Some(U256::one()));
+	assert_eq!(inv(3, 8), Some(U256::from(3)));
+	assert_eq!(inv(7, 10), Some(U256::from(3)));
+	assert_eq!(inv(27, 12), None);
+	assert_eq!(inv(6, 9), None);
+	assert_eq!(inv(2, 4), None);
+	assert_eq!(inv(0, 7), None);
+	assert_eq!(inv(14, 7), None);
+	assert_eq!(inv(3, 1), None);
+	assert_eq!(inv(3, 0), None);
+	// Reduces `self` first.
+	assert_eq!(inv(24, 7), Some(U256::from(5)));
+
+	let secp256k1_p =
+		U256::from_str_radix("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F", 16).unwrap();
+	assert_eq!(U256::from(2).mod_inv(secp256k1_p), Some((secp256k1_p >> 1) + 1));
+	assert_eq!(
+		U256::from(3).mod_inv(secp256k1_p),
+		Some(U256::from_str_radix("aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa9fffffd75", 16).unwrap())
+	);
+	let g_x = U256::from_str_radix("79BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798", 16).unwrap();
+	assert_eq!(
+		g_x.mod_inv(secp256k1_p),
+		Some(U256::from_str_radix("237afdf1d2938d86870aaeb8ad77626a67b8e794abfb076be61d003687ca9ef6", 16).unwrap())
+	);
+
+	// Moduli at the edges of the range.
+	assert_eq!(U256::from(2).mod_inv(U256::MAX), Some(U256::one() << 255));
+	assert_eq!(U256::MAX.mod_inv(U256::MAX), None);
+	assert_eq!(
+		U256::from(3).mod_inv(U256::one() << 255),
+		Some(U256::from_str_radix("2aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaab", 16).unwrap())
+	);
+	assert_eq!(
+		U256::from(12345).mod_inv(U256::from(1000003) << 64),
+		Some(U256::from_str_radix("37ed94963847d55d4be09", 16).unwrap())
+	);
+}
+
+#[test]
+fn uint256_sum_and_product() {
+	let values = [U256::from(3), U256::from(5), U256::from(7)];
+	assert_eq!(values.iter().sum::<U256>(), U256::from(15));
+	assert_eq!(values.iter().copied().sum::<U256>(), U256::from(15));
+	assert_eq!(values.iter().product::<U256>(), U256::from(105));
+	assert_eq!(values.iter().copied().product::<U256>(), U256::from(105));
+	assert_eq!(core::iter::empty::<U256>().sum::<U256>(), U256::zero());
+	assert_eq!(core::iter::empty::<U256>().product::<U256>(), U256::one());
+
+	assert_eq!(U256::checked_sum(values), Some(U256::from(15)));
+	assert_eq!(U256::checked_product(values.iter().copied()), Some(U256::from(105)));
+	assert_eq!(U256::checked_sum(vec![]), Some(U256::zero()));
+	assert_eq!(U256::checked_product(vec![]), Some(U256::one()));
+
+	// Each item fits, but the total doesn't.
+	let halves = [U256::MAX / 2, U256::MAX / 2, U256::from(2)];
+	assert_eq!(U256::checked_sum(halves), None);
+	assert_eq!(U256::checked_sum(halves[..2].iter().copied()), Some(U256::MAX - 1));
+	assert_eq!(U256::checked_product(vec![U256::one() << 128; 2]), None);
+	assert_eq!(U256::checked_product(vec![U256::one() << 128, U256::one() << 127]), Some(U256::one() << 255));
+}
+
+#[test]
+#[should_panic(expected = "arithmetic operation overflow")]
+fn uint256_sum_overflow() {
+	let _: U256 = [U256::MAX, U256::one()].iter().sum();
+}
+
+#[test]
+#[should_panic(expected = "arithmetic operation overflow")]
+fn uint256_product_overflow() {
+	let _: U256 = vec![U256::one() << 128; 2].into_iter().product();
+}
+
+#[test]
+fn uint256_take_and_put() {
+	let x = U256::from_str_radix("0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20", 16).unwrap();
+	let mut buffer = [0u8; 70];
+	let mut output = &mut buffer[..];
+	x.put_be(&mut output);
+	x.put_le(&mut output);
+	assert_eq!(output.len(), 6);
+	assert_eq!(buffer[..32], x.to_be_bytes());
+	assert_eq!(buffer[32..64], x.to_little_endian());
+
+	let mut input = &buffer[..];
+	assert_eq!(U256::take_be(&mut input), Some(x));
+	assert_eq!(U256::take_le(&mut input), Some(x));
+	assert_eq!(input, &[0u8; 6]);
+	assert_eq!(U256::take_be(&mut input), None);
+	assert_eq!(input.len(), 6);
+
+	let mut input = &buffer[1..33];
+	assert_eq!(U256::take_be(&mut input), Some(U256::from_big_endian(&buffer[1..33])));
+	assert!(input.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "output is too short to write the integer to")]
+fn uint256_put_into_short_output() {
+	let mut buffer = [0u8; 31];
+	U256::one().put_be(&mut &mut buffer[..]);
+}
+
+#[test]
+fn uint256_read_and_write() {
+	use std::io::{Cursor, ErrorKind};
+
+	let x = U256::MAX / 3;
+	let mut writer = Vec::new();
+	x.write_be(&mut writer).unwrap();
+	x.write_le(&mut writer).unwrap();
+	assert_eq!(writer[..32], x.to_big_endian());
+	assert_eq!(writer[32..], x.to_little_endian());
+
+	let mut reader = Cursor::new(&writer);
+	assert_eq!(U256::read_be(&mut reader).unwrap(), x);
+	assert_eq!(U256::read_le(&mut reader).unwrap(), x);
+	assert_eq!(U256::read_be(&mut reader).unwrap_err().kind(), ErrorKind::UnexpectedEof);
+
+	let mut short = &writer[..31];
+	assert_eq!(U256::read_le(&mut short).unwrap_err().kind(), ErrorKind::UnexpectedEof);
+	assert_eq!(x.write_be(&mut &mut [0u8; 31][..]).unwrap_err().kind(), ErrorKind::WriteZero);
+}
+
+#[test]
+fn uint256_leading_and_trailing_ones() {
+	assert_eq!(U256::zero().leading_ones(), 0);
+	assert_eq!(U256::MAX.leading_ones(), 256);
+	assert_eq!(U256::MAX.trailing_ones(), 256);
+	assert_eq!((U256::MAX >> 1).leading_ones(), 0);
+	assert_eq!((U256::MAX << 70).leading_ones(), 186);
+	assert_eq!((U256::MAX << 70).trailing_ones(), 0);
+	assert_eq!((U256::MAX >> 130).trailing_ones(), 126);
+	assert_eq!(U256::from(0b1011).trailing_ones(), 2);
+}
+
+#[test]
+fn uint256_bit_range() {
+	let x = U256([0x8899aabbccddeeff, 0x0011223344556677, 0xfedcba9876543210, 0x0123456789abcdef]);
+	assert_eq!(x.bit_range(0..256), x);
+	assert_eq!(x.bit_range(0..0), U256::zero());
+	assert_eq!(x.bit_range(256..256), U256::zero());
+	assert_eq!(x.bit_range(0..64), U256::from(0x8899aabbccddeeffu64));
+	assert_eq!(x.bit_range(192..256), U256::from(0x0123456789abcdefu64));
+	// crossing limb boundaries
+	assert_eq!(x.bit_range(56..72), U256::from(0x7788));
+	assert_eq!(x.bit_range(60..196), x.bit_range(60..256) & ((U256::one() << 136) - 1));
+	assert_eq!(x.bit_range(124..132), U256::from(0x00));
+	assert_eq!(x.bit_range(188..196), U256::from(0xff));
+
+	assert_eq!(x.checked_bit_range(0..257), None);
+	#[allow(clippy::reversed_empty_ranges)]
+	let decreasing = 10..9;
+	assert_eq!(x.checked_bit_range(decreasing), None);
+}
+
+#[test]
+fn uint256_set_bit_range() {
+	let mut x = U256::zero();
+	x.set_bit_range(60..68, U256::from(0xab));
+	assert_eq!(x, U256::from(0xabu64) << 60);
+	x.set_bit_range(64..192, U256::MAX);
+	assert_eq!(x, (((U256::one() << 128) - 1) << 64) | (U256::from(0xbu64) << 60));
+	// bits of the value beyond the range are ignored
+	let mut y = U256::MAX;
+	y.set_bit_range(100..104, U256::from(0xf0));
+	assert_eq!(y, U256::MAX ^ (U256::from(0xf) << 100));
+	y.set_bit_range(256..256, U256::MAX);
+	assert_eq!(y.bit_range(100..104), U256::zero());
+}
+
+#[test]
+#[should_panic(expected = "bit range out of bounds")]
+fn uint256_bit_range_beyond_width() {
+	let _ = U256::MAX.bit_range(200..257);
+}
+
+#[test]
+#[should_panic(expected = "bit range out of bounds")]
+fn uint256_set_bit_range_beyond_width() {
+	U256::zero().set_bit_range(300..310, U256::one());
+}
+
+#[test]
+fn uint256_div_mod_quotient_correction() {
+	// Dividends and divisors for which the estimated quotient digit is one too large, or the top
+	// words of the partial remainder equal those of the divisor.
+	let cases = [
+		(
+			U256([0x0000000000000000, 0x0000000000000000, 0x8000000000000000, 0x0000000000000002]),
+			U256([0x0000000000000001, 0x0000000000000000, 0x8000000000000000, 0x0000000000000000]),
+			U256([0x0000000000000004, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]),
+			U256([0xfffffffffffffffc, 0xffffffffffffffff, 0x7fffffffffffffff, 0x0000000000000000]),
+		),
+		(
+			U256([0x7fffffffffffffff, 0x7fffffffffffffff, 0x7fffffffffffffff, 0xfffffffffffffffe]),
+			U256([0x8000000000000001, 0x8000000000000000, 0x0000000000000000, 0x0000000000000001]),
+			U256([0xfffffffffffffffd, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]),
+			U256([0x0000000000000002, 0x8000000000000000, 0x0000000000000000, 0x0000000000000001]),
+		),
+		(
+			U256([0x8000000000000000, 0x8000000000000000, 0x7fffffffffffffff, 0x8000000000000000]),
+			U256([0xffffffffffffffff, 0x0000000000000000, 0x8000000000000001, 0x0000000000000000]),
+			U256([0xfffffffffffffffe, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]),
+			U256([0x7ffffffffffffffe, 0x8000000000000003, 0x8000000000000000, 0x0000000000000000]),
+		),
+		(
+			U256([0x8000000000000001, 0x0000000000000002, 0x7fffffffffffffff, 0xfffffffffffffffe]),
+			U256([0xffffffffffffffff, 0x7fffffffffffffff, 0xfffffffffffffffe, 0x0000000000000000]),
+			U256([0xffffffffffffffff, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]),
+			U256([0x8000000000000000, 0x8000000000000003, 0xfffffffffffffffd, 0x0000000000000000]),
+		),
+		(
+			U256([0x7fffffffffffffff, 0x8000000000000000, 0x8000000000000000, 0xfffffffffffffffe]),
+			U256([0xffffffffffffffff, 0x8000000000000000, 0xfffffffffffffffe, 0x0000000000000000]),
+			U256([0xffffffffffffffff, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]),
+			U256([0x7ffffffffffffffe, 0x0000000000000002, 0xfffffffffffffffe, 0x0000000000000000]),
+		),
+	];
+	for (x, y, q, r) in cases {
+		assert_eq!(x.div_mod(y), (q, r), "{:?} / {:?}", x, y);
+		assert_eq!(q * y + r, x);
+	}
+}
+
+#[test]
+fn uint512_div_mod_quotient_correction() {
+	let cases = [
+		(
+			U512([
+				0x0000000000000001,
+				0x0000000000000000,
+				0xfffffffffffffffe,
+				0x8000000000000000,
+				0x7fffffffffffffff,
+				0xfffffffffffffffe,
+				0x8000000000000000,
+				0x0000000000000000,
+			]),
+			U512([
+				0x8000000000000000,
+				0xfffffffffffffffe,
+				0x8000000000000000,
+				0x0000000000000000,
+				0x0000000000000000,
+				0x0000000000000000,
+				0x0000000000000000,
+				0x0000000000000000,
+			]),
+			U512([
+				0x0000000000000003,
+				0xffffffffffffffff,
+				0xffffffffffffffff,
+				0xffffffffffffffff,
+				0x0000000000000000,
+				0x0000000000000000,
+				0x0000000000000000,
+				0x0000000000000000,
+			]),
+			U512([
+				0x8000000000000001,
+				0x8000000000000004,
+				0x7ffffffffffffff9,
+				0x0000000000000000,
+				0x0000000000000000,
+				0x0000000000000000,
+				0x0000000000000000,
+				0x0000000000000000,
+			]),
+		),
+		(
+			U512([
+				0xfffffffffffffffe,
+				0x8000000000000001,
+				0x0000000000000000,
+				0x0000000000000002,
+				0x0000000000000002,
+				0xfffffffffffffffe,
+				0xffffffffffffffff,
+				0x0000000000000000,
+			]),
+			U512([
+				0xffffffffffffffff,
+				0x0000000000000001,
+				0xffffffffffffffff,
+				0x7fffffffffffffff,
+				0x0000000000000000,
+				0x0000000000000000,
+				0x0000000000000000,
+				0x0000000000000000,
+			]),
+			U512([
+				0xfffffffffffffffc,
+				0xffffffffffffffff,
+				0xffffffffffffffff,
+				0x0000000000000001,
+				0x0000000000000000,
+				0x0000000000000000,
+				0x0000000000000000,
+				0x0000000000000000,
+			]),
+			U512([
+				0xfffffffffffffffa,
+				0x8000000000000009,
+				0xfffffffffffffffc,
+				0x0000000000000003,
+				0x0000000000000000,
+				0x0000000000000000,
+				0x0000000000000000,
+				0x0000000000000000,
+			]),
+		),
+	];
+	for (x, y, q, r) in cases {
+		assert_eq!(x.div_mod(y), (q, r), "{:?} / {:?}", x, y);
+		assert_eq!(q * y + r, x);
+	}
+}
+
+/// Schoolbook division as implemented before the quotient estimate was reworked, used to check
+/// that both agree. Limbs are little-endian and the top limb of `v` is non-zero.
+fn div_mod_reference(u: &[u64], v: &[u64]) -> (Vec<u64>, Vec<u64>) {
+	let n = v.len();
+	if n == 1 {
+		let mut q = vec![0u64; u.len()];
+		let mut r = 0u64;
+		for j in (0..u.len()).rev() {
+			let d = (u128::from(r) << 64) | u128::from(u[j]);
+			q[j] = (d / u128::from(v[0])) as u64;
+			r = (d % u128::from(v[0])) as u64;
+		}
+		return (q, vec![r])
+	}
+
+	let shl = |x: &[u64], s: u32| -> Vec<u64> {
+		let mut out = vec![0u64; x.len() + 1];
+		for (i, &w) in x.iter().enumerate() {
+			out[i] |= w << s;
+			if s > 0 {
+				out[i + 1] = w >> (64 - s);
+			}
+		}
+		out
+	};
+	let s = v[n - 1].leading_zeros();
+	let mut u = shl(u, s);
+	let v = &shl(v, s)[..n];
+	let m = u.len() - n - 1;
+	let (v_n_1, v_n_2) = (v[n - 1], v[n - 2]);
+	let mut q = vec![0u64; m + 1];
+
+	for j in (0..=m).rev() {
+		let u_jn = u[j + n];
+		let mut q_hat = if u_jn < v_n_1 {
+			let d = (u128::from(u_jn) << 64) | u128::from(u[j + n - 1]);
+			let (mut q_hat, mut r_hat) = ((d / u128::from(v_n_1)) as u64, (d % u128::from(v_n_1)) as u64);
+			loop {
+				let p = u128::from(q_hat) * u128::from(v_n_2);
+				if ((p >> 64) as u64, p as u64) <= (r_hat, u[j + n - 2]) {
+					break
+				}
+				q_hat -= 1;
+				let (new_r_hat, overflow) = r_hat.overflowing_add(v_n_1);
+				r_hat = new_r_hat;
+				if overflow {
+					break
+				}
+			}
+			q_hat
+		} else {
+			u64::MAX
+		};
+
+		// u[j..=j + n] -= q_hat * v
+		let (mut mul_carry, mut borrow) = (0u64, false);
+		for i in 0..=n {
+			let p = u128::from(q_hat) * u128::from(v.get(i).copied().unwrap_or(0)) + u128::from(mul_carry);
+			mul_carry = (p >> 64) as u64;
+			let (d, b1) = u[j + i].overflowing_sub(p as u64);
+			let (d, b2) = d.overflowing_sub(u64::from(borrow));
+			u[j + i] = d;
+			borrow = b1 || b2;
+		}
+		if borrow {
+			q_hat -= 1;
+			let mut carry = false;
+			for i in 0..n {
+				let (a, c1) = u[j + i].overflowing_add(v[i]);
+				let (a, c2) = a.overflowing_add(u64::from(carry));
+				u[j + i] = a;
+				carry = c1 || c2;
+			}
+			u[j + n] = u[j + n].wrapping_add(u64::from(carry));
+		}
+		q[j] = q_hat;
+	}
+
+	let r = (0..n)
+		.map(|i| if s == 0 { u[i] } else { (u[i] >> s) | (u[i + 1] << (64 - s)) })
+		.collect();
+	(q, r)
+}
+
+#[test]
+fn div_mod_agrees_with_reference() {
+	use rand::{rngs::StdRng, Rng, SeedableRng};
+
+	fn limb(rng: &mut StdRng) -> u64 {
+		// Limbs close to the word boundaries make the quotient estimate and the add back likely.
+		match rng.gen_range(0..8) {
+			0 => 0,
+			1 => 1,
+			2 => u64::MAX,
+			3 => u64::MAX - 1,
+			4 => 1 << 63,
+			5 => (1 << 63) - 1,
+			_ => rng.gen(),
+		}
+	}
+
+	fn check<const N: usize>(rng: &mut StdRng, div_mod: impl Fn([u64; N], [u64; N]) -> ([u64; N], [u64; N])) {
+		for _ in 0..20_000 {
+			let (mut x, mut y) = ([0u64; N], [0u64; N]);
+			let x_len = rng.gen_range(1..=N);
+			let y_len = rng.gen_range(1..=N);
+			x[..x_len].iter_mut().for_each(|w| *w = limb(rng));
+			y[..y_len].iter_mut().for_each(|w| *w = limb(rng));
+			let y_len = match y.iter().rposition(|&w| w != 0) {
+				Some(top) => top + 1,
+				None => continue,
+			};
+			let (q, r) = div_mod(x, y);
+			let (ref_q, ref_r) = div_mod_reference(&x, &y[..y_len]);
+			assert_eq!(q[..ref_q.len()], ref_q[..], "{:?} / {:?}", x, y);
+			assert!(q[ref_q.len()..].iter().all(|&w| w == 0));
+			assert_eq!(r[..y_len], ref_r[..], "{:?} % {:?}", x, y);
+			assert!(r[y_len..].iter().all(|&w| w == 0));
+		}
+	}
+
+	let mut rng = StdRng::seed_from_u64(0xd1d1);
+	check(&mut rng, |x, y| {
+		let (q, r) = U256(x).div_mod(U256(y));
+		(q.0, r.0)
+	});
+	check(&mut rng, |x, y| {
+		let (q, r) = U512(x).div_mod(U512(y));
+		(q.0, r.0)
+	});
+}
+
 #[test]
 fn uint256_mul1() {
 	assert_eq!(U256::from(1u64) * U256::from(10u64), U256::from(10u64));
@@ -643,6 +1768,33 @@ fn display_uint() {
 	assert_eq!(format!("{:#032X}", s), "0x000000000A364C995584F929F39615");
 }
 
+#[test]
+fn display_uint_binary_and_octal() {
+	for x in [0u128, 1, 2, 7, 8, 0o1234567, 0xdead_beef, u64::MAX as u128, 1 << 64, u128::MAX / 3, u128::MAX] {
+		let u = U256::from(x);
+		assert_eq!(format!("{:b}", u), format!("{:b}", x));
+		assert_eq!(format!("{:o}", u), format!("{:o}", x));
+		assert_eq!(format!("{:#b}", u), format!("{:#b}", x));
+		assert_eq!(format!("{:#o}", u), format!("{:#o}", x));
+		assert_eq!(format!("{:#0140b}", u), format!("{:#0140b}", x));
+		assert_eq!(format!("{:>50o}", u), format!("{:>50o}", x));
+		assert_eq!(format!("{:*<50o}", u), format!("{:*<50o}", x));
+		assert_eq!(format!("{:^#50o}", u), format!("{:^#50o}", x));
+	}
+
+	assert_eq!(format!("{:b}", U256::one() << 255), format!("1{}", "0".repeat(255)));
+	assert_eq!(format!("{:b}", U256::MAX), "1".repeat(256));
+	assert_eq!(format!("{:o}", U256::MAX), format!("1{}", "7".repeat(85)));
+	// octal digits straddling word boundaries
+	assert_eq!(format!("{:o}", U256::one() << 128), format!("4{}", "0".repeat(42)));
+	assert_eq!(
+		format!("{:o}", (U256::from(0o7) << 63) | (U256::from(0o5) << 189)),
+		format!("5{}7{}", "0".repeat(41), "0".repeat(21))
+	);
+	assert_eq!(format!("{:#o}", U512::MAX), format!("0o3{}", "7".repeat(170)));
+	assert_eq!(format!("{:#b}", U512::one() << 300), format!("0b1{}", "0".repeat(300)));
+}
+
 #[test]
 fn display_uint_zero() {
 	let s = U256::from(0);
@@ -1197,9 +2349,19 @@ pub mod laws {
 	macro_rules! uint_laws {
 		($mod_name:ident, $uint_ty:ident) => {
 			mod $mod_name {
+				use num_bigint::BigUint;
 				use quickcheck::{TestResult, quickcheck};
 				use super::$uint_ty;
 
+				fn gcd(mut a: $uint_ty, mut b: $uint_ty) -> $uint_ty {
+					while !b.is_zero() {
+						let r = a % b;
+						a = b;
+						b = r;
+					}
+					a
+				}
+
 				quickcheck! {
 					fn associative_add(x: $uint_ty, y: $uint_ty, z: $uint_ty) -> TestResult {
 						if x.overflowing_add(y).1 || y.overflowing_add(z).1 || (x + y).overflowing_add(z).1 {
@@ -1312,13 +2474,312 @@ pub mod laws {
 					}
 				}
 
+				quickcheck! {
+					fn isqrt_of_square(x: $uint_ty) -> bool {
+						let r = x.integer_sqrt();
+						(r * r).integer_sqrt() == r
+					}
+				}
+
+				quickcheck! {
+					fn isqrt_near_max(d: u64) -> bool {
+						let x = $uint_ty::MAX - d;
+						let s = x.integer_sqrt();
+						let higher = s + 1;
+						s * s <= x && higher.checked_mul(higher).map_or(true, |y| y > x)
+					}
+				}
+
+				quickcheck! {
+					fn next_power_of_two_is_smallest(x: $uint_ty) -> bool {
+						match x.checked_next_power_of_two() {
+							Some(p) => p.is_power_of_two() && p >= x && (p >> 1) < x.max($uint_ty::one()),
+							None => x > $uint_ty::one() << ($uint_ty::MAX.bits() - 1),
+						}
+					}
+				}
+
+				quickcheck! {
+					fn checked_ilog_matches_biguint(x: $uint_ty, base: u64) -> TestResult {
+						if x.is_zero() || base < 2 {
+							return TestResult::discard();
+						}
+
+						let log = x.checked_ilog($uint_ty::from(base)).unwrap();
+						let x = BigUint::from_bytes_le(&x.to_little_endian());
+						let base = BigUint::from(base);
+						TestResult::from_bool(base.pow(log) <= x && base.pow(log + 1) > x)
+					}
+				}
+
+				quickcheck! {
+					fn byte_arrays_roundtrip(x: $uint_ty) -> bool {
+						$uint_ty::from_be_bytes(x.to_be_bytes()) == x && $uint_ty::from_le_bytes(x.to_le_bytes()) == x
+					}
+				}
+
+				quickcheck! {
+					fn byte_arrays_match_slice_conversions(x: $uint_ty) -> bool {
+						let be = x.to_be_bytes();
+						let le = x.to_le_bytes();
+						be == x.to_big_endian() && le == x.to_little_endian() &&
+							$uint_ty::from_big_endian(&be) == x && $uint_ty::from_little_endian(&le) == x
+					}
+				}
+
+				quickcheck! {
+					fn const_ops_match_operators(x: $uint_ty, y: $uint_ty) -> bool {
+						let check = |(expected, overflow): ($uint_ty, bool), op: fn($uint_ty, $uint_ty) -> $uint_ty| {
+							let result = std::panic::catch_unwind(|| op(x, y));
+							if overflow { result.is_err() } else { result.ok() == Some(expected) }
+						};
+						check(x.overflowing_add(y), $uint_ty::const_add) &&
+							check(x.overflowing_sub(y), $uint_ty::const_sub) &&
+							check(x.overflowing_mul(y), $uint_ty::const_mul)
+					}
+				}
+
+				quickcheck! {
+					fn from_str_radix_matches_biguint(x: $uint_ty, radix: u32, separators: Vec<usize>) -> bool {
+						let radix = radix % 35 + 2;
+						let mut digits = BigUint::from_bytes_le(&x.to_little_endian()).to_str_radix(radix);
+						for position in separators {
+							digits.insert(position % digits.len() + 1, '_');
+						}
+						let prefixed = match radix {
+							2 => format!("0b{}", digits),
+							8 => format!("0o{}", digits),
+							10 => digits.clone(),
+							16 => format!("0x{}", digits.to_uppercase()),
+							_ => return $uint_ty::from_str_radix(&digits, radix).unwrap() == x,
+						};
+						$uint_ty::from_str_radix(&digits, radix).unwrap() == x &&
+							$uint_ty::from_prefixed_str(&prefixed).unwrap() == x
+					}
+				}
+
+				quickcheck! {
+					fn from_str_radix_overflow_matches_biguint(x: $uint_ty, radix: u32) -> bool {
+						let radix = radix % 35 + 2;
+						let too_large = (BigUint::from_bytes_le(&x.to_little_endian()) + 1u8) << $uint_ty::MAX.bits();
+						let err = $uint_ty::from_str_radix(&too_large.to_str_radix(radix), radix).unwrap_err();
+						err.kind() == uint::FromStrRadixErrKind::Overflow
+					}
+				}
+
+				quickcheck! {
+					fn leading_and_trailing_ones_match_bit(x: $uint_ty) -> bool {
+						let width = x.0.len() * 64;
+						let leading = (0..width).rev().take_while(|&i| x.bit(i)).count();
+						let trailing = (0..width).take_while(|&i| x.bit(i)).count();
+						x.leading_ones() as usize == leading
+							&& x.trailing_ones() as usize == trailing
+							&& (!x).leading_ones() == x.leading_zeros()
+							&& (!x).trailing_ones() == x.trailing_zeros()
+					}
+
+					fn bit_range_matches_bit(x: $uint_ty, start: usize, len: usize) -> bool {
+						let width = x.0.len() * 64;
+						let start = start % (width + 1);
+						let end = start + len % (width - start + 1);
+						let mut expected = $uint_ty::zero();
+						for i in start..end {
+							if x.bit(i) {
+								expected |= $uint_ty::one() << (i - start);
+							}
+						}
+						x.bit_range(start..end) == expected && x.checked_bit_range(start..end) == Some(expected)
+					}
+
+					fn set_bit_range_matches_bit(x: $uint_ty, value: $uint_ty, start: usize, len: usize) -> bool {
+						let width = x.0.len() * 64;
+						let start = start % (width + 1);
+						let end = start + len % (width - start + 1);
+						let mut set = x;
+						set.set_bit_range(start..end, value);
+						(0..width).all(|i| {
+							let expected = if (start..end).contains(&i) { value.bit(i - start) } else { x.bit(i) };
+							set.bit(i) == expected
+						})
+					}
+				}
+
+				quickcheck! {
+					fn iter_ones_matches_bit(x: $uint_ty) -> bool {
+						let ones: Vec<usize> = (0..x.0.len() * 64).filter(|&i| x.bit(i)).collect();
+						let zeros: Vec<usize> = (0..x.0.len() * 64).filter(|&i| !x.bit(i)).collect();
+						x.iter_ones().collect::<Vec<_>>() == ones
+							&& x.iter_zeros().collect::<Vec<_>>() == zeros
+							&& x.iter_ones().rev().eq(ones.iter().rev().cloned())
+							&& x.iter_ones().len() == ones.len()
+							&& x.count_ones() as usize == ones.len()
+							&& x.count_zeros() as usize == zeros.len()
+					}
+
+					fn iter_ones_from_both_ends(x: $uint_ty, from_back: Vec<bool>) -> bool {
+						let mut ones: Vec<usize> = (0..x.0.len() * 64).filter(|&i| x.bit(i)).collect();
+						let mut iter = x.iter_ones();
+						let mut from_back = from_back.into_iter();
+						loop {
+							let (got, expected) = if from_back.next().unwrap_or(false) {
+								(iter.next_back(), ones.pop())
+							} else {
+								(iter.next(), if ones.is_empty() { None } else { Some(ones.remove(0)) })
+							};
+							if got != expected {
+								return false
+							}
+							if got.is_none() {
+								return true
+							}
+						}
+					}
+				}
+
+				quickcheck! {
+					fn carrying_add_matches_biguint(a: $uint_ty, b: $uint_ty, carry: bool) -> bool {
+						let to_biguint = |x: $uint_ty| BigUint::from_bytes_le(&x.to_little_endian());
+						let modulus = BigUint::from(1u8) << (a.0.len() * 64);
+						let sum = to_biguint(a) + to_biguint(b) + BigUint::from(carry as u8);
+						let (res, overflow) = a.carrying_add(b, carry);
+						to_biguint(res) == &sum % &modulus && overflow == (sum >= modulus)
+					}
+
+					fn borrowing_sub_matches_biguint(a: $uint_ty, b: $uint_ty, borrow: bool) -> bool {
+						let to_biguint = |x: $uint_ty| BigUint::from_bytes_le(&x.to_little_endian());
+						let modulus = BigUint::from(1u8) << (a.0.len() * 64);
+						let subtrahend = to_biguint(b) + BigUint::from(borrow as u8);
+						let (res, overflow) = a.borrowing_sub(b, borrow);
+						let expected = (to_biguint(a) + &modulus - &subtrahend) % &modulus;
+						to_biguint(res) == expected && overflow == (subtrahend > to_biguint(a))
+					}
+
+					fn carrying_mul_matches_biguint(a: $uint_ty, b: $uint_ty, carry: $uint_ty) -> bool {
+						let to_biguint = |x: $uint_ty| BigUint::from_bytes_le(&x.to_little_endian());
+						let width = a.0.len() * 64;
+						let (lo, hi) = a.carrying_mul(b, carry);
+						to_biguint(lo) + (to_biguint(hi) << width) == to_biguint(a) * to_biguint(b) + to_biguint(carry)
+					}
+				}
+
+				quickcheck! {
+					fn mod_inv_with_odd_modulus(a: $uint_ty, modulus: $uint_ty) -> TestResult {
+						let modulus = modulus | $uint_ty::one();
+						if modulus == $uint_ty::one() {
+							return TestResult::discard();
+						}
+						match a.mod_inv(modulus) {
+							Some(inv) => TestResult::from_bool(inv < modulus && a.mul_mod(inv, modulus) == $uint_ty::one()),
+							None => TestResult::from_bool(gcd(a, modulus) != $uint_ty::one()),
+						}
+					}
+
+					fn mod_inv_with_any_modulus(a: $uint_ty, modulus: $uint_ty) -> TestResult {
+						if modulus <= $uint_ty::one() {
+							return TestResult::discard();
+						}
+						match a.mod_inv(modulus) {
+							Some(inv) => TestResult::from_bool(inv < modulus && a.mul_mod(inv, modulus) == $uint_ty::one()),
+							None => TestResult::from_bool(gcd(a, modulus) != $uint_ty::one()),
+						}
+					}
+				}
+
+				quickcheck! {
+					fn overflowing_add_matches_biguint(x: $uint_ty, y: $uint_ty) -> bool {
+						let to_biguint = |x: $uint_ty| BigUint::from_bytes_le(&x.to_little_endian());
+						let modulus = BigUint::from(1u8) << $uint_ty::MAX.bits();
+						let sum = to_biguint(x) + to_biguint(y);
+						let (res, overflow) = x.overflowing_add(y);
+						to_biguint(res) == &sum % &modulus && overflow == (sum >= modulus)
+					}
+
+					fn overflowing_sub_matches_biguint(x: $uint_ty, y: $uint_ty) -> bool {
+						let to_biguint = |x: $uint_ty| BigUint::from_bytes_le(&x.to_little_endian());
+						let modulus = BigUint::from(1u8) << $uint_ty::MAX.bits();
+						let (res, overflow) = x.overflowing_sub(y);
+						to_biguint(res) == (to_biguint(x) + &modulus - to_biguint(y)) % &modulus && overflow == (x < y)
+					}
+
+					fn overflowing_mul_matches_biguint(x: $uint_ty, y: $uint_ty) -> bool {
+						let to_biguint = |x: $uint_ty| BigUint::from_bytes_le(&x.to_little_endian());
+						let modulus = BigUint::from(1u8) << $uint_ty::MAX.bits();
+						let product = to_biguint(x) * to_biguint(y);
+						let (res, overflow) = x.overflowing_mul(y);
+						to_biguint(res) == &product % &modulus && overflow == (product >= modulus)
+					}
+
+					fn div_mod_matches_biguint(x: $uint_ty, y: $uint_ty) -> TestResult {
+						if y.is_zero() {
+							return TestResult::discard();
+						}
+						let to_biguint = |x: $uint_ty| BigUint::from_bytes_le(&x.to_little_endian());
+						let (q, r) = x.div_mod(y);
+						TestResult::from_bool(
+							to_biguint(q) == to_biguint(x) / to_biguint(y) && to_biguint(r) == to_biguint(x) % to_biguint(y)
+						)
+					}
+				}
+
+				quickcheck! {
+					fn add_mod_matches_biguint(x: $uint_ty, y: $uint_ty, modulus: $uint_ty) -> bool {
+						let to_biguint = |x: $uint_ty| BigUint::from_bytes_le(&x.to_little_endian());
+						let expected = if modulus.is_zero() {
+							BigUint::from(0u8)
+						} else {
+							(to_biguint(x) + to_biguint(y)) % to_biguint(modulus)
+						};
+						to_biguint(x.add_mod(y, modulus)) == expected && x.add_mod(modulus, modulus) == x.add_mod($uint_ty::zero(), modulus)
+					}
+				}
+
+				quickcheck! {
+					fn mul_mod_matches_biguint(x: $uint_ty, y: $uint_ty, modulus: $uint_ty) -> bool {
+						let to_biguint = |x: $uint_ty| BigUint::from_bytes_le(&x.to_little_endian());
+						let expected = if modulus.is_zero() {
+							BigUint::from(0u8)
+						} else {
+							(to_biguint(x) * to_biguint(y)) % to_biguint(modulus)
+						};
+						to_biguint(x.mul_mod(y, modulus)) == expected && x.mul_mod(y, $uint_ty::one()).is_zero()
+					}
+				}
+
+				quickcheck! {
+					fn pow_mod_matches_biguint(x: $uint_ty, exp: $uint_ty, modulus: $uint_ty) -> TestResult {
+						if modulus.is_zero() {
+							return TestResult::discard();
+						}
+
+						let to_biguint = |x: $uint_ty| BigUint::from_bytes_le(&x.to_little_endian());
+						let expected = to_biguint(x).modpow(&to_biguint(exp), &to_biguint(modulus));
+						TestResult::from_bool(
+							to_biguint(x.pow_mod(exp, modulus)) == expected
+						)
+					}
+				}
+
+				quickcheck! {
+					fn widening_mul_matches_biguint(x: $uint_ty, y: $uint_ty) -> bool {
+						let to_biguint = |x: $uint_ty| BigUint::from_bytes_le(&x.to_little_endian());
+						let (lo, hi) = x.widening_mul(y);
+						let (product, overflow) = x.overflowing_mul(y);
+						let bits = $uint_ty::MAX.bits();
+						lo == product && hi.is_zero() == !overflow &&
+							(to_biguint(hi) << bits) + to_biguint(lo) == to_biguint(x) * to_biguint(y)
+					}
+				}
+
 				quickcheck! {
 					fn pow_mul(x: $uint_ty) -> TestResult {
-						if x.overflowing_pow($uint_ty::from(2)).1 || x.overflowing_pow($uint_ty::from(3)).1 {
-							// On overflow `checked_pow` should return `None`.
+						// On overflow `checked_pow` should return `None`. The square can fit even if the cube doesn't.
+						if x.overflowing_pow($uint_ty::from(2)).1 {
 							assert_eq!(x.checked_pow($uint_ty::from(2)), None);
+						}
+						if x.overflowing_pow($uint_ty::from(3)).1 {
 							assert_eq!(x.checked_pow($uint_ty::from(3)), None);
-
+						}
+						if x.overflowing_pow($uint_ty::from(2)).1 || x.overflowing_pow($uint_ty::from(3)).1 {
 							return TestResult::discard();
 						}
 